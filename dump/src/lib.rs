@@ -2,8 +2,9 @@
 #![allow(clippy::wrong_self_convention)]
 
 use meilisearch_types::error::ResponseError;
+use meilisearch_types::index_quotas::IndexQuota;
 use meilisearch_types::keys::Key;
-use meilisearch_types::milli::update::IndexDocumentsMethod;
+use meilisearch_types::milli::update::{IndexDocumentsMethod, PrimaryKeyDefinition};
 use meilisearch_types::settings::Unchecked;
 use meilisearch_types::tasks::{Details, IndexSwap, KindWithContent, Status, Task, TaskId};
 use meilisearch_types::InstanceUid;
@@ -41,6 +42,13 @@ pub struct IndexMetadata {
     pub created_at: OffsetDateTime,
     #[serde(with = "time::serde::rfc3339")]
     pub updated_at: OffsetDateTime,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub read_only: bool,
+    #[serde(default, skip_serializing_if = "IndexQuota::is_unlimited")]
+    pub quota: IndexQuota,
+    /// The fields of the composite primary key, the `primary_key` is then only its name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub composite_primary_key: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
@@ -100,22 +108,48 @@ pub enum KindDump {
     DocumentDeletion {
         documents_ids: Vec<String>,
     },
+    DocumentDeletionByFilter {
+        filter: serde_json::Value,
+    },
+    DocumentExpiration,
+    DocumentEdition {
+        filter: Option<serde_json::Value>,
+        context: Option<serde_json::Map<String, serde_json::Value>>,
+        function: String,
+    },
     DocumentClear,
+    DocumentPurge,
     Settings {
         settings: Box<meilisearch_types::settings::Settings<Unchecked>>,
         is_deletion: bool,
         allow_index_creation: bool,
+        #[serde(default)]
+        is_rollback: bool,
     },
     IndexDeletion,
     IndexCreation {
-        primary_key: Option<String>,
+        primary_key: Option<PrimaryKeyDefinition>,
     },
     IndexUpdate {
-        primary_key: Option<String>,
+        primary_key: Option<PrimaryKeyDefinition>,
+        new_index_uid: Option<String>,
+        #[serde(default)]
+        migrate_api_keys: bool,
     },
+    IndexClone {
+        target_uid: String,
+    },
+    IndexReadOnlyUpdate {
+        read_only: bool,
+    },
+    IndexCompaction,
     IndexSwap {
         swaps: Vec<IndexSwap>,
     },
+    AliasUpdate {
+        alias: String,
+        indexes: Vec<String>,
+    },
     TaskCancelation {
         query: String,
         tasks: RoaringBitmap,
@@ -166,21 +200,43 @@ impl From<KindWithContent> for KindDump {
             KindWithContent::DocumentDeletion { documents_ids, .. } => {
                 KindDump::DocumentDeletion { documents_ids }
             }
+            KindWithContent::DocumentDeletionByFilter { filter_expr, .. } => {
+                KindDump::DocumentDeletionByFilter { filter: filter_expr }
+            }
+            KindWithContent::DocumentExpiration { .. } => KindDump::DocumentExpiration,
+            KindWithContent::DocumentEdition { filter_expr, context, function, .. } => {
+                KindDump::DocumentEdition { filter: filter_expr, context, function }
+            }
             KindWithContent::DocumentClear { .. } => KindDump::DocumentClear,
+            KindWithContent::DocumentPurge { .. } => KindDump::DocumentPurge,
             KindWithContent::SettingsUpdate {
                 new_settings,
                 is_deletion,
                 allow_index_creation,
+                is_rollback,
                 ..
-            } => KindDump::Settings { settings: new_settings, is_deletion, allow_index_creation },
+            } => KindDump::Settings {
+                settings: new_settings,
+                is_deletion,
+                allow_index_creation,
+                is_rollback,
+            },
             KindWithContent::IndexDeletion { .. } => KindDump::IndexDeletion,
             KindWithContent::IndexCreation { primary_key, .. } => {
                 KindDump::IndexCreation { primary_key }
             }
-            KindWithContent::IndexUpdate { primary_key, .. } => {
-                KindDump::IndexUpdate { primary_key }
+            KindWithContent::IndexUpdate {
+                primary_key, new_index_uid, migrate_api_keys, ..
+            } => KindDump::IndexUpdate { primary_key, new_index_uid, migrate_api_keys },
+            KindWithContent::IndexClone { target_uid, .. } => KindDump::IndexClone { target_uid },
+            KindWithContent::IndexReadOnlyUpdate { read_only, .. } => {
+                KindDump::IndexReadOnlyUpdate { read_only }
             }
+            KindWithContent::IndexCompaction { .. } => KindDump::IndexCompaction,
             KindWithContent::IndexSwap { swaps } => KindDump::IndexSwap { swaps },
+            KindWithContent::AliasUpdate { alias, indexes } => {
+                KindDump::AliasUpdate { alias, indexes }
+            }
             KindWithContent::TaskCancelation { query, tasks } => {
                 KindDump::TaskCancelation { query, tasks }
             }
@@ -227,6 +283,9 @@ pub(crate) mod test {
             primary_key: None,
             created_at: datetime!(2022-11-20 12:00 UTC),
             updated_at: datetime!(2022-11-21 00:00 UTC),
+            read_only: false,
+            quota: Default::default(),
+            composite_primary_key: None,
         }
     }
 
@@ -260,6 +319,11 @@ pub(crate) mod test {
             typo_tolerance: Setting::NotSet,
             faceting: Setting::NotSet,
             pagination: Setting::NotSet,
+            ttl_attribute: Setting::NotSet,
+            default_ttl: Setting::NotSet,
+            primary_key_generation: Setting::NotSet,
+            schema: Setting::NotSet,
+            soft_deleted_purge_threshold: Setting::NotSet,
             _kind: std::marker::PhantomData,
         };
         settings.check()
@@ -282,6 +346,12 @@ pub(crate) mod test {
                     details: Some(Details::DocumentAdditionOrUpdate {
                         received_documents: 12,
                         indexed_documents: Some(10),
+                        imported_files: None,
+                        version_conflicts: None,
+                        version_conflicts_count: None,
+                        generated_ids: None,
+                        generated_ids_count: None,
+                        schema_violations: None,
                     }),
                     error: None,
                     enqueued_at: datetime!(2022-11-11 0:00 UTC),
@@ -305,6 +375,12 @@ pub(crate) mod test {
                     details: Some(Details::DocumentAdditionOrUpdate {
                         received_documents: 2,
                         indexed_documents: None,
+                        imported_files: None,
+                        version_conflicts: None,
+                        version_conflicts_count: None,
+                        generated_ids: None,
+                        generated_ids_count: None,
+                        schema_violations: None,
                     }),
                     error: None,
                     enqueued_at: datetime!(2022-11-11 0:00 UTC),
@@ -79,10 +79,14 @@ impl CompatV5ToV6 {
                     },
                     kind: match task.content {
                         v5::tasks::TaskContent::IndexCreation { primary_key, .. } => {
-                            v6::Kind::IndexCreation { primary_key }
+                            v6::Kind::IndexCreation { primary_key: primary_key.map(Into::into) }
                         }
                         v5::tasks::TaskContent::IndexUpdate { primary_key, .. } => {
-                            v6::Kind::IndexUpdate { primary_key }
+                            v6::Kind::IndexUpdate {
+                                primary_key: primary_key.map(Into::into),
+                                new_index_uid: None,
+                                migrate_api_keys: false,
+                            }
                         }
                         v5::tasks::TaskContent::IndexDeletion { .. } => v6::Kind::IndexDeletion,
                         v5::tasks::TaskContent::DocumentAddition {
@@ -120,6 +124,7 @@ impl CompatV5ToV6 {
                             is_deletion,
                             allow_index_creation,
                             settings: Box::new(settings.into()),
+                            is_rollback: false,
                         },
                         v5::tasks::TaskContent::Dump { uid: _ } => {
                             // in v6 we compute the dump_uid from the started_at processing time
@@ -132,14 +137,21 @@ impl CompatV5ToV6 {
                             v6::Details::DocumentAdditionOrUpdate {
                                 received_documents: received_documents as u64,
                                 indexed_documents,
+                                imported_files: None,
+                                version_conflicts: None,
+                                version_conflicts_count: None,
+                                generated_ids: None,
+                                generated_ids_count: None,
+                                schema_violations: None,
                             }
                         }
                         v5::Details::Settings { settings } => {
                             v6::Details::SettingsUpdate { settings: Box::new(settings.into()) }
                         }
-                        v5::Details::IndexInfo { primary_key } => {
-                            v6::Details::IndexInfo { primary_key }
-                        }
+                        v5::Details::IndexInfo { primary_key } => v6::Details::IndexInfo {
+                            primary_key: primary_key.map(Into::into),
+                            new_index_uid: None,
+                        },
                         v5::Details::DocumentDeletion {
                             received_document_ids,
                             deleted_documents,
@@ -371,6 +383,11 @@ impl<T> From<v5::Settings<T>> for v6::Settings<v6::Unchecked> {
                 v5::Setting::Reset => v6::Setting::Reset,
                 v5::Setting::NotSet => v6::Setting::NotSet,
             },
+            ttl_attribute: v6::Setting::NotSet,
+            default_ttl: v6::Setting::NotSet,
+            primary_key_generation: v6::Setting::NotSet,
+            schema: v6::Setting::NotSet,
+            soft_deleted_purge_threshold: v6::Setting::NotSet,
             _kind: std::marker::PhantomData,
         }
     }
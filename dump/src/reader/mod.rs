@@ -107,6 +107,24 @@ impl DumpReader {
             DumpReader::Compat(compat) => compat.keys(),
         }
     }
+
+    pub fn index_templates(
+        &mut self,
+    ) -> Result<Box<dyn Iterator<Item = Result<v6::IndexTemplate>> + '_>> {
+        match self {
+            DumpReader::Current(current) => Ok(current.index_templates()),
+            // The older dumps didn't have any index template.
+            DumpReader::Compat(_) => Ok(Box::new(std::iter::empty())),
+        }
+    }
+
+    pub fn aliases(&mut self) -> Result<Box<dyn Iterator<Item = Result<v6::IndexAlias>> + '_>> {
+        match self {
+            DumpReader::Current(current) => Ok(current.aliases()),
+            // The older dumps didn't have any alias.
+            DumpReader::Compat(_) => Ok(Box::new(std::iter::empty())),
+        }
+    }
 }
 
 impl From<V6Reader> for DumpReader {
@@ -161,6 +179,16 @@ impl DumpIndexReader {
         }
     }
 
+    pub fn settings_history(
+        &mut self,
+    ) -> Result<Box<dyn Iterator<Item = Result<v6::SettingsVersion>> + '_>> {
+        match self {
+            DumpIndexReader::Current(v6) => Ok(v6.settings_history()),
+            // The older dumps didn't have any history of the settings.
+            DumpIndexReader::Compat(_) => Ok(Box::new(std::iter::empty())),
+        }
+    }
+
     pub fn settings(&mut self) -> Result<v6::Settings<v6::Checked>> {
         match self {
             DumpIndexReader::Current(v6) => v6.settings(),
@@ -155,6 +155,9 @@ impl From<Index> for IndexMetadataV1 {
                 primary_key: index.primary_key,
                 created_at: index.created_at,
                 updated_at: index.updated_at,
+                read_only: false,
+                quota: Default::default(),
+                composite_primary_key: None,
             },
         }
     }
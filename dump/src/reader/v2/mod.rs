@@ -152,6 +152,9 @@ impl V2IndexReader {
             // FIXME: Iterate over the whole task queue to find the creation and last update date.
             created_at: OffsetDateTime::now_utc(),
             updated_at: OffsetDateTime::now_utc(),
+            read_only: false,
+            quota: Default::default(),
+            composite_primary_key: None,
         };
 
         let ret = V2IndexReader {
@@ -194,6 +194,9 @@ impl V3IndexReader {
             primary_key: meta.primary_key,
             created_at: created_at.unwrap_or(current_time),
             updated_at: updated_at.unwrap_or(current_time),
+            read_only: false,
+            quota: Default::default(),
+            composite_primary_key: None,
         };
 
         let ret = V3IndexReader {
@@ -226,6 +226,9 @@ impl V5IndexReader {
             primary_key: meta.primary_key,
             created_at: created_at.unwrap_or_else(OffsetDateTime::now_utc),
             updated_at: updated_at.unwrap_or_else(OffsetDateTime::now_utc),
+            read_only: false,
+            quota: Default::default(),
+            composite_primary_key: None,
         };
 
         let ret = V5IndexReader {
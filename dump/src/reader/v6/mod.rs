@@ -18,6 +18,9 @@ pub type Unchecked = meilisearch_types::settings::Unchecked;
 
 pub type Task = crate::TaskDump;
 pub type Key = meilisearch_types::keys::Key;
+pub type IndexTemplate = meilisearch_types::index_templates::IndexTemplate;
+pub type IndexAlias = meilisearch_types::index_aliases::IndexAlias;
+pub type SettingsVersion = meilisearch_types::settings_history::SettingsVersion;
 
 // ===== Other types to clarify the code of the compat module
 // everything related to the tasks
@@ -48,6 +51,8 @@ pub struct V6Reader {
     metadata: Metadata,
     tasks: BufReader<File>,
     keys: BufReader<File>,
+    index_templates: Option<BufReader<File>>,
+    aliases: Option<BufReader<File>>,
 }
 
 impl V6Reader {
@@ -58,12 +63,26 @@ impl V6Reader {
             Err(e) if e.kind() == ErrorKind::NotFound => None,
             Err(e) => return Err(e.into()),
         };
+        // The index templates were introduced after the v6 dumps.
+        let index_templates = match File::open(dump.path().join("index-templates.jsonl")) {
+            Ok(file) => Some(BufReader::new(file)),
+            Err(e) if e.kind() == ErrorKind::NotFound => None,
+            Err(e) => return Err(e.into()),
+        };
+        // The aliases were introduced after the v6 dumps.
+        let aliases = match File::open(dump.path().join("aliases.jsonl")) {
+            Ok(file) => Some(BufReader::new(file)),
+            Err(e) if e.kind() == ErrorKind::NotFound => None,
+            Err(e) => return Err(e.into()),
+        };
 
         Ok(V6Reader {
             metadata: serde_json::from_reader(&*meta_file)?,
             instance_uid,
             tasks: BufReader::new(File::open(dump.path().join("tasks").join("queue.jsonl"))?),
             keys: BufReader::new(File::open(dump.path().join("keys.jsonl"))?),
+            index_templates,
+            aliases,
             dump,
         })
     }
@@ -130,6 +149,24 @@ impl V6Reader {
             (&mut self.keys).lines().map(|line| -> Result<_> { Ok(serde_json::from_str(&line?)?) }),
         )
     }
+
+    pub fn index_templates(&mut self) -> Box<dyn Iterator<Item = Result<IndexTemplate>> + '_> {
+        match &mut self.index_templates {
+            Some(templates) => Box::new(
+                templates.lines().map(|line| -> Result<_> { Ok(serde_json::from_str(&line?)?) }),
+            ),
+            None => Box::new(std::iter::empty()),
+        }
+    }
+
+    pub fn aliases(&mut self) -> Box<dyn Iterator<Item = Result<IndexAlias>> + '_> {
+        match &mut self.aliases {
+            Some(aliases) => Box::new(
+                aliases.lines().map(|line| -> Result<_> { Ok(serde_json::from_str(&line?)?) }),
+            ),
+            None => Box::new(std::iter::empty()),
+        }
+    }
 }
 
 pub struct UpdateFile {
@@ -159,16 +196,24 @@ impl Iterator for UpdateFile {
 pub struct V6IndexReader {
     metadata: IndexMetadata,
     documents: BufReader<File>,
+    settings_history: Option<BufReader<File>>,
     settings: BufReader<File>,
 }
 
 impl V6IndexReader {
     pub fn new(_name: String, path: &Path) -> Result<Self> {
         let metadata = File::open(path.join("metadata.json"))?;
+        // The history of the settings was introduced after the v6 dumps.
+        let settings_history = match File::open(path.join("settings-history.jsonl")) {
+            Ok(file) => Some(BufReader::new(file)),
+            Err(e) if e.kind() == ErrorKind::NotFound => None,
+            Err(e) => return Err(e.into()),
+        };
 
         let ret = V6IndexReader {
             metadata: serde_json::from_reader(metadata)?,
             documents: BufReader::new(File::open(path.join("documents.jsonl"))?),
+            settings_history,
             settings: BufReader::new(File::open(path.join("settings.json"))?),
         };
 
@@ -185,6 +230,17 @@ impl V6IndexReader {
             .map(|line| -> Result<_> { Ok(serde_json::from_str(&line?)?) }))
     }
 
+    pub fn settings_history(&mut self) -> Box<dyn Iterator<Item = Result<SettingsVersion>> + '_> {
+        match &mut self.settings_history {
+            Some(settings_history) => Box::new(
+                settings_history
+                    .lines()
+                    .map(|line| -> Result<_> { Ok(serde_json::from_str(&line?)?) }),
+            ),
+            None => Box::new(std::iter::empty()),
+        }
+    }
+
     pub fn settings(&mut self) -> Result<Settings<Checked>> {
         let settings: Settings<Unchecked> = serde_json::from_reader(&mut self.settings)?;
         Ok(settings.check())
@@ -4,8 +4,11 @@ use std::path::PathBuf;
 
 use flate2::write::GzEncoder;
 use flate2::Compression;
+use meilisearch_types::index_aliases::IndexAlias;
+use meilisearch_types::index_templates::IndexTemplate;
 use meilisearch_types::keys::Key;
 use meilisearch_types::settings::{Checked, Settings};
+use meilisearch_types::settings_history::SettingsVersion;
 use serde_json::{Map, Value};
 use tempfile::TempDir;
 use time::OffsetDateTime;
@@ -49,6 +52,14 @@ impl DumpWriter {
         KeyWriter::new(self.dir.path().to_path_buf())
     }
 
+    pub fn create_index_templates(&self) -> Result<IndexTemplateWriter> {
+        IndexTemplateWriter::new(self.dir.path().to_path_buf())
+    }
+
+    pub fn create_aliases(&self) -> Result<AliasWriter> {
+        AliasWriter::new(self.dir.path().to_path_buf())
+    }
+
     pub fn create_tasks_queue(&self) -> Result<TaskWriter> {
         TaskWriter::new(self.dir.path().join("tasks"))
     }
@@ -87,6 +98,50 @@ impl KeyWriter {
     }
 }
 
+pub struct IndexTemplateWriter {
+    templates: BufWriter<File>,
+}
+
+impl IndexTemplateWriter {
+    pub(crate) fn new(path: PathBuf) -> Result<Self> {
+        let templates = File::create(path.join("index-templates.jsonl"))?;
+        Ok(IndexTemplateWriter { templates: BufWriter::new(templates) })
+    }
+
+    pub fn push_template(&mut self, template: &IndexTemplate) -> Result<()> {
+        self.templates.write_all(&serde_json::to_vec(template)?)?;
+        self.templates.write_all(b"\n")?;
+        Ok(())
+    }
+
+    pub fn flush(mut self) -> Result<()> {
+        self.templates.flush()?;
+        Ok(())
+    }
+}
+
+pub struct AliasWriter {
+    aliases: BufWriter<File>,
+}
+
+impl AliasWriter {
+    pub(crate) fn new(path: PathBuf) -> Result<Self> {
+        let aliases = File::create(path.join("aliases.jsonl"))?;
+        Ok(AliasWriter { aliases: BufWriter::new(aliases) })
+    }
+
+    pub fn push_alias(&mut self, alias: &IndexAlias) -> Result<()> {
+        self.aliases.write_all(&serde_json::to_vec(alias)?)?;
+        self.aliases.write_all(b"\n")?;
+        Ok(())
+    }
+
+    pub fn flush(mut self) -> Result<()> {
+        self.aliases.flush()?;
+        Ok(())
+    }
+}
+
 pub struct TaskWriter {
     queue: BufWriter<File>,
     update_files: PathBuf,
@@ -150,6 +205,7 @@ impl UpdateFile {
 
 pub struct IndexWriter {
     documents: BufWriter<File>,
+    settings_history: BufWriter<File>,
     settings: File,
 }
 
@@ -161,9 +217,14 @@ impl IndexWriter {
         serde_json::to_writer(metadata_file, metadata)?;
 
         let documents = File::create(path.join("documents.jsonl"))?;
+        let settings_history = File::create(path.join("settings-history.jsonl"))?;
         let settings = File::create(path.join("settings.json"))?;
 
-        Ok(IndexWriter { documents: BufWriter::new(documents), settings })
+        Ok(IndexWriter {
+            documents: BufWriter::new(documents),
+            settings_history: BufWriter::new(settings_history),
+            settings,
+        })
     }
 
     pub fn push_document(&mut self, document: &Map<String, Value>) -> Result<()> {
@@ -172,12 +233,20 @@ impl IndexWriter {
         Ok(())
     }
 
+    pub fn push_settings_version(&mut self, settings_version: &SettingsVersion) -> Result<()> {
+        serde_json::to_writer(&mut self.settings_history, settings_version)?;
+        self.settings_history.write_all(b"\n")?;
+        Ok(())
+    }
+
     pub fn flush(&mut self) -> Result<()> {
         self.documents.flush()?;
+        self.settings_history.flush()?;
         Ok(())
     }
 
     pub fn settings(mut self, settings: &Settings<Checked>) -> Result<()> {
+        self.settings_history.flush()?;
         self.settings.write_all(&serde_json::to_vec(&settings)?)?;
         Ok(())
     }
@@ -279,6 +348,7 @@ pub(crate) mod test {
         │    └---- doggos/
         │    │    ├---- documents.jsonl
         │    │    ├---- metadata.json
+        │    │    ├---- settings-history.jsonl
         │    │    └---- settings.json
         ├---- tasks/
         │    ├---- update_files/
@@ -59,7 +59,7 @@ use nom::sequence::{delimited, preceded, terminated, tuple};
 use nom::Finish;
 use nom_locate::LocatedSpan;
 pub(crate) use value::parse_value;
-use value::word_exact;
+use value::{quote_value_if_needed, word_exact};
 
 pub type Span<'a> = LocatedSpan<&'a str, &'a str>;
 
@@ -167,6 +167,104 @@ impl<'a> FilterCondition<'a> {
         let span = Span::new_extra(input, input);
         parse_filter(span).finish().map(|(_rem, output)| Some(output))
     }
+
+    /// Returns a canonical representation of the filter that can be parsed back into the
+    /// same filter. Values are only quoted when needed and every nested `AND`/`OR` group is
+    /// surrounded by parentheses so that the precedence is explicit.
+    pub fn to_normalized_string(&self) -> String {
+        let mut output = String::new();
+        self.write_normalized(&mut output);
+        output
+    }
+
+    fn write_normalized(&self, output: &mut String) {
+        match self {
+            FilterCondition::Not(filter) => match filter.as_ref() {
+                FilterCondition::In { fid, els } => {
+                    output.push_str(&quote_value_if_needed(fid.value()));
+                    output.push_str(" NOT IN ");
+                    write_normalized_list(els, output);
+                }
+                FilterCondition::Condition { fid, op: Condition::Exists } => {
+                    output.push_str(&quote_value_if_needed(fid.value()));
+                    output.push_str(" NOT EXISTS");
+                }
+                filter => {
+                    output.push_str("NOT ");
+                    filter.write_normalized_group(output);
+                }
+            },
+            FilterCondition::Condition { fid, op } => {
+                output.push_str(&quote_value_if_needed(fid.value()));
+                output.push(' ');
+                match op {
+                    Condition::GreaterThan(token) => write_operation(">", token, output),
+                    Condition::GreaterThanOrEqual(token) => write_operation(">=", token, output),
+                    Condition::Equal(token) => write_operation("=", token, output),
+                    Condition::NotEqual(token) => write_operation("!=", token, output),
+                    Condition::LowerThan(token) => write_operation("<", token, output),
+                    Condition::LowerThanOrEqual(token) => write_operation("<=", token, output),
+                    Condition::Exists => output.push_str("EXISTS"),
+                    Condition::Between { from, to } => {
+                        output.push_str(&quote_value_if_needed(from.value()));
+                        write_operation(" TO", to, output);
+                    }
+                }
+            }
+            FilterCondition::In { fid, els } => {
+                output.push_str(&quote_value_if_needed(fid.value()));
+                output.push_str(" IN ");
+                write_normalized_list(els, output);
+            }
+            FilterCondition::Or(filters) | FilterCondition::And(filters) => {
+                let separator =
+                    if matches!(self, FilterCondition::Or(_)) { " OR " } else { " AND " };
+                for (i, filter) in filters.iter().enumerate() {
+                    if i != 0 {
+                        output.push_str(separator);
+                    }
+                    filter.write_normalized_group(output);
+                }
+            }
+            FilterCondition::GeoLowerThan { point: [lat, lng], radius } => {
+                output.push_str("_geoRadius(");
+                output.push_str(lat.value());
+                output.push_str(", ");
+                output.push_str(lng.value());
+                output.push_str(", ");
+                output.push_str(radius.value());
+                output.push(')');
+            }
+        }
+    }
+
+    /// Same as `write_normalized` but surrounds the `AND` and `OR` groups by parentheses.
+    fn write_normalized_group(&self, output: &mut String) {
+        if matches!(self, FilterCondition::Or(_) | FilterCondition::And(_)) {
+            output.push('(');
+            self.write_normalized(output);
+            output.push(')');
+        } else {
+            self.write_normalized(output);
+        }
+    }
+}
+
+fn write_operation(operator: &str, value: &Token, output: &mut String) {
+    output.push_str(operator);
+    output.push(' ');
+    output.push_str(&quote_value_if_needed(value.value()));
+}
+
+fn write_normalized_list(values: &[Token], output: &mut String) {
+    output.push('[');
+    for (i, value) in values.iter().enumerate() {
+        if i != 0 {
+            output.push_str(", ");
+        }
+        output.push_str(&quote_value_if_needed(value.value()));
+    }
+    output.push(']');
 }
 
 /// remove OPTIONAL whitespaces before AND after the provided parser.
@@ -674,6 +772,30 @@ pub mod tests {
         assert!(filter.token_at_depth(3).is_none());
     }
 
+    #[test]
+    fn normalized() {
+        fn n(s: &str) -> String {
+            let filter = FilterCondition::parse(s).unwrap().unwrap();
+            let normalized = filter.to_normalized_string();
+            // the normalized filter must be parsed back to the exact same filter
+            let reparsed = FilterCondition::parse(&normalized).unwrap().unwrap();
+            assert_eq!(filter.to_string(), reparsed.to_string());
+            normalized
+        }
+
+        insta::assert_display_snapshot!(n("channel   =  Ponce"), @"channel = Ponce");
+        insta::assert_display_snapshot!(n("'dog race' = 'Bernese Mountain'"), @r###""dog race" = "Bernese Mountain""###);
+        insta::assert_display_snapshot!(n(r#"channel = "Mister \"Mv\"""#), @r###"channel = "Mister \"Mv\"""###);
+        insta::assert_display_snapshot!(n("channel = 'AND'"), @r###"channel = "AND""###);
+        insta::assert_display_snapshot!(n("subscribers 100   TO 1000"), @"subscribers 100 TO 1000");
+        insta::assert_display_snapshot!(n("colour NOT IN[green,blue,]"), @"colour NOT IN [green, blue]");
+        insta::assert_display_snapshot!(n("NOT subscribers NOT EXISTS"), @"subscribers EXISTS");
+        insta::assert_display_snapshot!(n("NOT subscribers EXISTS"), @"subscribers NOT EXISTS");
+        insta::assert_display_snapshot!(n("NOT _geoRadius(12, 13, 14)"), @"NOT _geoRadius(12, 13, 14)");
+        insta::assert_display_snapshot!(n("x = 1 OR y = 2 AND z = 3"), @"x = 1 OR (y = 2 AND z = 3)");
+        insta::assert_display_snapshot!(n("NOT (x = 1 OR y = 2) AND (z > 3 AND z < 10)"), @"NOT (x = 1 OR y = 2) AND (z > 3 AND z < 10)");
+    }
+
     #[test]
     fn token_from_str() {
         let s = "test string that should not be parsed";
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use nom::branch::alt;
 use nom::bytes::complete::{take_till, take_while, take_while1};
 use nom::character::complete::{char, multispace0};
@@ -146,6 +148,20 @@ pub fn parse_value(input: Span) -> IResult<Token> {
     Ok((input, value))
 }
 
+/// Returns the value as it should be written in a filter: untouched when it can be parsed
+/// as a word, surrounded by double quotes with its inner double quotes escaped otherwise.
+pub(crate) fn quote_value_if_needed(value: &str) -> Cow<str> {
+    let is_word = !value.is_empty()
+        && value.chars().all(is_value_component)
+        && !is_keyword(value)
+        && !value.starts_with("_geo");
+    if is_word {
+        value.into()
+    } else {
+        format!("\"{}\"", value.replace('"', "\\\"")).into()
+    }
+}
+
 fn is_value_component(c: char) -> bool {
     c.is_alphanumeric() || ['_', '-', '.'].contains(&c)
 }
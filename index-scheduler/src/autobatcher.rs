@@ -8,7 +8,7 @@ The main function of the autobatcher is [`next_autobatch`].
 use std::ops::ControlFlow::{self, Break, Continue};
 
 use meilisearch_types::milli::update::IndexDocumentsMethod::{
-    self, ReplaceDocuments, UpdateDocuments,
+    self, DeepMergeDocuments, ReplaceDocuments, UpdateDocuments,
 };
 use meilisearch_types::tasks::TaskId;
 
@@ -21,11 +21,18 @@ use crate::KindWithContent;
 enum AutobatchKind {
     DocumentImport { method: IndexDocumentsMethod, allow_index_creation: bool },
     DocumentDeletion,
+    DocumentDeletionByFilter,
+    DocumentExpiration,
+    DocumentEdition,
     DocumentClear,
+    DocumentPurge,
     Settings { allow_index_creation: bool },
     IndexCreation,
     IndexDeletion,
     IndexUpdate,
+    IndexClone,
+    IndexReadOnlyUpdate,
+    IndexCompaction,
     IndexSwap,
 }
 
@@ -47,7 +54,13 @@ impl From<KindWithContent> for AutobatchKind {
                 AutobatchKind::DocumentImport { method, allow_index_creation }
             }
             KindWithContent::DocumentDeletion { .. } => AutobatchKind::DocumentDeletion,
+            KindWithContent::DocumentDeletionByFilter { .. } => {
+                AutobatchKind::DocumentDeletionByFilter
+            }
+            KindWithContent::DocumentExpiration { .. } => AutobatchKind::DocumentExpiration,
+            KindWithContent::DocumentEdition { .. } => AutobatchKind::DocumentEdition,
             KindWithContent::DocumentClear { .. } => AutobatchKind::DocumentClear,
+            KindWithContent::DocumentPurge { .. } => AutobatchKind::DocumentPurge,
             KindWithContent::SettingsUpdate { allow_index_creation, is_deletion, .. } => {
                 AutobatchKind::Settings {
                     allow_index_creation: allow_index_creation && !is_deletion,
@@ -56,8 +69,12 @@ impl From<KindWithContent> for AutobatchKind {
             KindWithContent::IndexDeletion { .. } => AutobatchKind::IndexDeletion,
             KindWithContent::IndexCreation { .. } => AutobatchKind::IndexCreation,
             KindWithContent::IndexUpdate { .. } => AutobatchKind::IndexUpdate,
+            KindWithContent::IndexClone { .. } => AutobatchKind::IndexClone,
+            KindWithContent::IndexReadOnlyUpdate { .. } => AutobatchKind::IndexReadOnlyUpdate,
+            KindWithContent::IndexCompaction { .. } => AutobatchKind::IndexCompaction,
             KindWithContent::IndexSwap { .. } => AutobatchKind::IndexSwap,
-            KindWithContent::TaskCancelation { .. }
+            KindWithContent::AliasUpdate { .. }
+            | KindWithContent::TaskCancelation { .. }
             | KindWithContent::TaskDeletion { .. }
             | KindWithContent::DumpCreation { .. }
             | KindWithContent::SnapshotCreation => {
@@ -80,6 +97,18 @@ pub enum BatchKind {
     DocumentDeletion {
         deletion_ids: Vec<TaskId>,
     },
+    DocumentDeletionByFilter {
+        id: TaskId,
+    },
+    DocumentExpiration {
+        id: TaskId,
+    },
+    DocumentEdition {
+        id: TaskId,
+    },
+    DocumentPurge {
+        id: TaskId,
+    },
     ClearAndSettings {
         other: Vec<TaskId>,
         allow_index_creation: bool,
@@ -104,6 +133,15 @@ pub enum BatchKind {
     IndexUpdate {
         id: TaskId,
     },
+    IndexClone {
+        id: TaskId,
+    },
+    IndexReadOnlyUpdate {
+        id: TaskId,
+    },
+    IndexCompaction {
+        id: TaskId,
+    },
     IndexSwap {
         id: TaskId,
     },
@@ -138,7 +176,18 @@ impl BatchKind {
             K::IndexCreation => (Break(BatchKind::IndexCreation { id: task_id }), true),
             K::IndexDeletion => (Break(BatchKind::IndexDeletion { ids: vec![task_id] }), false),
             K::IndexUpdate => (Break(BatchKind::IndexUpdate { id: task_id }), false),
+            K::IndexClone => (Break(BatchKind::IndexClone { id: task_id }), false),
+            K::IndexReadOnlyUpdate => {
+                (Break(BatchKind::IndexReadOnlyUpdate { id: task_id }), false)
+            }
+            K::IndexCompaction => (Break(BatchKind::IndexCompaction { id: task_id }), false),
             K::IndexSwap => (Break(BatchKind::IndexSwap { id: task_id }), false),
+            K::DocumentDeletionByFilter => {
+                (Break(BatchKind::DocumentDeletionByFilter { id: task_id }), false)
+            }
+            K::DocumentExpiration => (Break(BatchKind::DocumentExpiration { id: task_id }), false),
+            K::DocumentEdition => (Break(BatchKind::DocumentEdition { id: task_id }), false),
+            K::DocumentPurge => (Break(BatchKind::DocumentPurge { id: task_id }), false),
             K::DocumentClear => (Continue(BatchKind::DocumentClear { ids: vec![task_id] }), false),
             K::DocumentImport { method, allow_index_creation } => (
                 Continue(BatchKind::DocumentImport {
@@ -168,7 +217,7 @@ impl BatchKind {
 
         match (self, kind) {
             // We don't batch any of these operations
-            (this, K::IndexCreation | K::IndexUpdate | K::IndexSwap) => Break(this),
+            (this, K::IndexCreation | K::IndexUpdate | K::IndexClone | K::IndexReadOnlyUpdate | K::IndexCompaction | K::IndexSwap | K::DocumentDeletionByFilter | K::DocumentExpiration | K::DocumentEdition | K::DocumentPurge) => Break(this),
             // We must not batch tasks that don't have the same index creation rights if the index doesn't already exists.
             (this, kind) if !index_already_exists && this.allow_index_creation() == Some(false) && kind.allow_index_creation() == Some(true) => {
                 Break(this)
@@ -236,6 +285,17 @@ impl BatchKind {
                     import_ids,
                 })
             }
+            (
+                BatchKind::DocumentImport { method: DeepMergeDocuments { append_arrays }, allow_index_creation, mut import_ids },
+                K::DocumentImport { method: DeepMergeDocuments { append_arrays: other }, .. },
+            ) if append_arrays == other => {
+                import_ids.push(id);
+                Continue(BatchKind::DocumentImport {
+                    method: DeepMergeDocuments { append_arrays },
+                    allow_index_creation,
+                    import_ids,
+                })
+            }
 
             // but we can't autobatch documents if it's not the same kind
             // this match branch MUST be AFTER the previous one
@@ -362,8 +422,20 @@ impl BatchKind {
                     import_ids,
                 })
             }
+            (
+                BatchKind::SettingsAndDocumentImport { settings_ids, method: DeepMergeDocuments { append_arrays }, allow_index_creation, mut import_ids },
+                K::DocumentImport { method: DeepMergeDocuments { append_arrays: other }, .. },
+            ) if append_arrays == other => {
+                import_ids.push(id);
+                Continue(BatchKind::SettingsAndDocumentImport {
+                    settings_ids,
+                    method: DeepMergeDocuments { append_arrays },
+                    allow_index_creation,
+                    import_ids,
+                })
+            }
             // But we can't batch a settings and a doc op with another doc op
-            // this MUST be AFTER the two previous branch
+            // this MUST be AFTER the previous branches
             (
                 this @ BatchKind::SettingsAndDocumentImport { .. },
                 K::DocumentDeletion | K::DocumentImport { .. },
@@ -384,7 +456,14 @@ impl BatchKind {
                 BatchKind::IndexCreation { .. }
                 | BatchKind::IndexDeletion { .. }
                 | BatchKind::IndexUpdate { .. }
-                | BatchKind::IndexSwap { .. },
+                | BatchKind::IndexClone { .. }
+                | BatchKind::IndexReadOnlyUpdate { .. }
+                | BatchKind::IndexCompaction { .. }
+                | BatchKind::IndexSwap { .. }
+                | BatchKind::DocumentDeletionByFilter { .. }
+                | BatchKind::DocumentExpiration { .. }
+                | BatchKind::DocumentEdition { .. }
+                | BatchKind::DocumentPurge { .. },
                 _,
             ) => {
                 unreachable!()
@@ -467,16 +546,41 @@ mod tests {
         }
     }
 
+    fn doc_del_fil() -> KindWithContent {
+        KindWithContent::DocumentDeletionByFilter {
+            index_uid: String::from("doggo"),
+            filter_expr: serde_json::json!("cuteness > 100"),
+        }
+    }
+
+    fn doc_exp() -> KindWithContent {
+        KindWithContent::DocumentExpiration { index_uid: String::from("doggo") }
+    }
+
+    fn doc_edit() -> KindWithContent {
+        KindWithContent::DocumentEdition {
+            index_uid: String::from("doggo"),
+            filter_expr: None,
+            context: None,
+            function: String::from("doc.cuteness += 1"),
+        }
+    }
+
     fn doc_clr() -> KindWithContent {
         KindWithContent::DocumentClear { index_uid: String::from("doggo") }
     }
 
+    fn doc_purge() -> KindWithContent {
+        KindWithContent::DocumentPurge { index_uid: String::from("doggo") }
+    }
+
     fn settings(allow_index_creation: bool) -> KindWithContent {
         KindWithContent::SettingsUpdate {
             index_uid: String::from("doggo"),
             new_settings: Default::default(),
             is_deletion: false,
             allow_index_creation,
+            is_rollback: false,
         }
     }
 
@@ -485,13 +589,33 @@ mod tests {
     }
 
     fn idx_update() -> KindWithContent {
-        KindWithContent::IndexUpdate { index_uid: String::from("doggo"), primary_key: None }
+        KindWithContent::IndexUpdate {
+            index_uid: String::from("doggo"),
+            primary_key: None,
+            new_index_uid: None,
+            migrate_api_keys: false,
+        }
     }
 
     fn idx_del() -> KindWithContent {
         KindWithContent::IndexDeletion { index_uid: String::from("doggo") }
     }
 
+    fn idx_clone() -> KindWithContent {
+        KindWithContent::IndexClone {
+            index_uid: String::from("doggo"),
+            target_uid: String::from("catto"),
+        }
+    }
+
+    fn idx_read_only(read_only: bool) -> KindWithContent {
+        KindWithContent::IndexReadOnlyUpdate { index_uid: String::from("doggo"), read_only }
+    }
+
+    fn idx_compaction() -> KindWithContent {
+        KindWithContent::IndexCompaction { index_uid: String::from("doggo") }
+    }
+
     fn idx_swap() -> KindWithContent {
         KindWithContent::IndexSwap {
             swaps: vec![IndexSwap { indexes: (String::from("doggo"), String::from("catto")) }],
@@ -526,6 +650,14 @@ mod tests {
         debug_snapshot!(autobatch_from(false, [doc_imp(UpdateDocuments, false)]), @"Some((DocumentImport { method: UpdateDocuments, allow_index_creation: false, import_ids: [0] }, false))");
         debug_snapshot!(autobatch_from(false, [doc_imp(UpdateDocuments, false), doc_imp(UpdateDocuments, false), doc_imp(UpdateDocuments, false)]), @"Some((DocumentImport { method: UpdateDocuments, allow_index_creation: false, import_ids: [0, 1, 2] }, false))");
 
+        // we can autobatch one or multiple `DeepMergeDocuments` together if they use the same array strategy.
+        debug_snapshot!(autobatch_from(true, [doc_imp(DeepMergeDocuments { append_arrays: false }, true), doc_imp(DeepMergeDocuments { append_arrays: false }, true)]), @"Some((DocumentImport { method: DeepMergeDocuments { append_arrays: false }, allow_index_creation: true, import_ids: [0, 1] }, true))");
+        debug_snapshot!(autobatch_from(true, [doc_imp(DeepMergeDocuments { append_arrays: true }, true), doc_imp(DeepMergeDocuments { append_arrays: true }, true)]), @"Some((DocumentImport { method: DeepMergeDocuments { append_arrays: true }, allow_index_creation: true, import_ids: [0, 1] }, true))");
+        debug_snapshot!(autobatch_from(true, [doc_imp(DeepMergeDocuments { append_arrays: false }, true), doc_imp(DeepMergeDocuments { append_arrays: true }, true)]), @"Some((DocumentImport { method: DeepMergeDocuments { append_arrays: false }, allow_index_creation: true, import_ids: [0] }, true))");
+        debug_snapshot!(autobatch_from(true, [doc_imp(DeepMergeDocuments { append_arrays: false }, true), doc_imp(UpdateDocuments, true)]), @"Some((DocumentImport { method: DeepMergeDocuments { append_arrays: false }, allow_index_creation: true, import_ids: [0] }, true))");
+        debug_snapshot!(autobatch_from(true, [doc_imp(UpdateDocuments, true), doc_imp(DeepMergeDocuments { append_arrays: false }, true)]), @"Some((DocumentImport { method: UpdateDocuments, allow_index_creation: true, import_ids: [0] }, true))");
+        debug_snapshot!(autobatch_from(true, [doc_imp(DeepMergeDocuments { append_arrays: true }, true), settings(true), doc_imp(DeepMergeDocuments { append_arrays: true }, true)]), @"Some((SettingsAndDocumentImport { settings_ids: [1], method: DeepMergeDocuments { append_arrays: true }, allow_index_creation: true, import_ids: [0, 2] }, true))");
+
         // we can autobatch one or multiple DocumentDeletion together
         debug_snapshot!(autobatch_from(true, [doc_del()]), @"Some((DocumentDeletion { deletion_ids: [0] }, false))");
         debug_snapshot!(autobatch_from(true, [doc_del(), doc_del(), doc_del()]), @"Some((DocumentDeletion { deletion_ids: [0, 1, 2] }, false))");
@@ -567,6 +699,86 @@ mod tests {
         debug_snapshot!(autobatch_from(true, [doc_del(), idx_swap()]), @"Some((DocumentDeletion { deletion_ids: [0] }, false))");
     }
 
+    #[test]
+    fn index_clone_dont_autobatch() {
+        // an index clone is always processed alone
+        debug_snapshot!(autobatch_from(true, [idx_clone()]), @"Some((IndexClone { id: 0 }, false))");
+        debug_snapshot!(autobatch_from(true, [idx_clone(), doc_imp(ReplaceDocuments, true)]), @"Some((IndexClone { id: 0 }, false))");
+        debug_snapshot!(autobatch_from(true, [doc_imp(ReplaceDocuments, true), idx_clone()]), @"Some((DocumentImport { method: ReplaceDocuments, allow_index_creation: true, import_ids: [0] }, true))");
+        debug_snapshot!(autobatch_from(true, [settings(true), idx_clone()]), @"Some((Settings { allow_index_creation: true, settings_ids: [0] }, true))");
+    }
+
+    #[test]
+    fn index_read_only_update_dont_autobatch() {
+        // a read-only update is always processed alone
+        debug_snapshot!(autobatch_from(true, [idx_read_only(true)]), @"Some((IndexReadOnlyUpdate { id: 0 }, false))");
+        debug_snapshot!(autobatch_from(true, [idx_read_only(false), doc_imp(ReplaceDocuments, true)]), @"Some((IndexReadOnlyUpdate { id: 0 }, false))");
+        debug_snapshot!(autobatch_from(true, [doc_imp(ReplaceDocuments, true), idx_read_only(true)]), @"Some((DocumentImport { method: ReplaceDocuments, allow_index_creation: true, import_ids: [0] }, true))");
+    }
+
+    #[test]
+    fn index_compaction_dont_autobatch() {
+        // a compaction is always processed alone
+        debug_snapshot!(autobatch_from(true, [idx_compaction()]), @"Some((IndexCompaction { id: 0 }, false))");
+        debug_snapshot!(autobatch_from(true, [idx_compaction(), idx_compaction()]), @"Some((IndexCompaction { id: 0 }, false))");
+        debug_snapshot!(autobatch_from(true, [doc_imp(ReplaceDocuments, true), idx_compaction()]), @"Some((DocumentImport { method: ReplaceDocuments, allow_index_creation: true, import_ids: [0] }, true))");
+    }
+
+    #[test]
+    fn document_deletion_by_filter_dont_autobatch() {
+        // a deletion by filter is always processed alone
+        debug_snapshot!(autobatch_from(true, [doc_del_fil()]), @"Some((DocumentDeletionByFilter { id: 0 }, false))");
+        debug_snapshot!(autobatch_from(false, [doc_del_fil()]), @"Some((DocumentDeletionByFilter { id: 0 }, false))");
+        debug_snapshot!(autobatch_from(true, [doc_del_fil(), doc_del_fil()]), @"Some((DocumentDeletionByFilter { id: 0 }, false))");
+        debug_snapshot!(autobatch_from(true, [doc_del_fil(), doc_del()]), @"Some((DocumentDeletionByFilter { id: 0 }, false))");
+        debug_snapshot!(autobatch_from(true, [doc_del_fil(), idx_del()]), @"Some((DocumentDeletionByFilter { id: 0 }, false))");
+
+        // and it stops the batches that come before it
+        debug_snapshot!(autobatch_from(true, [doc_del(), doc_del_fil()]), @"Some((DocumentDeletion { deletion_ids: [0] }, false))");
+        debug_snapshot!(autobatch_from(true, [doc_imp(ReplaceDocuments, true), doc_del_fil()]), @"Some((DocumentImport { method: ReplaceDocuments, allow_index_creation: true, import_ids: [0] }, true))");
+        debug_snapshot!(autobatch_from(true, [doc_clr(), doc_del_fil()]), @"Some((DocumentClear { ids: [0] }, false))");
+        debug_snapshot!(autobatch_from(true, [settings(true), doc_del_fil()]), @"Some((Settings { allow_index_creation: true, settings_ids: [0] }, true))");
+    }
+
+    #[test]
+    fn document_expiration_dont_autobatch() {
+        // a document expiration is always processed alone
+        debug_snapshot!(autobatch_from(true, [doc_exp()]), @"Some((DocumentExpiration { id: 0 }, false))");
+        debug_snapshot!(autobatch_from(false, [doc_exp()]), @"Some((DocumentExpiration { id: 0 }, false))");
+        debug_snapshot!(autobatch_from(true, [doc_exp(), doc_del()]), @"Some((DocumentExpiration { id: 0 }, false))");
+        debug_snapshot!(autobatch_from(true, [doc_exp(), idx_del()]), @"Some((DocumentExpiration { id: 0 }, false))");
+
+        // and it stops the batches that come before it
+        debug_snapshot!(autobatch_from(true, [doc_del(), doc_exp()]), @"Some((DocumentDeletion { deletion_ids: [0] }, false))");
+        debug_snapshot!(autobatch_from(true, [doc_imp(ReplaceDocuments, true), doc_exp()]), @"Some((DocumentImport { method: ReplaceDocuments, allow_index_creation: true, import_ids: [0] }, true))");
+    }
+
+    #[test]
+    fn document_purge_dont_autobatch() {
+        // a document purge is always processed alone
+        debug_snapshot!(autobatch_from(true, [doc_purge()]), @"Some((DocumentPurge { id: 0 }, false))");
+        debug_snapshot!(autobatch_from(true, [doc_purge(), doc_purge()]), @"Some((DocumentPurge { id: 0 }, false))");
+        debug_snapshot!(autobatch_from(true, [doc_purge(), doc_del()]), @"Some((DocumentPurge { id: 0 }, false))");
+
+        // and it stops the batches that come before it
+        debug_snapshot!(autobatch_from(true, [doc_del(), doc_purge()]), @"Some((DocumentDeletion { deletion_ids: [0] }, false))");
+    }
+
+    #[test]
+    fn document_edition_dont_autobatch() {
+        // a document edition is always processed alone
+        debug_snapshot!(autobatch_from(true, [doc_edit()]), @"Some((DocumentEdition { id: 0 }, false))");
+        debug_snapshot!(autobatch_from(false, [doc_edit()]), @"Some((DocumentEdition { id: 0 }, false))");
+        debug_snapshot!(autobatch_from(true, [doc_edit(), doc_edit()]), @"Some((DocumentEdition { id: 0 }, false))");
+        debug_snapshot!(autobatch_from(true, [doc_edit(), doc_imp(UpdateDocuments, true)]), @"Some((DocumentEdition { id: 0 }, false))");
+        debug_snapshot!(autobatch_from(true, [doc_edit(), idx_del()]), @"Some((DocumentEdition { id: 0 }, false))");
+
+        // and it stops the batches that come before it
+        debug_snapshot!(autobatch_from(true, [doc_del(), doc_edit()]), @"Some((DocumentDeletion { deletion_ids: [0] }, false))");
+        debug_snapshot!(autobatch_from(true, [doc_imp(UpdateDocuments, true), doc_edit()]), @"Some((DocumentImport { method: UpdateDocuments, allow_index_creation: true, import_ids: [0] }, true))");
+        debug_snapshot!(autobatch_from(true, [settings(true), doc_edit()]), @"Some((Settings { allow_index_creation: true, settings_ids: [0] }, true))");
+    }
+
     #[test]
     fn document_addition_batch_with_settings() {
         // simple case
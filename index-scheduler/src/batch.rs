@@ -20,22 +20,26 @@ one indexing operation.
 use std::collections::{BTreeSet, HashSet};
 use std::ffi::OsStr;
 use std::fs::{self, File};
-use std::io::BufWriter;
+use std::io::{BufReader, BufWriter};
 
 use dump::IndexMetadata;
 use log::{debug, error, info};
 use meilisearch_types::heed::{RoTxn, RwTxn};
-use meilisearch_types::milli::documents::{obkv_to_object, DocumentsBatchReader};
+use meilisearch_types::index_aliases::IndexAlias;
+use meilisearch_types::milli::documents::{
+    obkv_to_object, DocumentsBatchBuilder, DocumentsBatchReader,
+};
 use meilisearch_types::milli::heed::CompactionOption;
 use meilisearch_types::milli::update::{
-    DocumentAdditionResult, DocumentDeletionResult, IndexDocumentsConfig, IndexDocumentsMethod,
-    Settings as MilliSettings,
+    DocumentAdditionResult, DocumentDeletionResult, GeneratedDocumentsIds, IndexDocumentsConfig,
+    IndexDocumentsMethod, PrimaryKeyDefinition, Settings as MilliSettings, VersionConflicts,
 };
-use meilisearch_types::milli::{self, BEU32};
+use meilisearch_types::milli::{self, Filter, BEU32};
 use meilisearch_types::settings::{apply_settings_to_builder, Settings, Unchecked};
 use meilisearch_types::tasks::{Details, IndexSwap, Kind, KindWithContent, Status, Task};
 use meilisearch_types::{compression, Index, VERSION_FILE_NAME};
 use roaring::RoaringBitmap;
+use serde_json::Value;
 use time::macros::format_description;
 use time::OffsetDateTime;
 use uuid::Uuid;
@@ -44,6 +48,17 @@ use crate::autobatcher::{self, BatchKind};
 use crate::utils::{self, swap_index_uid_in_task};
 use crate::{Error, IndexScheduler, ProcessingTasks, Result, TaskId};
 
+/// The minimum number of soft-deleted documents purged in a single index transaction.
+const MIN_PURGE_CHUNK_SIZE: u64 = 10_000;
+
+/// Every purged chunk scans the word databases of the whole index, a chunk purges
+/// at least one document out of `PURGE_CHUNK_RATIO` so that the scans pay off.
+const PURGE_CHUNK_RATIO: u64 = 10;
+
+/// The maximum number of soft-deleted documents purged by a single task, the remaining
+/// documents are purged by a new task registered at the end of the queue.
+const MAX_PURGED_DOCUMENTS_PER_TASK: u64 = 100_000;
+
 /// Represents a combination of tasks that can all be processed at the same time.
 ///
 /// A batch contains the set of tasks that it represents (accessible through
@@ -68,12 +83,32 @@ pub(crate) enum Batch {
     },
     IndexCreation {
         index_uid: String,
-        primary_key: Option<String>,
+        primary_key: Option<PrimaryKeyDefinition>,
         task: Task,
     },
     IndexUpdate {
         index_uid: String,
-        primary_key: Option<String>,
+        primary_key: Option<PrimaryKeyDefinition>,
+        new_index_uid: Option<String>,
+        migrate_api_keys: bool,
+        task: Task,
+    },
+    IndexClone {
+        index_uid: String,
+        target_uid: String,
+        task: Task,
+    },
+    IndexReadOnlyUpdate {
+        index_uid: String,
+        read_only: bool,
+        task: Task,
+    },
+    IndexCompaction {
+        index_uid: String,
+        task: Task,
+    },
+    DocumentPurge {
+        index_uid: String,
         task: Task,
     },
     IndexDeletion {
@@ -84,6 +119,9 @@ pub(crate) enum Batch {
     IndexSwap {
         task: Task,
     },
+    AliasUpdate {
+        task: Task,
+    },
 }
 
 /// A [batch](Batch) that combines multiple tasks operating on an index.
@@ -103,6 +141,18 @@ pub(crate) enum IndexOperation {
         documents: Vec<Vec<String>>,
         tasks: Vec<Task>,
     },
+    DocumentDeletionByFilter {
+        index_uid: String,
+        task: Task,
+    },
+    DocumentExpiration {
+        index_uid: String,
+        task: Task,
+    },
+    DocumentEdition {
+        index_uid: String,
+        task: Task,
+    },
     DocumentClear {
         index_uid: String,
         tasks: Vec<Task>,
@@ -144,7 +194,11 @@ impl Batch {
             | Batch::TaskDeletion(task)
             | Batch::Dump(task)
             | Batch::IndexCreation { task, .. }
-            | Batch::IndexUpdate { task, .. } => vec![task.uid],
+            | Batch::IndexUpdate { task, .. }
+            | Batch::IndexClone { task, .. }
+            | Batch::IndexReadOnlyUpdate { task, .. }
+            | Batch::IndexCompaction { task, .. }
+            | Batch::DocumentPurge { task, .. } => vec![task.uid],
             Batch::SnapshotCreation(tasks) | Batch::IndexDeletion { tasks, .. } => {
                 tasks.iter().map(|task| task.uid).collect()
             }
@@ -165,8 +219,11 @@ impl Batch {
                     settings_tasks: other,
                     ..
                 } => tasks.iter().chain(other).map(|task| task.uid).collect(),
+                IndexOperation::DocumentDeletionByFilter { task, .. }
+                | IndexOperation::DocumentExpiration { task, .. }
+                | IndexOperation::DocumentEdition { task, .. } => vec![task.uid],
             },
-            Batch::IndexSwap { task } => vec![task.uid],
+            Batch::IndexSwap { task } | Batch::AliasUpdate { task } => vec![task.uid],
         }
     }
 }
@@ -176,12 +233,48 @@ impl IndexOperation {
         match self {
             IndexOperation::DocumentImport { index_uid, .. }
             | IndexOperation::DocumentDeletion { index_uid, .. }
+            | IndexOperation::DocumentDeletionByFilter { index_uid, .. }
+            | IndexOperation::DocumentExpiration { index_uid, .. }
+            | IndexOperation::DocumentEdition { index_uid, .. }
             | IndexOperation::DocumentClear { index_uid, .. }
             | IndexOperation::Settings { index_uid, .. }
             | IndexOperation::DocumentClearAndSetting { index_uid, .. }
             | IndexOperation::SettingsAndDocumentImport { index_uid, .. } => index_uid,
         }
     }
+
+    /// Return the update files containing the documents imported by this operation.
+    pub fn content_files(&self) -> &[Uuid] {
+        match self {
+            IndexOperation::DocumentImport { content_files, .. }
+            | IndexOperation::SettingsAndDocumentImport { content_files, .. } => content_files,
+            IndexOperation::DocumentDeletion { .. }
+            | IndexOperation::DocumentDeletionByFilter { .. }
+            | IndexOperation::DocumentExpiration { .. }
+            | IndexOperation::DocumentEdition { .. }
+            | IndexOperation::DocumentClear { .. }
+            | IndexOperation::Settings { .. }
+            | IndexOperation::DocumentClearAndSetting { .. } => &[],
+        }
+    }
+
+    /// Return the ids of the settings tasks of this operation, they produce a new version
+    /// of the settings of the index once processed.
+    pub fn settings_task_ids(&self) -> Vec<TaskId> {
+        match self {
+            IndexOperation::Settings { tasks, .. }
+            | IndexOperation::DocumentClearAndSetting { settings_tasks: tasks, .. }
+            | IndexOperation::SettingsAndDocumentImport { settings_tasks: tasks, .. } => {
+                tasks.iter().map(|task| task.uid).collect()
+            }
+            IndexOperation::DocumentImport { .. }
+            | IndexOperation::DocumentDeletion { .. }
+            | IndexOperation::DocumentDeletionByFilter { .. }
+            | IndexOperation::DocumentExpiration { .. }
+            | IndexOperation::DocumentEdition { .. }
+            | IndexOperation::DocumentClear { .. } => Vec::new(),
+        }
+    }
 }
 
 impl IndexScheduler {
@@ -261,6 +354,27 @@ impl IndexScheduler {
                     must_create_index,
                 }))
             }
+            BatchKind::DocumentDeletionByFilter { id } => {
+                let task = self.get_task(rtxn, id)?.ok_or(Error::CorruptedTaskQueue)?;
+                Ok(Some(Batch::IndexOperation {
+                    op: IndexOperation::DocumentDeletionByFilter { index_uid, task },
+                    must_create_index,
+                }))
+            }
+            BatchKind::DocumentExpiration { id } => {
+                let task = self.get_task(rtxn, id)?.ok_or(Error::CorruptedTaskQueue)?;
+                Ok(Some(Batch::IndexOperation {
+                    op: IndexOperation::DocumentExpiration { index_uid, task },
+                    must_create_index,
+                }))
+            }
+            BatchKind::DocumentEdition { id } => {
+                let task = self.get_task(rtxn, id)?.ok_or(Error::CorruptedTaskQueue)?;
+                Ok(Some(Batch::IndexOperation {
+                    op: IndexOperation::DocumentEdition { index_uid, task },
+                    must_create_index,
+                }))
+            }
             BatchKind::Settings { settings_ids, .. } => {
                 let tasks = self.get_existing_tasks(rtxn, settings_ids)?;
 
@@ -386,11 +500,49 @@ impl IndexScheduler {
             }
             BatchKind::IndexUpdate { id } => {
                 let task = self.get_task(rtxn, id)?.ok_or(Error::CorruptedTaskQueue)?;
-                let primary_key = match &task.kind {
-                    KindWithContent::IndexUpdate { primary_key, .. } => primary_key.clone(),
+                let (primary_key, new_index_uid, migrate_api_keys) = match &task.kind {
+                    KindWithContent::IndexUpdate {
+                        primary_key,
+                        new_index_uid,
+                        migrate_api_keys,
+                        ..
+                    } => (primary_key.clone(), new_index_uid.clone(), *migrate_api_keys),
+                    _ => unreachable!(),
+                };
+                Ok(Some(Batch::IndexUpdate {
+                    index_uid,
+                    primary_key,
+                    new_index_uid,
+                    migrate_api_keys,
+                    task,
+                }))
+            }
+            BatchKind::IndexClone { id } => {
+                let task = self.get_task(rtxn, id)?.ok_or(Error::CorruptedTaskQueue)?;
+                // The task may have been batched from the tasks of the target index.
+                let (index_uid, target_uid) = match &task.kind {
+                    KindWithContent::IndexClone { index_uid, target_uid } => {
+                        (index_uid.clone(), target_uid.clone())
+                    }
+                    _ => unreachable!(),
+                };
+                Ok(Some(Batch::IndexClone { index_uid, target_uid, task }))
+            }
+            BatchKind::IndexReadOnlyUpdate { id } => {
+                let task = self.get_task(rtxn, id)?.ok_or(Error::CorruptedTaskQueue)?;
+                let read_only = match &task.kind {
+                    KindWithContent::IndexReadOnlyUpdate { read_only, .. } => *read_only,
                     _ => unreachable!(),
                 };
-                Ok(Some(Batch::IndexUpdate { index_uid, primary_key, task }))
+                Ok(Some(Batch::IndexReadOnlyUpdate { index_uid, read_only, task }))
+            }
+            BatchKind::IndexCompaction { id } => {
+                let task = self.get_task(rtxn, id)?.ok_or(Error::CorruptedTaskQueue)?;
+                Ok(Some(Batch::IndexCompaction { index_uid, task }))
+            }
+            BatchKind::DocumentPurge { id } => {
+                let task = self.get_task(rtxn, id)?.ok_or(Error::CorruptedTaskQueue)?;
+                Ok(Some(Batch::DocumentPurge { index_uid, task }))
             }
             BatchKind::IndexDeletion { ids } => Ok(Some(Batch::IndexDeletion {
                 index_uid,
@@ -455,15 +607,23 @@ impl IndexScheduler {
         let task_id = if let Some(task_id) = enqueued.min() { task_id } else { return Ok(None) };
         let task = self.get_task(rtxn, task_id)?.ok_or(Error::CorruptedTaskQueue)?;
 
-        // If the task is not associated with any index, verify that it is an index swap and
-        // create the batch directly. Otherwise, get the index name associated with the task
-        // and use the autobatcher to batch the enqueued tasks associated with it
+        // If the task is not associated with any index, verify that it is an index swap or an
+        // alias update and create the batch directly. Otherwise, get the index name associated
+        // with the task and use the autobatcher to batch the enqueued tasks associated with it
 
         let index_name = if let Some(&index_name) = task.indexes().first() {
             index_name
         } else {
-            assert!(matches!(&task.kind, KindWithContent::IndexSwap { swaps } if swaps.is_empty()));
-            return Ok(Some(Batch::IndexSwap { task }));
+            match &task.kind {
+                KindWithContent::IndexSwap { swaps } => {
+                    assert!(swaps.is_empty());
+                    return Ok(Some(Batch::IndexSwap { task }));
+                }
+                KindWithContent::AliasUpdate { .. } => {
+                    return Ok(Some(Batch::AliasUpdate { task }));
+                }
+                _ => unreachable!(),
+            }
         };
 
         let index_already_exists = self.index_mapper.exists(rtxn, index_name)?;
@@ -698,6 +858,20 @@ impl IndexScheduler {
 
                 let rtxn = self.env.read_txn()?;
 
+                // 1.1. dump the index templates
+                let mut dump_templates = dump.create_index_templates()?;
+                for template in self.index_mapper.index_templates(&rtxn)? {
+                    dump_templates.push_template(&template)?;
+                }
+                dump_templates.flush()?;
+
+                // 1.2. dump the aliases
+                let mut dump_aliases = dump.create_aliases()?;
+                for (uid, indexes) in self.index_mapper.aliases(&rtxn)? {
+                    dump_aliases.push_alias(&IndexAlias { uid, indexes })?;
+                }
+                dump_aliases.flush()?;
+
                 // 2. dump the tasks
                 let mut dump_tasks = dump.create_tasks_queue()?;
                 for ret in self.all_tasks.iter(&rtxn)? {
@@ -746,12 +920,18 @@ impl IndexScheduler {
 
                 // 3. Dump the indexes
                 for (uid, index) in self.index_mapper.indexes(&rtxn)? {
+                    let read_only = self.index_mapper.is_read_only(&rtxn, &uid)?;
+                    let quota = self.index_mapper.quota(&rtxn, &uid)?;
+                    let settings_history = self.index_mapper.settings_history(&rtxn, &uid)?;
                     let rtxn = index.read_txn()?;
                     let metadata = IndexMetadata {
                         uid: uid.clone(),
                         primary_key: index.primary_key(&rtxn)?.map(String::from),
                         created_at: index.created_at(&rtxn)?,
                         updated_at: index.updated_at(&rtxn)?,
+                        read_only,
+                        quota,
+                        composite_primary_key: index.composite_primary_key(&rtxn)?,
                     };
                     let mut index_dumper = dump.create_index(&uid, &metadata)?;
 
@@ -765,7 +945,12 @@ impl IndexScheduler {
                         index_dumper.push_document(&document)?;
                     }
 
-                    // 3.2. Dump the settings
+                    // 3.2. Dump the history of the settings
+                    for settings_version in &settings_history {
+                        index_dumper.push_settings_version(settings_version)?;
+                    }
+
+                    // 3.3. Dump the settings
                     let settings = meilisearch_types::settings::settings(&index, &rtxn)?;
                     index_dumper.settings(&settings)?;
                 }
@@ -784,20 +969,75 @@ impl IndexScheduler {
                 Ok(vec![task])
             }
             Batch::IndexOperation { op, must_create_index } => {
-                let index_uid = op.index_uid();
+                let index_uid = op.index_uid().to_string();
                 let index = if must_create_index {
                     // create the index if it doesn't already exist
                     let wtxn = self.env.write_txn()?;
-                    self.index_mapper.create_index(wtxn, index_uid, None)?
+                    let template = self.index_mapper.index_template(&wtxn, &index_uid)?;
+                    let template = template.map(|settings| settings.check());
+                    self.index_mapper.create_index(wtxn, &index_uid, None, template.as_ref())?
                 } else {
                     let rtxn = self.env.read_txn()?;
-                    self.index_mapper.index(&rtxn, index_uid)?
+                    self.check_index_is_writable(&rtxn, &index_uid)?;
+                    self.index_mapper.index(&rtxn, &index_uid)?
                 };
 
+                let settings_task_ids = op.settings_task_ids();
+                let imported_size = op
+                    .content_files()
+                    .iter()
+                    .map(|uuid| self.file_store.get_size(*uuid))
+                    .sum::<std::result::Result<u64, _>>()?;
                 let mut index_wtxn = index.write_txn()?;
+                let documents_before = index.number_of_documents(&index_wtxn)?;
+                // The first settings update of an index also records the settings it replaces,
+                // so that it's always possible to roll back to them.
+                let initial_settings = if settings_task_ids.is_empty()
+                    || self.index_mapper.has_settings_history(&self.env.read_txn()?, &index_uid)?
+                {
+                    None
+                } else {
+                    let settings = meilisearch_types::settings::settings(&index, &index_wtxn)?;
+                    Some((index.created_at(&index_wtxn)?, settings))
+                };
                 let tasks = self.apply_index_operation(&mut index_wtxn, &index, op)?;
+                self.check_index_quota(
+                    &index_wtxn,
+                    &index_uid,
+                    &index,
+                    documents_before,
+                    imported_size,
+                )?;
+                let new_settings = if settings_task_ids.is_empty() {
+                    None
+                } else {
+                    Some(meilisearch_types::settings::settings(&index, &index_wtxn)?)
+                };
                 index_wtxn.commit()?;
 
+                // The history is best-effort, the versions are lost if we crash before this commit.
+                if let Some(new_settings) = new_settings {
+                    let mut wtxn = self.env.write_txn()?;
+                    if let Some((created_at, initial_settings)) = initial_settings {
+                        self.index_mapper.push_settings_version(
+                            &mut wtxn,
+                            &index_uid,
+                            Vec::new(),
+                            created_at,
+                            initial_settings.into_unchecked(),
+                        )?;
+                    }
+                    self.index_mapper.push_settings_version(
+                        &mut wtxn,
+                        &index_uid,
+                        settings_task_ids,
+                        OffsetDateTime::now_utc(),
+                        new_settings.into_unchecked(),
+                    )?;
+                    self.index_mapper.update_maintained_index(&mut wtxn, &index_uid, &index)?;
+                    wtxn.commit()?;
+                }
+
                 Ok(tasks)
             }
             Batch::IndexCreation { index_uid, primary_key, task } => {
@@ -805,16 +1045,47 @@ impl IndexScheduler {
                 if self.index_mapper.exists(&wtxn, &index_uid)? {
                     return Err(Error::IndexAlreadyExists(index_uid));
                 }
-                self.index_mapper.create_index(wtxn, &index_uid, None)?;
+                let template = self.index_mapper.index_template(&wtxn, &index_uid)?;
+                let template = template.map(|settings| settings.check());
+                self.index_mapper.create_index(wtxn, &index_uid, None, template.as_ref())?;
 
-                self.process_batch(Batch::IndexUpdate { index_uid, primary_key, task })
+                self.process_batch(Batch::IndexUpdate {
+                    index_uid,
+                    primary_key,
+                    new_index_uid: None,
+                    migrate_api_keys: false,
+                    task,
+                })
+            }
+            Batch::IndexClone { index_uid, target_uid, mut task } => {
+                let index = self.index_mapper.clone_index(&self.env, &index_uid, &target_uid)?;
+
+                let rtxn = index.read_txn()?;
+                let cloned_documents = index.number_of_documents(&rtxn)?;
+
+                task.status = Status::Succeeded;
+                task.details = Some(Details::IndexClone {
+                    target_uid,
+                    cloned_documents: Some(cloned_documents),
+                });
+                Ok(vec![task])
             }
-            Batch::IndexUpdate { index_uid, primary_key, mut task } => {
+            Batch::IndexUpdate {
+                index_uid,
+                primary_key,
+                new_index_uid,
+                migrate_api_keys,
+                mut task,
+            } => {
                 let rtxn = self.env.read_txn()?;
+                self.check_index_is_writable(&rtxn, &index_uid)?;
                 let index = self.index_mapper.index(&rtxn, &index_uid)?;
+                drop(rtxn);
 
+                // The primary key is changed in a transaction that is only committed once
+                // the index is renamed for good, an index we can't rename is left untouched.
+                let mut index_wtxn = index.write_txn()?;
                 if let Some(primary_key) = primary_key.clone() {
-                    let mut index_wtxn = index.write_txn()?;
                     let mut builder = MilliSettings::new(
                         &mut index_wtxn,
                         &index,
@@ -826,15 +1097,110 @@ impl IndexScheduler {
                         |indexing_step| debug!("update: {:?}", indexing_step),
                         || must_stop_processing.get(),
                     )?;
-                    index_wtxn.commit()?;
                 }
+                if let Some(new_index_uid) = &new_index_uid {
+                    let mut wtxn = self.env.write_txn()?;
+                    self.apply_index_rename(&mut wtxn, task.uid, &index_uid, new_index_uid)?;
+                    wtxn.commit()?;
+                }
+                index_wtxn.commit()?;
+
                 task.status = Status::Succeeded;
-                task.details = Some(Details::IndexInfo { primary_key });
+                // The index is renamed for good, the API keys can now follow it. The rename
+                // can't be undone, the task fails to report the keys that were left behind.
+                if let (Some(new_index_uid), true, Some(migration)) =
+                    (&new_index_uid, migrate_api_keys, &self.api_keys_migration)
+                {
+                    if let Err(e) = (migration.0)(&index_uid, new_index_uid) {
+                        error!(
+                            "Could not migrate the API keys of `{}` to `{}`: {}",
+                            index_uid, new_index_uid, e
+                        );
+                        task.status = Status::Failed;
+                        task.error = Some(
+                            Error::ApiKeysMigration {
+                                from: index_uid.clone(),
+                                to: new_index_uid.clone(),
+                                error: e.to_string(),
+                            }
+                            .into(),
+                        );
+                    }
+                }
+                task.details = Some(Details::IndexInfo { primary_key, new_index_uid });
+
+                Ok(vec![task])
+            }
+            Batch::IndexReadOnlyUpdate { index_uid, read_only, mut task } => {
+                let mut wtxn = self.env.write_txn()?;
+                self.index_mapper.set_read_only(&mut wtxn, &index_uid, read_only)?;
+                wtxn.commit()?;
+
+                task.status = Status::Succeeded;
+                task.details = Some(Details::IndexReadOnlyUpdate { read_only });
+                Ok(vec![task])
+            }
+            Batch::IndexCompaction { index_uid, mut task } => {
+                let (pre_compaction_size, post_compaction_size) =
+                    self.index_mapper.compact_index(&self.env, &index_uid)?;
+
+                task.status = Status::Succeeded;
+                task.details = Some(Details::IndexCompaction {
+                    pre_compaction_size: Some(pre_compaction_size),
+                    post_compaction_size: Some(post_compaction_size),
+                });
+                Ok(vec![task])
+            }
+            Batch::DocumentPurge { index_uid, mut task } => {
+                let index = {
+                    let rtxn = self.env.read_txn()?;
+                    self.check_index_is_writable(&rtxn, &index_uid)?;
+                    self.index_mapper.index(&rtxn, &index_uid)?
+                };
+
+                // Every chunk is committed on its own so that the purge doesn't hold a
+                // huge transaction and that the work done survives a cancelation.
+                let mut purged_documents = 0;
+                while purged_documents < MAX_PURGED_DOCUMENTS_PER_TASK {
+                    if self.must_stop_processing.get() {
+                        return Err(milli::Error::InternalError(
+                            milli::InternalError::AbortedIndexation,
+                        )
+                        .into());
+                    }
+
+                    let mut index_wtxn = index.write_txn()?;
+                    let index_documents = index.number_of_documents(&index_wtxn)?
+                        + index.number_of_soft_deleted_documents(&index_wtxn)?;
+                    let chunk_size = MIN_PURGE_CHUNK_SIZE.max(index_documents / PURGE_CHUNK_RATIO);
+                    let mut builder =
+                        milli::update::PurgeSoftDeletedDocuments::new(&mut index_wtxn, &index);
+                    builder.max_documents(
+                        chunk_size.min(MAX_PURGED_DOCUMENTS_PER_TASK - purged_documents),
+                    );
+                    let purged = builder.execute()?;
+                    index_wtxn.commit()?;
 
+                    if purged == 0 {
+                        break;
+                    }
+                    purged_documents += purged;
+                }
+
+                // We give the other tasks a chance to be processed before purging the rest.
+                let rtxn = index.read_txn()?;
+                if index.number_of_soft_deleted_documents(&rtxn)? != 0 {
+                    self.register_document_purge(&index_uid)?;
+                }
+
+                task.status = Status::Succeeded;
+                task.details =
+                    Some(Details::DocumentPurge { purged_documents: Some(purged_documents) });
                 Ok(vec![task])
             }
             Batch::IndexDeletion { index_uid, index_has_been_created, mut tasks } => {
                 let wtxn = self.env.write_txn()?;
+                self.check_index_is_writable(&wtxn, &index_uid)?;
 
                 // it's possible that the index doesn't exist
                 let number_of_documents = || -> Result<u64> {
@@ -898,6 +1264,26 @@ impl IndexScheduler {
                 task.status = Status::Succeeded;
                 Ok(vec![task])
             }
+            Batch::AliasUpdate { mut task } => {
+                let (alias, indexes) =
+                    if let KindWithContent::AliasUpdate { alias, indexes } = &task.kind {
+                        (alias, indexes)
+                    } else {
+                        unreachable!()
+                    };
+
+                // The alias is repointed in a single write transaction, searches
+                // either see all the old indexes or all the new ones.
+                let mut wtxn = self.env.write_txn()?;
+                if indexes.is_empty() {
+                    self.index_mapper.delete_alias(&mut wtxn, alias)?;
+                } else {
+                    self.index_mapper.put_alias(&mut wtxn, alias, indexes)?;
+                }
+                wtxn.commit()?;
+                task.status = Status::Succeeded;
+                Ok(vec![task])
+            }
         }
     }
 
@@ -943,6 +1329,99 @@ impl IndexScheduler {
         Ok(())
     }
 
+    /// The tasks writing to a read-only index can still have been enqueued
+    /// before the index was made read-only, they must fail.
+    fn check_index_is_writable(&self, rtxn: &RoTxn, index_uid: &str) -> Result<()> {
+        if self.index_mapper.is_read_only(rtxn, index_uid)? {
+            Err(Error::IndexReadOnly(index_uid.to_string()))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Make sure the uncommitted changes of `index_wtxn` keep the index within its quota.
+    ///
+    /// A batch that removes documents is always accepted so that an index above its quota
+    /// can still be cleaned up, any other batch is checked even if it only updates documents.
+    /// LMDB only reports the used size of the committed writes, so the size of the index
+    /// once the transaction is committed is estimated by adding the size of the imported
+    /// documents, `imported_size`, to it. This lets us reject the batch that would cross
+    /// the size limit instead of the ones following it.
+    fn check_index_quota(
+        &self,
+        index_wtxn: &RoTxn,
+        index_uid: &str,
+        index: &Index,
+        documents_before: u64,
+        imported_size: u64,
+    ) -> Result<()> {
+        let quota = {
+            let rtxn = self.env.read_txn()?;
+            self.index_mapper.quota(&rtxn, index_uid)?
+        };
+        if quota.is_unlimited() {
+            return Ok(());
+        }
+
+        let number_of_documents = index.number_of_documents(index_wtxn)?;
+        if number_of_documents < documents_before {
+            return Ok(());
+        }
+
+        if let Some(max_documents) = quota.max_documents {
+            if number_of_documents > max_documents {
+                return Err(Error::IndexMaxDocumentsExceeded {
+                    index: index_uid.to_string(),
+                    number_of_documents,
+                    max_documents,
+                });
+            }
+        }
+
+        if let Some(max_size) = quota.max_size_bytes {
+            let size = index.used_size()?.saturating_add(imported_size);
+            if size > max_size {
+                return Err(Error::IndexMaxSizeExceeded {
+                    index: index_uid.to_string(),
+                    size,
+                    max_size,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Rename the index `from` into `to`, the tasks enqueued before the rename
+    /// are updated to refer to the new index uid.
+    fn apply_index_rename(
+        &self,
+        wtxn: &mut RwTxn,
+        task_id: u32,
+        from: &str,
+        to: &str,
+    ) -> Result<()> {
+        // 1. Rename in the index mapper, this fails if `to` is already taken
+        self.index_mapper.rename(wtxn, from, to)?;
+
+        // 2. Get the task set for index = from that appeared before the rename task
+        let mut from_task_ids = self.index_tasks(wtxn, from)?;
+        from_task_ids.remove_range(task_id..);
+
+        // 3. from -> to in the task's KindWithContent
+        for task_id in &from_task_ids {
+            let mut task = self.get_task(wtxn, task_id)?.ok_or(Error::CorruptedTaskQueue)?;
+            swap_index_uid_in_task(&mut task, (from, to));
+            self.all_tasks.put(wtxn, &BEU32::new(task_id), &task)?;
+        }
+
+        // 4. move the tasks from indexuid = from to indexuid = to
+        self.update_index(wtxn, from, |from_tasks| *from_tasks -= &from_task_ids)?;
+        self.update_index(wtxn, to, |to_tasks| *to_tasks |= &from_task_ids)?;
+
+        Ok(())
+    }
+
     /// Process the index operation on the given index.
     ///
     /// ## Return
@@ -1019,10 +1498,15 @@ impl IndexScheduler {
                     builder = new_builder;
 
                     let user_result = match user_result {
-                        Ok(count) => Ok(DocumentAdditionResult {
-                            indexed_documents: count,
-                            number_of_documents: count, // TODO: this is wrong, we should use the value stored in the Details.
-                        }),
+                        Ok(count) => Ok((
+                            DocumentAdditionResult {
+                                indexed_documents: count,
+                                number_of_documents: count, // TODO: this is wrong, we should use the value stored in the Details.
+                            },
+                            builder.take_version_conflicts(),
+                            builder.take_generated_documents_ids(),
+                            builder.take_schema_violations(),
+                        )),
                         Err(e) => Err(milli::Error::from(e)),
                     };
 
@@ -1047,12 +1531,40 @@ impl IndexScheduler {
                 for (task, (ret, count)) in
                     tasks.iter_mut().zip(results.into_iter().zip(documents_counts))
                 {
+                    // The report of the imported files is computed when registering the task.
+                    let imported_files = match task.details.take() {
+                        Some(Details::DocumentAdditionOrUpdate { imported_files, .. }) => {
+                            imported_files
+                        }
+                        _ => None,
+                    };
                     match ret {
-                        Ok(DocumentAdditionResult { indexed_documents, number_of_documents }) => {
+                        Ok((
+                            DocumentAdditionResult { indexed_documents, .. },
+                            VersionConflicts {
+                                count: version_conflicts_count,
+                                sample: version_conflicts,
+                            },
+                            GeneratedDocumentsIds { ids: generated_ids },
+                            schema_violations,
+                        )) => {
+                            let generated_ids_count = generated_ids.len() as u64;
                             task.status = Status::Succeeded;
+                            // The documents skipped because of a version conflict were
+                            // received but not indexed.
                             task.details = Some(Details::DocumentAdditionOrUpdate {
-                                received_documents: number_of_documents,
+                                received_documents: count,
                                 indexed_documents: Some(indexed_documents),
+                                imported_files,
+                                version_conflicts: (version_conflicts_count != 0)
+                                    .then_some(version_conflicts),
+                                version_conflicts_count: (version_conflicts_count != 0)
+                                    .then_some(version_conflicts_count),
+                                generated_ids: (generated_ids_count != 0).then_some(generated_ids),
+                                generated_ids_count: (generated_ids_count != 0)
+                                    .then_some(generated_ids_count),
+                                schema_violations: (!schema_violations.is_empty())
+                                    .then_some(schema_violations),
                             });
                         }
                         Err(error) => {
@@ -1060,6 +1572,12 @@ impl IndexScheduler {
                             task.details = Some(Details::DocumentAdditionOrUpdate {
                                 received_documents: count,
                                 indexed_documents: Some(count),
+                                imported_files,
+                                version_conflicts: None,
+                                version_conflicts_count: None,
+                                generated_ids: None,
+                                generated_ids_count: None,
+                                schema_violations: None,
                             });
                             task.error = Some(error.into())
                         }
@@ -1086,13 +1604,123 @@ impl IndexScheduler {
 
                 Ok(tasks)
             }
+            IndexOperation::DocumentDeletionByFilter { index_uid: _, mut task } => {
+                let filter =
+                    if let KindWithContent::DocumentDeletionByFilter { filter_expr, .. } =
+                        &task.kind
+                    {
+                        filter_expr
+                    } else {
+                        unreachable!()
+                    };
+
+                // The filter is evaluated against the state of the index at processing time,
+                // in the same transaction as the deletion.
+                let deleted_documents = match Filter::from_json(filter)? {
+                    Some(filter) => {
+                        let candidates = filter.evaluate(index_wtxn, index)?;
+                        let mut builder = milli::update::DeleteDocuments::new(index_wtxn, index)?;
+                        builder.delete_documents(&candidates);
+                        builder.execute()?.deleted_documents
+                    }
+                    None => 0,
+                };
+
+                task.status = Status::Succeeded;
+                task.details = Some(Details::DocumentDeletionByFilter {
+                    original_filter: filter.to_string(),
+                    deleted_documents: Some(deleted_documents),
+                });
+
+                Ok(vec![task])
+            }
+            IndexOperation::DocumentExpiration { index_uid: _, mut task } => {
+                // The expired documents are computed at processing time so that the documents
+                // whose ttl has been extended since the registration of the task are kept.
+                let expired = index.expired_documents_ids(index_wtxn, OffsetDateTime::now_utc())?;
+                let deleted_documents = if expired.is_empty() {
+                    0
+                } else {
+                    let mut builder = milli::update::DeleteDocuments::new(index_wtxn, index)?;
+                    builder.delete_documents(&expired);
+                    builder.execute()?.deleted_documents
+                };
+
+                task.status = Status::Succeeded;
+                task.details = Some(Details::DocumentExpiration {
+                    deleted_documents: Some(deleted_documents),
+                });
+
+                Ok(vec![task])
+            }
+            IndexOperation::DocumentEdition { index_uid: _, mut task } => {
+                let (filter, context, function) =
+                    if let KindWithContent::DocumentEdition {
+                        filter_expr, context, function, ..
+                    } = &task.kind
+                    {
+                        (filter_expr, context, function)
+                    } else {
+                        unreachable!()
+                    };
+
+                // Like the deletion by filter, the filter is evaluated against the state
+                // of the index at processing time.
+                let documents_ids = index.documents_ids(index_wtxn)?;
+                let candidates = match filter.as_ref().map(Filter::from_json) {
+                    Some(Ok(Some(filter))) => filter.evaluate(index_wtxn, index)? & documents_ids,
+                    None | Some(Ok(None)) => documents_ids,
+                    Some(Err(e)) => return Err(e.into()),
+                };
+
+                let edited_documents = self.edit_documents_by_function(
+                    index_wtxn,
+                    index,
+                    &candidates,
+                    context.as_ref(),
+                    function,
+                )?;
+
+                task.status = Status::Succeeded;
+                task.details = Some(Details::DocumentEdition {
+                    original_filter: filter.as_ref().map(|filter| filter.to_string()),
+                    context: context.clone(),
+                    function: function.clone(),
+                    edited_documents: Some(edited_documents),
+                });
+
+                Ok(vec![task])
+            }
             IndexOperation::Settings { index_uid: _, settings, mut tasks } => {
+                let is_rollback = |task: &Task| {
+                    matches!(task.kind, KindWithContent::SettingsUpdate { is_rollback: true, .. })
+                };
+                // A rollback enqueues a whole version of the settings, its details only list
+                // the ones that differ from the settings of the index when it's applied.
+                let mut current_settings = if tasks.iter().any(is_rollback) {
+                    Some(meilisearch_types::settings::settings(index, index_wtxn)?)
+                } else {
+                    None
+                };
+
                 let indexer_config = self.index_mapper.indexer_config();
                 let mut builder = milli::update::Settings::new(index_wtxn, index, indexer_config);
 
                 for (task, (_, settings)) in tasks.iter_mut().zip(settings) {
                     let checked_settings = settings.clone().check();
-                    task.details = Some(Details::SettingsUpdate { settings: Box::new(settings) });
+                    let details = match current_settings.as_mut() {
+                        Some(current) => {
+                            let details = if is_rollback(task) {
+                                current.diff(&checked_settings).into_unchecked()
+                            } else {
+                                settings
+                            };
+                            current.apply(&checked_settings);
+                            details
+                        }
+                        None => settings,
+                    };
+                    task.details = Some(Details::SettingsUpdate { settings: Box::new(details) });
                     apply_settings_to_builder(&checked_settings, &mut builder);
 
                     // We can apply the status right now and if an update fail later
@@ -1173,6 +1801,124 @@ impl IndexScheduler {
         }
     }
 
+    /// Run the edition `function` on each of the `candidates` documents and reindex
+    /// the ones that were modified.
+    ///
+    /// The function sees the document as a `doc` variable and the optional `context`
+    /// as a constant. It is not allowed to change the primary key of a document.
+    ///
+    /// ## Return
+    /// The number of documents that were edited.
+    fn edit_documents_by_function<'txn, 'i>(
+        &self,
+        index_wtxn: &'txn mut RwTxn<'i, '_>,
+        index: &'i Index,
+        candidates: &RoaringBitmap,
+        context: Option<&milli::Object>,
+        function: &str,
+    ) -> Result<u64> {
+        let primary_key_fields = match index.primary_key_definition(index_wtxn)? {
+            Some(primary_key) => primary_key.fields().to_vec(),
+            // An index without a primary key cannot contain any document.
+            None => return Ok(0),
+        };
+        // A field of the primary key can be nested, e.g. `a.b`, and must be looked up
+        // the same way the indexer does to not let the function change it unnoticed.
+        let field_value = |document: &milli::Object, field: &str| {
+            let mut values = Vec::new();
+            let document = Value::Object(document.clone());
+            milli::update::fetch_matching_values(document, field, &mut values);
+            match values.len() {
+                0 => Value::Null,
+                1 => values.pop().unwrap(),
+                _ => Value::Array(values),
+            }
+        };
+        // The values of a composite primary key are displayed as an array.
+        let primary_key_value = |document: &milli::Object| match primary_key_fields.as_slice() {
+            [field] => field_value(document, field),
+            fields => Value::Array(fields.iter().map(|f| field_value(document, f)).collect()),
+        };
+
+        let engine = document_edition_engine();
+        let ast = engine.compile(function).map_err(Error::DocumentEditionCompilationError)?;
+        let context = context
+            .map(rhai::serde::to_dynamic)
+            .transpose()
+            .map_err(Error::DocumentEditionContextError)?;
+
+        let fields_ids_map = index.fields_ids_map(index_wtxn)?;
+        // The edited documents can be numerous, they are written on disk and not kept in RAM.
+        let file = tempfile::tempfile()?;
+        let mut builder = DocumentsBatchBuilder::new(BufWriter::new(file));
+        for (_docid, obkv) in index.documents(index_wtxn, candidates)? {
+            if self.must_stop_processing.get() {
+                return Err(
+                    milli::Error::InternalError(milli::InternalError::AbortedIndexation).into()
+                );
+            }
+
+            let document = milli::all_obkv_to_json(obkv, &fields_ids_map)?;
+            let document_id = primary_key_value(&document);
+            let runtime_error =
+                |error: Box<rhai::EvalAltResult>| Error::DocumentEditionRuntimeError {
+                    document_id: display_document_id(&document_id),
+                    error,
+                };
+
+            let mut scope = rhai::Scope::new();
+            if let Some(context) = &context {
+                scope.push_constant_dynamic("context", context.clone());
+            }
+            scope.push_dynamic("doc", rhai::serde::to_dynamic(&document).map_err(runtime_error)?);
+            engine.run_ast_with_scope(&mut scope, &ast).map_err(runtime_error)?;
+
+            let new_document = scope.get_value::<rhai::Dynamic>("doc").unwrap_or_default();
+            let new_document: milli::Object =
+                rhai::serde::from_dynamic(&new_document).map_err(runtime_error)?;
+            if primary_key_value(&new_document) != document_id {
+                return Err(Error::DocumentEditionCannotModifyPrimaryKey {
+                    document_id: display_document_id(&document_id),
+                });
+            }
+
+            // Only the documents that were really modified are reindexed.
+            if new_document != document {
+                builder.append_json_object(&new_document)?;
+            }
+        }
+
+        let edited_documents = builder.documents_count() as u64;
+        if edited_documents == 0 {
+            return Ok(0);
+        }
+
+        let file = builder.into_inner()?.into_inner().map_err(|e| e.into_error())?;
+        let reader =
+            DocumentsBatchReader::from_reader(BufReader::new(file)).map_err(milli::Error::from)?;
+
+        let must_stop_processing = self.must_stop_processing.clone();
+        let indexer_config = self.index_mapper.indexer_config();
+        let config = IndexDocumentsConfig {
+            update_method: IndexDocumentsMethod::ReplaceDocuments,
+            ..Default::default()
+        };
+        let builder = milli::update::IndexDocuments::new(
+            index_wtxn,
+            index,
+            indexer_config,
+            config,
+            |indexing_step| debug!("update: {:?}", indexing_step),
+            || must_stop_processing.get(),
+        )?;
+        let (builder, user_result) = builder.add_documents(reader)?;
+        user_result.map_err(milli::Error::from)?;
+        let addition = builder.execute()?;
+        info!("document edition done: {:?}", addition);
+
+        Ok(edited_documents)
+    }
+
     /// Delete each given task from all the databases (if it is deleteable).
     ///
     /// Return the number of tasks that were actually deleted.
@@ -1284,3 +2030,36 @@ impl IndexScheduler {
         Ok(content_files_to_delete)
     }
 }
+
+/// The maximum number of operations an edition function can run on a single document.
+const DOCUMENT_EDITION_MAX_OPERATIONS: u64 = 1_000_000;
+/// The maximum length in bytes of the strings an edition function can build.
+const DOCUMENT_EDITION_MAX_STRING_SIZE: usize = 1024 * 1024;
+/// The maximum number of elements of the arrays and objects an edition function can build.
+const DOCUMENT_EDITION_MAX_COLLECTION_SIZE: usize = 100_000;
+
+/// Create the engine used to run the edition functions.
+///
+/// The engine is sandboxed: it cannot import modules, its output is discarded and
+/// the work it can do on each document is bounded.
+fn document_edition_engine() -> rhai::Engine {
+    let mut engine = rhai::Engine::new();
+    engine.set_module_resolver(rhai::module_resolvers::DummyModuleResolver::new());
+    engine.on_print(|_| ());
+    engine.on_debug(|_, _, _| ());
+    engine.set_max_operations(DOCUMENT_EDITION_MAX_OPERATIONS);
+    engine.set_max_string_size(DOCUMENT_EDITION_MAX_STRING_SIZE);
+    engine.set_max_array_size(DOCUMENT_EDITION_MAX_COLLECTION_SIZE);
+    engine.set_max_map_size(DOCUMENT_EDITION_MAX_COLLECTION_SIZE);
+    engine.set_max_call_levels(32);
+    engine.set_max_expr_depths(64, 32);
+    engine
+}
+
+/// Display the primary key value of a document the way users sent it.
+fn display_document_id(document_id: &Value) -> String {
+    match document_id {
+        Value::String(id) => id.clone(),
+        otherwise => otherwise.to_string(),
+    }
+}
@@ -50,6 +50,24 @@ pub enum Error {
     IndexNotFound(String),
     #[error("Index `{0}` already exists.")]
     IndexAlreadyExists(String),
+    #[error("Index `{0}` is read-only.")]
+    IndexReadOnly(String),
+    #[error("Index `{index}` would contain {number_of_documents} documents but its quota is {max_documents} documents.")]
+    IndexMaxDocumentsExceeded { index: String, number_of_documents: u64, max_documents: u64 },
+    #[error("Index `{index}` would take about {size} bytes but its quota is {max_size} bytes.")]
+    IndexMaxSizeExceeded { index: String, size: u64, max_size: u64 },
+    #[error("Index template `{0}` not found.")]
+    IndexTemplateNotFound(String),
+    #[error("Alias `{0}` not found.")]
+    AliasNotFound(String),
+    #[error("Version `{version}` of the settings of index `{index}` not found.")]
+    SettingsVersionNotFound { index: String, version: u32 },
+    #[error("An alias named `{0}` already exists.")]
+    AliasAlreadyExists(String),
+    #[error(
+        "Index `{from}` has been renamed to `{to}` but its API keys could not be migrated: {error}"
+    )]
+    ApiKeysMigration { from: String, to: String, error: String },
     #[error(
         "Indexes must be declared only once during a swap. `{0}` was specified several times."
     )]
@@ -104,6 +122,14 @@ pub enum Error {
     TaskDeletionWithEmptyQuery,
     #[error("Query parameters to filter the tasks to cancel are missing. Available query parameters are: `uids`, `indexUids`, `statuses`, `types`, `beforeEnqueuedAt`, `afterEnqueuedAt`, `beforeStartedAt`, `afterStartedAt`, `beforeFinishedAt`, `afterFinishedAt`.")]
     TaskCancelationWithEmptyQuery,
+    #[error("The edition function could not be compiled: {0}")]
+    DocumentEditionCompilationError(rhai::ParseError),
+    #[error("The edition context could not be converted: {0}")]
+    DocumentEditionContextError(Box<rhai::EvalAltResult>),
+    #[error("The edition function failed on the document `{document_id}`: {error}")]
+    DocumentEditionRuntimeError { document_id: String, error: Box<rhai::EvalAltResult> },
+    #[error("The edition function cannot modify the primary key of the document `{document_id}`.")]
+    DocumentEditionCannotModifyPrimaryKey { document_id: String },
 
     #[error(transparent)]
     Dump(#[from] dump::Error),
@@ -139,6 +165,14 @@ impl ErrorCode for Error {
         match self {
             Error::IndexNotFound(_) => Code::IndexNotFound,
             Error::IndexAlreadyExists(_) => Code::IndexAlreadyExists,
+            Error::IndexReadOnly(_) => Code::IndexReadOnly,
+            Error::IndexMaxDocumentsExceeded { .. } => Code::IndexQuotaExceeded,
+            Error::IndexMaxSizeExceeded { .. } => Code::IndexQuotaExceeded,
+            Error::IndexTemplateNotFound(_) => Code::IndexTemplateNotFound,
+            Error::AliasNotFound(_) => Code::AliasNotFound,
+            Error::SettingsVersionNotFound { .. } => Code::SettingsVersionNotFound,
+            Error::AliasAlreadyExists(_) => Code::AliasAlreadyExists,
+            Error::ApiKeysMigration { .. } => Code::Internal,
             Error::SwapDuplicateIndexesFound(_) => Code::InvalidSwapDuplicateIndexFound,
             Error::SwapDuplicateIndexFound(_) => Code::InvalidSwapDuplicateIndexFound,
             Error::SwapIndexNotFound(_) => Code::InvalidSwapIndexes,
@@ -152,6 +186,12 @@ impl ErrorCode for Error {
             Error::TaskNotFound(_) => Code::TaskNotFound,
             Error::TaskDeletionWithEmptyQuery => Code::MissingTaskFilters,
             Error::TaskCancelationWithEmptyQuery => Code::MissingTaskFilters,
+            Error::DocumentEditionCompilationError(_) => Code::InvalidDocumentEditionFunction,
+            Error::DocumentEditionContextError(_) => Code::InvalidDocumentEditionContext,
+            Error::DocumentEditionRuntimeError { .. } => Code::InvalidDocumentEditionFunction,
+            Error::DocumentEditionCannotModifyPrimaryKey { .. } => {
+                Code::InvalidDocumentEditionFunction
+            }
             Error::Dump(e) => e.error_code(),
             Error::Milli(e) => e.error_code(),
             Error::ProcessBatchPanicked => Code::Internal,
@@ -1,22 +1,37 @@
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
+use std::ops::RangeInclusive;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
 use std::{fs, thread};
 
-use log::error;
-use meilisearch_types::heed::types::Str;
-use meilisearch_types::heed::{Database, Env, EnvOpenOptions, RoTxn, RwTxn};
-use meilisearch_types::milli::update::IndexerConfig;
+use log::{debug, error};
+use meilisearch_types::heed::types::{SerdeJson, Str, Unit};
+use meilisearch_types::heed::{CompactionOption, Database, Env, EnvOpenOptions, RoTxn, RwTxn};
+use meilisearch_types::index_quotas::IndexQuota;
+use meilisearch_types::index_templates::{pattern_matches, IndexTemplate};
+use meilisearch_types::milli::update::{IndexerConfig, Settings as MilliSettings, SettingsChanges};
 use meilisearch_types::milli::Index;
+use meilisearch_types::settings::{apply_settings_to_builder, Checked, Settings, Unchecked};
+use meilisearch_types::settings_history::SettingsVersion;
 use time::OffsetDateTime;
 use uuid::Uuid;
 
 use self::IndexStatus::{Available, BeingDeleted};
-use crate::uuid_codec::UuidCodec;
+use crate::uuid_codec::{UuidCodec, UuidU32Codec};
 use crate::{clamp_to_page_size, Error, Result};
 
 const INDEX_MAPPING: &str = "index-mapping";
+const ALIAS_MAPPING: &str = "alias-mapping";
+const INDEX_TEMPLATES: &str = "index-templates";
+const READ_ONLY_INDEXES: &str = "read-only-indexes";
+const INDEX_QUOTAS: &str = "index-quotas";
+const SETTINGS_HISTORY: &str = "settings-history";
+const MAINTAINED_INDEXES: &str = "maintained-indexes";
+
+/// The number of versions of the settings kept in the history of an index,
+/// the oldest ones are forgotten when a new version is recorded.
+const MAX_SETTINGS_VERSIONS: u32 = 100;
 
 /// Structure managing meilisearch's indexes.
 ///
@@ -25,6 +40,12 @@ const INDEX_MAPPING: &str = "index-mapping";
 /// 2. Opening indexes and storing references to these opened indexes
 /// 3. Accessing indexes through their uuid
 /// 4. Mapping a user-defined name to each index uuid.
+/// 5. Mapping the aliases to the names of the indexes they point to.
+/// 6. Storing the templates of settings applied to the new indexes.
+/// 7. Keeping track of the indexes that can't be written to.
+/// 8. Storing the quotas of the indexes.
+/// 9. Storing the history of the settings of the indexes.
+/// 10. Keeping track of the indexes the run loop must maintain.
 #[derive(Clone)]
 pub struct IndexMapper {
     /// Keep track of the opened indexes. Used mainly by the index resolver.
@@ -33,6 +54,26 @@ pub struct IndexMapper {
     /// Map an index name with an index uuid currently available on disk.
     pub(crate) index_mapping: Database<Str, UuidCodec>,
 
+    /// Map an alias with the names of the indexes it points to.
+    pub(crate) alias_mapping: Database<Str, SerdeJson<Vec<String>>>,
+
+    /// Map an index uid pattern with the settings applied to the indexes matching it.
+    pub(crate) index_templates: Database<Str, SerdeJson<Settings<Unchecked>>>,
+
+    /// The uuids of the read-only indexes, they follow the indexes when they are renamed or swapped.
+    pub(crate) read_only_indexes: Database<UuidCodec, Unit>,
+
+    /// The quotas of the indexes by uuid, the indexes without quota are unlimited.
+    pub(crate) index_quotas: Database<UuidCodec, SerdeJson<IndexQuota>>,
+
+    /// The successive versions of the settings of the indexes, keyed by uuid and version
+    /// so that recording a version doesn't rewrite the whole history of the index.
+    pub(crate) settings_history: Database<UuidU32Codec, SerdeJson<SettingsVersion>>,
+
+    /// The uuids of the indexes with a `ttlAttribute` or a `softDeletedPurgeThreshold`,
+    /// the only ones the periodic maintenance of the run loop looks at.
+    pub(crate) maintained_indexes: Database<UuidCodec, Unit>,
+
     /// Path to the folder where the LMDB environments of each index are.
     base_path: PathBuf,
     index_size: usize,
@@ -59,6 +100,12 @@ impl IndexMapper {
         Ok(Self {
             index_map: Arc::default(),
             index_mapping: env.create_database(Some(INDEX_MAPPING))?,
+            alias_mapping: env.create_database(Some(ALIAS_MAPPING))?,
+            index_templates: env.create_database(Some(INDEX_TEMPLATES))?,
+            read_only_indexes: env.create_database(Some(READ_ONLY_INDEXES))?,
+            index_quotas: env.create_database(Some(INDEX_QUOTAS))?,
+            settings_history: env.create_database(Some(SETTINGS_HISTORY))?,
+            maintained_indexes: env.create_database(Some(MAINTAINED_INDEXES))?,
             base_path,
             index_size,
             indexer_config: Arc::new(indexer_config),
@@ -84,11 +131,15 @@ impl IndexMapper {
     }
 
     /// Get or create the index.
+    ///
+    /// The template settings are only applied when the index is created, the
+    /// index isn't registered if they can't be applied.
     pub fn create_index(
         &self,
         mut wtxn: RwTxn,
         name: &str,
         date: Option<(OffsetDateTime, OffsetDateTime)>,
+        template: Option<&Settings<Checked>>,
     ) -> Result<Index> {
         match self.index(&wtxn, name) {
             Ok(index) => {
@@ -96,6 +147,10 @@ impl IndexMapper {
                 Ok(index)
             }
             Err(Error::IndexNotFound(_)) => {
+                if self.alias_mapping.get(&wtxn, name)?.is_some() {
+                    return Err(Error::AliasAlreadyExists(name.to_string()));
+                }
+
                 let uuid = Uuid::new_v4();
                 self.index_mapping.put(&mut wtxn, name, &uuid)?;
 
@@ -104,6 +159,15 @@ impl IndexMapper {
 
                 let index = self.create_or_open_index(&index_path, date)?;
 
+                if let Some(settings) = template {
+                    if let Err(e) = self.apply_template(&index, settings) {
+                        index.prepare_for_closing().wait();
+                        let _ = fs::remove_dir_all(&index_path);
+                        return Err(e);
+                    }
+                    self.update_maintained_index(&mut wtxn, name, &index)?;
+                }
+
                 wtxn.commit()?;
                 // TODO: it would be better to lazily create the index. But we need an Index::open function for milli.
                 if let Some(BeingDeleted) =
@@ -118,6 +182,124 @@ impl IndexMapper {
         }
     }
 
+    fn apply_template(&self, index: &Index, settings: &Settings<Checked>) -> Result<()> {
+        let mut index_wtxn = index.write_txn()?;
+        let mut builder = MilliSettings::new(&mut index_wtxn, index, &self.indexer_config);
+        apply_settings_to_builder(settings, &mut builder);
+        builder.execute(|indexing_step| debug!("update: {:?}", indexing_step), || false)?;
+        index_wtxn.commit()?;
+        Ok(())
+    }
+
+    /// Copy an index into a new index named `target`. The LMDB environment is copied and
+    /// compacted, the documents are not indexed again.
+    ///
+    /// The copy is made without holding a write transaction of the task database, the
+    /// availability of the name is checked again before registering the new index.
+    pub fn clone_index(&self, env: &Env, name: &str, target: &str) -> Result<Index> {
+        let index = {
+            let rtxn = env.read_txn()?;
+            self.check_name_is_available(&rtxn, target)?;
+            self.index(&rtxn, name)?
+        };
+
+        let uuid = Uuid::new_v4();
+        let index_path = self.base_path.join(uuid.to_string());
+        fs::create_dir_all(&index_path)?;
+        if let Err(e) = index.copy_to_path(index_path.join("data.mdb"), CompactionOption::Enabled) {
+            // We don't want to leave a partial copy on disk.
+            let _ = fs::remove_dir_all(&index_path);
+            return Err(e.into());
+        }
+        let clone = self.create_or_open_index(&index_path, None)?;
+
+        let mut wtxn = env.write_txn()?;
+        if let Err(e) = self.check_name_is_available(&wtxn, target) {
+            clone.prepare_for_closing().wait();
+            let _ = fs::remove_dir_all(&index_path);
+            return Err(e);
+        }
+        self.index_mapping.put(&mut wtxn, target, &uuid)?;
+        self.update_maintained_index(&mut wtxn, target, &clone)?;
+        wtxn.commit()?;
+        if let Some(BeingDeleted) =
+            self.index_map.write().unwrap().insert(uuid, Available(clone.clone()))
+        {
+            panic!("Uuid v4 conflict.");
+        }
+
+        Ok(clone)
+    }
+
+    /// Replace the LMDB environment of an index by a compacted copy of it and return
+    /// the on-disk sizes of the index before and after the compaction.
+    ///
+    /// The copy gets a new uuid, the readers that already opened the old environment
+    /// keep using it until they drop it, then it's removed from disk. The write transaction
+    /// is only opened once the copy is done, to swap the uuids.
+    pub fn compact_index(&self, env: &Env, name: &str) -> Result<(u64, u64)> {
+        let (uuid, index) = {
+            let rtxn = env.read_txn()?;
+            let uuid = self
+                .index_mapping
+                .get(&rtxn, name)?
+                .ok_or_else(|| Error::IndexNotFound(name.to_string()))?;
+            (uuid, self.index(&rtxn, name)?)
+        };
+        let pre_compaction_size = index.on_disk_size()?;
+
+        let compacted_uuid = Uuid::new_v4();
+        let compacted_path = self.base_path.join(compacted_uuid.to_string());
+        fs::create_dir_all(&compacted_path)?;
+        if let Err(e) =
+            index.copy_to_path(compacted_path.join("data.mdb"), CompactionOption::Enabled)
+        {
+            // We don't want to leave a partial copy on disk.
+            let _ = fs::remove_dir_all(&compacted_path);
+            return Err(e.into());
+        }
+        let compacted = self.create_or_open_index(&compacted_path, None)?;
+        let post_compaction_size = compacted.on_disk_size()?;
+
+        let mut wtxn = env.write_txn()?;
+        if self.index_mapping.get(&wtxn, name)? != Some(uuid) {
+            compacted.prepare_for_closing().wait();
+            let _ = fs::remove_dir_all(&compacted_path);
+            return Err(Error::IndexNotFound(name.to_string()));
+        }
+        // The entries identified by the uuid of the index must follow it.
+        if self.read_only_indexes.delete(&mut wtxn, &uuid)? {
+            self.read_only_indexes.put(&mut wtxn, &compacted_uuid, &())?;
+        }
+        if let Some(quota) = self.index_quotas.get(&wtxn, &uuid)? {
+            self.index_quotas.delete(&mut wtxn, &uuid)?;
+            self.index_quotas.put(&mut wtxn, &compacted_uuid, &quota)?;
+        }
+        if self.maintained_indexes.delete(&mut wtxn, &uuid)? {
+            self.maintained_indexes.put(&mut wtxn, &compacted_uuid, &())?;
+        }
+        let history = self
+            .settings_history
+            .range(&wtxn, &settings_history_range(uuid))?
+            .map(|ret| ret.map_err(Error::from))
+            .collect::<Result<Vec<_>>>()?;
+        self.settings_history.delete_range(&mut wtxn, &settings_history_range(uuid))?;
+        for ((_, version), settings_version) in history {
+            self.settings_history.put(&mut wtxn, &(compacted_uuid, version), &settings_version)?;
+        }
+        self.index_mapping.put(&mut wtxn, name, &compacted_uuid)?;
+        wtxn.commit()?;
+
+        if let Some(BeingDeleted) =
+            self.index_map.write().unwrap().insert(compacted_uuid, Available(compacted))
+        {
+            panic!("Uuid v4 conflict.");
+        }
+        self.close_and_remove_index(uuid, name);
+
+        Ok((pre_compaction_size, post_compaction_size))
+    }
+
     /// Removes the index from the mapping table and the in-memory index map
     /// but keeps the associated tasks.
     pub fn delete_index(&self, mut wtxn: RwTxn, name: &str) -> Result<()> {
@@ -128,8 +310,22 @@ impl IndexMapper {
 
         // Once we retrieved the UUID of the index we remove it from the mapping table.
         assert!(self.index_mapping.delete(&mut wtxn, name)?);
+        self.read_only_indexes.delete(&mut wtxn, &uuid)?;
+        self.index_quotas.delete(&mut wtxn, &uuid)?;
+        self.maintained_indexes.delete(&mut wtxn, &uuid)?;
+        self.settings_history.delete_range(&mut wtxn, &settings_history_range(uuid))?;
+        // The aliases must not point to an index that doesn't exist anymore.
+        self.remove_index_from_aliases(&mut wtxn, name)?;
 
         wtxn.commit()?;
+        self.close_and_remove_index(uuid, name);
+
+        Ok(())
+    }
+
+    /// Forbid the index to be opened again and remove it from disk once every
+    /// reference to its environment has been dropped.
+    fn close_and_remove_index(&self, uuid: Uuid, name: &str) {
         // We remove the index from the in-memory index map.
         let mut lock = self.index_map.write().unwrap();
         let closing_event = match lock.insert(uuid, BeingDeleted) {
@@ -163,8 +359,6 @@ impl IndexMapper {
                 assert!(matches!(index_map.write().unwrap().remove(&uuid), Some(BeingDeleted)));
             })
             .unwrap();
-
-        Ok(())
     }
 
     pub fn exists(&self, rtxn: &RoTxn, name: &str) -> Result<bool> {
@@ -240,11 +434,328 @@ impl IndexMapper {
         Ok(())
     }
 
+    /// Gives the uuid of the index `name` to `new_name`, the aliases pointing
+    /// to the index are updated accordingly.
+    pub fn rename(&self, wtxn: &mut RwTxn, name: &str, new_name: &str) -> Result<()> {
+        self.check_name_is_available(wtxn, new_name)?;
+        let uuid = self
+            .index_mapping
+            .get(wtxn, name)?
+            .ok_or_else(|| Error::IndexNotFound(name.to_string()))?;
+
+        self.index_mapping.delete(wtxn, name)?;
+        self.index_mapping.put(wtxn, new_name, &uuid)?;
+
+        let aliases: Vec<_> = self
+            .aliases(wtxn)?
+            .into_iter()
+            .filter(|(_, indexes)| indexes.iter().any(|index| index == name))
+            .collect();
+        for (alias, mut indexes) in aliases {
+            for index in indexes.iter_mut().filter(|index| *index == name) {
+                *index = new_name.to_string();
+            }
+            self.alias_mapping.put(wtxn, &alias, &indexes)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns an error if an index or an alias is already named `name`.
+    pub fn check_name_is_available(&self, rtxn: &RoTxn, name: &str) -> Result<()> {
+        if self.exists(rtxn, name)? {
+            Err(Error::IndexAlreadyExists(name.to_string()))
+        } else if self.alias_mapping.get(rtxn, name)?.is_some() {
+            Err(Error::AliasAlreadyExists(name.to_string()))
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn index_exists(&self, rtxn: &RoTxn, name: &str) -> Result<bool> {
         Ok(self.index_mapping.get(rtxn, name)?.is_some())
     }
 
+    /// Return the names of the indexes an alias points to, `None` if there is no such alias.
+    pub fn alias(&self, rtxn: &RoTxn, name: &str) -> Result<Option<Vec<String>>> {
+        Ok(self.alias_mapping.get(rtxn, name)?)
+    }
+
+    /// Return all the aliases with the names of the indexes they point to.
+    pub fn aliases(&self, rtxn: &RoTxn) -> Result<Vec<(String, Vec<String>)>> {
+        self.alias_mapping
+            .iter(rtxn)?
+            .map(|ret| ret.map(|(name, indexes)| (name.to_string(), indexes)).map_err(Error::from))
+            .collect()
+    }
+
+    /// Create or repoint an alias, all the indexes it points to must exist.
+    pub fn put_alias(&self, wtxn: &mut RwTxn, name: &str, indexes: &[String]) -> Result<()> {
+        if self.index_exists(wtxn, name)? {
+            return Err(Error::IndexAlreadyExists(name.to_string()));
+        }
+        for index in indexes {
+            if !self.index_exists(wtxn, index)? {
+                return Err(Error::IndexNotFound(index.clone()));
+            }
+        }
+
+        self.alias_mapping.put(wtxn, name, &indexes.to_vec())?;
+
+        Ok(())
+    }
+
+    /// Remove an alias, the indexes it was pointing to are left untouched.
+    pub fn delete_alias(&self, wtxn: &mut RwTxn, name: &str) -> Result<()> {
+        if self.alias_mapping.delete(wtxn, name)? {
+            Ok(())
+        } else {
+            Err(Error::AliasNotFound(name.to_string()))
+        }
+    }
+
+    /// Remove an index from all the aliases, the aliases that don't point
+    /// to any index anymore are removed too.
+    fn remove_index_from_aliases(&self, wtxn: &mut RwTxn, index: &str) -> Result<()> {
+        let aliases: Vec<_> = self
+            .aliases(wtxn)?
+            .into_iter()
+            .filter(|(_, indexes)| indexes.iter().any(|name| name == index))
+            .collect();
+
+        for (alias, mut indexes) in aliases {
+            indexes.retain(|name| name != index);
+            if indexes.is_empty() {
+                self.alias_mapping.delete(wtxn, &alias)?;
+            } else {
+                self.alias_mapping.put(wtxn, &alias, &indexes)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Return the indexes a name refers to, either the index with this name or
+    /// all the indexes the alias with this name points to.
+    pub fn resolve(&self, rtxn: &RoTxn, name: &str) -> Result<Vec<(String, Index)>> {
+        if self.index_exists(rtxn, name)? {
+            return Ok(vec![(name.to_string(), self.index(rtxn, name)?)]);
+        }
+
+        match self.alias(rtxn, name)? {
+            Some(indexes) => indexes
+                .into_iter()
+                .map(|index_name| self.index(rtxn, &index_name).map(|index| (index_name, index)))
+                .collect(),
+            None => Err(Error::IndexNotFound(name.to_string())),
+        }
+    }
+
+    /// Return the settings of the template to apply to a new index, the template with
+    /// the longest pattern is chosen when several patterns match the index uid.
+    pub fn index_template(&self, rtxn: &RoTxn, name: &str) -> Result<Option<Settings<Unchecked>>> {
+        let mut best: Option<(&str, Settings<Unchecked>)> = None;
+        for result in self.index_templates.iter(rtxn)? {
+            let (pattern, settings) = result?;
+            let is_better = best.as_ref().map_or(true, |(best, _)| pattern.len() > best.len());
+            if is_better && pattern_matches(pattern, name) {
+                best = Some((pattern, settings));
+            }
+        }
+        Ok(best.map(|(_, settings)| settings))
+    }
+
+    /// Return all the index templates.
+    pub fn index_templates(&self, rtxn: &RoTxn) -> Result<Vec<IndexTemplate>> {
+        self.index_templates
+            .iter(rtxn)?
+            .map(|ret| {
+                ret.map(|(pattern, settings)| IndexTemplate {
+                    pattern: pattern.to_string(),
+                    settings,
+                })
+                .map_err(Error::from)
+            })
+            .collect()
+    }
+
+    /// Check the settings of a template against a new index, the templates that would
+    /// make the creation of the indexes fail are rejected this way.
+    pub fn check_index_template(&self, template: &IndexTemplate) -> Result<()> {
+        let mut changes = SettingsChanges::default();
+        apply_settings_to_builder(&template.settings.clone().check(), &mut changes);
+        Ok(changes.validate(None)?)
+    }
+
+    /// Create or replace the template of a pattern.
+    pub fn put_index_template(&self, wtxn: &mut RwTxn, template: &IndexTemplate) -> Result<()> {
+        Ok(self.index_templates.put(wtxn, &template.pattern, &template.settings)?)
+    }
+
+    pub fn delete_index_template(&self, wtxn: &mut RwTxn, pattern: &str) -> Result<()> {
+        if self.index_templates.delete(wtxn, pattern)? {
+            Ok(())
+        } else {
+            Err(Error::IndexTemplateNotFound(pattern.to_string()))
+        }
+    }
+
+    /// Return whether the index is read-only, `false` if there is no index with this name.
+    pub fn is_read_only(&self, rtxn: &RoTxn, name: &str) -> Result<bool> {
+        match self.index_mapping.get(rtxn, name)? {
+            Some(uuid) => Ok(self.read_only_indexes.get(rtxn, &uuid)?.is_some()),
+            None => Ok(false),
+        }
+    }
+
+    pub fn set_read_only(&self, wtxn: &mut RwTxn, name: &str, read_only: bool) -> Result<()> {
+        let uuid = self
+            .index_mapping
+            .get(wtxn, name)?
+            .ok_or_else(|| Error::IndexNotFound(name.to_string()))?;
+        if read_only {
+            self.read_only_indexes.put(wtxn, &uuid, &())?;
+        } else {
+            self.read_only_indexes.delete(wtxn, &uuid)?;
+        }
+        Ok(())
+    }
+
+    /// Return the indexes the run loop must maintain, may open them if they weren't already opened.
+    pub fn maintained_indexes(&self, rtxn: &RoTxn) -> Result<Vec<(String, Index)>> {
+        let mut indexes = Vec::new();
+        for ret in self.index_mapping.iter(rtxn)? {
+            let (name, uuid) = ret?;
+            if self.maintained_indexes.get(rtxn, &uuid)?.is_some() {
+                indexes.push((name.to_string(), self.index(rtxn, name)?));
+            }
+        }
+        Ok(indexes)
+    }
+
+    /// Record whether the settings of the index require the periodic maintenance of the
+    /// run loop. Must be called every time the settings of an index may have changed.
+    pub fn update_maintained_index(
+        &self,
+        wtxn: &mut RwTxn,
+        name: &str,
+        index: &Index,
+    ) -> Result<()> {
+        let uuid = self
+            .index_mapping
+            .get(wtxn, name)?
+            .ok_or_else(|| Error::IndexNotFound(name.to_string()))?;
+        let index_rtxn = index.read_txn()?;
+        if index.ttl_field(&index_rtxn)?.is_some()
+            || index.soft_deleted_purge_threshold(&index_rtxn)?.is_some()
+        {
+            self.maintained_indexes.put(wtxn, &uuid, &())?;
+        } else {
+            self.maintained_indexes.delete(wtxn, &uuid)?;
+        }
+        Ok(())
+    }
+
+    pub fn quota(&self, rtxn: &RoTxn, name: &str) -> Result<IndexQuota> {
+        let uuid = self
+            .index_mapping
+            .get(rtxn, name)?
+            .ok_or_else(|| Error::IndexNotFound(name.to_string()))?;
+        Ok(self.index_quotas.get(rtxn, &uuid)?.unwrap_or_default())
+    }
+
+    pub fn set_quota(&self, wtxn: &mut RwTxn, name: &str, quota: &IndexQuota) -> Result<()> {
+        let uuid = self
+            .index_mapping
+            .get(wtxn, name)?
+            .ok_or_else(|| Error::IndexNotFound(name.to_string()))?;
+        if quota.is_unlimited() {
+            self.index_quotas.delete(wtxn, &uuid)?;
+        } else {
+            self.index_quotas.put(wtxn, &uuid, quota)?;
+        }
+        Ok(())
+    }
+
+    pub fn settings_history(&self, rtxn: &RoTxn, name: &str) -> Result<Vec<SettingsVersion>> {
+        let uuid = self
+            .index_mapping
+            .get(rtxn, name)?
+            .ok_or_else(|| Error::IndexNotFound(name.to_string()))?;
+        self.settings_history
+            .range(rtxn, &settings_history_range(uuid))?
+            .map(|ret| ret.map(|(_, settings_version)| settings_version).map_err(Error::from))
+            .collect()
+    }
+
+    pub fn settings_version(
+        &self,
+        rtxn: &RoTxn,
+        name: &str,
+        version: u32,
+    ) -> Result<Option<SettingsVersion>> {
+        let uuid = self
+            .index_mapping
+            .get(rtxn, name)?
+            .ok_or_else(|| Error::IndexNotFound(name.to_string()))?;
+        Ok(self.settings_history.get(rtxn, &(uuid, version))?)
+    }
+
+    /// Whether at least one version of the settings of the index has been recorded.
+    pub fn has_settings_history(&self, rtxn: &RoTxn, name: &str) -> Result<bool> {
+        let uuid = self
+            .index_mapping
+            .get(rtxn, name)?
+            .ok_or_else(|| Error::IndexNotFound(name.to_string()))?;
+        Ok(self.settings_history.range(rtxn, &settings_history_range(uuid))?.next().is_some())
+    }
+
+    /// Append a new version of the settings to the history of the index and return its number,
+    /// only the last [`MAX_SETTINGS_VERSIONS`] versions are kept.
+    pub fn push_settings_version(
+        &self,
+        wtxn: &mut RwTxn,
+        name: &str,
+        task_uids: Vec<u32>,
+        applied_at: OffsetDateTime,
+        settings: Settings<Unchecked>,
+    ) -> Result<u32> {
+        let uuid = self
+            .index_mapping
+            .get(wtxn, name)?
+            .ok_or_else(|| Error::IndexNotFound(name.to_string()))?;
+        let last = self.settings_history.rev_range(wtxn, &settings_history_range(uuid))?.next();
+        let version = last.transpose()?.map_or(1, |((_, last), _)| last + 1);
+        let settings_version = SettingsVersion { version, task_uids, applied_at, settings };
+        self.settings_history.put(wtxn, &(uuid, version), &settings_version)?;
+        if let Some(last_forgotten) = version.checked_sub(MAX_SETTINGS_VERSIONS) {
+            self.settings_history.delete_range(wtxn, &((uuid, 0)..=(uuid, last_forgotten)))?;
+        }
+        Ok(version)
+    }
+
+    /// Insert a version of the settings in the history of the index as is,
+    /// replacing the version with the same number if there is one.
+    pub fn put_settings_version(
+        &self,
+        wtxn: &mut RwTxn,
+        name: &str,
+        settings_version: &SettingsVersion,
+    ) -> Result<()> {
+        let uuid = self
+            .index_mapping
+            .get(wtxn, name)?
+            .ok_or_else(|| Error::IndexNotFound(name.to_string()))?;
+        self.settings_history.put(wtxn, &(uuid, settings_version.version), settings_version)?;
+        Ok(())
+    }
+
     pub fn indexer_config(&self) -> &IndexerConfig {
         &self.indexer_config
     }
 }
+
+/// The range of keys of the history of the settings of an index.
+fn settings_history_range(uuid: Uuid) -> RangeInclusive<(Uuid, u32)> {
+    (uuid, 0)..=(uuid, u32::MAX)
+}
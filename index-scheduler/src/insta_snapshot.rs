@@ -167,19 +167,44 @@ fn snapshot_details(d: &Details) -> String {
         Details::DocumentAdditionOrUpdate {
             received_documents,
             indexed_documents,
+            imported_files: _,
+            version_conflicts: _,
+            version_conflicts_count: _,
+            generated_ids: _,
+            generated_ids_count: _,
+            schema_violations: _,
         } => {
             format!("{{ received_documents: {received_documents}, indexed_documents: {indexed_documents:?} }}")
         }
         Details::SettingsUpdate { settings } => {
             format!("{{ settings: {settings:?} }}")
         }
-        Details::IndexInfo { primary_key } => {
+        Details::IndexInfo { primary_key, new_index_uid: None } => {
             format!("{{ primary_key: {primary_key:?} }}")
         }
+        Details::IndexInfo { primary_key, new_index_uid: Some(new_index_uid) } => {
+            format!("{{ primary_key: {primary_key:?}, new_index_uid: {new_index_uid:?} }}")
+        }
         Details::DocumentDeletion {
             provided_ids: received_document_ids,
             deleted_documents,
         } => format!("{{ received_document_ids: {received_document_ids}, deleted_documents: {deleted_documents:?} }}"),
+        Details::DocumentDeletionByFilter {
+            original_filter,
+            deleted_documents,
+        } => format!("{{ original_filter: {original_filter}, deleted_documents: {deleted_documents:?} }}"),
+        Details::DocumentExpiration { deleted_documents } => {
+            format!("{{ deleted_documents: {deleted_documents:?} }}")
+        },
+        Details::DocumentPurge { purged_documents } => {
+            format!("{{ purged_documents: {purged_documents:?} }}")
+        },
+        Details::DocumentEdition {
+            original_filter,
+            context,
+            function,
+            edited_documents,
+        } => format!("{{ original_filter: {original_filter:?}, context: {context:?}, function: {function:?}, edited_documents: {edited_documents:?} }}"),
         Details::ClearAll { deleted_documents } => {
             format!("{{ deleted_documents: {deleted_documents:?} }}")
         },
@@ -200,9 +225,21 @@ fn snapshot_details(d: &Details) -> String {
         Details::Dump { dump_uid } => {
             format!("{{ dump_uid: {dump_uid:?} }}")
         },
+        Details::IndexClone { target_uid, cloned_documents } => {
+            format!("{{ target_uid: {target_uid:?}, cloned_documents: {cloned_documents:?} }}")
+        }
+        Details::IndexReadOnlyUpdate { read_only } => {
+            format!("{{ read_only: {read_only:?} }}")
+        }
+        Details::IndexCompaction { pre_compaction_size, post_compaction_size } => {
+            format!("{{ pre_compaction_size: {pre_compaction_size:?}, post_compaction_size: {post_compaction_size:?} }}")
+        }
         Details::IndexSwap { swaps } => {
             format!("{{ swaps: {swaps:?} }}")
         }
+        Details::AliasUpdate { alias, indexes } => {
+            format!("{{ alias: {alias:?}, indexes: {indexes:?} }}")
+        }
     }
 }
 
@@ -35,7 +35,7 @@ use std::path::PathBuf;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering::Relaxed;
 use std::sync::{Arc, RwLock};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use dump::{KindDump, TaskDump, UpdateFile};
 pub use error::Error;
@@ -43,11 +43,16 @@ use file_store::FileStore;
 use meilisearch_types::error::ResponseError;
 use meilisearch_types::heed::types::{OwnedType, SerdeBincode, SerdeJson, Str};
 use meilisearch_types::heed::{self, Database, Env, RoTxn};
+use meilisearch_types::index_aliases::IndexAlias;
+use meilisearch_types::index_quotas::IndexQuota;
+use meilisearch_types::index_templates::IndexTemplate;
 use meilisearch_types::milli;
 use meilisearch_types::milli::documents::DocumentsBatchBuilder;
-use meilisearch_types::milli::update::IndexerConfig;
+use meilisearch_types::milli::update::{IndexerConfig, SettingsChanges, SettingsImpact};
 use meilisearch_types::milli::{CboRoaringBitmapCodec, Index, RoaringBitmapCodec, BEU32};
-use meilisearch_types::tasks::{Kind, KindWithContent, Status, Task};
+use meilisearch_types::settings::{apply_settings_to_builder, Checked, Settings, Unchecked};
+use meilisearch_types::settings_history::SettingsVersion;
+use meilisearch_types::tasks::{Details, Kind, KindWithContent, Status, Task};
 use roaring::RoaringBitmap;
 use synchronoise::SignalEvent;
 use time::OffsetDateTime;
@@ -60,6 +65,10 @@ use crate::utils::{check_index_swap_validity, clamp_to_page_size};
 pub(crate) type BEI128 =
     meilisearch_types::heed::zerocopy::I128<meilisearch_types::heed::byteorder::BE>;
 
+/// How often the run loop looks for indexes containing expired documents
+/// or too many soft-deleted documents.
+const INDEXES_MAINTENANCE_INTERVAL: Duration = Duration::from_secs(60);
+
 /// Defines a subset of tasks to be retrieved from the [`IndexScheduler`].
 ///
 /// An empty/default query (where each field is set to `None`) matches all tasks.
@@ -211,6 +220,16 @@ pub enum Breakpoint {
     InsideProcessBatch,
 }
 
+/// Moves the API keys restricted to an index from its old uid to its new one.
+#[derive(Clone)]
+pub struct ApiKeysMigration(pub Arc<dyn Fn(&str, &str) -> anyhow::Result<()> + Send + Sync>);
+
+impl std::fmt::Debug for ApiKeysMigration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ApiKeysMigration").finish_non_exhaustive()
+    }
+}
+
 #[derive(Debug)]
 pub struct IndexSchedulerOptions {
     /// The path to the version file of Meilisearch.
@@ -236,6 +255,8 @@ pub struct IndexSchedulerOptions {
     /// Set to `true` iff the index scheduler is allowed to automatically
     /// batch tasks together, to process multiple tasks at once.
     pub autobatching_enabled: bool,
+    /// Called to migrate the API keys of an index once it has been renamed.
+    pub api_keys_migration: Option<ApiKeysMigration>,
 }
 
 /// Structure which holds meilisearch's indexes and schedules the tasks
@@ -285,6 +306,9 @@ pub struct IndexScheduler {
     /// Whether auto-batching is enabled or not.
     pub(crate) autobatching_enabled: bool,
 
+    /// Migrates the API keys of the renamed indexes.
+    pub(crate) api_keys_migration: Option<ApiKeysMigration>,
+
     /// The path used to create the dumps.
     pub(crate) dumps_path: PathBuf,
 
@@ -334,6 +358,7 @@ impl IndexScheduler {
             index_mapper: self.index_mapper.clone(),
             wake_up: self.wake_up.clone(),
             autobatching_enabled: self.autobatching_enabled,
+            api_keys_migration: self.api_keys_migration.clone(),
             snapshots_path: self.snapshots_path.clone(),
             dumps_path: self.dumps_path.clone(),
             auth_path: self.auth_path.clone(),
@@ -361,7 +386,7 @@ impl IndexScheduler {
         std::fs::create_dir_all(&options.dumps_path)?;
 
         let env = heed::EnvOpenOptions::new()
-            .max_dbs(10)
+            .max_dbs(15)
             .map_size(clamp_to_page_size(options.task_db_size))
             .open(options.tasks_path)?;
         let file_store = FileStore::new(&options.update_file_path)?;
@@ -389,6 +414,7 @@ impl IndexScheduler {
             // we want to start the loop right away in case meilisearch was ctrl+Ced while processing things
             wake_up: Arc::new(SignalEvent::auto(true)),
             autobatching_enabled: options.autobatching_enabled,
+            api_keys_migration: options.api_keys_migration,
             dumps_path: options.dumps_path,
             snapshots_path: options.snapshots_path,
             auth_path: options.auth_path,
@@ -422,8 +448,18 @@ impl IndexScheduler {
                 #[cfg(test)]
                 run.breakpoint(Breakpoint::Init);
 
+                let mut last_maintenance = Instant::now();
                 loop {
-                    run.wake_up.wait();
+                    // Timing out is a wake up like any other, the maintenance tasks
+                    // are processed by the tick that directly follows their registration.
+                    run.wake_up.wait_timeout(INDEXES_MAINTENANCE_INTERVAL);
+
+                    if last_maintenance.elapsed() >= INDEXES_MAINTENANCE_INTERVAL {
+                        last_maintenance = Instant::now();
+                        if let Err(e) = run.register_indexes_maintenance() {
+                            log::error!("{}", e);
+                        }
+                    }
 
                     match run.tick() {
                         Ok(0) => (),
@@ -467,6 +503,141 @@ impl IndexScheduler {
         self.index_mapper.indexes(&rtxn)
     }
 
+    /// Return and open the indexes a name refers to, the index itself or
+    /// the indexes of the alias with this name.
+    pub fn resolve_indexes(&self, name: &str) -> Result<Vec<(String, Index)>> {
+        let rtxn = self.env.read_txn()?;
+        self.index_mapper.resolve(&rtxn, name)
+    }
+
+    /// Return the names of the indexes an alias points to.
+    pub fn alias(&self, name: &str) -> Result<Vec<String>> {
+        let rtxn = self.env.read_txn()?;
+        self.index_mapper.alias(&rtxn, name)?.ok_or_else(|| Error::AliasNotFound(name.to_string()))
+    }
+
+    /// Return all the aliases with the names of the indexes they point to.
+    pub fn aliases(&self) -> Result<Vec<(String, Vec<String>)>> {
+        let rtxn = self.env.read_txn()?;
+        self.index_mapper.aliases(&rtxn)
+    }
+
+    /// Create or repoint an alias without registering a task, e.g. to import a dump.
+    pub fn put_alias(&self, alias: &IndexAlias) -> Result<()> {
+        let mut wtxn = self.env.write_txn()?;
+        self.index_mapper.put_alias(&mut wtxn, &alias.uid, &alias.indexes)?;
+        wtxn.commit()?;
+        Ok(())
+    }
+
+    /// Return whether the index is read-only.
+    pub fn is_index_read_only(&self, name: &str) -> Result<bool> {
+        let rtxn = self.env.read_txn()?;
+        self.index_mapper.is_read_only(&rtxn, name)
+    }
+
+    /// Return the quota of the index, the limits that are not set are unlimited.
+    pub fn index_quota(&self, name: &str) -> Result<IndexQuota> {
+        let rtxn = self.env.read_txn()?;
+        self.index_mapper.quota(&rtxn, name)
+    }
+
+    /// Return the versions of the settings of the index, from the oldest to the newest.
+    ///
+    /// The history is best-effort: a version is recorded after the settings are committed
+    /// to the index, a crash in between leaves the update out of the history.
+    pub fn settings_history(&self, name: &str) -> Result<Vec<SettingsVersion>> {
+        let rtxn = self.env.read_txn()?;
+        self.index_mapper.settings_history(&rtxn, name)
+    }
+
+    /// Return the given version of the settings of the index.
+    pub fn settings_version(&self, name: &str, version: u32) -> Result<SettingsVersion> {
+        let rtxn = self.env.read_txn()?;
+        self.index_mapper
+            .settings_version(&rtxn, name, version)?
+            .ok_or_else(|| Error::SettingsVersionNotFound { index: name.to_string(), version })
+    }
+
+    /// Insert a version of the settings in the history of the index, used to import the dumps.
+    pub fn put_settings_version(
+        &self,
+        name: &str,
+        settings_version: &SettingsVersion,
+    ) -> Result<()> {
+        let mut wtxn = self.env.write_txn()?;
+        self.index_mapper.put_settings_version(&mut wtxn, name, settings_version)?;
+        wtxn.commit()?;
+        Ok(())
+    }
+
+    /// Compute what applying the settings would do to the index, without changing it.
+    ///
+    /// The impact is computed from a read transaction, it doesn't wait for the batch
+    /// currently writing to the index.
+    pub fn settings_dry_run(
+        &self,
+        name: &str,
+        settings: &Settings<Checked>,
+    ) -> Result<SettingsImpact> {
+        let index = self.index(name)?;
+        let rtxn = index.read_txn()?;
+        let mut changes = SettingsChanges::default();
+        apply_settings_to_builder(settings, &mut changes);
+        // The dry run must reject the settings the task would fail on.
+        changes.validate(Some((&index, &rtxn)))?;
+        Ok(changes.impact(&index, &rtxn)?)
+    }
+
+    /// Make the index read-only or writable again without enqueuing a task.
+    ///
+    /// This is only used to restore the indexes of a dump.
+    pub fn set_index_read_only(&self, name: &str, read_only: bool) -> Result<()> {
+        let mut wtxn = self.env.write_txn()?;
+        self.index_mapper.set_read_only(&mut wtxn, name, read_only)?;
+        wtxn.commit()?;
+        Ok(())
+    }
+
+    /// Replace the quota of the index, it's only checked by the batches processed afterward.
+    pub fn update_index_quota(&self, name: &str, quota: &IndexQuota) -> Result<()> {
+        let mut wtxn = self.env.write_txn()?;
+        self.index_mapper.set_quota(&mut wtxn, name, quota)?;
+        wtxn.commit()?;
+        Ok(())
+    }
+
+    /// Return all the index templates.
+    pub fn index_templates(&self) -> Result<Vec<IndexTemplate>> {
+        let rtxn = self.env.read_txn()?;
+        self.index_mapper.index_templates(&rtxn)
+    }
+
+    /// Return the settings of the template with the given pattern.
+    pub fn index_template(&self, pattern: &str) -> Result<Settings<Unchecked>> {
+        let rtxn = self.env.read_txn()?;
+        self.index_mapper
+            .index_templates
+            .get(&rtxn, pattern)?
+            .ok_or_else(|| Error::IndexTemplateNotFound(pattern.to_string()))
+    }
+
+    /// Create or replace an index template, it is only applied to the indexes created afterward.
+    pub fn put_index_template(&self, template: &IndexTemplate) -> Result<()> {
+        self.index_mapper.check_index_template(template)?;
+        let mut wtxn = self.env.write_txn()?;
+        self.index_mapper.put_index_template(&mut wtxn, template)?;
+        wtxn.commit()?;
+        Ok(())
+    }
+
+    pub fn delete_index_template(&self, pattern: &str) -> Result<()> {
+        let mut wtxn = self.env.write_txn()?;
+        self.index_mapper.delete_index_template(&mut wtxn, pattern)?;
+        wtxn.commit()?;
+        Ok(())
+    }
+
     /// Return the task ids matched by the given query from the index scheduler's point of view.
     pub(crate) fn get_task_ids(&self, rtxn: &RoTxn, query: &Query) -> Result<RoaringBitmap> {
         let ProcessingTasks {
@@ -689,6 +860,18 @@ impl IndexScheduler {
     ///
     /// If it fails and data was associated with the task, it tries to delete the associated data.
     pub fn register(&self, kind: KindWithContent) -> Result<Task> {
+        let details = kind.default_details();
+        self.register_with_details(kind, details)
+    }
+
+    /// Register a new task in the scheduler with details that can't be deduced from its kind.
+    ///
+    /// If it fails and data was associated with the task, it tries to delete the associated data.
+    pub fn register_with_details(
+        &self,
+        kind: KindWithContent,
+        details: Option<Details>,
+    ) -> Result<Task> {
         let mut wtxn = self.env.write_txn()?;
 
         let mut task = Task {
@@ -698,7 +881,7 @@ impl IndexScheduler {
             finished_at: None,
             error: None,
             canceled_by: None,
-            details: kind.default_details(),
+            details,
             status: Status::Enqueued,
             kind: kind.clone(),
         };
@@ -708,6 +891,12 @@ impl IndexScheduler {
         // If the register task is an index swap task, verify that it is well-formed
         // (that it does not contain duplicate indexes).
         check_index_swap_validity(&task)?;
+        // A read-only index only accepts the tasks that don't write to it.
+        if let Some(index_uid) = utils::written_index(&task.kind) {
+            if self.index_mapper.is_read_only(&wtxn, index_uid)? {
+                return Err(Error::IndexReadOnly(index_uid.to_string()));
+            }
+        }
 
         // Get rid of the mutability.
         let task = task;
@@ -806,15 +995,36 @@ impl IndexScheduler {
                     documents_ids,
                     index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
                 },
+                KindDump::DocumentDeletionByFilter { filter } => {
+                    KindWithContent::DocumentDeletionByFilter {
+                        filter_expr: filter,
+                        index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
+                    }
+                }
+                KindDump::DocumentExpiration => KindWithContent::DocumentExpiration {
+                    index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
+                },
+                KindDump::DocumentEdition { filter, context, function } => {
+                    KindWithContent::DocumentEdition {
+                        index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
+                        filter_expr: filter,
+                        context,
+                        function,
+                    }
+                }
                 KindDump::DocumentClear => KindWithContent::DocumentClear {
                     index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
                 },
-                KindDump::Settings { settings, is_deletion, allow_index_creation } => {
+                KindDump::DocumentPurge => KindWithContent::DocumentPurge {
+                    index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
+                },
+                KindDump::Settings { settings, is_deletion, allow_index_creation, is_rollback } => {
                     KindWithContent::SettingsUpdate {
                         index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
                         new_settings: settings,
                         is_deletion,
                         allow_index_creation,
+                        is_rollback,
                     }
                 }
                 KindDump::IndexDeletion => KindWithContent::IndexDeletion {
@@ -824,11 +1034,31 @@ impl IndexScheduler {
                     index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
                     primary_key,
                 },
-                KindDump::IndexUpdate { primary_key } => KindWithContent::IndexUpdate {
+                KindDump::IndexUpdate { primary_key, new_index_uid, migrate_api_keys } => {
+                    KindWithContent::IndexUpdate {
+                        index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
+                        primary_key,
+                        new_index_uid,
+                        migrate_api_keys,
+                    }
+                }
+                KindDump::IndexClone { target_uid } => KindWithContent::IndexClone {
+                    index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
+                    target_uid,
+                },
+                KindDump::IndexReadOnlyUpdate { read_only } => {
+                    KindWithContent::IndexReadOnlyUpdate {
+                        index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
+                        read_only,
+                    }
+                }
+                KindDump::IndexCompaction => KindWithContent::IndexCompaction {
                     index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
-                    primary_key,
                 },
                 KindDump::IndexSwap { swaps } => KindWithContent::IndexSwap { swaps },
+                KindDump::AliasUpdate { alias, indexes } => {
+                    KindWithContent::AliasUpdate { alias, indexes }
+                }
                 KindDump::TaskCancelation { query, tasks } => {
                     KindWithContent::TaskCancelation { query, tasks }
                 }
@@ -871,10 +1101,20 @@ impl IndexScheduler {
         date: Option<(OffsetDateTime, OffsetDateTime)>,
     ) -> Result<Index> {
         let wtxn = self.env.write_txn()?;
-        let index = self.index_mapper.create_index(wtxn, name, date)?;
+        let index = self.index_mapper.create_index(wtxn, name, date, None)?;
         Ok(index)
     }
 
+    /// Record whether the index needs the periodic maintenance of the run loop, must be
+    /// called after updating the settings of an index without registering a task.
+    pub fn update_maintained_index(&self, name: &str) -> Result<()> {
+        let mut wtxn = self.env.write_txn()?;
+        let index = self.index_mapper.index(&wtxn, name)?;
+        self.index_mapper.update_maintained_index(&mut wtxn, name, &index)?;
+        wtxn.commit()?;
+        Ok(())
+    }
+
     /// Create a file and register it in the index scheduler.
     ///
     /// The returned file and uuid can be used to associate
@@ -896,6 +1136,85 @@ impl IndexScheduler {
         Ok(self.file_store.delete(uuid)?)
     }
 
+    /// Look for the maintenance the writable indexes need in a single pass over the
+    /// indexes with a `ttlAttribute` or a `softDeletedPurgeThreshold`.
+    ///
+    /// Registers a [`KindWithContent::DocumentExpiration`] task for every index containing
+    /// expired documents, unless such a task is already enqueued for this index, and a
+    /// [`KindWithContent::DocumentPurge`] task for every index whose proportion of
+    /// soft-deleted documents reached its `softDeletedPurgeThreshold` setting.
+    fn register_indexes_maintenance(&self) -> Result<()> {
+        let now = OffsetDateTime::now_utc();
+        let mut to_expire = Vec::new();
+        let mut to_purge = Vec::new();
+        {
+            let rtxn = self.env.read_txn()?;
+            let enqueued = self.get_status(&rtxn, Status::Enqueued)?;
+
+            for (index_uid, index) in self.index_mapper.maintained_indexes(&rtxn)? {
+                // The documents of a read-only index are kept until it's writable again.
+                if self.index_mapper.is_read_only(&rtxn, &index_uid)? {
+                    continue;
+                }
+                let index_rtxn = index.read_txn()?;
+
+                if let Some(threshold) = index.soft_deleted_purge_threshold(&index_rtxn)? {
+                    let soft_deleted = index.number_of_soft_deleted_documents(&index_rtxn)?;
+                    let documents = index.number_of_documents(&index_rtxn)?;
+                    if soft_deleted != 0
+                        && soft_deleted * 100 >= threshold as u64 * (documents + soft_deleted)
+                    {
+                        to_purge.push(index_uid.clone());
+                    }
+                }
+
+                if index.expired_documents_ids(&index_rtxn, now)?.is_empty() {
+                    continue;
+                }
+
+                let mut already_enqueued = false;
+                for task_id in self.index_tasks(&rtxn, &index_uid)? & &enqueued {
+                    let task = self.get_task(&rtxn, task_id)?.ok_or(Error::CorruptedTaskQueue)?;
+                    if matches!(task.kind, KindWithContent::DocumentExpiration { .. }) {
+                        already_enqueued = true;
+                        break;
+                    }
+                }
+
+                if !already_enqueued {
+                    to_expire.push(index_uid);
+                }
+            }
+        }
+
+        for index_uid in to_expire {
+            self.register(KindWithContent::DocumentExpiration { index_uid })?;
+        }
+        for index_uid in to_purge {
+            self.register_document_purge(&index_uid)?;
+        }
+
+        Ok(())
+    }
+
+    /// Register a [`KindWithContent::DocumentPurge`] task for this index, unless
+    /// such a task is already waiting to be processed.
+    pub(crate) fn register_document_purge(&self, index_uid: &str) -> Result<()> {
+        let rtxn = self.env.read_txn()?;
+        let mut enqueued = self.get_status(&rtxn, Status::Enqueued)?;
+        // The processing tasks are still enqueued on disk.
+        enqueued -= &self.processing_tasks.read().unwrap().processing;
+        let purges = self.get_kind(&rtxn, Kind::DocumentPurge)? & enqueued;
+        let already_enqueued = !(self.index_tasks(&rtxn, index_uid)? & purges).is_empty();
+        drop(rtxn);
+
+        if !already_enqueued {
+            self.register(KindWithContent::DocumentPurge { index_uid: index_uid.to_string() })?;
+        }
+
+        Ok(())
+    }
+
     /// Perform one iteration of the run loop.
     ///
     /// 1. Find the next batch of tasks to be processed.
@@ -1080,6 +1399,7 @@ mod tests {
     use meilisearch_types::milli::update::IndexDocumentsMethod::{
         ReplaceDocuments, UpdateDocuments,
     };
+    use meilisearch_types::settings::Setting;
     use meilisearch_types::tasks::IndexSwap;
     use meilisearch_types::VERSION_FILE_NAME;
     use tempfile::{NamedTempFile, TempDir};
@@ -1121,6 +1441,7 @@ mod tests {
                 index_size: 1000 * 1000,   // 1 MB, we don't use MiB on purpose.
                 indexer_config: IndexerConfig::default(),
                 autobatching_enabled,
+                api_keys_migration: None,
             };
 
             let index_scheduler = Self::new(options, sender, planned_failures).unwrap();
@@ -1163,7 +1484,10 @@ mod tests {
 
     /// Return a `KindWithContent::IndexCreation` task
     fn index_creation_task(index: &'static str, primary_key: &'static str) -> KindWithContent {
-        KindWithContent::IndexCreation { index_uid: S(index), primary_key: Some(S(primary_key)) }
+        KindWithContent::IndexCreation {
+            index_uid: S(index),
+            primary_key: Some(S(primary_key).into()),
+        }
     }
     /// Create a `KindWithContent::DocumentImport` task that imports documents.
     ///
@@ -3031,4 +3355,83 @@ mod tests {
         // No matter what happens in process_batch, the index_scheduler should be internally consistent
         snapshot!(snapshot_index_scheduler(&index_scheduler), name: "index_creation_failed");
     }
+
+    #[test]
+    fn only_maintain_the_indexes_with_a_ttl_or_a_purge_threshold() {
+        let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+
+        let settings = |ttl_attribute| KindWithContent::SettingsUpdate {
+            index_uid: S("catto"),
+            new_settings: Box::new(Settings { ttl_attribute, ..Default::default() }),
+            is_deletion: false,
+            allow_index_creation: true,
+            is_rollback: false,
+        };
+        let maintained_indexes = |index_scheduler: &IndexScheduler| {
+            let rtxn = index_scheduler.env.read_txn().unwrap();
+            let indexes = index_scheduler.index_mapper.maintained_indexes(&rtxn).unwrap();
+            indexes.into_iter().map(|(name, _)| name).collect::<Vec<_>>()
+        };
+
+        index_scheduler.register(index_creation_task("doggo", "bone")).unwrap();
+        index_scheduler.register(settings(Setting::Set(S("expiresAt")))).unwrap();
+        handle.advance_n_successful_batches(2);
+        assert_eq!(maintained_indexes(&index_scheduler), vec![S("catto")]);
+
+        index_scheduler.register(settings(Setting::Reset)).unwrap();
+        handle.advance_one_successful_batch();
+        assert!(maintained_indexes(&index_scheduler).is_empty());
+    }
+
+    #[test]
+    fn primary_key_is_not_changed_when_the_rename_fails() {
+        let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+
+        index_scheduler
+            .register(KindWithContent::IndexCreation { index_uid: S("doggos"), primary_key: None })
+            .unwrap();
+        index_scheduler.register(index_creation_task("catto", "mouse")).unwrap();
+        handle.advance_n_successful_batches(2);
+
+        index_scheduler
+            .register(KindWithContent::IndexUpdate {
+                index_uid: S("doggos"),
+                primary_key: Some(S("bone").into()),
+                new_index_uid: Some(S("catto")),
+                migrate_api_keys: false,
+            })
+            .unwrap();
+        handle.advance_one_failed_batch();
+
+        let index = index_scheduler.index("doggos").unwrap();
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.primary_key(&rtxn).unwrap(), None);
+    }
+
+    #[test]
+    fn settings_history_only_keeps_the_last_versions() {
+        let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+
+        index_scheduler.register(index_creation_task("catto", "mouse")).unwrap();
+        handle.advance_one_successful_batch();
+
+        let mut wtxn = index_scheduler.env.write_txn().unwrap();
+        for task_uid in 0..150 {
+            index_scheduler
+                .index_mapper
+                .push_settings_version(
+                    &mut wtxn,
+                    "catto",
+                    vec![task_uid],
+                    OffsetDateTime::now_utc(),
+                    Settings::default(),
+                )
+                .unwrap();
+        }
+        wtxn.commit().unwrap();
+
+        let history = index_scheduler.settings_history("catto").unwrap();
+        let versions: Vec<_> = history.iter().map(|version| version.version).collect();
+        assert_eq!(versions, (51..=150).collect::<Vec<_>>());
+    }
 }
@@ -239,11 +239,24 @@ pub fn swap_index_uid_in_task(task: &mut Task, swap: (&str, &str)) {
     match &mut task.kind {
         K::DocumentAdditionOrUpdate { index_uid, .. } => index_uids.push(index_uid),
         K::DocumentDeletion { index_uid, .. } => index_uids.push(index_uid),
+        K::DocumentDeletionByFilter { index_uid, .. } => index_uids.push(index_uid),
+        K::DocumentExpiration { index_uid } => index_uids.push(index_uid),
+        K::DocumentEdition { index_uid, .. } => index_uids.push(index_uid),
         K::DocumentClear { index_uid } => index_uids.push(index_uid),
+        K::DocumentPurge { index_uid } => index_uids.push(index_uid),
         K::SettingsUpdate { index_uid, .. } => index_uids.push(index_uid),
         K::IndexDeletion { index_uid } => index_uids.push(index_uid),
         K::IndexCreation { index_uid, .. } => index_uids.push(index_uid),
-        K::IndexUpdate { index_uid, .. } => index_uids.push(index_uid),
+        K::IndexUpdate { index_uid, new_index_uid, .. } => {
+            index_uids.push(index_uid);
+            index_uids.extend(new_index_uid.as_mut());
+        }
+        K::IndexClone { index_uid, target_uid } => {
+            index_uids.push(index_uid);
+            index_uids.push(target_uid);
+        }
+        K::IndexReadOnlyUpdate { index_uid, .. } => index_uids.push(index_uid),
+        K::IndexCompaction { index_uid } => index_uids.push(index_uid),
         K::IndexSwap { swaps } => {
             for IndexSwap { indexes: (lhs, rhs) } in swaps.iter_mut() {
                 if lhs == swap.0 || lhs == swap.1 {
@@ -254,7 +267,8 @@ pub fn swap_index_uid_in_task(task: &mut Task, swap: (&str, &str)) {
                 }
             }
         }
-        K::TaskCancelation { .. }
+        K::AliasUpdate { .. }
+        | K::TaskCancelation { .. }
         | K::TaskDeletion { .. }
         | K::DumpCreation { .. }
         | K::SnapshotCreation => (),
@@ -278,6 +292,33 @@ pub fn swap_index_uid_in_task(task: &mut Task, swap: (&str, &str)) {
     }
 }
 
+/// Return the index written to by this kind of task, the kinds that are rejected on a read-only index.
+pub(crate) fn written_index(kind: &KindWithContent) -> Option<&str> {
+    use KindWithContent as K;
+    match kind {
+        K::DocumentAdditionOrUpdate { index_uid, .. }
+        | K::DocumentDeletion { index_uid, .. }
+        | K::DocumentDeletionByFilter { index_uid, .. }
+        | K::DocumentExpiration { index_uid }
+        | K::DocumentEdition { index_uid, .. }
+        | K::DocumentClear { index_uid }
+        | K::DocumentPurge { index_uid }
+        | K::SettingsUpdate { index_uid, .. }
+        | K::IndexUpdate { index_uid, .. }
+        | K::IndexDeletion { index_uid } => Some(index_uid),
+        K::IndexCreation { .. }
+        | K::IndexClone { .. }
+        | K::IndexReadOnlyUpdate { .. }
+        | K::IndexCompaction { .. }
+        | K::IndexSwap { .. }
+        | K::AliasUpdate { .. }
+        | K::TaskCancelation { .. }
+        | K::TaskDeletion { .. }
+        | K::DumpCreation { .. }
+        | K::SnapshotCreation => None,
+    }
+}
+
 /// Remove references to task ids that are greater than the id of the given task.
 pub(crate) fn filter_out_references_to_newer_tasks(task: &mut Task) {
     let new_nbr_of_matched_tasks = match &mut task.kind {
@@ -401,7 +442,42 @@ impl IndexScheduler {
                             assert_eq!(&sw1, sw2);
                         }
                     }
-                    Details::DocumentAdditionOrUpdate { received_documents, indexed_documents } => {
+                    Details::IndexClone { target_uid: t1, cloned_documents } => {
+                        match &kind {
+                            KindWithContent::IndexClone { target_uid: t2, .. } => {
+                                assert_eq!(&t1, t2);
+                            }
+                            _ => panic!(),
+                        }
+                        if cloned_documents.is_none() {
+                            assert_ne!(status, Status::Succeeded);
+                        }
+                    }
+                    Details::IndexReadOnlyUpdate { read_only: r1 } => match &kind {
+                        KindWithContent::IndexReadOnlyUpdate { read_only: r2, .. } => {
+                            assert_eq!(&r1, r2);
+                        }
+                        _ => panic!(),
+                    },
+                    Details::IndexCompaction { pre_compaction_size, post_compaction_size } => {
+                        assert_eq!(kind.as_kind(), Kind::IndexCompaction);
+                        if status == Status::Succeeded {
+                            assert!(pre_compaction_size.is_some());
+                            assert!(post_compaction_size.is_some());
+                        }
+                    }
+                    Details::AliasUpdate { alias: a1, indexes: i1 } => match &kind {
+                        KindWithContent::AliasUpdate { alias: a2, indexes: i2 } => {
+                            assert_eq!(&a1, a2);
+                            assert_eq!(&i1, i2);
+                        }
+                        _ => panic!(),
+                    },
+                    Details::DocumentAdditionOrUpdate {
+                        received_documents,
+                        indexed_documents,
+                        ..
+                    } => {
                         assert_eq!(kind.as_kind(), Kind::DocumentAdditionOrUpdate);
                         match indexed_documents {
                             Some(0) => assert_ne!(status, Status::Enqueued),
@@ -419,15 +495,29 @@ impl IndexScheduler {
                     Details::SettingsUpdate { settings: _ } => {
                         assert_eq!(kind.as_kind(), Kind::SettingsUpdate);
                     }
-                    Details::IndexInfo { primary_key: pk1 } => match &kind {
-                        KindWithContent::IndexCreation { index_uid, primary_key: pk2 }
-                        | KindWithContent::IndexUpdate { index_uid, primary_key: pk2 } => {
+                    Details::IndexInfo { primary_key: pk1, new_index_uid: uid1 } => match &kind {
+                        KindWithContent::IndexCreation { index_uid, primary_key: pk2 } => {
                             self.index_tasks
                                 .get(&rtxn, index_uid.as_str())
                                 .unwrap()
                                 .unwrap()
                                 .contains(uid);
                             assert_eq!(&pk1, pk2);
+                            assert!(uid1.is_none());
+                        }
+                        KindWithContent::IndexUpdate {
+                            index_uid,
+                            primary_key: pk2,
+                            new_index_uid: uid2,
+                            ..
+                        } => {
+                            self.index_tasks
+                                .get(&rtxn, index_uid.as_str())
+                                .unwrap()
+                                .unwrap()
+                                .contains(uid);
+                            assert_eq!(&pk1, pk2);
+                            assert_eq!(&uid1, uid2);
                         }
                         _ => panic!(),
                     },
@@ -451,6 +541,57 @@ impl IndexScheduler {
                             assert_ne!(status, Status::Succeeded);
                         }
                     }
+                    Details::DocumentDeletionByFilter { deleted_documents, original_filter: _ } => {
+                        assert_eq!(kind.as_kind(), Kind::DocumentDeletion);
+                        match &kind {
+                            KindWithContent::DocumentDeletionByFilter {
+                                index_uid,
+                                filter_expr: _,
+                            } => {
+                                assert_eq!(&task_index_uid.unwrap(), index_uid);
+                            }
+                            _ => panic!(),
+                        }
+                        if deleted_documents.is_none() {
+                            assert_ne!(status, Status::Succeeded);
+                        }
+                    }
+                    Details::DocumentExpiration { deleted_documents } => {
+                        assert_eq!(kind.as_kind(), Kind::DocumentDeletion);
+                        match &kind {
+                            KindWithContent::DocumentExpiration { index_uid } => {
+                                assert_eq!(&task_index_uid.unwrap(), index_uid);
+                            }
+                            _ => panic!(),
+                        }
+                        if deleted_documents.is_none() {
+                            assert_ne!(status, Status::Succeeded);
+                        }
+                    }
+                    Details::DocumentPurge { purged_documents } => {
+                        assert_eq!(kind.as_kind(), Kind::DocumentPurge);
+                        match &kind {
+                            KindWithContent::DocumentPurge { index_uid } => {
+                                assert_eq!(&task_index_uid.unwrap(), index_uid);
+                            }
+                            _ => panic!(),
+                        }
+                        if purged_documents.is_none() {
+                            assert_ne!(status, Status::Succeeded);
+                        }
+                    }
+                    Details::DocumentEdition { edited_documents, .. } => {
+                        assert_eq!(kind.as_kind(), Kind::DocumentEdition);
+                        match &kind {
+                            KindWithContent::DocumentEdition { index_uid, .. } => {
+                                assert_eq!(&task_index_uid.unwrap(), index_uid);
+                            }
+                            _ => panic!(),
+                        }
+                        if edited_documents.is_none() {
+                            assert_ne!(status, Status::Succeeded);
+                        }
+                    }
                     Details::ClearAll { deleted_documents } => {
                         assert!(matches!(
                             kind.as_kind(),
@@ -22,3 +22,28 @@ impl BytesEncode<'_> for UuidCodec {
         Some(Cow::Borrowed(item.as_bytes()))
     }
 }
+
+/// A heed codec for a pair of a Uuid and a number, the entries sharing the same
+/// Uuid are ordered by number.
+pub struct UuidU32Codec;
+
+impl<'a> BytesDecode<'a> for UuidU32Codec {
+    type DItem = (Uuid, u32);
+
+    fn bytes_decode(bytes: &'a [u8]) -> Option<Self::DItem> {
+        let uuid = bytes.get(..16)?.try_into().ok().map(Uuid::from_bytes)?;
+        let number = bytes.get(16..)?.try_into().ok().map(u32::from_be_bytes)?;
+        Some((uuid, number))
+    }
+}
+
+impl BytesEncode<'_> for UuidU32Codec {
+    type EItem = (Uuid, u32);
+
+    fn bytes_encode((uuid, number): &Self::EItem) -> Option<Cow<[u8]>> {
+        let mut bytes = Vec::with_capacity(20);
+        bytes.extend_from_slice(uuid.as_bytes());
+        bytes.extend_from_slice(&number.to_be_bytes());
+        Some(Cow::Owned(bytes))
+    }
+}
@@ -8,6 +8,7 @@ use std::path::Path;
 use std::sync::Arc;
 
 use error::{AuthControllerError, Result};
+use meilisearch_types::index_uid::IndexUid;
 use meilisearch_types::keys::{Action, CreateApiKey, Key, PatchApiKey};
 use meilisearch_types::star_or::StarOr;
 use serde::{Deserialize, Serialize};
@@ -117,6 +118,24 @@ impl AuthController {
         }
     }
 
+    /// Replace the index `name` by `new_name` in the indexes of every key.
+    pub fn rename_index_in_keys(&self, name: &str, new_name: &str) -> Result<()> {
+        for mut key in self.list_keys()? {
+            let mut renamed = false;
+            for index in key.indexes.iter_mut() {
+                if matches!(index, StarOr::Other(uid) if uid.as_str() == name) {
+                    *index = StarOr::Other(IndexUid::new_unchecked(new_name));
+                    renamed = true;
+                }
+            }
+            if renamed {
+                key.updated_at = OffsetDateTime::now_utc();
+                self.store.put_api_key(key)?;
+            }
+        }
+        Ok(())
+    }
+
     pub fn get_master_key(&self) -> Option<&String> {
         self.master_key.as_ref()
     }
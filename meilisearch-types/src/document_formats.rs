@@ -1,25 +1,37 @@
 use std::borrow::Borrow;
 use std::fmt::{self, Debug, Display};
 use std::fs::File;
-use std::io::{self, Seek, Write};
+use std::io::{self, BufReader, Seek, Write};
 use std::marker::PhantomData;
 
+use arrow_array::cast::AsArray;
+use arrow_array::types::{
+    ArrowPrimitiveType, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, UInt16Type,
+    UInt32Type, UInt64Type, UInt8Type,
+};
+use arrow_array::{Array, GenericListArray, OffsetSizeTrait, RecordBatch};
+use arrow_cast::display::{ArrayFormatter, FormatOptions};
+use arrow_schema::{ArrowError, DataType};
 use memmap2::MmapOptions;
 use milli::documents::{DocumentsBatchBuilder, Error};
 use milli::Object;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
 use serde::de::{SeqAccess, Visitor};
 use serde::{Deserialize, Deserializer};
 use serde_json::error::Category;
+use serde_json::Value;
 
 use crate::error::{Code, ErrorCode};
 
 type Result<T> = std::result::Result<T, DocumentFormatError>;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum PayloadType {
     Ndjson,
     Json,
     Csv,
+    Parquet,
+    ArrowStream,
 }
 
 impl fmt::Display for PayloadType {
@@ -28,6 +40,8 @@ impl fmt::Display for PayloadType {
             PayloadType::Ndjson => f.write_str("ndjson"),
             PayloadType::Json => f.write_str("json"),
             PayloadType::Csv => f.write_str("csv"),
+            PayloadType::Parquet => f.write_str("parquet"),
+            PayloadType::ArrowStream => f.write_str("arrow"),
         }
     }
 }
@@ -36,6 +50,7 @@ impl fmt::Display for PayloadType {
 pub enum DocumentFormatError {
     Io(io::Error),
     MalformedPayload(Error, PayloadType),
+    MalformedArrowPayload(ArrowError, PayloadType),
 }
 
 impl Display for DocumentFormatError {
@@ -74,6 +89,9 @@ impl Display for DocumentFormatError {
                 }
                 _ => write!(f, "The `{}` payload provided is malformed: `{}`.", b, me),
             },
+            Self::MalformedArrowPayload(e, b) => {
+                write!(f, "The `{}` payload provided is malformed: `{}`.", b, e)
+            }
         }
     }
 }
@@ -100,16 +118,63 @@ impl ErrorCode for DocumentFormatError {
         match self {
             DocumentFormatError::Io(e) => e.error_code(),
             DocumentFormatError::MalformedPayload(_, _) => Code::MalformedPayload,
+            DocumentFormatError::MalformedArrowPayload(_, _) => Code::MalformedPayload,
         }
     }
 }
 
+/// The error returned when a CSV delimiter or quote isn't a single ASCII character.
+#[derive(Debug)]
+pub struct InvalidCsvCharacter(String);
+
+impl Display for InvalidCsvCharacter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` is not a valid CSV character. It must be a single ASCII character.", self.0)
+    }
+}
+
+impl std::error::Error for InvalidCsvCharacter {}
+
+/// Parses a CSV delimiter or quote, it must be a single ASCII character.
+pub fn parse_csv_character(s: &str) -> std::result::Result<u8, InvalidCsvCharacter> {
+    match s.as_bytes() {
+        [byte] if byte.is_ascii() => Ok(*byte),
+        _ => Err(InvalidCsvCharacter(s.to_string())),
+    }
+}
+
 /// Reads CSV from input and write an obkv batch to writer.
-pub fn read_csv(file: &File, writer: impl Write + Seek) -> Result<u64> {
+pub fn read_csv(file: &File, writer: impl Write + Seek, delimiter: u8, quote: u8) -> Result<u64> {
+    read_into_batch(writer, |builder| append_csv(file, builder, delimiter, quote))
+}
+
+/// Reads JSON from temporary file  and write an obkv batch to writer.
+pub fn read_json(file: &File, writer: impl Write + Seek) -> Result<u64> {
+    read_into_batch(writer, |builder| append_json(file, builder))
+}
+
+/// Reads JSON from temporary file  and write an obkv batch to writer.
+pub fn read_ndjson(file: &File, writer: impl Write + Seek) -> Result<u64> {
+    read_into_batch(writer, |builder| append_ndjson(file, builder))
+}
+
+/// Reads Parquet from input and write an obkv batch to writer.
+pub fn read_parquet(file: &File, writer: impl Write + Seek) -> Result<u64> {
+    read_into_batch(writer, |builder| append_parquet(file, builder))
+}
+
+/// Reads an Arrow IPC stream from input and write an obkv batch to writer.
+pub fn read_arrow_stream(file: &File, writer: impl Write + Seek) -> Result<u64> {
+    read_into_batch(writer, |builder| append_arrow_stream(file, builder))
+}
+
+/// Writes the documents appended by `append` in a new obkv batch and returns their number.
+fn read_into_batch<W: Write>(
+    writer: W,
+    append: impl FnOnce(&mut DocumentsBatchBuilder<W>) -> Result<()>,
+) -> Result<u64> {
     let mut builder = DocumentsBatchBuilder::new(writer);
-    let mmap = unsafe { MmapOptions::new().map(file)? };
-    let csv = csv::Reader::from_reader(mmap.as_ref());
-    builder.append_csv(csv).map_err(|e| (PayloadType::Csv, e))?;
+    append(&mut builder)?;
 
     let count = builder.documents_count();
     let _ = builder.into_inner().map_err(DocumentFormatError::Io)?;
@@ -117,9 +182,25 @@ pub fn read_csv(file: &File, writer: impl Write + Seek) -> Result<u64> {
     Ok(count as u64)
 }
 
-/// Reads JSON from temporary file  and write an obkv batch to writer.
-pub fn read_json(file: &File, writer: impl Write + Seek) -> Result<u64> {
-    let mut builder = DocumentsBatchBuilder::new(writer);
+/// Appends the documents of a CSV file to an obkv batch.
+///
+/// The `append_*` functions can be called one after the other on the same batch. When
+/// they fail, the documents read before the error are already in the batch.
+pub fn append_csv<W: Write>(
+    file: &File,
+    builder: &mut DocumentsBatchBuilder<W>,
+    delimiter: u8,
+    quote: u8,
+) -> Result<()> {
+    let mmap = unsafe { MmapOptions::new().map(file)? };
+    let csv =
+        csv::ReaderBuilder::new().delimiter(delimiter).quote(quote).from_reader(mmap.as_ref());
+    builder.append_csv(csv).map_err(|e| (PayloadType::Csv, e))?;
+    Ok(())
+}
+
+/// Appends the documents of a JSON file, an object or an array of objects, to an obkv batch.
+pub fn append_json<W: Write>(file: &File, builder: &mut DocumentsBatchBuilder<W>) -> Result<()> {
     let mmap = unsafe { MmapOptions::new().map(file)? };
     let mut deserializer = serde_json::Deserializer::from_slice(&mmap);
 
@@ -145,15 +226,11 @@ pub fn read_json(file: &File, writer: impl Write + Seek) -> Result<u64> {
         }
     }
 
-    let count = builder.documents_count();
-    let _ = builder.into_inner().map_err(DocumentFormatError::Io)?;
-
-    Ok(count as u64)
+    Ok(())
 }
 
-/// Reads JSON from temporary file  and write an obkv batch to writer.
-pub fn read_ndjson(file: &File, writer: impl Write + Seek) -> Result<u64> {
-    let mut builder = DocumentsBatchBuilder::new(writer);
+/// Appends the documents of a NDJSON file to an obkv batch.
+pub fn append_ndjson<W: Write>(file: &File, builder: &mut DocumentsBatchBuilder<W>) -> Result<()> {
     let mmap = unsafe { MmapOptions::new().map(file)? };
 
     for result in serde_json::Deserializer::from_slice(&mmap).into_iter() {
@@ -161,10 +238,168 @@ pub fn read_ndjson(file: &File, writer: impl Write + Seek) -> Result<u64> {
         builder.append_json_object(&object).map_err(Into::into).map_err(DocumentFormatError::Io)?;
     }
 
-    let count = builder.documents_count();
-    let _ = builder.into_inner().map_err(Into::into).map_err(DocumentFormatError::Io)?;
+    Ok(())
+}
 
-    Ok(count as u64)
+/// Appends the documents of a Parquet file to an obkv batch.
+pub fn append_parquet<W: Write>(file: &File, builder: &mut DocumentsBatchBuilder<W>) -> Result<()> {
+    let reader = ParquetRecordBatchReaderBuilder::try_new(file.try_clone()?)
+        .and_then(|builder| builder.build())
+        .map_err(|e| DocumentFormatError::MalformedArrowPayload(e.into(), PayloadType::Parquet))?;
+    append_record_batches(reader, builder, PayloadType::Parquet)
+}
+
+/// Appends the documents of an Arrow IPC stream file to an obkv batch.
+pub fn append_arrow_stream<W: Write>(
+    file: &File,
+    builder: &mut DocumentsBatchBuilder<W>,
+) -> Result<()> {
+    let reader = arrow_ipc::reader::StreamReader::try_new(BufReader::new(file), None)
+        .map_err(|e| DocumentFormatError::MalformedArrowPayload(e, PayloadType::ArrowStream))?;
+    append_record_batches(reader, builder, PayloadType::ArrowStream)
+}
+
+/// Converts the record batches one by one into JSON objects, the nested struct and list
+/// columns are respectively converted into JSON objects and arrays.
+///
+/// The null values are kept as explicit `null`s in the documents.
+fn append_record_batches<W: Write>(
+    batches: impl Iterator<Item = std::result::Result<RecordBatch, ArrowError>>,
+    builder: &mut DocumentsBatchBuilder<W>,
+    payload_type: PayloadType,
+) -> Result<()> {
+    for batch in batches {
+        let batch =
+            batch.map_err(|e| DocumentFormatError::MalformedArrowPayload(e, payload_type))?;
+
+        let schema = batch.schema();
+        let mut columns = batch
+            .columns()
+            .iter()
+            .map(|column| column_values(column.as_ref()))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| DocumentFormatError::MalformedArrowPayload(e, payload_type))?;
+
+        for row in 0..batch.num_rows() {
+            let object: Object = schema
+                .fields()
+                .iter()
+                .zip(&mut columns)
+                .map(|(field, values)| (field.name().clone(), values[row].take()))
+                .collect();
+            builder.append_json_object(&object).map_err(DocumentFormatError::Io)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Converts an Arrow array into the JSON values of each of its rows.
+///
+/// The numbers, booleans and strings are kept as is, the floats that aren't finite
+/// become `null`s, and the types without a JSON equivalent, e.g. the dates or the
+/// decimals, are displayed as strings.
+fn column_values(array: &dyn Array) -> std::result::Result<Vec<Value>, ArrowError> {
+    let values = match array.data_type() {
+        DataType::Null => vec![Value::Null; array.len()],
+        DataType::Boolean => array.as_boolean().iter().map(Value::from).collect(),
+        DataType::Int8 => primitive_values::<Int8Type>(array),
+        DataType::Int16 => primitive_values::<Int16Type>(array),
+        DataType::Int32 => primitive_values::<Int32Type>(array),
+        DataType::Int64 => primitive_values::<Int64Type>(array),
+        DataType::UInt8 => primitive_values::<UInt8Type>(array),
+        DataType::UInt16 => primitive_values::<UInt16Type>(array),
+        DataType::UInt32 => primitive_values::<UInt32Type>(array),
+        DataType::UInt64 => primitive_values::<UInt64Type>(array),
+        DataType::Float16 | DataType::Float32 | DataType::Float64 => {
+            let floats = arrow_cast::cast(array, &DataType::Float64)?;
+            floats.as_primitive::<Float64Type>().iter().map(Value::from).collect()
+        }
+        DataType::Utf8 => array.as_string::<i32>().iter().map(Value::from).collect(),
+        DataType::LargeUtf8 => array.as_string::<i64>().iter().map(Value::from).collect(),
+        DataType::Utf8View => array.as_string_view().iter().map(Value::from).collect(),
+        DataType::List(_) => list_values(array.as_list::<i32>())?,
+        DataType::LargeList(_) => list_values(array.as_list::<i64>())?,
+        DataType::FixedSizeList(_, size) => {
+            let list = array.as_fixed_size_list();
+            let mut values = column_values(list.values().as_ref())?;
+            let size = *size as usize;
+            (0..list.len())
+                .map(|row| {
+                    if list.is_null(row) {
+                        Value::Null
+                    } else {
+                        values[row * size..(row + 1) * size].iter_mut().map(Value::take).collect()
+                    }
+                })
+                .collect()
+        }
+        DataType::Struct(fields) => {
+            let structs = array.as_struct();
+            let mut columns = structs
+                .columns()
+                .iter()
+                .map(|column| column_values(column.as_ref()))
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            (0..structs.len())
+                .map(|row| {
+                    if structs.is_null(row) {
+                        Value::Null
+                    } else {
+                        fields
+                            .iter()
+                            .zip(&mut columns)
+                            .map(|(field, values)| (field.name().clone(), values[row].take()))
+                            .collect()
+                    }
+                })
+                .collect()
+        }
+        DataType::Dictionary(_, value_type) => {
+            column_values(arrow_cast::cast(array, value_type)?.as_ref())?
+        }
+        _ => {
+            let formatter = ArrayFormatter::try_new(array, &FormatOptions::default())?;
+            (0..array.len())
+                .map(|row| {
+                    if array.is_null(row) {
+                        Value::Null
+                    } else {
+                        Value::String(formatter.value(row).to_string())
+                    }
+                })
+                .collect()
+        }
+    };
+
+    Ok(values)
+}
+
+fn primitive_values<T>(array: &dyn Array) -> Vec<Value>
+where
+    T: ArrowPrimitiveType,
+    T::Native: Into<Value>,
+{
+    array.as_primitive::<T>().iter().map(Value::from).collect()
+}
+
+fn list_values<O: OffsetSizeTrait>(
+    list: &GenericListArray<O>,
+) -> std::result::Result<Vec<Value>, ArrowError> {
+    let mut values = column_values(list.values().as_ref())?;
+    Ok(list
+        .value_offsets()
+        .windows(2)
+        .enumerate()
+        .map(|(row, offsets)| {
+            if list.is_null(row) {
+                Value::Null
+            } else {
+                let (start, end) = (offsets[0].as_usize(), offsets[1].as_usize());
+                values[start..end].iter_mut().map(Value::take).collect()
+            }
+        })
+        .collect())
 }
 
 /// The actual handling of the deserialization process in serde
@@ -212,3 +447,131 @@ where
     let visitor = SeqVisitor(f, PhantomData);
     deserializer.deserialize_seq(visitor)
 }
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+    use std::sync::Arc;
+
+    use arrow_array::builder::{ListBuilder, StringBuilder};
+    use arrow_array::{
+        ArrayRef, Date32Array, DictionaryArray, Float64Array, Int64Array, StringArray, StructArray,
+    };
+    use arrow_schema::{DataType, Field, Fields, Schema};
+    use milli::documents::{obkv_to_object, DocumentsBatchReader};
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    fn record_batch() -> RecordBatch {
+        let address_fields = Fields::from(vec![Field::new("city", DataType::Utf8, true)]);
+        let tags_field = Field::new("item", DataType::Utf8, true);
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("address", DataType::Struct(address_fields.clone()), true),
+            Field::new("tags", DataType::List(Arc::new(tags_field)), true),
+        ]));
+
+        let ids = Int64Array::from(vec![1, 2]);
+        let cities = StringArray::from(vec![Some("Paris"), None]);
+        let address = StructArray::new(address_fields, vec![Arc::new(cities) as ArrayRef], None);
+        let mut tags = ListBuilder::new(StringBuilder::new());
+        tags.values().append_value("a");
+        tags.values().append_value("b");
+        tags.append(true);
+        tags.append(false);
+
+        RecordBatch::try_new(
+            schema,
+            vec![Arc::new(ids), Arc::new(address), Arc::new(tags.finish())],
+        )
+        .unwrap()
+    }
+
+    fn documents_from_batch(batch: Vec<u8>) -> Vec<Value> {
+        let (mut cursor, index) = DocumentsBatchReader::from_reader(Cursor::new(batch))
+            .unwrap()
+            .into_cursor_and_fields_index();
+
+        let mut documents = Vec::new();
+        while let Some(document) = cursor.next_document().unwrap() {
+            documents.push(Value::from(obkv_to_object(&document, &index).unwrap()));
+        }
+        documents
+    }
+
+    #[test]
+    fn parquet_documents() {
+        let batch = record_batch();
+        let mut file = tempfile::tempfile().unwrap();
+        let mut writer =
+            parquet::arrow::ArrowWriter::try_new(file.try_clone().unwrap(), batch.schema(), None)
+                .unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        file.rewind().unwrap();
+
+        let mut output = Cursor::new(Vec::new());
+        let count = read_parquet(&file, &mut output).unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(
+            documents_from_batch(output.into_inner()),
+            vec![
+                json!({ "id": 1, "address": { "city": "Paris" }, "tags": ["a", "b"] }),
+                json!({ "id": 2, "address": { "city": null }, "tags": null }),
+            ]
+        );
+    }
+
+    #[test]
+    fn arrow_stream_documents() {
+        let batch = record_batch();
+        let mut file = tempfile::tempfile().unwrap();
+        let mut writer =
+            arrow_ipc::writer::StreamWriter::try_new(file.try_clone().unwrap(), &batch.schema())
+                .unwrap();
+        writer.write(&batch).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+        file.rewind().unwrap();
+
+        let mut output = Cursor::new(Vec::new());
+        let count = read_arrow_stream(&file, &mut output).unwrap();
+        assert_eq!(count, 4);
+        assert_eq!(
+            documents_from_batch(output.into_inner())[..2],
+            [
+                json!({ "id": 1, "address": { "city": "Paris" }, "tags": ["a", "b"] }),
+                json!({ "id": 2, "address": { "city": null }, "tags": null }),
+            ]
+        );
+    }
+
+    #[test]
+    fn arrow_column_values() {
+        let floats = Float64Array::from(vec![Some(1.5), Some(f64::NAN), None]);
+        assert_eq!(column_values(&floats).unwrap(), vec![json!(1.5), Value::Null, Value::Null]);
+
+        let dates = Date32Array::from(vec![Some(0), None]);
+        assert_eq!(column_values(&dates).unwrap(), vec![json!("1970-01-01"), Value::Null]);
+
+        let dictionary: DictionaryArray<Int32Type> =
+            vec![Some("a"), None, Some("a")].into_iter().collect();
+        assert_eq!(column_values(&dictionary).unwrap(), vec![json!("a"), Value::Null, json!("a")]);
+    }
+
+    #[test]
+    fn malformed_parquet_documents() {
+        let mut file = tempfile::tempfile().unwrap();
+        file.write_all(b"id,name\n1,kefir").unwrap();
+        file.rewind().unwrap();
+
+        let error = read_parquet(&file, Cursor::new(Vec::new())).unwrap_err();
+        assert!(matches!(
+            error,
+            DocumentFormatError::MalformedArrowPayload(_, PayloadType::Parquet)
+        ));
+        assert!(error.to_string().starts_with("The `parquet` payload provided is malformed: `"));
+    }
+}
@@ -10,7 +10,7 @@ use deserr::{DeserializeError, IntoValue, MergeWithError, ValuePointerRef};
 use milli::heed::{Error as HeedError, MdbError};
 use serde::{Deserialize, Serialize};
 
-use self::deserr_codes::MissingIndexUid;
+use self::deserr_codes::{MissingDocumentEditionFunction, MissingDocumentFilter, MissingIndexUid};
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
@@ -171,12 +171,16 @@ macro_rules! make_error_codes {
     }
 }
 make_error_codes! {
+AliasAlreadyExists                    , invalid       , CONFLICT ;
+AliasNotFound                         , invalid       , NOT_FOUND ;
 ApiKeyAlreadyExists                   , invalid       , CONFLICT ;
 ApiKeyNotFound                        , invalid       , NOT_FOUND ;
 BadParameter                          , invalid       , BAD_REQUEST;
 BadRequest                            , invalid       , BAD_REQUEST;
 DatabaseSizeLimitReached              , internal      , INTERNAL_SERVER_ERROR;
+DocumentImportDisabled                , invalid       , BAD_REQUEST ;
 DocumentNotFound                      , invalid       , NOT_FOUND;
+DocumentSchemaMismatch                , invalid       , BAD_REQUEST ;
 DumpAlreadyProcessing                 , invalid       , CONFLICT;
 DumpNotFound                          , invalid       , NOT_FOUND;
 DumpProcessFailed                     , internal      , INTERNAL_SERVER_ERROR;
@@ -190,7 +194,6 @@ ImmutableApiKeyExpiresAt              , invalid       , BAD_REQUEST;
 ImmutableApiKeyCreatedAt              , invalid       , BAD_REQUEST;
 ImmutableApiKeyUpdatedAt              , invalid       , BAD_REQUEST;
 
-ImmutableIndexUid                     , invalid       , BAD_REQUEST;
 ImmutableIndexCreatedAt               , invalid       , BAD_REQUEST;
 ImmutableIndexUpdatedAt               , invalid       , BAD_REQUEST;
 
@@ -200,7 +203,11 @@ IndexNotFound                         , invalid       , NOT_FOUND;
 IndexPrimaryKeyAlreadyExists          , invalid       , BAD_REQUEST ;
 IndexPrimaryKeyNoCandidateFound       , invalid       , BAD_REQUEST ;
 IndexPrimaryKeyMultipleCandidatesFound, invalid       , BAD_REQUEST;
+IndexQuotaExceeded                    , invalid       , BAD_REQUEST ;
+IndexReadOnly                         , invalid       , FORBIDDEN ;
+IndexTemplateNotFound                 , invalid       , NOT_FOUND ;
 Internal                              , internal      , INTERNAL_SERVER_ERROR ;
+InvalidAliasIndexes                   , invalid       , BAD_REQUEST ;
 InvalidApiKeyActions                  , invalid       , BAD_REQUEST ;
 InvalidApiKeyDescription              , invalid       , BAD_REQUEST ;
 InvalidApiKeyExpiresAt                , invalid       , BAD_REQUEST ;
@@ -210,15 +217,32 @@ InvalidApiKeyName                     , invalid       , BAD_REQUEST ;
 InvalidApiKeyOffset                   , invalid       , BAD_REQUEST ;
 InvalidApiKeyUid                      , invalid       , BAD_REQUEST ;
 InvalidApiKey                         , authentication, FORBIDDEN ;
+InvalidContentEncoding                , invalid       , UNSUPPORTED_MEDIA_TYPE ;
 InvalidContentType                    , invalid       , UNSUPPORTED_MEDIA_TYPE ;
+InvalidDocumentAppendArrays           , invalid       , BAD_REQUEST ;
+InvalidDocumentCsvDelimiter           , invalid       , BAD_REQUEST ;
+InvalidDocumentCsvQuote               , invalid       , BAD_REQUEST ;
+InvalidDocumentEditionContext         , invalid       , BAD_REQUEST ;
+InvalidDocumentEditionFunction        , invalid       , BAD_REQUEST ;
+InvalidDocumentExportFormat           , invalid       , BAD_REQUEST ;
 InvalidDocumentFields                 , invalid       , BAD_REQUEST ;
+InvalidDocumentFilter                 , invalid       , BAD_REQUEST ;
 InvalidDocumentGeoField               , invalid       , BAD_REQUEST ;
 InvalidDocumentId                     , invalid       , BAD_REQUEST ;
+InvalidDocumentImportFormat           , invalid       , BAD_REQUEST ;
+InvalidDocumentImportPath             , invalid       , BAD_REQUEST ;
 InvalidDocumentLimit                  , invalid       , BAD_REQUEST ;
 InvalidDocumentOffset                 , invalid       , BAD_REQUEST ;
+InvalidDocumentVersion                , invalid       , BAD_REQUEST ;
+InvalidFilterExplain                  , invalid       , BAD_REQUEST ;
 InvalidIndexLimit                     , invalid       , BAD_REQUEST ;
+InvalidIndexMaxDocuments              , invalid       , BAD_REQUEST ;
+InvalidIndexMaxSizeBytes              , invalid       , BAD_REQUEST ;
+InvalidIndexMigrateApiKeys            , invalid       , BAD_REQUEST ;
 InvalidIndexOffset                    , invalid       , BAD_REQUEST ;
 InvalidIndexPrimaryKey                , invalid       , BAD_REQUEST ;
+InvalidIndexReadOnly                  , invalid       , BAD_REQUEST ;
+InvalidIndexTemplatePattern           , invalid       , BAD_REQUEST ;
 InvalidIndexUid                       , invalid       , BAD_REQUEST ;
 InvalidMinWordLengthForTypo           , invalid       , BAD_REQUEST ;
 InvalidSearchAttributesToCrop         , invalid       , BAD_REQUEST ;
@@ -238,16 +262,21 @@ InvalidSearchPage                     , invalid       , BAD_REQUEST ;
 InvalidSearchQ                        , invalid       , BAD_REQUEST ;
 InvalidSearchShowMatchesPosition      , invalid       , BAD_REQUEST ;
 InvalidSearchSort                     , invalid       , BAD_REQUEST ;
+InvalidSettingsDefaultTtl             , invalid       , BAD_REQUEST ;
 InvalidSettingsDisplayedAttributes    , invalid       , BAD_REQUEST ;
 InvalidSettingsDistinctAttribute      , invalid       , BAD_REQUEST ;
 InvalidSettingsFaceting               , invalid       , BAD_REQUEST ;
 InvalidSettingsFilterableAttributes   , invalid       , BAD_REQUEST ;
 InvalidSettingsPagination             , invalid       , BAD_REQUEST ;
+InvalidSettingsPrimaryKeyGeneration   , invalid       , BAD_REQUEST ;
 InvalidSettingsRankingRules           , invalid       , BAD_REQUEST ;
+InvalidSettingsSchema                 , invalid       , BAD_REQUEST ;
 InvalidSettingsSearchableAttributes   , invalid       , BAD_REQUEST ;
+InvalidSettingsSoftDeletedPurgeThreshold, invalid     , BAD_REQUEST ;
 InvalidSettingsSortableAttributes     , invalid       , BAD_REQUEST ;
 InvalidSettingsStopWords              , invalid       , BAD_REQUEST ;
 InvalidSettingsSynonyms               , invalid       , BAD_REQUEST ;
+InvalidSettingsTtlAttribute           , invalid       , BAD_REQUEST ;
 InvalidSettingsTypoTolerance          , invalid       , BAD_REQUEST ;
 InvalidState                          , internal      , INTERNAL_SERVER_ERROR ;
 InvalidStoreFile                      , internal      , INTERNAL_SERVER_ERROR ;
@@ -273,13 +302,17 @@ MissingApiKeyExpiresAt                , invalid       , BAD_REQUEST ;
 MissingApiKeyIndexes                  , invalid       , BAD_REQUEST ;
 MissingAuthorizationHeader            , authentication, UNAUTHORIZED ;
 MissingContentType                    , invalid       , UNSUPPORTED_MEDIA_TYPE ;
+MissingDocumentEditionFunction        , invalid       , BAD_REQUEST ;
+MissingDocumentFilter                 , invalid       , BAD_REQUEST ;
 MissingDocumentId                     , invalid       , BAD_REQUEST ;
+MissingIndexReadOnly                  , invalid       , BAD_REQUEST ;
 MissingIndexUid                       , invalid       , BAD_REQUEST ;
 MissingMasterKey                      , authentication, UNAUTHORIZED ;
 MissingPayload                        , invalid       , BAD_REQUEST ;
 MissingTaskFilters                    , invalid       , BAD_REQUEST ;
 NoSpaceLeftOnDevice                   , system        , UNPROCESSABLE_ENTITY;
 PayloadTooLarge                       , invalid       , PAYLOAD_TOO_LARGE ;
+SettingsVersionNotFound               , invalid       , NOT_FOUND ;
 TaskNotFound                          , invalid       , NOT_FOUND ;
 TooManyOpenFiles                      , system        , UNPROCESSABLE_ENTITY ;
 UnretrievableDocument                 , internal      , BAD_REQUEST ;
@@ -338,6 +371,7 @@ impl ErrorCode for milli::Error {
                     UserError::MaxDatabaseSizeReached => Code::DatabaseSizeLimitReached,
                     UserError::AttributeLimitReached => Code::MaxFieldsLimitExceeded,
                     UserError::InvalidFilter(_) => Code::InvalidSearchFilter,
+                    UserError::InvalidFilterExpression(..) => Code::InvalidSearchFilter,
                     UserError::MissingDocumentId { .. } => Code::MissingDocumentId,
                     UserError::InvalidDocumentId { .. } | UserError::TooManyDocumentIds { .. } => {
                         Code::InvalidDocumentId
@@ -347,15 +381,23 @@ impl ErrorCode for milli::Error {
                         Code::IndexPrimaryKeyMultipleCandidatesFound
                     }
                     UserError::PrimaryKeyCannotBeChanged(_) => Code::IndexPrimaryKeyAlreadyExists,
+                    UserError::InvalidCompositePrimaryKey(_) => Code::InvalidIndexPrimaryKey,
                     UserError::SortRankingRuleMissing => Code::InvalidSearchSort,
                     UserError::InvalidFacetsDistribution { .. } => Code::BadRequest,
                     UserError::InvalidSortableAttribute { .. } => Code::InvalidSearchSort,
                     UserError::CriterionError(_) => Code::InvalidSettingsRankingRules,
                     UserError::InvalidGeoField { .. } => Code::InvalidDocumentGeoField,
+                    UserError::InvalidDocumentVersion { .. } => Code::InvalidDocumentVersion,
+                    UserError::DocumentSchemaMismatch { .. } => Code::DocumentSchemaMismatch,
+                    UserError::InvalidDocumentSchemaRange { .. } => Code::InvalidSettingsSchema,
                     UserError::SortError(_) => Code::InvalidSearchSort,
                     UserError::InvalidMinTypoWordLenSetting(_, _) => {
                         Code::InvalidMinWordLengthForTypo
                     }
+                    UserError::DefaultTtlWithoutTtlField => Code::InvalidSettingsDefaultTtl,
+                    UserError::InvalidSoftDeletedPurgeThreshold(_) => {
+                        Code::InvalidSettingsSoftDeletedPurgeThreshold
+                    }
                 }
             }
         }
@@ -467,6 +509,43 @@ impl DeserrError<MissingIndexUid> {
     }
 }
 
+impl DeserrError<MissingIndexReadOnly> {
+    pub fn missing_index_read_only(field: &str, location: ValuePointerRef) -> Self {
+        let x = unwrap_any(Self::error::<Infallible>(
+            None,
+            deserr::ErrorKind::MissingField { field },
+            location,
+        ));
+        Self { msg: x.msg, code: MissingIndexReadOnly.error_code(), _phantom: PhantomData }
+    }
+}
+
+impl DeserrError<MissingDocumentFilter> {
+    pub fn missing_document_filter(field: &str, location: ValuePointerRef) -> Self {
+        let x = unwrap_any(Self::error::<Infallible>(
+            None,
+            deserr::ErrorKind::MissingField { field },
+            location,
+        ));
+        Self { msg: x.msg, code: MissingDocumentFilter.error_code(), _phantom: PhantomData }
+    }
+}
+
+impl DeserrError<MissingDocumentEditionFunction> {
+    pub fn missing_document_edition_function(field: &str, location: ValuePointerRef) -> Self {
+        let x = unwrap_any(Self::error::<Infallible>(
+            None,
+            deserr::ErrorKind::MissingField { field },
+            location,
+        ));
+        Self {
+            msg: x.msg,
+            code: MissingDocumentEditionFunction.error_code(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
 impl<C: Default + ErrorCode> deserr::DeserializeError for DeserrError<C> {
     fn error<V: IntoValue>(
         _self_: Option<Self>,
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// An alias and the names of the indexes it points to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexAlias {
+    pub uid: String,
+    pub indexes: Vec<String>,
+}
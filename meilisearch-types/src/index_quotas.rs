@@ -0,0 +1,24 @@
+use deserr::DeserializeFromValue;
+use serde::{Deserialize, Serialize};
+
+use crate::error::deserr_codes::*;
+use crate::error::DeserrError;
+
+/// The limits an index can't grow beyond, a `None` limit means the index is unlimited.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, DeserializeFromValue,
+)]
+#[serde(rename_all = "camelCase")]
+#[deserr(error = DeserrError, rename_all = camelCase, deny_unknown_fields)]
+pub struct IndexQuota {
+    #[deserr(error = DeserrError<InvalidIndexMaxDocuments>)]
+    pub max_documents: Option<u64>,
+    #[deserr(error = DeserrError<InvalidIndexMaxSizeBytes>)]
+    pub max_size_bytes: Option<u64>,
+}
+
+impl IndexQuota {
+    pub fn is_unlimited(&self) -> bool {
+        self.max_documents.is_none() && self.max_size_bytes.is_none()
+    }
+}
@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Code, ResponseError};
+use crate::settings::{Settings, Unchecked};
+
+/// The default settings of the indexes created with an uid matching a pattern.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexTemplate {
+    pub pattern: String,
+    pub settings: Settings<Unchecked>,
+}
+
+/// Checks that a pattern is made of the characters allowed in an index uid and of `*`.
+pub fn validate_pattern(pattern: String) -> Result<String, ResponseError> {
+    if pattern.is_empty()
+        || pattern.len() > 400
+        || !pattern.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '*')
+    {
+        Err(ResponseError::from_msg(
+            format!(
+                "`{pattern}` is not a valid index template pattern. A pattern can contain only \
+                alphanumeric characters, hyphens (-), underscores (_) and wildcards (*)."
+            ),
+            Code::InvalidIndexTemplatePattern,
+        ))
+    } else {
+        Ok(pattern)
+    }
+}
+
+/// Returns `true` if the index uid matches the pattern, where `*` matches any
+/// sequence of characters, including an empty one.
+pub fn pattern_matches(pattern: &str, uid: &str) -> bool {
+    let mut parts = pattern.split('*');
+    // There is always a first part, possibly empty.
+    let first = parts.next().unwrap_or_default();
+    let mut rest = match uid.strip_prefix(first) {
+        Some(rest) => rest,
+        None => return false,
+    };
+
+    let mut parts: Vec<_> = parts.collect();
+    let last = match parts.pop() {
+        Some(last) => last,
+        // There is no wildcard in the pattern.
+        None => return rest.is_empty(),
+    };
+
+    for part in parts {
+        match rest.find(part) {
+            Some(position) => rest = &rest[position + part.len()..],
+            None => return false,
+        }
+    }
+
+    rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn patterns() {
+        assert!(pattern_matches("customer-*", "customer-1"));
+        assert!(pattern_matches("customer-*", "customer-"));
+        assert!(!pattern_matches("customer-*", "customer"));
+        assert!(pattern_matches("*", "movies"));
+        assert!(pattern_matches("movies", "movies"));
+        assert!(!pattern_matches("movies", "movies-1"));
+        assert!(pattern_matches("*-logs-*", "app-logs-2023"));
+        assert!(!pattern_matches("*-logs-*", "app-log-2023"));
+        assert!(pattern_matches("a*b*c", "abc"));
+        assert!(!pattern_matches("a*b*c", "acb"));
+        assert!(!pattern_matches("ab*ba", "aba"));
+    }
+}
@@ -1,9 +1,13 @@
 pub mod compression;
 pub mod document_formats;
 pub mod error;
+pub mod index_aliases;
+pub mod index_quotas;
+pub mod index_templates;
 pub mod index_uid;
 pub mod keys;
 pub mod settings;
+pub mod settings_history;
 pub mod star_or;
 pub mod tasks;
 pub mod versioning;
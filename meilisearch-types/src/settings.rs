@@ -7,12 +7,12 @@ use std::str::FromStr;
 
 use deserr::{DeserializeError, DeserializeFromValue, ErrorKind, MergeWithError, ValuePointerRef};
 use fst::IntoStreamer;
-use milli::update::Setting;
-use milli::{Criterion, CriterionError, Index, DEFAULT_VALUES_PER_FACET};
+use milli::update::{PrimaryKeyGeneration, Setting};
+use milli::{Criterion, CriterionError, DocumentSchema, Index, DEFAULT_VALUES_PER_FACET};
 use serde::{Deserialize, Serialize, Serializer};
 
 use crate::error::deserr_codes::*;
-use crate::error::{unwrap_any, DeserrError};
+use crate::error::{unwrap_any, DeserrError, TakeErrorMessage};
 
 /// The maximimum number of results that the engine
 /// will be able to return in one search call.
@@ -173,6 +173,21 @@ pub struct Settings<T> {
     #[serde(default, skip_serializing_if = "Setting::is_not_set")]
     #[deserr(error = DeserrError<InvalidSettingsPagination>)]
     pub pagination: Setting<PaginationSettings>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(error = DeserrError<InvalidSettingsTtlAttribute>)]
+    pub ttl_attribute: Setting<String>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(error = DeserrError<InvalidSettingsDefaultTtl>)]
+    pub default_ttl: Setting<u64>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(error = DeserrError<InvalidSettingsPrimaryKeyGeneration>)]
+    pub primary_key_generation: Setting<PrimaryKeyGenerationView>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(error = DeserrError<InvalidSettingsSchema>)]
+    pub schema: Setting<DocumentSchemaView>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(error = DeserrError<InvalidSettingsSoftDeletedPurgeThreshold>)]
+    pub soft_deleted_purge_threshold: Setting<u8>,
 
     #[serde(skip)]
     #[deserr(skip)]
@@ -193,6 +208,11 @@ impl Settings<Checked> {
             typo_tolerance: Setting::Reset,
             faceting: Setting::Reset,
             pagination: Setting::Reset,
+            ttl_attribute: Setting::Reset,
+            default_ttl: Setting::Reset,
+            primary_key_generation: Setting::Reset,
+            schema: Setting::Reset,
+            soft_deleted_purge_threshold: Setting::Reset,
             _kind: PhantomData,
         }
     }
@@ -210,6 +230,11 @@ impl Settings<Checked> {
             typo_tolerance,
             faceting,
             pagination,
+            ttl_attribute,
+            default_ttl,
+            primary_key_generation,
+            schema,
+            soft_deleted_purge_threshold,
             ..
         } = self;
 
@@ -225,9 +250,90 @@ impl Settings<Checked> {
             typo_tolerance,
             faceting,
             pagination,
+            ttl_attribute,
+            default_ttl,
+            primary_key_generation,
+            schema,
+            soft_deleted_purge_threshold,
             _kind: PhantomData,
         }
     }
+
+    /// Returns the settings that must be applied to go from `self` to `target`,
+    /// the settings that are identical in both are left unset.
+    pub fn diff(&self, target: &Settings<Checked>) -> Settings<Checked> {
+        Settings {
+            displayed_attributes: diff_setting(
+                &self.displayed_attributes,
+                &target.displayed_attributes,
+            ),
+            searchable_attributes: diff_setting(
+                &self.searchable_attributes,
+                &target.searchable_attributes,
+            ),
+            filterable_attributes: diff_setting(
+                &self.filterable_attributes,
+                &target.filterable_attributes,
+            ),
+            sortable_attributes: diff_setting(
+                &self.sortable_attributes,
+                &target.sortable_attributes,
+            ),
+            ranking_rules: diff_setting(&self.ranking_rules, &target.ranking_rules),
+            stop_words: diff_setting(&self.stop_words, &target.stop_words),
+            synonyms: diff_setting(&self.synonyms, &target.synonyms),
+            distinct_attribute: diff_setting(&self.distinct_attribute, &target.distinct_attribute),
+            typo_tolerance: diff_setting(&self.typo_tolerance, &target.typo_tolerance),
+            faceting: diff_setting(&self.faceting, &target.faceting),
+            pagination: diff_setting(&self.pagination, &target.pagination),
+            ttl_attribute: diff_setting(&self.ttl_attribute, &target.ttl_attribute),
+            default_ttl: diff_setting(&self.default_ttl, &target.default_ttl),
+            primary_key_generation: diff_setting(
+                &self.primary_key_generation,
+                &target.primary_key_generation,
+            ),
+            schema: diff_setting(&self.schema, &target.schema),
+            soft_deleted_purge_threshold: diff_setting(
+                &self.soft_deleted_purge_threshold,
+                &target.soft_deleted_purge_threshold,
+            ),
+            _kind: PhantomData,
+        }
+    }
+
+    /// Overrides `self` with the settings of `update` that are not left unset.
+    pub fn apply(&mut self, update: &Settings<Checked>) {
+        apply_setting(&mut self.displayed_attributes, &update.displayed_attributes);
+        apply_setting(&mut self.searchable_attributes, &update.searchable_attributes);
+        apply_setting(&mut self.filterable_attributes, &update.filterable_attributes);
+        apply_setting(&mut self.sortable_attributes, &update.sortable_attributes);
+        apply_setting(&mut self.ranking_rules, &update.ranking_rules);
+        apply_setting(&mut self.stop_words, &update.stop_words);
+        apply_setting(&mut self.synonyms, &update.synonyms);
+        apply_setting(&mut self.distinct_attribute, &update.distinct_attribute);
+        apply_setting(&mut self.typo_tolerance, &update.typo_tolerance);
+        apply_setting(&mut self.faceting, &update.faceting);
+        apply_setting(&mut self.pagination, &update.pagination);
+        apply_setting(&mut self.ttl_attribute, &update.ttl_attribute);
+        apply_setting(&mut self.default_ttl, &update.default_ttl);
+        apply_setting(&mut self.primary_key_generation, &update.primary_key_generation);
+        apply_setting(&mut self.schema, &update.schema);
+        apply_setting(&mut self.soft_deleted_purge_threshold, &update.soft_deleted_purge_threshold);
+    }
+}
+
+fn diff_setting<T: Clone + PartialEq>(current: &Setting<T>, target: &Setting<T>) -> Setting<T> {
+    if current == target {
+        Setting::NotSet
+    } else {
+        target.clone()
+    }
+}
+
+fn apply_setting<T: Clone>(current: &mut Setting<T>, update: &Setting<T>) {
+    if !update.is_not_set() {
+        *current = update.clone();
+    }
 }
 
 impl Settings<Unchecked> {
@@ -266,6 +372,11 @@ impl Settings<Unchecked> {
             typo_tolerance: self.typo_tolerance,
             faceting: self.faceting,
             pagination: self.pagination,
+            ttl_attribute: self.ttl_attribute,
+            default_ttl: self.default_ttl,
+            primary_key_generation: self.primary_key_generation,
+            schema: self.schema,
+            soft_deleted_purge_threshold: self.soft_deleted_purge_threshold,
             _kind: PhantomData,
         }
     }
@@ -281,7 +392,7 @@ pub struct Facets {
 
 pub fn apply_settings_to_builder(
     settings: &Settings<Checked>,
-    builder: &mut milli::update::Settings,
+    builder: &mut milli::update::SettingsChanges,
 ) {
     match settings.searchable_attributes {
         Setting::Set(ref names) => builder.set_searchable_fields(names.clone()),
@@ -409,6 +520,36 @@ pub fn apply_settings_to_builder(
         Setting::Reset => builder.reset_pagination_max_total_hits(),
         Setting::NotSet => (),
     }
+
+    match settings.ttl_attribute {
+        Setting::Set(ref attr) => builder.set_ttl_field(attr.clone()),
+        Setting::Reset => builder.reset_ttl_field(),
+        Setting::NotSet => (),
+    }
+
+    match settings.default_ttl {
+        Setting::Set(ttl) => builder.set_default_ttl(ttl),
+        Setting::Reset => builder.reset_default_ttl(),
+        Setting::NotSet => (),
+    }
+
+    match settings.primary_key_generation {
+        Setting::Set(generation) => builder.set_primary_key_generation(generation.into()),
+        Setting::Reset => builder.reset_primary_key_generation(),
+        Setting::NotSet => (),
+    }
+
+    match settings.schema {
+        Setting::Set(ref schema) => builder.set_document_schema(schema.0.clone()),
+        Setting::Reset => builder.reset_document_schema(),
+        Setting::NotSet => (),
+    }
+
+    match settings.soft_deleted_purge_threshold {
+        Setting::Set(percentage) => builder.set_soft_deleted_purge_threshold(percentage),
+        Setting::Reset => builder.reset_soft_deleted_purge_threshold(),
+        Setting::NotSet => (),
+    }
 }
 
 pub fn settings(
@@ -436,6 +577,12 @@ pub fn settings(
         .transpose()?
         .unwrap_or_default();
     let distinct_field = index.distinct_field(rtxn)?.map(String::from);
+    let ttl_field = index.ttl_field(rtxn)?.map(String::from);
+    let default_ttl = index.default_ttl(rtxn)?;
+    let primary_key_generation = index.primary_key_generation(rtxn)?;
+
+    let schema = index.document_schema(rtxn)?;
+    let soft_deleted_purge_threshold = index.soft_deleted_purge_threshold(rtxn)?;
 
     // in milli each word in the synonyms map were split on their separator. Since we lost
     // this information we are going to put space between words.
@@ -497,6 +644,26 @@ pub fn settings(
         typo_tolerance: Setting::Set(typo_tolerance),
         faceting: Setting::Set(faceting),
         pagination: Setting::Set(pagination),
+        ttl_attribute: match ttl_field {
+            Some(field) => Setting::Set(field),
+            None => Setting::Reset,
+        },
+        default_ttl: match default_ttl {
+            Some(ttl) => Setting::Set(ttl),
+            None => Setting::Reset,
+        },
+        primary_key_generation: match primary_key_generation {
+            Some(generation) => Setting::Set(generation.into()),
+            None => Setting::Reset,
+        },
+        schema: match schema {
+            Some(schema) => Setting::Set(DocumentSchemaView(schema)),
+            None => Setting::Reset,
+        },
+        soft_deleted_purge_threshold: match soft_deleted_purge_threshold {
+            Some(percentage) => Setting::Set(percentage),
+            None => Setting::Reset,
+        },
         _kind: PhantomData,
     })
 }
@@ -597,6 +764,47 @@ impl From<RankingRuleView> for Criterion {
     }
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, DeserializeFromValue)]
+pub enum PrimaryKeyGenerationView {
+    /// Generates fully random UUIDs.
+    #[serde(rename = "uuidv4")]
+    #[deserr(rename = "uuidv4")]
+    UuidV4,
+    /// Generates UUIDs starting with their creation date.
+    #[serde(rename = "uuidv7")]
+    #[deserr(rename = "uuidv7")]
+    UuidV7,
+}
+impl From<PrimaryKeyGeneration> for PrimaryKeyGenerationView {
+    fn from(value: PrimaryKeyGeneration) -> Self {
+        match value {
+            PrimaryKeyGeneration::UuidV4 => PrimaryKeyGenerationView::UuidV4,
+            PrimaryKeyGeneration::UuidV7 => PrimaryKeyGenerationView::UuidV7,
+        }
+    }
+}
+impl From<PrimaryKeyGenerationView> for PrimaryKeyGeneration {
+    fn from(value: PrimaryKeyGenerationView) -> Self {
+        match value {
+            PrimaryKeyGenerationView::UuidV4 => PrimaryKeyGeneration::UuidV4,
+            PrimaryKeyGenerationView::UuidV7 => PrimaryKeyGeneration::UuidV7,
+        }
+    }
+}
+
+/// The schema the documents are checked against, it is parsed with serde as
+/// it describes values of any type.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, DeserializeFromValue)]
+#[serde(transparent)]
+#[deserr(from(serde_json::Value) = DocumentSchemaView::from_json -> TakeErrorMessage<serde_json::Error>)]
+pub struct DocumentSchemaView(pub DocumentSchema);
+
+impl DocumentSchemaView {
+    fn from_json(value: serde_json::Value) -> Result<Self, TakeErrorMessage<serde_json::Error>> {
+        serde_json::from_value(value).map(DocumentSchemaView).map_err(TakeErrorMessage)
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
@@ -616,6 +824,11 @@ pub(crate) mod test {
             typo_tolerance: Setting::NotSet,
             faceting: Setting::NotSet,
             pagination: Setting::NotSet,
+            ttl_attribute: Setting::NotSet,
+            default_ttl: Setting::NotSet,
+            primary_key_generation: Setting::NotSet,
+            schema: Setting::NotSet,
+            soft_deleted_purge_threshold: Setting::NotSet,
             _kind: PhantomData::<Unchecked>,
         };
 
@@ -637,6 +850,11 @@ pub(crate) mod test {
             typo_tolerance: Setting::NotSet,
             faceting: Setting::NotSet,
             pagination: Setting::NotSet,
+            ttl_attribute: Setting::NotSet,
+            default_ttl: Setting::NotSet,
+            primary_key_generation: Setting::NotSet,
+            schema: Setting::NotSet,
+            soft_deleted_purge_threshold: Setting::NotSet,
             _kind: PhantomData::<Unchecked>,
         };
 
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::settings::{Settings, Unchecked};
+
+/// A snapshot of the settings of an index, taken each time a settings update is applied to it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsVersion {
+    /// The versions of an index start at 1 and are incremented by each settings update.
+    /// The first version holds the settings the index had before its first settings update,
+    /// only the most recent versions are kept.
+    pub version: u32,
+    /// The uids of the tasks that were processed together to produce this version,
+    /// empty for the first version.
+    pub task_uids: Vec<u32>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub applied_at: OffsetDateTime,
+    /// All the settings of the index, as returned by [`crate::settings::settings`].
+    pub settings: Settings<Unchecked>,
+}
@@ -1,9 +1,10 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::fmt::{Display, Write};
 use std::str::FromStr;
 
 use enum_iterator::Sequence;
-use milli::update::IndexDocumentsMethod;
+use milli::update::{IndexDocumentsMethod, PrimaryKeyDefinition};
+use milli::SchemaViolations;
 use roaring::RoaringBitmap;
 use serde::{Deserialize, Serialize, Serializer};
 use time::{Duration, OffsetDateTime};
@@ -45,13 +46,21 @@ impl Task {
             | SnapshotCreation
             | TaskCancelation { .. }
             | TaskDeletion { .. }
-            | IndexSwap { .. } => None,
+            | IndexSwap { .. }
+            | AliasUpdate { .. } => None,
             DocumentAdditionOrUpdate { index_uid, .. }
             | DocumentDeletion { index_uid, .. }
+            | DocumentDeletionByFilter { index_uid, .. }
+            | DocumentExpiration { index_uid }
+            | DocumentEdition { index_uid, .. }
             | DocumentClear { index_uid }
+            | DocumentPurge { index_uid }
             | SettingsUpdate { index_uid, .. }
             | IndexCreation { index_uid, .. }
             | IndexUpdate { index_uid, .. }
+            | IndexClone { index_uid, .. }
+            | IndexReadOnlyUpdate { index_uid, .. }
+            | IndexCompaction { index_uid }
             | IndexDeletion { index_uid } => Some(index_uid),
         }
     }
@@ -66,12 +75,20 @@ impl Task {
         match self.kind {
             KindWithContent::DocumentAdditionOrUpdate { content_file, .. } => Some(content_file),
             KindWithContent::DocumentDeletion { .. }
+            | KindWithContent::DocumentDeletionByFilter { .. }
+            | KindWithContent::DocumentExpiration { .. }
+            | KindWithContent::DocumentEdition { .. }
             | KindWithContent::DocumentClear { .. }
+            | KindWithContent::DocumentPurge { .. }
             | KindWithContent::SettingsUpdate { .. }
             | KindWithContent::IndexDeletion { .. }
             | KindWithContent::IndexCreation { .. }
             | KindWithContent::IndexUpdate { .. }
+            | KindWithContent::IndexClone { .. }
+            | KindWithContent::IndexReadOnlyUpdate { .. }
+            | KindWithContent::IndexCompaction { .. }
             | KindWithContent::IndexSwap { .. }
+            | KindWithContent::AliasUpdate { .. }
             | KindWithContent::TaskCancelation { .. }
             | KindWithContent::TaskDeletion { .. }
             | KindWithContent::DumpCreation { .. }
@@ -95,29 +112,75 @@ pub enum KindWithContent {
         index_uid: String,
         documents_ids: Vec<String>,
     },
+    DocumentDeletionByFilter {
+        index_uid: String,
+        filter_expr: serde_json::Value,
+    },
+    /// Registered by the scheduler itself to delete the documents whose ttl has expired.
+    DocumentExpiration {
+        index_uid: String,
+    },
+    DocumentEdition {
+        index_uid: String,
+        filter_expr: Option<serde_json::Value>,
+        context: Option<milli::Object>,
+        function: String,
+    },
     DocumentClear {
         index_uid: String,
     },
+    /// Physically removes the soft-deleted documents of an index, by chunks.
+    DocumentPurge {
+        index_uid: String,
+    },
     SettingsUpdate {
         index_uid: String,
         new_settings: Box<Settings<Unchecked>>,
         is_deletion: bool,
         allow_index_creation: bool,
+        /// Whether the settings are a version of the settings history being restored, the
+        /// details of the task only list the ones that differ when it's processed.
+        #[serde(default)]
+        is_rollback: bool,
     },
     IndexDeletion {
         index_uid: String,
     },
     IndexCreation {
         index_uid: String,
-        primary_key: Option<String>,
+        primary_key: Option<PrimaryKeyDefinition>,
     },
     IndexUpdate {
         index_uid: String,
-        primary_key: Option<String>,
+        primary_key: Option<PrimaryKeyDefinition>,
+        /// The uid the index is renamed to.
+        new_index_uid: Option<String>,
+        /// Whether the API keys restricted to the index must follow it once it's renamed.
+        #[serde(default)]
+        migrate_api_keys: bool,
+    },
+    /// Copies an index with its settings and documents into a new index.
+    IndexClone {
+        index_uid: String,
+        target_uid: String,
+    },
+    /// Makes an index read-only or writable again, it's never rejected on a read-only index.
+    IndexReadOnlyUpdate {
+        index_uid: String,
+        read_only: bool,
+    },
+    /// Rewrites the LMDB environment of an index to give back the space of the deleted entries.
+    IndexCompaction {
+        index_uid: String,
     },
     IndexSwap {
         swaps: Vec<IndexSwap>,
     },
+    /// Points an alias to the given indexes, the alias is removed when there is none.
+    AliasUpdate {
+        alias: String,
+        indexes: Vec<String>,
+    },
     TaskCancelation {
         query: String,
         tasks: RoaringBitmap,
@@ -139,17 +202,39 @@ pub struct IndexSwap {
     pub indexes: (String, String),
 }
 
+/// The outcome of the import of one of the files of a documents import.
+///
+/// A file that fails in the middle of its import still reports the documents read before the
+/// error, they are imported with the other files.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportedFile {
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub received_documents: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
 impl KindWithContent {
     pub fn as_kind(&self) -> Kind {
         match self {
             KindWithContent::DocumentAdditionOrUpdate { .. } => Kind::DocumentAdditionOrUpdate,
             KindWithContent::DocumentDeletion { .. } => Kind::DocumentDeletion,
+            KindWithContent::DocumentDeletionByFilter { .. } => Kind::DocumentDeletion,
+            KindWithContent::DocumentExpiration { .. } => Kind::DocumentDeletion,
+            KindWithContent::DocumentEdition { .. } => Kind::DocumentEdition,
             KindWithContent::DocumentClear { .. } => Kind::DocumentDeletion,
+            KindWithContent::DocumentPurge { .. } => Kind::DocumentPurge,
             KindWithContent::SettingsUpdate { .. } => Kind::SettingsUpdate,
             KindWithContent::IndexCreation { .. } => Kind::IndexCreation,
             KindWithContent::IndexDeletion { .. } => Kind::IndexDeletion,
             KindWithContent::IndexUpdate { .. } => Kind::IndexUpdate,
+            KindWithContent::IndexClone { .. } => Kind::IndexClone,
+            KindWithContent::IndexReadOnlyUpdate { .. } => Kind::IndexReadOnlyUpdate,
+            KindWithContent::IndexCompaction { .. } => Kind::IndexCompaction,
             KindWithContent::IndexSwap { .. } => Kind::IndexSwap,
+            KindWithContent::AliasUpdate { .. } => Kind::AliasUpdate,
             KindWithContent::TaskCancelation { .. } => Kind::TaskCancelation,
             KindWithContent::TaskDeletion { .. } => Kind::TaskDeletion,
             KindWithContent::DumpCreation { .. } => Kind::DumpCreation,
@@ -164,14 +249,26 @@ impl KindWithContent {
             DumpCreation { .. }
             | SnapshotCreation
             | TaskCancelation { .. }
-            | TaskDeletion { .. } => vec![],
+            | TaskDeletion { .. }
+            | AliasUpdate { .. } => vec![],
             DocumentAdditionOrUpdate { index_uid, .. }
             | DocumentDeletion { index_uid, .. }
+            | DocumentDeletionByFilter { index_uid, .. }
+            | DocumentExpiration { index_uid }
+            | DocumentEdition { index_uid, .. }
             | DocumentClear { index_uid }
+            | DocumentPurge { index_uid }
             | SettingsUpdate { index_uid, .. }
             | IndexCreation { index_uid, .. }
-            | IndexUpdate { index_uid, .. }
+            | IndexReadOnlyUpdate { index_uid, .. }
+            | IndexCompaction { index_uid }
             | IndexDeletion { index_uid } => vec![index_uid],
+            IndexUpdate { index_uid, new_index_uid, .. } => {
+                let mut indexes = vec![index_uid.as_str()];
+                indexes.extend(new_index_uid.as_deref());
+                indexes
+            }
+            IndexClone { index_uid, target_uid } => vec![index_uid, target_uid],
             IndexSwap { swaps } => {
                 let mut indexes = HashSet::<&str>::default();
                 for swap in swaps {
@@ -191,6 +288,12 @@ impl KindWithContent {
                 Some(Details::DocumentAdditionOrUpdate {
                     received_documents: *documents_count,
                     indexed_documents: None,
+                    imported_files: None,
+                    version_conflicts: None,
+                    version_conflicts_count: None,
+                    generated_ids: None,
+                    generated_ids_count: None,
+                    schema_violations: None,
                 })
             }
             KindWithContent::DocumentDeletion { index_uid: _, documents_ids } => {
@@ -199,19 +302,57 @@ impl KindWithContent {
                     deleted_documents: None,
                 })
             }
+            KindWithContent::DocumentDeletionByFilter { index_uid: _, filter_expr } => {
+                Some(Details::DocumentDeletionByFilter {
+                    original_filter: filter_expr.to_string(),
+                    deleted_documents: None,
+                })
+            }
+            KindWithContent::DocumentExpiration { .. } => {
+                Some(Details::DocumentExpiration { deleted_documents: None })
+            }
+            KindWithContent::DocumentEdition { index_uid: _, filter_expr, context, function } => {
+                Some(Details::DocumentEdition {
+                    original_filter: filter_expr.as_ref().map(|filter| filter.to_string()),
+                    context: context.clone(),
+                    function: function.clone(),
+                    edited_documents: None,
+                })
+            }
             KindWithContent::DocumentClear { .. } | KindWithContent::IndexDeletion { .. } => {
                 Some(Details::ClearAll { deleted_documents: None })
             }
+            KindWithContent::DocumentPurge { .. } => {
+                Some(Details::DocumentPurge { purged_documents: None })
+            }
             KindWithContent::SettingsUpdate { new_settings, .. } => {
                 Some(Details::SettingsUpdate { settings: new_settings.clone() })
             }
-            KindWithContent::IndexCreation { primary_key, .. }
-            | KindWithContent::IndexUpdate { primary_key, .. } => {
-                Some(Details::IndexInfo { primary_key: primary_key.clone() })
+            KindWithContent::IndexCreation { primary_key, .. } => {
+                Some(Details::IndexInfo { primary_key: primary_key.clone(), new_index_uid: None })
             }
+            KindWithContent::IndexUpdate { primary_key, new_index_uid, .. } => {
+                Some(Details::IndexInfo {
+                    primary_key: primary_key.clone(),
+                    new_index_uid: new_index_uid.clone(),
+                })
+            }
+            KindWithContent::IndexClone { target_uid, .. } => {
+                Some(Details::IndexClone { target_uid: target_uid.clone(), cloned_documents: None })
+            }
+            KindWithContent::IndexReadOnlyUpdate { read_only, .. } => {
+                Some(Details::IndexReadOnlyUpdate { read_only: *read_only })
+            }
+            KindWithContent::IndexCompaction { .. } => Some(Details::IndexCompaction {
+                pre_compaction_size: None,
+                post_compaction_size: None,
+            }),
             KindWithContent::IndexSwap { swaps } => {
                 Some(Details::IndexSwap { swaps: swaps.clone() })
             }
+            KindWithContent::AliasUpdate { alias, indexes } => {
+                Some(Details::AliasUpdate { alias: alias.clone(), indexes: indexes.clone() })
+            }
             KindWithContent::TaskCancelation { query, tasks } => Some(Details::TaskCancelation {
                 matched_tasks: tasks.len(),
                 canceled_tasks: None,
@@ -233,6 +374,12 @@ impl KindWithContent {
                 Some(Details::DocumentAdditionOrUpdate {
                     received_documents: *documents_count,
                     indexed_documents: Some(0),
+                    imported_files: None,
+                    version_conflicts: None,
+                    version_conflicts_count: None,
+                    generated_ids: None,
+                    generated_ids_count: None,
+                    schema_violations: None,
                 })
             }
             KindWithContent::DocumentDeletion { index_uid: _, documents_ids } => {
@@ -241,20 +388,59 @@ impl KindWithContent {
                     deleted_documents: Some(0),
                 })
             }
+            KindWithContent::DocumentDeletionByFilter { index_uid: _, filter_expr } => {
+                Some(Details::DocumentDeletionByFilter {
+                    original_filter: filter_expr.to_string(),
+                    deleted_documents: Some(0),
+                })
+            }
+            KindWithContent::DocumentExpiration { .. } => {
+                Some(Details::DocumentExpiration { deleted_documents: Some(0) })
+            }
+            KindWithContent::DocumentEdition { index_uid: _, filter_expr, context, function } => {
+                Some(Details::DocumentEdition {
+                    original_filter: filter_expr.as_ref().map(|filter| filter.to_string()),
+                    context: context.clone(),
+                    function: function.clone(),
+                    edited_documents: Some(0),
+                })
+            }
             KindWithContent::DocumentClear { .. } => {
                 Some(Details::ClearAll { deleted_documents: None })
             }
+            KindWithContent::DocumentPurge { .. } => {
+                Some(Details::DocumentPurge { purged_documents: Some(0) })
+            }
             KindWithContent::SettingsUpdate { new_settings, .. } => {
                 Some(Details::SettingsUpdate { settings: new_settings.clone() })
             }
             KindWithContent::IndexDeletion { .. } => None,
-            KindWithContent::IndexCreation { primary_key, .. }
-            | KindWithContent::IndexUpdate { primary_key, .. } => {
-                Some(Details::IndexInfo { primary_key: primary_key.clone() })
+            KindWithContent::IndexCreation { primary_key, .. } => {
+                Some(Details::IndexInfo { primary_key: primary_key.clone(), new_index_uid: None })
+            }
+            KindWithContent::IndexUpdate { primary_key, new_index_uid, .. } => {
+                Some(Details::IndexInfo {
+                    primary_key: primary_key.clone(),
+                    new_index_uid: new_index_uid.clone(),
+                })
             }
+            KindWithContent::IndexClone { target_uid, .. } => Some(Details::IndexClone {
+                target_uid: target_uid.clone(),
+                cloned_documents: Some(0),
+            }),
+            KindWithContent::IndexReadOnlyUpdate { read_only, .. } => {
+                Some(Details::IndexReadOnlyUpdate { read_only: *read_only })
+            }
+            KindWithContent::IndexCompaction { .. } => Some(Details::IndexCompaction {
+                pre_compaction_size: None,
+                post_compaction_size: None,
+            }),
             KindWithContent::IndexSwap { .. } => {
                 todo!()
             }
+            KindWithContent::AliasUpdate { alias, indexes } => {
+                Some(Details::AliasUpdate { alias: alias.clone(), indexes: indexes.clone() })
+            }
             KindWithContent::TaskCancelation { query, tasks } => Some(Details::TaskCancelation {
                 matched_tasks: tasks.len(),
                 canceled_tasks: Some(0),
@@ -278,21 +464,49 @@ impl From<&KindWithContent> for Option<Details> {
                 Some(Details::DocumentAdditionOrUpdate {
                     received_documents: *documents_count,
                     indexed_documents: None,
+                    imported_files: None,
+                    version_conflicts: None,
+                    version_conflicts_count: None,
+                    generated_ids: None,
+                    generated_ids_count: None,
+                    schema_violations: None,
                 })
             }
             KindWithContent::DocumentDeletion { .. } => None,
+            KindWithContent::DocumentDeletionByFilter { .. } => None,
+            KindWithContent::DocumentExpiration { .. } => None,
+            KindWithContent::DocumentEdition { .. } => None,
             KindWithContent::DocumentClear { .. } => None,
+            KindWithContent::DocumentPurge { .. } => {
+                Some(Details::DocumentPurge { purged_documents: None })
+            }
             KindWithContent::SettingsUpdate { new_settings, .. } => {
                 Some(Details::SettingsUpdate { settings: new_settings.clone() })
             }
             KindWithContent::IndexDeletion { .. } => None,
             KindWithContent::IndexCreation { primary_key, .. } => {
-                Some(Details::IndexInfo { primary_key: primary_key.clone() })
+                Some(Details::IndexInfo { primary_key: primary_key.clone(), new_index_uid: None })
             }
-            KindWithContent::IndexUpdate { primary_key, .. } => {
-                Some(Details::IndexInfo { primary_key: primary_key.clone() })
+            KindWithContent::IndexUpdate { primary_key, new_index_uid, .. } => {
+                Some(Details::IndexInfo {
+                    primary_key: primary_key.clone(),
+                    new_index_uid: new_index_uid.clone(),
+                })
+            }
+            KindWithContent::IndexClone { target_uid, .. } => {
+                Some(Details::IndexClone { target_uid: target_uid.clone(), cloned_documents: None })
+            }
+            KindWithContent::IndexReadOnlyUpdate { read_only, .. } => {
+                Some(Details::IndexReadOnlyUpdate { read_only: *read_only })
             }
+            KindWithContent::IndexCompaction { .. } => Some(Details::IndexCompaction {
+                pre_compaction_size: None,
+                post_compaction_size: None,
+            }),
             KindWithContent::IndexSwap { .. } => None,
+            KindWithContent::AliasUpdate { alias, indexes } => {
+                Some(Details::AliasUpdate { alias: alias.clone(), indexes: indexes.clone() })
+            }
             KindWithContent::TaskCancelation { query, tasks } => Some(Details::TaskCancelation {
                 matched_tasks: tasks.len(),
                 canceled_tasks: None,
@@ -366,11 +580,17 @@ impl FromStr for Status {
 pub enum Kind {
     DocumentAdditionOrUpdate,
     DocumentDeletion,
+    DocumentEdition,
+    DocumentPurge,
     SettingsUpdate,
     IndexCreation,
     IndexDeletion,
     IndexUpdate,
+    IndexClone,
+    IndexReadOnlyUpdate,
+    IndexCompaction,
     IndexSwap,
+    AliasUpdate,
     TaskCancelation,
     TaskDeletion,
     DumpCreation,
@@ -382,11 +602,17 @@ impl Kind {
         match self {
             Kind::DocumentAdditionOrUpdate
             | Kind::DocumentDeletion
+            | Kind::DocumentEdition
+            | Kind::DocumentPurge
             | Kind::SettingsUpdate
             | Kind::IndexCreation
             | Kind::IndexDeletion
-            | Kind::IndexUpdate => true,
+            | Kind::IndexUpdate
+            | Kind::IndexClone
+            | Kind::IndexReadOnlyUpdate
+            | Kind::IndexCompaction => true,
             Kind::IndexSwap
+            | Kind::AliasUpdate
             | Kind::TaskCancelation
             | Kind::TaskDeletion
             | Kind::DumpCreation
@@ -399,11 +625,17 @@ impl Display for Kind {
         match self {
             Kind::DocumentAdditionOrUpdate => write!(f, "documentAdditionOrUpdate"),
             Kind::DocumentDeletion => write!(f, "documentDeletion"),
+            Kind::DocumentEdition => write!(f, "documentEdition"),
+            Kind::DocumentPurge => write!(f, "documentPurge"),
             Kind::SettingsUpdate => write!(f, "settingsUpdate"),
             Kind::IndexCreation => write!(f, "indexCreation"),
             Kind::IndexDeletion => write!(f, "indexDeletion"),
             Kind::IndexUpdate => write!(f, "indexUpdate"),
+            Kind::IndexClone => write!(f, "indexClone"),
+            Kind::IndexReadOnlyUpdate => write!(f, "indexReadOnlyUpdate"),
+            Kind::IndexCompaction => write!(f, "indexCompaction"),
             Kind::IndexSwap => write!(f, "indexSwap"),
+            Kind::AliasUpdate => write!(f, "aliasUpdate"),
             Kind::TaskCancelation => write!(f, "taskCancelation"),
             Kind::TaskDeletion => write!(f, "taskDeletion"),
             Kind::DumpCreation => write!(f, "dumpCreation"),
@@ -419,14 +651,26 @@ impl FromStr for Kind {
             Ok(Kind::IndexCreation)
         } else if kind.eq_ignore_ascii_case("indexUpdate") {
             Ok(Kind::IndexUpdate)
+        } else if kind.eq_ignore_ascii_case("indexClone") {
+            Ok(Kind::IndexClone)
+        } else if kind.eq_ignore_ascii_case("indexReadOnlyUpdate") {
+            Ok(Kind::IndexReadOnlyUpdate)
+        } else if kind.eq_ignore_ascii_case("indexCompaction") {
+            Ok(Kind::IndexCompaction)
         } else if kind.eq_ignore_ascii_case("indexSwap") {
             Ok(Kind::IndexSwap)
+        } else if kind.eq_ignore_ascii_case("aliasUpdate") {
+            Ok(Kind::AliasUpdate)
         } else if kind.eq_ignore_ascii_case("indexDeletion") {
             Ok(Kind::IndexDeletion)
         } else if kind.eq_ignore_ascii_case("documentAdditionOrUpdate") {
             Ok(Kind::DocumentAdditionOrUpdate)
         } else if kind.eq_ignore_ascii_case("documentDeletion") {
             Ok(Kind::DocumentDeletion)
+        } else if kind.eq_ignore_ascii_case("documentEdition") {
+            Ok(Kind::DocumentEdition)
+        } else if kind.eq_ignore_ascii_case("documentPurge") {
+            Ok(Kind::DocumentPurge)
         } else if kind.eq_ignore_ascii_case("settingsUpdate") {
             Ok(Kind::SettingsUpdate)
         } else if kind.eq_ignore_ascii_case("taskCancelation") {
@@ -459,15 +703,90 @@ impl FromStr for Kind {
 
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub enum Details {
-    DocumentAdditionOrUpdate { received_documents: u64, indexed_documents: Option<u64> },
-    SettingsUpdate { settings: Box<Settings<Unchecked>> },
-    IndexInfo { primary_key: Option<String> },
-    DocumentDeletion { provided_ids: usize, deleted_documents: Option<u64> },
-    ClearAll { deleted_documents: Option<u64> },
-    TaskCancelation { matched_tasks: u64, canceled_tasks: Option<u64>, original_filter: String },
-    TaskDeletion { matched_tasks: u64, deleted_tasks: Option<u64>, original_filter: String },
-    Dump { dump_uid: Option<String> },
-    IndexSwap { swaps: Vec<IndexSwap> },
+    DocumentAdditionOrUpdate {
+        received_documents: u64,
+        indexed_documents: Option<u64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        imported_files: Option<Vec<ImportedFile>>,
+        /// A sample of the ids of the documents skipped because their `_version` didn't match.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        version_conflicts: Option<Vec<String>>,
+        /// The number of documents skipped because their `_version` didn't match.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        version_conflicts_count: Option<u64>,
+        /// The ids generated for the documents that didn't have a primary key value,
+        /// keyed by the position of the document in the payload.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        generated_ids: Option<BTreeMap<u64, String>>,
+        /// The number of documents that got a generated id.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        generated_ids_count: Option<u64>,
+        /// The documents skipped or coerced because they didn't match the schema of the index.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        schema_violations: Option<SchemaViolations>,
+    },
+    SettingsUpdate {
+        settings: Box<Settings<Unchecked>>,
+    },
+    IndexInfo {
+        primary_key: Option<PrimaryKeyDefinition>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        new_index_uid: Option<String>,
+    },
+    IndexClone {
+        target_uid: String,
+        cloned_documents: Option<u64>,
+    },
+    IndexReadOnlyUpdate {
+        read_only: bool,
+    },
+    IndexCompaction {
+        pre_compaction_size: Option<u64>,
+        post_compaction_size: Option<u64>,
+    },
+    DocumentDeletion {
+        provided_ids: usize,
+        deleted_documents: Option<u64>,
+    },
+    DocumentDeletionByFilter {
+        original_filter: String,
+        deleted_documents: Option<u64>,
+    },
+    DocumentExpiration {
+        deleted_documents: Option<u64>,
+    },
+    DocumentPurge {
+        purged_documents: Option<u64>,
+    },
+    DocumentEdition {
+        original_filter: Option<String>,
+        context: Option<milli::Object>,
+        function: String,
+        edited_documents: Option<u64>,
+    },
+    ClearAll {
+        deleted_documents: Option<u64>,
+    },
+    TaskCancelation {
+        matched_tasks: u64,
+        canceled_tasks: Option<u64>,
+        original_filter: String,
+    },
+    TaskDeletion {
+        matched_tasks: u64,
+        deleted_tasks: Option<u64>,
+        original_filter: String,
+    },
+    Dump {
+        dump_uid: Option<String>,
+    },
+    IndexSwap {
+        swaps: Vec<IndexSwap>,
+    },
+    AliasUpdate {
+        alias: String,
+        indexes: Vec<String>,
+    },
 }
 
 impl Details {
@@ -478,13 +797,23 @@ impl Details {
                 *indexed_documents = Some(0)
             }
             Self::DocumentDeletion { deleted_documents, .. } => *deleted_documents = Some(0),
+            Self::DocumentDeletionByFilter { deleted_documents, .. } => {
+                *deleted_documents = Some(0)
+            }
+            Self::DocumentExpiration { deleted_documents } => *deleted_documents = Some(0),
+            Self::DocumentPurge { purged_documents } => *purged_documents = Some(0),
+            Self::DocumentEdition { edited_documents, .. } => *edited_documents = Some(0),
             Self::ClearAll { deleted_documents } => *deleted_documents = Some(0),
+            Self::IndexClone { cloned_documents, .. } => *cloned_documents = Some(0),
             Self::TaskCancelation { canceled_tasks, .. } => *canceled_tasks = Some(0),
             Self::TaskDeletion { deleted_tasks, .. } => *deleted_tasks = Some(0),
             Self::SettingsUpdate { .. }
             | Self::IndexInfo { .. }
+            | Self::IndexReadOnlyUpdate { .. }
+            | Self::IndexCompaction { .. }
             | Self::Dump { .. }
-            | Self::IndexSwap { .. } => (),
+            | Self::IndexSwap { .. }
+            | Self::AliasUpdate { .. } => (),
         }
 
         details
@@ -220,6 +220,7 @@ struct Infos {
     ignore_missing_dump: bool,
     ignore_dump_if_db_exists: bool,
     import_snapshot: bool,
+    import_dir: bool,
     schedule_snapshot: Option<u64>,
     snapshot_dir: bool,
     ignore_missing_snapshot: bool,
@@ -268,6 +269,7 @@ impl From<Opt> for Infos {
             ignore_missing_dump,
             ignore_dump_if_db_exists,
             dump_dir,
+            import_dir,
             log_level,
             indexer_options,
             config_file_path,
@@ -292,6 +294,7 @@ impl From<Opt> for Infos {
             ignore_missing_dump,
             ignore_dump_if_db_exists,
             import_snapshot: import_snapshot.is_some(),
+            import_dir: import_dir.is_some(),
             schedule_snapshot,
             snapshot_dir: snapshot_dir != PathBuf::from("snapshots/"),
             ignore_missing_snapshot,
@@ -795,6 +798,7 @@ pub struct DocumentsDeletionAggregator {
     per_document_id: bool,
     clear_all: bool,
     per_batch: bool,
+    per_filter: bool,
 }
 
 impl DocumentsDeletionAggregator {
@@ -808,6 +812,7 @@ impl DocumentsDeletionAggregator {
             DocumentDeletionKind::PerDocumentId => ret.per_document_id = true,
             DocumentDeletionKind::ClearAll => ret.clear_all = true,
             DocumentDeletionKind::PerBatch => ret.per_batch = true,
+            DocumentDeletionKind::PerFilter => ret.per_filter = true,
         }
 
         ret
@@ -827,6 +832,7 @@ impl DocumentsDeletionAggregator {
         self.per_document_id |= other.per_document_id;
         self.clear_all |= other.clear_all;
         self.per_batch |= other.per_batch;
+        self.per_filter |= other.per_filter;
     }
 
     pub fn into_event(self, user: &User, event_name: &str) -> Option<Track> {
@@ -20,14 +20,38 @@ pub enum MeilisearchHttpError {
     DocumentNotFound(String),
     #[error("Invalid syntax for the filter parameter: `expected {}, found: {1}`.", .0.join(", "))]
     InvalidExpression(&'static [&'static str], Value),
+    #[error("The filter used to delete documents cannot be empty.")]
+    EmptyFilter,
     #[error("A {0} payload is missing.")]
     MissingPayload(PayloadType),
+    #[error("The `csvDelimiter` parameter can only be used with a `csv` payload, but a `{0}` payload was sent.")]
+    CsvDelimiterWithWrongPayloadType(PayloadType),
+    #[error("The `csvQuote` parameter can only be used with a `csv` payload, but a `{0}` payload was sent.")]
+    CsvQuoteWithWrongPayloadType(PayloadType),
     #[error("The provided payload reached the size limit.")]
     PayloadTooLarge,
+    #[error("The Content-Encoding `{0}` is not supported. Accepted values for the Content-Encoding header are: `gzip`, `deflate`, `br`, `zstd`, `identity`.")]
+    InvalidContentEncoding(String),
+    #[error("Importing documents requires the `--import-dir` option to be set.")]
+    DocumentImportDisabled,
+    #[error("The import directory cannot be accessed: {0}.")]
+    ImportDir(std::io::Error),
+    #[error("The import path `{0}` is outside of the import directory.")]
+    ImportPathOutsideImportDir(String),
+    #[error("The import path `{0}` is invalid: {1}.")]
+    InvalidImportPath(String, glob::PatternError),
+    #[error("No file matches the import path `{0}`.")]
+    NoFileMatchingImportPath(String),
     #[error("Two indexes must be given for each swap. The list `{:?}` contains {} indexes.",
         .0, .0.len()
     )]
     SwapIndexPayloadWrongLength(Vec<String>),
+    #[error("The `sort` parameter cannot be used to search an alias pointing to several indexes.")]
+    SortOnMultiIndexAlias,
+    #[error("The `csv` format cannot be used to export an alias pointing to several indexes.")]
+    CsvExportOnMultiIndexAlias,
+    #[error("A filter cannot be validated against an alias pointing to several indexes.")]
+    FilterValidationOnMultiIndexAlias,
     #[error(transparent)]
     IndexUid(#[from] IndexUidFormatError),
     #[error(transparent)]
@@ -53,10 +77,24 @@ impl ErrorCode for MeilisearchHttpError {
         match self {
             MeilisearchHttpError::MissingContentType(_) => Code::MissingContentType,
             MeilisearchHttpError::MissingPayload(_) => Code::MissingPayload,
+            MeilisearchHttpError::CsvDelimiterWithWrongPayloadType(_) => {
+                Code::InvalidDocumentCsvDelimiter
+            }
+            MeilisearchHttpError::CsvQuoteWithWrongPayloadType(_) => Code::InvalidDocumentCsvQuote,
             MeilisearchHttpError::InvalidContentType(_, _) => Code::InvalidContentType,
             MeilisearchHttpError::DocumentNotFound(_) => Code::DocumentNotFound,
             MeilisearchHttpError::InvalidExpression(_, _) => Code::InvalidSearchFilter,
+            MeilisearchHttpError::SortOnMultiIndexAlias => Code::InvalidSearchSort,
+            MeilisearchHttpError::CsvExportOnMultiIndexAlias => Code::InvalidDocumentExportFormat,
+            MeilisearchHttpError::FilterValidationOnMultiIndexAlias => Code::InvalidIndexUid,
+            MeilisearchHttpError::EmptyFilter => Code::InvalidDocumentFilter,
             MeilisearchHttpError::PayloadTooLarge => Code::PayloadTooLarge,
+            MeilisearchHttpError::InvalidContentEncoding(_) => Code::InvalidContentEncoding,
+            MeilisearchHttpError::DocumentImportDisabled => Code::DocumentImportDisabled,
+            MeilisearchHttpError::ImportDir(_) => Code::Internal,
+            MeilisearchHttpError::ImportPathOutsideImportDir(_)
+            | MeilisearchHttpError::InvalidImportPath(_, _)
+            | MeilisearchHttpError::NoFileMatchingImportPath(_) => Code::InvalidDocumentImportPath,
             MeilisearchHttpError::SwapIndexPayloadWrongLength(_) => Code::InvalidSwapIndexes,
             MeilisearchHttpError::IndexUid(e) => e.error_code(),
             MeilisearchHttpError::SerdeJson(_) => Code::Internal,
@@ -104,7 +142,7 @@ impl ErrorCode for PayloadError {
         match self {
             PayloadError::Payload(e) => match e {
                 aweb::error::PayloadError::Incomplete(_) => Code::Internal,
-                aweb::error::PayloadError::EncodingCorrupted => Code::Internal,
+                aweb::error::PayloadError::EncodingCorrupted => Code::MalformedPayload,
                 aweb::error::PayloadError::Overflow => Code::PayloadTooLarge,
                 aweb::error::PayloadError::UnknownLength => Code::Internal,
                 aweb::error::PayloadError::Http2Payload(_) => Code::Internal,
@@ -1,16 +1,30 @@
+use std::io;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
-use actix_http::encoding::Decoder as Decompress;
+use actix_web::http::header::CONTENT_ENCODING;
 use actix_web::{dev, web, FromRequest, HttpRequest};
+use async_compression::tokio::bufread::{BrotliDecoder, GzipDecoder, ZlibDecoder, ZstdDecoder};
 use futures::future::{ready, Ready};
-use futures::Stream;
+use futures::{Stream, TryStreamExt};
+use tokio::io::AsyncRead;
+use tokio_util::io::{ReaderStream, StreamReader};
 
 use crate::error::MeilisearchHttpError;
 
+/// The maximum size of the chunks yielded by a compressed payload once decompressed.
+/// Decompressing by small chunks ensures that we never hold more than this amount
+/// of decompressed bytes in memory before checking the payload size limit.
+const DECOMPRESSED_CHUNK_SIZE: usize = 64 * 1024;
+
 pub struct Payload {
-    payload: Decompress<dev::Payload>,
-    limit: usize,
+    payload: PayloadStream,
+    remaining: usize,
+}
+
+enum PayloadStream {
+    Plain(dev::Payload),
+    Decompressed(ReaderStream<Pin<Box<dyn AsyncRead>>>),
 }
 
 pub struct PayloadConfig {
@@ -40,10 +54,51 @@ impl FromRequest for Payload {
             .app_data::<PayloadConfig>()
             .map(|c| c.limit)
             .unwrap_or(PayloadConfig::default().limit);
-        ready(Ok(Payload {
-            payload: Decompress::from_headers(payload.take(), req.headers()),
-            limit,
-        }))
+
+        let encoding = match req.headers().get(CONTENT_ENCODING).map(|e| e.to_str()) {
+            Some(Ok(encoding)) => encoding.trim().to_ascii_lowercase(),
+            Some(Err(_)) => {
+                return ready(Err(MeilisearchHttpError::InvalidContentEncoding(
+                    String::from_utf8_lossy(req.headers()[CONTENT_ENCODING].as_bytes())
+                        .into_owned(),
+                )))
+            }
+            None => String::from("identity"),
+        };
+
+        let payload = match decompress(&encoding, payload.take()) {
+            Some(payload) => payload,
+            None => return ready(Err(MeilisearchHttpError::InvalidContentEncoding(encoding))),
+        };
+
+        ready(Ok(Payload { payload, remaining: limit }))
+    }
+}
+
+/// Wraps the payload into a streaming decoder according to its `Content-Encoding`,
+/// returns `None` if the encoding is not supported.
+fn decompress(encoding: &str, payload: dev::Payload) -> Option<PayloadStream> {
+    if matches!(encoding, "identity" | "") {
+        return Some(PayloadStream::Plain(payload));
+    }
+
+    let reader = StreamReader::new(payload.map_err(|e| io::Error::new(io::ErrorKind::Other, e)));
+    let decoder: Pin<Box<dyn AsyncRead>> = match encoding {
+        "gzip" | "x-gzip" => Box::pin(GzipDecoder::new(reader)),
+        "deflate" => Box::pin(ZlibDecoder::new(reader)),
+        "br" => Box::pin(BrotliDecoder::new(reader)),
+        "zstd" => Box::pin(ZstdDecoder::new(reader)),
+        _ => return None,
+    };
+    Some(PayloadStream::Decompressed(ReaderStream::with_capacity(decoder, DECOMPRESSED_CHUNK_SIZE)))
+}
+
+/// Retrieves the original payload error if there is one,
+/// any other error is raised by the decoder because of a corrupted payload.
+fn decompression_error(error: io::Error) -> MeilisearchHttpError {
+    match error.into_inner().map(|e| e.downcast::<actix_web::error::PayloadError>()) {
+        Some(Ok(error)) => MeilisearchHttpError::from(*error),
+        _ => MeilisearchHttpError::from(actix_web::error::PayloadError::EncodingCorrupted),
     }
 }
 
@@ -52,18 +107,25 @@ impl Stream for Payload {
 
     #[inline]
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        match Pin::new(&mut self.payload).poll_next(cx) {
-            Poll::Ready(Some(result)) => match result {
-                Ok(bytes) => match self.limit.checked_sub(bytes.len()) {
-                    Some(new_limit) => {
-                        self.limit = new_limit;
-                        Poll::Ready(Some(Ok(bytes)))
-                    }
-                    None => Poll::Ready(Some(Err(MeilisearchHttpError::PayloadTooLarge))),
-                },
-                x => Poll::Ready(Some(x.map_err(MeilisearchHttpError::from))),
+        let poll = match &mut self.payload {
+            PayloadStream::Plain(payload) => Pin::new(payload)
+                .poll_next(cx)
+                .map(|o| o.map(|r| r.map_err(MeilisearchHttpError::from))),
+            PayloadStream::Decompressed(payload) => {
+                Pin::new(payload).poll_next(cx).map(|o| o.map(|r| r.map_err(decompression_error)))
+            }
+        };
+
+        match poll {
+            // The limit is checked on the decompressed size of the payload.
+            Poll::Ready(Some(Ok(bytes))) => match self.remaining.checked_sub(bytes.len()) {
+                Some(remaining) => {
+                    self.remaining = remaining;
+                    Poll::Ready(Some(Ok(bytes)))
+                }
+                None => Poll::Ready(Some(Err(MeilisearchHttpError::PayloadTooLarge))),
             },
-            otherwise => otherwise.map(|o| o.map(|o| o.map_err(MeilisearchHttpError::from))),
+            otherwise => otherwise,
         }
     }
 }
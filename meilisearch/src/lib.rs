@@ -31,17 +31,20 @@ use anyhow::bail;
 use error::PayloadError;
 use extractors::payload::PayloadConfig;
 use http::header::CONTENT_TYPE;
-use index_scheduler::{IndexScheduler, IndexSchedulerOptions};
+use index_scheduler::{ApiKeysMigration, IndexScheduler, IndexSchedulerOptions};
 use log::error;
 use meilisearch_auth::AuthController;
 use meilisearch_types::milli::documents::{DocumentsBatchBuilder, DocumentsBatchReader};
-use meilisearch_types::milli::update::{IndexDocumentsConfig, IndexDocumentsMethod};
+use meilisearch_types::milli::update::{
+    IndexDocumentsConfig, IndexDocumentsMethod, PrimaryKeyDefinition,
+};
 use meilisearch_types::settings::apply_settings_to_builder;
 use meilisearch_types::tasks::KindWithContent;
 use meilisearch_types::versioning::{check_version_file, create_version_file};
 use meilisearch_types::{compression, milli, VERSION_FILE_NAME};
 pub use option::Opt;
 use option::ScheduleSnapshot;
+use routes::indexes::documents::DocumentsImportConfig;
 
 use crate::error::MeilisearchHttpError;
 
@@ -196,6 +199,12 @@ fn open_or_create_database_unchecked(
     // wrap our two builders in a closure that'll be executed later.
     let auth_controller = AuthController::new(&opt.db_path, &opt.master_key);
     let index_scheduler_builder = || -> anyhow::Result<_> {
+        // The API keys follow a renamed index once the rename has been processed.
+        let api_keys_migration = auth_controller.as_ref().ok().cloned().map(|auth_controller| {
+            ApiKeysMigration(Arc::new(move |from: &str, to: &str| {
+                Ok(auth_controller.rename_index_in_keys(from, to)?)
+            }))
+        });
         Ok(IndexScheduler::new(IndexSchedulerOptions {
             version_file_path: opt.db_path.join(VERSION_FILE_NAME),
             auth_path: opt.db_path.join("auth"),
@@ -208,6 +217,7 @@ fn open_or_create_database_unchecked(
             index_size: opt.max_index_size.get_bytes() as usize,
             indexer_config: (&opt.indexer_options).try_into()?,
             autobatching_enabled: true,
+            api_keys_migration,
         })?)
     };
 
@@ -285,7 +295,7 @@ fn import_dump(
     // 3. Import the indexes.
     for index_reader in dump_reader.indexes()? {
         let mut index_reader = index_reader?;
-        let metadata = index_reader.metadata();
+        let metadata = index_reader.metadata().clone();
         log::info!("Importing index `{}`.", metadata.uid);
 
         let date = Some((metadata.created_at, metadata.updated_at));
@@ -295,8 +305,12 @@ fn import_dump(
 
         let mut builder = milli::update::Settings::new(&mut wtxn, &index, indexer_config);
         // 3.1 Import the primary key if there is one.
-        if let Some(ref primary_key) = metadata.primary_key {
-            builder.set_primary_key(primary_key.to_string());
+        match (&metadata.composite_primary_key, &metadata.primary_key) {
+            (Some(fields), _) => {
+                builder.set_primary_key(PrimaryKeyDefinition::Composite(fields.clone()))
+            }
+            (None, Some(primary_key)) => builder.set_primary_key(primary_key.to_string()),
+            (None, None) => (),
         }
 
         // 3.2 Import the settings.
@@ -327,6 +341,8 @@ fn import_dump(
             indexer_config,
             IndexDocumentsConfig {
                 update_method: IndexDocumentsMethod::ReplaceDocuments,
+                // The documents are restored with the `_version` they had when dumped.
+                skip_version_check: true,
                 ..Default::default()
             },
             |indexing_step| log::debug!("update: {:?}", indexing_step),
@@ -338,9 +354,30 @@ fn import_dump(
         builder.execute()?;
         wtxn.commit()?;
         log::info!("All documents successfully imported.");
+
+        // 3.4 Import the quota and the read-only flag, once the documents are in, and
+        // let the run loop maintain the index if its settings require it.
+        index_scheduler.update_index_quota(&metadata.uid, &metadata.quota)?;
+        index_scheduler.set_index_read_only(&metadata.uid, metadata.read_only)?;
+        index_scheduler.update_maintained_index(&metadata.uid)?;
+
+        // 3.5 Import the history of the settings.
+        for settings_version in index_reader.settings_history()? {
+            index_scheduler.put_settings_version(&metadata.uid, &settings_version?)?;
+        }
+    }
+
+    // 4. Import the index templates.
+    for template in dump_reader.index_templates()? {
+        index_scheduler.put_index_template(&template?)?;
+    }
+
+    // 5. Import the aliases, once the indexes they point to exist.
+    for alias in dump_reader.aliases()? {
+        index_scheduler.put_alias(&alias?)?;
     }
 
-    // 4. Import the tasks.
+    // 6. Import the tasks.
     for ret in dump_reader.tasks()? {
         let (task, file) = ret?;
         index_scheduler.register_dumped_task(task, file)?;
@@ -379,6 +416,7 @@ pub fn configure_data(
                 }),
         )
         .app_data(PayloadConfig::new(http_payload_size_limit))
+        .app_data(web::Data::new(DocumentsImportConfig::new(opt.import_dir.clone())))
         .app_data(
             web::QueryConfig::default().error_handler(|err, _req| PayloadError::from(err).into()),
         );
@@ -46,6 +46,7 @@ const MEILI_IMPORT_DUMP: &str = "MEILI_IMPORT_DUMP";
 const MEILI_IGNORE_MISSING_DUMP: &str = "MEILI_IGNORE_MISSING_DUMP";
 const MEILI_IGNORE_DUMP_IF_DB_EXISTS: &str = "MEILI_IGNORE_DUMP_IF_DB_EXISTS";
 const MEILI_DUMP_DIR: &str = "MEILI_DUMP_DIR";
+const MEILI_IMPORT_DIR: &str = "MEILI_IMPORT_DIR";
 const MEILI_LOG_LEVEL: &str = "MEILI_LOG_LEVEL";
 #[cfg(feature = "metrics")]
 const MEILI_ENABLE_METRICS_ROUTE: &str = "MEILI_ENABLE_METRICS_ROUTE";
@@ -280,6 +281,11 @@ pub struct Opt {
     #[serde(default = "default_dump_dir")]
     pub dump_dir: PathBuf,
 
+    /// Sets the directory from which documents can be imported with the
+    /// `/indexes/{index_uid}/documents/import` route. The route is disabled when not provided.
+    #[clap(long, env = MEILI_IMPORT_DIR)]
+    pub import_dir: Option<PathBuf>,
+
     /// Defines how much detail should be present in Meilisearch's logs.
     ///
     /// Meilisearch currently supports six log levels, listed in order of increasing verbosity: OFF, ERROR, WARN, INFO, DEBUG, TRACE.
@@ -373,6 +379,7 @@ impl Opt {
             snapshot_dir,
             schedule_snapshot,
             dump_dir,
+            import_dir,
             log_level,
             indexer_options,
             import_snapshot: _,
@@ -422,6 +429,9 @@ impl Opt {
         }
 
         export_to_env_if_not_present(MEILI_DUMP_DIR, dump_dir);
+        if let Some(import_dir) = import_dir {
+            export_to_env_if_not_present(MEILI_IMPORT_DIR, import_dir);
+        }
         export_to_env_if_not_present(MEILI_LOG_LEVEL, log_level.to_string());
         #[cfg(feature = "metrics")]
         {
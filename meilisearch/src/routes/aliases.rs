@@ -0,0 +1,129 @@
+use actix_web::web::Data;
+use actix_web::{web, HttpRequest, HttpResponse};
+use deserr::DeserializeFromValue;
+use index_scheduler::IndexScheduler;
+use log::debug;
+use meilisearch_types::error::deserr_codes::InvalidAliasIndexes;
+use meilisearch_types::error::{Code, DeserrError, ResponseError};
+use meilisearch_types::index_uid::IndexUid;
+use meilisearch_types::tasks::KindWithContent;
+use serde::Serialize;
+use serde_json::json;
+
+use super::indexes::ListIndexes;
+use super::SummarizedTaskView;
+use crate::analytics::Analytics;
+use crate::extractors::authentication::policies::*;
+use crate::extractors::authentication::{AuthenticationError, GuardedData};
+use crate::extractors::json::ValidatedJson;
+use crate::extractors::query_parameters::QueryParameter;
+use crate::extractors::sequential_extractor::SeqHandler;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("").route(web::get().to(list_aliases))).service(
+        // The alias is named `index_uid` in the path so that the API keys
+        // restricted to some indexes can be restricted to some aliases too.
+        web::resource("/{index_uid}")
+            .route(web::get().to(SeqHandler(get_alias)))
+            .route(web::put().to(SeqHandler(update_alias)))
+            .route(web::delete().to(SeqHandler(delete_alias))),
+    );
+}
+
+/// The indexes an alias points to are not part of its view, the API keys
+/// restricted to an alias must not learn the names of the underlying indexes.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AliasView {
+    pub uid: String,
+}
+
+pub async fn list_aliases(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::INDEXES_GET }>, Data<IndexScheduler>>,
+    paginate: QueryParameter<ListIndexes, DeserrError>,
+) -> Result<HttpResponse, ResponseError> {
+    let search_rules = &index_scheduler.filters().search_rules;
+    let aliases: Vec<_> = index_scheduler
+        .aliases()?
+        .into_iter()
+        .filter(|(uid, _)| search_rules.is_index_authorized(uid))
+        .map(|(uid, _indexes)| AliasView { uid })
+        .collect();
+
+    let ret = paginate.as_pagination().auto_paginate_sized(aliases.into_iter());
+
+    debug!("returns: {:?}", ret);
+    Ok(HttpResponse::Ok().json(ret))
+}
+
+pub async fn get_alias(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::INDEXES_GET }>, Data<IndexScheduler>>,
+    alias: web::Path<String>,
+) -> Result<HttpResponse, ResponseError> {
+    index_scheduler.alias(&alias)?;
+    let alias_view = AliasView { uid: alias.into_inner() };
+
+    debug!("returns: {:?}", alias_view);
+    Ok(HttpResponse::Ok().json(alias_view))
+}
+
+#[derive(DeserializeFromValue, Debug)]
+#[deserr(error = DeserrError, rename_all = camelCase, deny_unknown_fields)]
+pub struct UpdateAliasRequest {
+    #[deserr(error = DeserrError<InvalidAliasIndexes>)]
+    indexes: Vec<String>,
+}
+
+pub async fn update_alias(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::INDEXES_UPDATE }>, Data<IndexScheduler>>,
+    alias: web::Path<String>,
+    body: ValidatedJson<UpdateAliasRequest, DeserrError>,
+    req: HttpRequest,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let UpdateAliasRequest { indexes } = body.into_inner();
+    let alias = IndexUid::try_from(alias.into_inner())?.into_inner();
+
+    if indexes.is_empty() {
+        return Err(ResponseError::from_msg(
+            "An alias must point to at least one index.".to_string(),
+            Code::InvalidAliasIndexes,
+        ));
+    }
+    let search_rules = &index_scheduler.filters().search_rules;
+    for index in &indexes {
+        IndexUid::try_from(index.clone())?;
+        if !search_rules.is_index_authorized(index) {
+            return Err(AuthenticationError::InvalidToken.into());
+        }
+    }
+
+    analytics.publish(
+        "Alias Updated".to_string(),
+        json!({ "number_of_indexes": indexes.len() }),
+        Some(&req),
+    );
+
+    let task = KindWithContent::AliasUpdate { alias, indexes };
+    let task: SummarizedTaskView =
+        tokio::task::spawn_blocking(move || index_scheduler.register(task)).await??.into();
+
+    debug!("returns: {:?}", task);
+    Ok(HttpResponse::Accepted().json(task))
+}
+
+pub async fn delete_alias(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::INDEXES_UPDATE }>, Data<IndexScheduler>>,
+    alias: web::Path<String>,
+    req: HttpRequest,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    analytics.publish("Alias Deleted".to_string(), json!({}), Some(&req));
+
+    let task = KindWithContent::AliasUpdate { alias: alias.into_inner(), indexes: Vec::new() };
+    let task: SummarizedTaskView =
+        tokio::task::spawn_blocking(move || index_scheduler.register(task)).await??.into();
+
+    debug!("returns: {:?}", task);
+    Ok(HttpResponse::Accepted().json(task))
+}
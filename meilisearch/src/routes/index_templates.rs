@@ -0,0 +1,93 @@
+use actix_web::web::Data;
+use actix_web::{web, HttpRequest, HttpResponse};
+use index_scheduler::IndexScheduler;
+use log::debug;
+use meilisearch_types::error::{DeserrError, ResponseError};
+use meilisearch_types::index_templates::{validate_pattern, IndexTemplate};
+use meilisearch_types::settings::{Settings, Unchecked};
+use serde_json::json;
+
+use super::indexes::ListIndexes;
+use crate::analytics::Analytics;
+use crate::extractors::authentication::policies::*;
+use crate::extractors::authentication::GuardedData;
+use crate::extractors::json::ValidatedJson;
+use crate::extractors::query_parameters::QueryParameter;
+use crate::extractors::sequential_extractor::SeqHandler;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("").route(web::get().to(list_index_templates))).service(
+        // The pattern is named `index_uid` in the path so that the API keys
+        // restricted to some indexes can be restricted to some templates too.
+        web::resource("/{index_uid}")
+            .route(web::get().to(SeqHandler(get_index_template)))
+            .route(web::put().to(SeqHandler(put_index_template)))
+            .route(web::delete().to(SeqHandler(delete_index_template))),
+    );
+}
+
+pub async fn list_index_templates(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::INDEXES_GET }>, Data<IndexScheduler>>,
+    paginate: QueryParameter<ListIndexes, DeserrError>,
+) -> Result<HttpResponse, ResponseError> {
+    let search_rules = &index_scheduler.filters().search_rules;
+    let templates: Vec<_> = index_scheduler
+        .index_templates()?
+        .into_iter()
+        .filter(|template| search_rules.is_index_authorized(&template.pattern))
+        .collect();
+
+    let ret = paginate.as_pagination().auto_paginate_sized(templates.into_iter());
+
+    debug!("returns: {:?}", ret);
+    Ok(HttpResponse::Ok().json(ret))
+}
+
+pub async fn get_index_template(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::INDEXES_GET }>, Data<IndexScheduler>>,
+    pattern: web::Path<String>,
+) -> Result<HttpResponse, ResponseError> {
+    let settings = index_scheduler.index_template(&pattern)?;
+    let template = IndexTemplate { pattern: pattern.into_inner(), settings };
+
+    debug!("returns: {:?}", template);
+    Ok(HttpResponse::Ok().json(template))
+}
+
+pub async fn put_index_template(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::INDEXES_CREATE }>, Data<IndexScheduler>>,
+    pattern: web::Path<String>,
+    body: ValidatedJson<Settings<Unchecked>, DeserrError>,
+    req: HttpRequest,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let pattern = validate_pattern(pattern.into_inner())?;
+    let template = IndexTemplate { pattern, settings: body.into_inner() };
+
+    analytics.publish(
+        "Index Template Updated".to_string(),
+        json!({ "with_wildcard": template.pattern.contains('*') }),
+        Some(&req),
+    );
+
+    let template = tokio::task::spawn_blocking(move || {
+        index_scheduler.put_index_template(&template).map(|()| template)
+    })
+    .await??;
+
+    debug!("returns: {:?}", template);
+    Ok(HttpResponse::Ok().json(template))
+}
+
+pub async fn delete_index_template(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::INDEXES_DELETE }>, Data<IndexScheduler>>,
+    pattern: web::Path<String>,
+    req: HttpRequest,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    analytics.publish("Index Template Deleted".to_string(), json!({}), Some(&req));
+
+    tokio::task::spawn_blocking(move || index_scheduler.delete_index_template(&pattern)).await??;
+
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -1,45 +1,67 @@
-use std::io::ErrorKind;
+use std::io::{self, ErrorKind, Seek};
+use std::mem;
 use std::num::ParseIntError;
+use std::path::{Component, Path, PathBuf};
 
 use actix_web::http::header::CONTENT_TYPE;
 use actix_web::web::Data;
 use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
 use bstr::ByteSlice;
+use bytes::Bytes;
 use deserr::DeserializeFromValue;
+use flate2::read::GzDecoder;
 use futures::StreamExt;
 use index_scheduler::IndexScheduler;
 use log::debug;
-use meilisearch_types::document_formats::{read_csv, read_json, read_ndjson, PayloadType};
+use meilisearch_types::document_formats::{
+    append_arrow_stream, append_csv, append_json, append_ndjson, append_parquet,
+    parse_csv_character, read_arrow_stream, read_csv, read_json, read_ndjson, read_parquet,
+    DocumentFormatError, InvalidCsvCharacter, PayloadType,
+};
 use meilisearch_types::error::deserr_codes::*;
-use meilisearch_types::error::{DeserrError, ResponseError, TakeErrorMessage};
+use meilisearch_types::error::{Code, DeserrError, ResponseError, TakeErrorMessage};
 use meilisearch_types::heed::RoTxn;
 use meilisearch_types::index_uid::IndexUid;
+use meilisearch_types::milli::documents::DocumentsBatchBuilder;
 use meilisearch_types::milli::update::IndexDocumentsMethod;
+use meilisearch_types::milli::FieldsIdsMap;
 use meilisearch_types::star_or::StarOr;
-use meilisearch_types::tasks::KindWithContent;
+use meilisearch_types::tasks::{Details, ImportedFile, KindWithContent};
 use meilisearch_types::{milli, Document, Index};
 use mime::Mime;
 use once_cell::sync::Lazy;
+use roaring::RoaringBitmap;
 use serde::Deserialize;
 use serde_cs::vec::CS;
 use serde_json::Value;
 use tempfile::tempfile;
+use time::OffsetDateTime;
 use tokio::fs::File;
 use tokio::io::{AsyncSeekExt, AsyncWriteExt, BufWriter};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
 
-use super::search::parse_usize_take_error_message;
+use super::search::{parse_bool_take_error_message, parse_usize_take_error_message};
 use crate::analytics::{Analytics, DocumentDeletionKind};
 use crate::error::MeilisearchHttpError;
 use crate::error::PayloadError::ReceivePayload;
 use crate::extractors::authentication::policies::*;
 use crate::extractors::authentication::GuardedData;
+use crate::extractors::json::ValidatedJson;
 use crate::extractors::payload::Payload;
 use crate::extractors::query_parameters::QueryParameter;
 use crate::extractors::sequential_extractor::SeqHandler;
 use crate::routes::{fold_star_or, PaginationView, SummarizedTaskView};
+use crate::search::parse_filter;
 
 static ACCEPTED_CONTENT_TYPE: Lazy<Vec<String>> = Lazy::new(|| {
-    vec!["application/json".to_string(), "application/x-ndjson".to_string(), "text/csv".to_string()]
+    vec![
+        "application/json".to_string(),
+        "application/x-ndjson".to_string(),
+        "text/csv".to_string(),
+        "application/vnd.apache.parquet".to_string(),
+        "application/vnd.apache.arrow.stream".to_string(),
+    ]
 });
 
 /// Extracts the mime type from the content type and return
@@ -70,10 +92,17 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .route(web::get().to(SeqHandler(get_all_documents)))
             .route(web::post().to(SeqHandler(add_documents)))
             .route(web::put().to(SeqHandler(update_documents)))
+            .route(web::patch().to(SeqHandler(deep_merge_documents)))
             .route(web::delete().to(SeqHandler(clear_all_documents))),
     )
-    // this route needs to be before the /documents/{document_id} to match properly
+    // these routes need to be before the /documents/{document_id} to match properly
     .service(web::resource("/delete-batch").route(web::post().to(SeqHandler(delete_documents))))
+    .service(web::resource("/delete").route(web::post().to(SeqHandler(delete_documents_by_filter))))
+    .service(web::resource("/edit").route(web::post().to(SeqHandler(edit_documents_by_function))))
+    .service(web::resource("/fetch").route(web::post().to(SeqHandler(fetch_documents))))
+    .service(web::resource("/export").route(web::get().to(SeqHandler(export_documents))))
+    .service(web::resource("/import").route(web::post().to(SeqHandler(import_documents))))
+    .service(web::resource("/purge").route(web::post().to(SeqHandler(purge_documents))))
     .service(
         web::resource("/{document_id}")
             .route(web::get().to(SeqHandler(get_document)))
@@ -96,12 +125,38 @@ pub async fn get_document(
     let GetDocument { fields } = params.into_inner();
     let attributes_to_retrieve = fields.and_then(fold_star_or);
 
-    let index = index_scheduler.index(&path.index_uid)?;
-    let document = retrieve_document(&index, &path.document_id, attributes_to_retrieve)?;
+    // An alias can point to several indexes, the document is taken from the first one containing it.
+    let mut document = Err(MeilisearchHttpError::DocumentNotFound(path.document_id.clone()).into());
+    for (_, index) in index_scheduler.resolve_indexes(&path.index_uid)? {
+        document = find_document(&index, &path.document_id, attributes_to_retrieve.clone());
+        if document.is_ok() {
+            break;
+        }
+    }
+    let document = document?;
     debug!("returns: {:?}", document);
     Ok(HttpResponse::Ok().json(document))
 }
 
+fn find_document(
+    index: &Index,
+    document_id: &str,
+    attributes_to_retrieve: Option<Vec<String>>,
+) -> Result<Document, ResponseError> {
+    // A valid document id never starts with a `{`, it is the object of the key parts.
+    let document_id = if document_id.starts_with('{') {
+        match serde_json::from_str(document_id) {
+            Ok(parts) => document_id_from_parts(index, parts)?,
+            Err(_) => {
+                return Err(MeilisearchHttpError::DocumentNotFound(document_id.to_string()).into())
+            }
+        }
+    } else {
+        document_id.to_string()
+    };
+    retrieve_document(index, &document_id, attributes_to_retrieve)
+}
+
 pub async fn delete_document(
     index_scheduler: GuardedData<ActionPolicy<{ actions::DOCUMENTS_DELETE }>, Data<IndexScheduler>>,
     path: web::Path<DocumentParam>,
@@ -127,6 +182,8 @@ pub struct BrowseQuery {
     limit: usize,
     #[deserr(error = DeserrError<InvalidDocumentLimit>)]
     fields: Option<CS<StarOr<String>>>,
+    #[deserr(error = DeserrError<InvalidDocumentFilter>)]
+    filter: Option<String>,
 }
 
 pub async fn get_all_documents(
@@ -135,11 +192,13 @@ pub async fn get_all_documents(
     params: QueryParameter<BrowseQuery, DeserrError>,
 ) -> Result<HttpResponse, ResponseError> {
     debug!("called with params: {:?}", params);
-    let BrowseQuery { limit, offset, fields } = params.into_inner();
+    let BrowseQuery { limit, offset, fields, filter } = params.into_inner();
     let attributes_to_retrieve = fields.and_then(fold_star_or);
+    let filter = filter.map(filter_from_query_parameter);
 
-    let index = index_scheduler.index(&index_uid)?;
-    let (total, documents) = retrieve_documents(&index, offset, limit, attributes_to_retrieve)?;
+    let indexes = index_scheduler.resolve_indexes(&index_uid)?;
+    let (total, documents) =
+        retrieve_alias_documents(&indexes, offset, limit, filter, attributes_to_retrieve)?;
 
     let ret = PaginationView::new(offset, limit, total as usize, documents);
 
@@ -147,11 +206,302 @@ pub async fn get_all_documents(
     Ok(HttpResponse::Ok().json(ret))
 }
 
+/// The filter of a query parameter can either be a filter expression or a JSON array of expressions.
+fn filter_from_query_parameter(filter: String) -> Value {
+    match serde_json::from_str(&filter) {
+        Ok(filter @ Value::Array(_)) => filter,
+        _ => Value::String(filter),
+    }
+}
+
+#[derive(Debug, DeserializeFromValue)]
+#[deserr(error = DeserrError, rename_all = camelCase, deny_unknown_fields)]
+pub struct FetchDocumentsQuery {
+    #[deserr(error = DeserrError<InvalidDocumentOffset>, default)]
+    offset: usize,
+    #[deserr(error = DeserrError<InvalidDocumentLimit>, default = crate::routes::PAGINATION_DEFAULT_LIMIT())]
+    limit: usize,
+    #[deserr(error = DeserrError<InvalidDocumentFields>)]
+    fields: Option<Vec<StarOr<String>>>,
+    #[deserr(error = DeserrError<InvalidDocumentFilter>)]
+    filter: Option<Value>,
+}
+
+pub async fn fetch_documents(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::DOCUMENTS_GET }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+    params: ValidatedJson<FetchDocumentsQuery, DeserrError>,
+) -> Result<HttpResponse, ResponseError> {
+    debug!("called with params: {:?}", params);
+    let FetchDocumentsQuery { limit, offset, fields, filter } = params.into_inner();
+    let attributes_to_retrieve = fields.and_then(fold_star_or);
+
+    let indexes = index_scheduler.resolve_indexes(&index_uid)?;
+    let (total, documents) =
+        retrieve_alias_documents(&indexes, offset, limit, filter, attributes_to_retrieve)?;
+
+    let ret = PaginationView::new(offset, limit, total as usize, documents);
+
+    debug!("returns: {:?}", ret);
+    Ok(HttpResponse::Ok().json(ret))
+}
+
+/// The size, in bytes, above which the exported documents are sent to the client.
+const EXPORT_CHUNK_SIZE: usize = 64 * 1024;
+/// The number of chunks that can wait to be sent to the client before the export pauses.
+const EXPORT_CHANNEL_CAPACITY: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, DeserializeFromValue)]
+#[deserr(rename_all = camelCase)]
+pub enum ExportFormat {
+    Ndjson,
+    Csv,
+}
+
+impl Default for ExportFormat {
+    fn default() -> Self {
+        Self::Ndjson
+    }
+}
+
+#[derive(Debug, DeserializeFromValue)]
+#[deserr(error = DeserrError, rename_all = camelCase, deny_unknown_fields)]
+pub struct ExportQuery {
+    #[deserr(error = DeserrError<InvalidDocumentFields>)]
+    fields: Option<CS<StarOr<String>>>,
+    #[deserr(error = DeserrError<InvalidDocumentFilter>)]
+    filter: Option<String>,
+    #[deserr(error = DeserrError<InvalidDocumentExportFormat>, default)]
+    format: ExportFormat,
+}
+
+pub async fn export_documents(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::DOCUMENTS_GET }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+    params: QueryParameter<ExportQuery, DeserrError>,
+) -> Result<HttpResponse, ResponseError> {
+    debug!("called with params: {:?}", params);
+    let ExportQuery { fields, filter, format } = params.into_inner();
+    let attributes_to_retrieve: Option<Vec<String>> = fields.and_then(fold_star_or);
+    let filter = filter.map(filter_from_query_parameter);
+
+    // The documents of the indexes an alias points to are exported one index after the
+    // other, the CSV columns can't be shared by indexes with different fields.
+    let indexes = index_scheduler.resolve_indexes(&index_uid)?;
+    if indexes.len() > 1 && format == ExportFormat::Csv {
+        return Err(MeilisearchHttpError::CsvExportOnMultiIndexAlias.into());
+    }
+    let (sender, mut receiver) = mpsc::channel(EXPORT_CHANNEL_CAPACITY);
+    tokio::task::spawn_blocking(move || {
+        let writer = ExportWriter::new(format);
+        if let Err(e) = export_to_channel(&indexes, filter, attributes_to_retrieve, writer, &sender)
+        {
+            // If the client is already gone there is nobody to report the error to.
+            let _ = sender.blocking_send(Err(e));
+        }
+    });
+
+    // Only the errors happening before anything was exported, like an invalid filter,
+    // can be reported with a proper status code.
+    let first_chunk = match receiver.recv().await {
+        Some(Ok(chunk)) => Some(Ok(chunk)),
+        Some(Err(e)) => return Err(e),
+        None => None,
+    };
+    let stream = futures::stream::iter(first_chunk).chain(ReceiverStream::new(receiver));
+
+    let content_type = match format {
+        ExportFormat::Ndjson => "application/x-ndjson",
+        ExportFormat::Csv => "text/csv",
+    };
+    Ok(HttpResponse::Ok().content_type(content_type).streaming(stream))
+}
+
+/// Sends the documents of the indexes matching the filter in the channel by chunks of
+/// about [`EXPORT_CHUNK_SIZE`] bytes.
+fn export_to_channel(
+    indexes: &[(String, Index)],
+    filter: Option<Value>,
+    attributes_to_retrieve: Option<Vec<String>>,
+    mut writer: ExportWriter,
+    sender: &mpsc::Sender<Result<Bytes, ResponseError>>,
+) -> Result<(), ResponseError> {
+    for (_, index) in indexes {
+        let exported = export_index(
+            index,
+            filter.as_ref(),
+            attributes_to_retrieve.as_deref(),
+            &mut writer,
+            sender,
+        )?;
+        if !exported {
+            // The client closed the connection, we can stop exporting.
+            return Ok(());
+        }
+    }
+
+    if let Some(chunk) = writer.take_chunk(1)? {
+        let _ = sender.blocking_send(Ok(chunk));
+    }
+
+    Ok(())
+}
+
+/// Writes the documents of an index matching the filter, the full chunks are sent in the
+/// channel. The index is read in a single read transaction so that it is exported at a
+/// single point in time. Returns `false` if the client closed the connection.
+fn export_index(
+    index: &Index,
+    filter: Option<&Value>,
+    attributes_to_retrieve: Option<&[String]>,
+    writer: &mut ExportWriter,
+    sender: &mpsc::Sender<Result<Bytes, ResponseError>>,
+) -> Result<bool, ResponseError> {
+    let rtxn = index.read_txn()?;
+    let candidates = filtered_documents_ids(index, &rtxn, filter)?;
+    let expired = index.expired_documents_ids(&rtxn, OffsetDateTime::now_utc())?;
+    let fields_ids_map = index.fields_ids_map(&rtxn)?;
+    let all_fields: Vec<_> = fields_ids_map.iter().map(|(id, _)| id).collect();
+
+    writer.write_header(&fields_ids_map, attributes_to_retrieve)?;
+
+    for result in index.all_documents(&rtxn)? {
+        let (id, document) = result?;
+        if candidates.as_ref().map_or(false, |candidates| !candidates.contains(id))
+            || expired.contains(id)
+        {
+            continue;
+        }
+
+        let document = milli::obkv_to_json(&all_fields, &fields_ids_map, document)?;
+        let document = match attributes_to_retrieve {
+            Some(attributes_to_retrieve) => permissive_json_pointer::select_values(
+                &document,
+                attributes_to_retrieve.iter().map(|s| s.as_ref()),
+            ),
+            None => document,
+        };
+        writer.write_document(&document)?;
+
+        if let Some(chunk) = writer.take_chunk(EXPORT_CHUNK_SIZE)? {
+            if sender.blocking_send(Ok(chunk)).is_err() {
+                return Ok(false);
+            }
+        }
+    }
+
+    Ok(true)
+}
+
+enum ExportWriter {
+    Ndjson(Vec<u8>),
+    Csv { columns: Vec<String>, writer: csv::Writer<Vec<u8>> },
+}
+
+impl ExportWriter {
+    fn new(format: ExportFormat) -> Self {
+        match format {
+            ExportFormat::Ndjson => ExportWriter::Ndjson(Vec::new()),
+            ExportFormat::Csv => ExportWriter::Csv {
+                columns: Vec::new(),
+                writer: csv::Writer::from_writer(Vec::new()),
+            },
+        }
+    }
+
+    /// Writes the CSV header. The columns are the top-level fields of the index, restricted
+    /// to the ones containing the attributes to retrieve.
+    fn write_header(
+        &mut self,
+        fields_ids_map: &FieldsIdsMap,
+        attributes_to_retrieve: Option<&[String]>,
+    ) -> Result<(), ResponseError> {
+        if let ExportWriter::Csv { columns, writer } = self {
+            let names: Vec<_> = fields_ids_map.iter().map(|(_, name)| name).collect();
+            // The fields ids map also contains the nested fields of the documents,
+            // they are written as JSON in the column of their top-level field.
+            let is_nested = |name: &str| {
+                names.iter().any(|parent| {
+                    name.strip_prefix(parent).map_or(false, |rest| rest.starts_with('.'))
+                })
+            };
+            let is_retrieved = |name: &str| {
+                attributes_to_retrieve.map_or(true, |attributes| {
+                    attributes.iter().any(|attribute| {
+                        attribute == name
+                            || attribute.strip_prefix(name).map_or(false, |r| r.starts_with('.'))
+                    })
+                })
+            };
+
+            *columns = names
+                .iter()
+                .filter(|&&name| !is_nested(name) && is_retrieved(name))
+                .map(|name| name.to_string())
+                .collect();
+            writer.write_record(columns.iter()).map_err(csv_error)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_document(&mut self, document: &Document) -> Result<(), ResponseError> {
+        match self {
+            ExportWriter::Ndjson(buffer) => {
+                serde_json::to_writer(&mut *buffer, document)
+                    .map_err(MeilisearchHttpError::from)?;
+                buffer.push(b'\n');
+            }
+            ExportWriter::Csv { columns, writer } => {
+                let record = columns.iter().map(|column| match document.get(column) {
+                    None | Some(Value::Null) => String::new(),
+                    Some(Value::String(s)) => s.clone(),
+                    Some(value) => value.to_string(),
+                });
+                writer.write_record(record).map_err(csv_error)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns everything written since the last chunk if it is at least `min_size` bytes long.
+    fn take_chunk(&mut self, min_size: usize) -> Result<Option<Bytes>, ResponseError> {
+        let buffer = match self {
+            ExportWriter::Ndjson(buffer) => buffer,
+            ExportWriter::Csv { writer, .. } => {
+                writer.flush()?;
+                writer.get_mut()
+            }
+        };
+
+        if buffer.len() >= min_size {
+            Ok(Some(Bytes::from(mem::take(buffer))))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+fn csv_error(error: csv::Error) -> ResponseError {
+    ResponseError::from_msg(error.to_string(), Code::Internal)
+}
+
 #[derive(Deserialize, Debug, DeserializeFromValue)]
 #[deserr(error = DeserrError, rename_all = camelCase, deny_unknown_fields)]
 pub struct UpdateDocumentsQuery {
     #[deserr(error = DeserrError<InvalidIndexPrimaryKey>)]
     pub primary_key: Option<String>,
+    #[deserr(error = DeserrError<InvalidDocumentCsvDelimiter>, from(&String) = parse_csv_character_take_error_message -> TakeErrorMessage<InvalidCsvCharacter>)]
+    pub csv_delimiter: Option<u8>,
+    #[deserr(error = DeserrError<InvalidDocumentCsvQuote>, from(&String) = parse_csv_character_take_error_message -> TakeErrorMessage<InvalidCsvCharacter>)]
+    pub csv_quote: Option<u8>,
+}
+
+fn parse_csv_character_take_error_message(
+    s: &str,
+) -> Result<Option<u8>, TakeErrorMessage<InvalidCsvCharacter>> {
+    parse_csv_character(s).map(Some).map_err(TakeErrorMessage)
 }
 
 pub async fn add_documents(
@@ -172,7 +522,7 @@ pub async fn add_documents(
         extract_mime_type(&req)?,
         index_scheduler,
         index_uid.into_inner(),
-        params.primary_key,
+        params,
         body,
         IndexDocumentsMethod::ReplaceDocuments,
         allow_index_creation,
@@ -200,7 +550,7 @@ pub async fn update_documents(
         extract_mime_type(&req)?,
         index_scheduler,
         index_uid,
-        params.into_inner().primary_key,
+        params.into_inner(),
         body,
         IndexDocumentsMethod::UpdateDocuments,
         allow_index_creation,
@@ -210,11 +560,55 @@ pub async fn update_documents(
     Ok(HttpResponse::Accepted().json(task))
 }
 
+#[derive(Deserialize, Debug, DeserializeFromValue)]
+#[deserr(error = DeserrError, rename_all = camelCase, deny_unknown_fields)]
+pub struct DeepMergeDocumentsQuery {
+    #[deserr(error = DeserrError<InvalidIndexPrimaryKey>)]
+    pub primary_key: Option<String>,
+    #[deserr(error = DeserrError<InvalidDocumentCsvDelimiter>, from(&String) = parse_csv_character_take_error_message -> TakeErrorMessage<InvalidCsvCharacter>)]
+    pub csv_delimiter: Option<u8>,
+    #[deserr(error = DeserrError<InvalidDocumentCsvQuote>, from(&String) = parse_csv_character_take_error_message -> TakeErrorMessage<InvalidCsvCharacter>)]
+    pub csv_quote: Option<u8>,
+    #[deserr(error = DeserrError<InvalidDocumentAppendArrays>, default, from(&String) = parse_bool_take_error_message -> TakeErrorMessage<std::str::ParseBoolError>)]
+    pub append_arrays: bool,
+}
+
+pub async fn deep_merge_documents(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::DOCUMENTS_ADD }>, Data<IndexScheduler>>,
+    path: web::Path<String>,
+    params: QueryParameter<DeepMergeDocumentsQuery, DeserrError>,
+    body: Payload,
+    req: HttpRequest,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    debug!("called with params: {:?}", params);
+    let index_uid = path.into_inner();
+    let DeepMergeDocumentsQuery { primary_key, csv_delimiter, csv_quote, append_arrays } =
+        params.into_inner();
+    let params = UpdateDocumentsQuery { primary_key, csv_delimiter, csv_quote };
+
+    analytics.update_documents(&params, index_scheduler.index(&index_uid).is_err(), &req);
+
+    let allow_index_creation = index_scheduler.filters().allow_index_creation;
+    let task = document_addition(
+        extract_mime_type(&req)?,
+        index_scheduler,
+        index_uid,
+        params,
+        body,
+        IndexDocumentsMethod::DeepMergeDocuments { append_arrays },
+        allow_index_creation,
+    )
+    .await?;
+
+    Ok(HttpResponse::Accepted().json(task))
+}
+
 async fn document_addition(
     mime_type: Option<Mime>,
     index_scheduler: GuardedData<ActionPolicy<{ actions::DOCUMENTS_ADD }>, Data<IndexScheduler>>,
     index_uid: String,
-    primary_key: Option<String>,
+    params: UpdateDocumentsQuery,
     mut body: Payload,
     method: IndexDocumentsMethod,
     allow_index_creation: bool,
@@ -223,6 +617,8 @@ async fn document_addition(
         Some(("application", "json")) => PayloadType::Json,
         Some(("application", "x-ndjson")) => PayloadType::Ndjson,
         Some(("text", "csv")) => PayloadType::Csv,
+        Some(("application", "vnd.apache.parquet")) => PayloadType::Parquet,
+        Some(("application", "vnd.apache.arrow.stream")) => PayloadType::ArrowStream,
         Some((type_, subtype)) => {
             return Err(MeilisearchHttpError::InvalidContentType(
                 format!("{}/{}", type_, subtype),
@@ -234,6 +630,16 @@ async fn document_addition(
         }
     };
 
+    let UpdateDocumentsQuery { primary_key, csv_delimiter, csv_quote } = params;
+    if !matches!(format, PayloadType::Csv) {
+        if csv_delimiter.is_some() {
+            return Err(MeilisearchHttpError::CsvDelimiterWithWrongPayloadType(format));
+        }
+        if csv_quote.is_some() {
+            return Err(MeilisearchHttpError::CsvQuoteWithWrongPayloadType(format));
+        }
+    }
+
     // is your indexUid valid?
     let index_uid = IndexUid::try_from(index_uid)?.into_inner();
 
@@ -277,8 +683,15 @@ async fn document_addition(
     let documents_count = tokio::task::spawn_blocking(move || {
         let documents_count = match format {
             PayloadType::Json => read_json(&read_file, update_file.as_file_mut())?,
-            PayloadType::Csv => read_csv(&read_file, update_file.as_file_mut())?,
+            PayloadType::Csv => read_csv(
+                &read_file,
+                update_file.as_file_mut(),
+                csv_delimiter.unwrap_or(b','),
+                csv_quote.unwrap_or(b'"'),
+            )?,
             PayloadType::Ndjson => read_ndjson(&read_file, update_file.as_file_mut())?,
+            PayloadType::Parquet => read_parquet(&read_file, update_file.as_file_mut())?,
+            PayloadType::ArrowStream => read_arrow_stream(&read_file, update_file.as_file_mut())?,
         };
         // we NEED to persist the file here because we moved the `udpate_file` in another task.
         update_file.persist()?;
@@ -328,6 +741,260 @@ async fn document_addition(
     Ok(task.into())
 }
 
+/// The directory from which the documents can be imported, the import route
+/// is disabled when it is not defined.
+pub struct DocumentsImportConfig {
+    import_dir: Option<PathBuf>,
+}
+
+impl DocumentsImportConfig {
+    pub fn new(import_dir: Option<PathBuf>) -> Self {
+        Self { import_dir }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, DeserializeFromValue)]
+#[deserr(rename_all = camelCase)]
+pub enum ImportFormat {
+    Json,
+    Ndjson,
+    Csv,
+    Parquet,
+    Arrow,
+}
+
+impl From<ImportFormat> for PayloadType {
+    fn from(format: ImportFormat) -> Self {
+        match format {
+            ImportFormat::Json => PayloadType::Json,
+            ImportFormat::Ndjson => PayloadType::Ndjson,
+            ImportFormat::Csv => PayloadType::Csv,
+            ImportFormat::Parquet => PayloadType::Parquet,
+            ImportFormat::Arrow => PayloadType::ArrowStream,
+        }
+    }
+}
+
+#[derive(Debug, DeserializeFromValue)]
+#[deserr(error = DeserrError, rename_all = camelCase, deny_unknown_fields)]
+pub struct ImportDocuments {
+    #[deserr(error = DeserrError<InvalidDocumentImportPath>)]
+    path: String,
+    #[deserr(error = DeserrError<InvalidDocumentImportFormat>)]
+    format: ImportFormat,
+    #[deserr(error = DeserrError<InvalidIndexPrimaryKey>)]
+    primary_key: Option<String>,
+    #[deserr(error = DeserrError<InvalidDocumentCsvDelimiter>, from(&String) = parse_csv_character_take_error_message -> TakeErrorMessage<InvalidCsvCharacter>)]
+    csv_delimiter: Option<u8>,
+    #[deserr(error = DeserrError<InvalidDocumentCsvQuote>, from(&String) = parse_csv_character_take_error_message -> TakeErrorMessage<InvalidCsvCharacter>)]
+    csv_quote: Option<u8>,
+}
+
+pub async fn import_documents(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::DOCUMENTS_ADD }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+    import_config: web::Data<DocumentsImportConfig>,
+    body: ValidatedJson<ImportDocuments, DeserrError>,
+    req: HttpRequest,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    debug!("called with params: {:?}", body);
+    let ImportDocuments { path, format, primary_key, csv_delimiter, csv_quote } = body.into_inner();
+    let format = PayloadType::from(format);
+    if !matches!(format, PayloadType::Csv) {
+        if csv_delimiter.is_some() {
+            return Err(MeilisearchHttpError::CsvDelimiterWithWrongPayloadType(format).into());
+        }
+        if csv_quote.is_some() {
+            return Err(MeilisearchHttpError::CsvQuoteWithWrongPayloadType(format).into());
+        }
+    }
+    let import_dir = match &import_config.import_dir {
+        Some(import_dir) => import_dir,
+        None => return Err(MeilisearchHttpError::DocumentImportDisabled.into()),
+    };
+    let canonical_import_dir =
+        import_dir.canonicalize().map_err(MeilisearchHttpError::ImportDir)?;
+
+    // is your indexUid valid?
+    let index_uid = IndexUid::try_from(index_uid.into_inner())?.into_inner();
+
+    let params = UpdateDocumentsQuery { primary_key, csv_delimiter, csv_quote };
+    analytics.add_documents(&params, index_scheduler.index(&index_uid).is_err(), &req);
+
+    let files = expand_import_path(import_dir, &canonical_import_dir, &path)?;
+    let (csv_delimiter, csv_quote) = (csv_delimiter.unwrap_or(b','), csv_quote.unwrap_or(b'"'));
+
+    let (uuid, mut update_file) = index_scheduler.create_update_file()?;
+    let imported = tokio::task::spawn_blocking(move || -> Result<_, MeilisearchHttpError> {
+        let mut builder = DocumentsBatchBuilder::new(update_file.as_file_mut());
+        let mut imported_files = Vec::with_capacity(files.len());
+        // A file that can't be read is reported in the task details without
+        // preventing the other files from being imported.
+        for file in files {
+            let path = file.display().to_string();
+            let documents_count = builder.documents_count();
+            let result = append_imported_file(
+                &mut builder,
+                &canonical_import_dir,
+                &file,
+                format,
+                csv_delimiter,
+                csv_quote,
+            );
+            // The documents read before an error are imported too.
+            let received_documents = (builder.documents_count() - documents_count) as u64;
+            let imported_file = match result {
+                Ok(()) => {
+                    ImportedFile { path, received_documents: Some(received_documents), error: None }
+                }
+                Err(e) => ImportedFile {
+                    path,
+                    received_documents: (received_documents != 0).then_some(received_documents),
+                    error: Some(e.to_string()),
+                },
+            };
+            imported_files.push(imported_file);
+        }
+        let documents_count = builder.documents_count() as u64;
+        builder.into_inner().map_err(milli::Error::from)?;
+        // we NEED to persist the file here because we moved the `udpate_file` in another task.
+        update_file.persist()?;
+        Ok((documents_count, imported_files))
+    })
+    .await;
+
+    let (documents_count, imported_files) = match imported {
+        Ok(Ok(imported)) => imported,
+        // in this case the file has not possibly be persisted.
+        Ok(Err(e)) => return Err(e.into()),
+        Err(e) => {
+            // Here the file MAY have been persisted or not.
+            // We don't know thus we ignore the file not found error.
+            match index_scheduler.delete_update_file(uuid) {
+                Ok(()) => (),
+                Err(index_scheduler::Error::FileStore(file_store::Error::IoError(e)))
+                    if e.kind() == ErrorKind::NotFound => {}
+                Err(e) => {
+                    log::warn!("Unknown error happened while deleting a malformed update file with uuid {uuid}: {e}");
+                }
+            }
+            return Err(MeilisearchHttpError::from(e).into());
+        }
+    };
+
+    let allow_index_creation = index_scheduler.filters().allow_index_creation;
+    let task = KindWithContent::DocumentAdditionOrUpdate {
+        method: IndexDocumentsMethod::ReplaceDocuments,
+        content_file: uuid,
+        documents_count,
+        primary_key: params.primary_key,
+        allow_index_creation,
+        index_uid,
+    };
+    let details = Details::DocumentAdditionOrUpdate {
+        received_documents: documents_count,
+        indexed_documents: None,
+        imported_files: Some(imported_files),
+        version_conflicts: None,
+        version_conflicts_count: None,
+        generated_ids: None,
+        generated_ids_count: None,
+        schema_violations: None,
+    };
+
+    let scheduler = index_scheduler.clone();
+    let task = match tokio::task::spawn_blocking(move || {
+        scheduler.register_with_details(task, Some(details))
+    })
+    .await?
+    {
+        Ok(task) => task,
+        Err(e) => {
+            index_scheduler.delete_update_file(uuid)?;
+            return Err(e.into());
+        }
+    };
+
+    let task: SummarizedTaskView = task.into();
+    debug!("returns: {:?}", task);
+    Ok(HttpResponse::Accepted().json(task))
+}
+
+/// Expands the import path, which is either absolute or relative to the import directory,
+/// into the files it matches. Paths that could reach outside of the import directory are
+/// rejected before anything is read from the filesystem.
+fn expand_import_path(
+    import_dir: &Path,
+    canonical_import_dir: &Path,
+    path: &str,
+) -> Result<Vec<PathBuf>, MeilisearchHttpError> {
+    let outside_import_dir = || MeilisearchHttpError::ImportPathOutsideImportDir(path.to_string());
+
+    let relative_path = Path::new(path);
+    let relative_path = if relative_path.is_absolute() {
+        relative_path
+            .strip_prefix(canonical_import_dir)
+            .or_else(|_| relative_path.strip_prefix(import_dir))
+            .map_err(|_| outside_import_dir())?
+    } else {
+        relative_path
+    };
+    if relative_path.components().any(|c| !matches!(c, Component::Normal(_) | Component::CurDir)) {
+        return Err(outside_import_dir());
+    }
+
+    // The import directory itself must never be interpreted as a pattern.
+    let pattern = Path::new(&glob::Pattern::escape(&canonical_import_dir.to_string_lossy()))
+        .join(relative_path);
+    let paths = glob::glob(&pattern.to_string_lossy())
+        .map_err(|e| MeilisearchHttpError::InvalidImportPath(path.to_string(), e))?;
+
+    // The directories that can't be read are ignored, just like a shell would.
+    let files: Vec<_> = paths.flatten().filter(|path| path.is_file()).collect();
+    if files.is_empty() {
+        return Err(MeilisearchHttpError::NoFileMatchingImportPath(path.to_string()));
+    }
+
+    Ok(files)
+}
+
+/// Appends the documents of an imported file to the update file, the files with
+/// a `.gz` extension are decompressed beforehand.
+fn append_imported_file<W: io::Write>(
+    builder: &mut DocumentsBatchBuilder<W>,
+    canonical_import_dir: &Path,
+    path: &Path,
+    format: PayloadType,
+    csv_delimiter: u8,
+    csv_quote: u8,
+) -> Result<(), MeilisearchHttpError> {
+    // A symbolic link could point outside of the import directory.
+    if !path.canonicalize().map_err(DocumentFormatError::Io)?.starts_with(canonical_import_dir) {
+        let path = path.display().to_string();
+        return Err(MeilisearchHttpError::ImportPathOutsideImportDir(path));
+    }
+
+    let mut file = std::fs::File::open(path).map_err(DocumentFormatError::Io)?;
+    if path.extension().map_or(false, |extension| extension == "gz") {
+        let mut decompressed = tempfile().map_err(DocumentFormatError::Io)?;
+        io::copy(&mut GzDecoder::new(io::BufReader::new(file)), &mut decompressed)
+            .map_err(DocumentFormatError::Io)?;
+        decompressed.rewind().map_err(DocumentFormatError::Io)?;
+        file = decompressed;
+    }
+
+    match format {
+        PayloadType::Json => append_json(&file, builder)?,
+        PayloadType::Csv => append_csv(&file, builder, csv_delimiter, csv_quote)?,
+        PayloadType::Ndjson => append_ndjson(&file, builder)?,
+        PayloadType::Parquet => append_parquet(&file, builder)?,
+        PayloadType::ArrowStream => append_arrow_stream(&file, builder)?,
+    }
+
+    Ok(())
+}
+
 pub async fn delete_documents(
     index_scheduler: GuardedData<ActionPolicy<{ actions::DOCUMENTS_DELETE }>, Data<IndexScheduler>>,
     path: web::Path<String>,
@@ -339,13 +1006,112 @@ pub async fn delete_documents(
 
     analytics.delete_documents(DocumentDeletionKind::PerBatch, &req);
 
+    let index_uid = path.into_inner();
+    // The ids given as the object of the key parts are derived from the primary key of the index.
+    let index = if body.iter().any(Value::is_object) {
+        Some(index_scheduler.index(&index_uid)?)
+    } else {
+        None
+    };
     let ids = body
-        .iter()
-        .map(|v| v.as_str().map(String::from).unwrap_or_else(|| v.to_string()))
-        .collect();
+        .into_inner()
+        .into_iter()
+        .map(|v| match (v, &index) {
+            (Value::Object(parts), Some(index)) => document_id_from_parts(index, parts),
+            (Value::String(id), _) => Ok(id),
+            (v, _) => Ok(v.to_string()),
+        })
+        .collect::<Result<_, _>>()?;
+
+    let task = KindWithContent::DocumentDeletion { index_uid, documents_ids: ids };
+    let task: SummarizedTaskView =
+        tokio::task::spawn_blocking(move || index_scheduler.register(task)).await??.into();
+
+    debug!("returns: {:?}", task);
+    Ok(HttpResponse::Accepted().json(task))
+}
+
+#[derive(Debug, DeserializeFromValue)]
+#[deserr(error = DeserrError, rename_all = camelCase, deny_unknown_fields)]
+pub struct DocumentDeletionByFilter {
+    #[deserr(error = DeserrError<InvalidDocumentFilter>, missing_field_error = DeserrError::missing_document_filter)]
+    filter: Value,
+}
+
+pub async fn delete_documents_by_filter(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::DOCUMENTS_DELETE }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+    body: ValidatedJson<DocumentDeletionByFilter, DeserrError>,
+    req: HttpRequest,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    debug!("called with params: {:?}", body);
+    let index_uid = index_uid.into_inner();
+    let filter = body.into_inner().filter;
+
+    analytics.delete_documents(DocumentDeletionKind::PerFilter, &req);
+
+    // We only ensure the filter is well formed before enqueuing the task. The attributes
+    // are checked against the filterable attributes of the index when the task is processed.
+    match parse_filter(&filter) {
+        Ok(Some(_)) => (),
+        Ok(None) => return Err(MeilisearchHttpError::EmptyFilter.into()),
+        Err(e) => return Err(ResponseError::from_msg(e.to_string(), Code::InvalidDocumentFilter)),
+    }
+
+    let task = KindWithContent::DocumentDeletionByFilter { index_uid, filter_expr: filter };
+    let task: SummarizedTaskView =
+        tokio::task::spawn_blocking(move || index_scheduler.register(task)).await??.into();
+
+    debug!("returns: {:?}", task);
+    Ok(HttpResponse::Accepted().json(task))
+}
+
+#[derive(Debug, DeserializeFromValue)]
+#[deserr(error = DeserrError, rename_all = camelCase, deny_unknown_fields)]
+pub struct DocumentEditionByFunction {
+    #[deserr(error = DeserrError<InvalidDocumentFilter>)]
+    filter: Option<Value>,
+    #[deserr(error = DeserrError<InvalidDocumentEditionContext>)]
+    context: Option<Value>,
+    #[deserr(error = DeserrError<InvalidDocumentEditionFunction>, missing_field_error = DeserrError::missing_document_edition_function)]
+    function: String,
+}
+
+pub async fn edit_documents_by_function(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::DOCUMENTS_ADD }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+    body: ValidatedJson<DocumentEditionByFunction, DeserrError>,
+) -> Result<HttpResponse, ResponseError> {
+    debug!("called with params: {:?}", body);
+    let index_uid = index_uid.into_inner();
+    let DocumentEditionByFunction { filter, context, function } = body.into_inner();
+
+    // As for the deletion by filter, the filter is only checked to be well formed here.
+    // An empty filter means that the function is applied to every document.
+    let filter = match filter {
+        Some(filter) => match parse_filter(&filter) {
+            Ok(Some(_)) => Some(filter),
+            Ok(None) => None,
+            Err(e) => {
+                return Err(ResponseError::from_msg(e.to_string(), Code::InvalidDocumentFilter))
+            }
+        },
+        None => None,
+    };
+    let context = match context {
+        Some(Value::Object(context)) => Some(context),
+        Some(Value::Null) | None => None,
+        Some(_) => {
+            return Err(ResponseError::from_msg(
+                "The `context` must be an object.".to_string(),
+                Code::InvalidDocumentEditionContext,
+            ))
+        }
+    };
 
     let task =
-        KindWithContent::DocumentDeletion { index_uid: path.into_inner(), documents_ids: ids };
+        KindWithContent::DocumentEdition { index_uid, filter_expr: filter, context, function };
     let task: SummarizedTaskView =
         tokio::task::spawn_blocking(move || index_scheduler.register(task)).await??.into();
 
@@ -369,42 +1135,141 @@ pub async fn clear_all_documents(
     Ok(HttpResponse::Accepted().json(task))
 }
 
-fn all_documents<'a>(
+pub async fn purge_documents(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::DOCUMENTS_DELETE }>, Data<IndexScheduler>>,
+    path: web::Path<String>,
+    req: HttpRequest,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    analytics.publish("Documents Purged".to_string(), serde_json::json!({}), Some(&req));
+
+    let task = KindWithContent::DocumentPurge { index_uid: path.into_inner() };
+    let task: SummarizedTaskView =
+        tokio::task::spawn_blocking(move || index_scheduler.register(task)).await??.into();
+
+    debug!("returns: {:?}", task);
+    Ok(HttpResponse::Accepted().json(task))
+}
+
+/// Returns the ids of the documents matching the filter, `None` if there is no filter.
+fn filtered_documents_ids(
     index: &Index,
-    rtxn: &'a RoTxn,
-) -> Result<impl Iterator<Item = Result<Document, ResponseError>> + 'a, ResponseError> {
-    let fields_ids_map = index.fields_ids_map(rtxn)?;
-    let all_fields: Vec<_> = fields_ids_map.iter().map(|(id, _)| id).collect();
+    rtxn: &RoTxn,
+    filter: Option<&Value>,
+) -> Result<Option<RoaringBitmap>, ResponseError> {
+    let filter = match filter {
+        Some(filter) => parse_filter(filter)
+            .map_err(|e| ResponseError::from_msg(e.to_string(), Code::InvalidDocumentFilter))?,
+        None => None,
+    };
 
-    Ok(index.all_documents(rtxn)?.map(move |ret| {
-        ret.map_err(ResponseError::from).and_then(|(_key, document)| -> Result<_, ResponseError> {
-            Ok(milli::obkv_to_json(&all_fields, &fields_ids_map, document)?)
-        })
-    }))
+    match filter {
+        Some(filter) => filter.evaluate(rtxn, index).map(Some).map_err(|e| match e {
+            milli::Error::UserError(milli::UserError::InvalidFilter(_)) => {
+                ResponseError::from_msg(e.to_string(), Code::InvalidDocumentFilter)
+            }
+            e => e.into(),
+        }),
+        None => Ok(None),
+    }
+}
+
+/// Retrieve the documents of the indexes a name refers to. The documents of the indexes
+/// an alias points to are listed one index after the other, in the order of the alias.
+fn retrieve_alias_documents(
+    indexes: &[(String, Index)],
+    mut offset: usize,
+    mut limit: usize,
+    filter: Option<Value>,
+    attributes_to_retrieve: Option<Vec<String>>,
+) -> Result<(u64, Vec<Document>), ResponseError> {
+    let mut total = 0;
+    let mut documents = Vec::new();
+    for (_, index) in indexes {
+        let (index_total, index_documents) = retrieve_documents(
+            index,
+            offset,
+            limit,
+            filter.clone(),
+            attributes_to_retrieve.clone(),
+        )?;
+        offset = offset.saturating_sub(index_total as usize);
+        limit -= index_documents.len();
+        total += index_total;
+        documents.extend(index_documents);
+    }
+
+    Ok((total, documents))
 }
 
 fn retrieve_documents<S: AsRef<str>>(
     index: &Index,
     offset: usize,
     limit: usize,
+    filter: Option<Value>,
     attributes_to_retrieve: Option<Vec<S>>,
 ) -> Result<(u64, Vec<Document>), ResponseError> {
     let rtxn = index.read_txn()?;
 
+    let mut candidates = match filtered_documents_ids(index, &rtxn, filter.as_ref())? {
+        Some(candidates) => candidates,
+        None => index.documents_ids(&rtxn)?,
+    };
+    // The expired documents are hidden even if they haven't been deleted yet.
+    candidates -= index.expired_documents_ids(&rtxn, OffsetDateTime::now_utc())?;
+
+    let fields_ids_map = index.fields_ids_map(&rtxn)?;
+    let all_fields: Vec<_> = fields_ids_map.iter().map(|(id, _)| id).collect();
+
+    // The documents are returned in the order of their internal ids.
     let mut documents = Vec::new();
-    for document in all_documents(index, &rtxn)?.skip(offset).take(limit) {
+    for (_id, document) in index.documents(&rtxn, candidates.iter().skip(offset).take(limit))? {
+        let document = milli::obkv_to_json(&all_fields, &fields_ids_map, document)?;
         let document = match &attributes_to_retrieve {
             Some(attributes_to_retrieve) => permissive_json_pointer::select_values(
-                &document?,
+                &document,
                 attributes_to_retrieve.iter().map(|s| s.as_ref()),
             ),
-            None => document?,
+            None => document,
         };
         documents.push(document);
     }
 
-    let number_of_documents = index.number_of_documents(&rtxn)?;
-    Ok((number_of_documents, documents))
+    Ok((candidates.len(), documents))
+}
+
+/// Derives the id of a document from an object containing the values of the fields
+/// of the primary key of the index, this is how composite primary keys are given.
+fn document_id_from_parts(index: &Index, parts: milli::Object) -> Result<String, ResponseError> {
+    let rtxn = index.read_txn()?;
+    let primary_key = match index.primary_key_definition(&rtxn)? {
+        Some(primary_key) => primary_key,
+        // An index without a primary key doesn't contain any document.
+        None => {
+            return Err(
+                MeilisearchHttpError::DocumentNotFound(Value::Object(parts).to_string()).into()
+            )
+        }
+    };
+
+    let mut values = Vec::new();
+    for field in primary_key.fields() {
+        match parts.get(field) {
+            Some(value) => values.push(value.clone()),
+            None => {
+                let error = milli::UserError::MissingDocumentId {
+                    primary_key: field.to_string(),
+                    document: parts,
+                };
+                return Err(milli::Error::from(error).into());
+            }
+        }
+    }
+
+    match milli::update::composite_document_id(values)? {
+        Ok(document_id) => Ok(document_id),
+        Err(error) => Err(milli::Error::from(error).into()),
+    }
 }
 
 fn retrieve_document<S: AsRef<str>>(
@@ -422,6 +1287,10 @@ fn retrieve_document<S: AsRef<str>>(
         .get(doc_id.as_bytes())
         .ok_or_else(|| MeilisearchHttpError::DocumentNotFound(doc_id.to_string()))?;
 
+    if index.expired_documents_ids(&txn, OffsetDateTime::now_utc())?.contains(internal_id) {
+        return Err(MeilisearchHttpError::DocumentNotFound(doc_id.to_string()).into());
+    }
+
     let document = index
         .documents(&txn, std::iter::once(internal_id))?
         .into_iter()
@@ -0,0 +1,265 @@
+use actix_web::web::Data;
+use actix_web::{web, HttpRequest, HttpResponse};
+use deserr::DeserializeFromValue;
+use index_scheduler::IndexScheduler;
+use log::debug;
+use meilisearch_types::error::deserr_codes::*;
+use meilisearch_types::error::{DeserrError, ResponseError};
+use meilisearch_types::heed::RoTxn;
+use meilisearch_types::milli::{self, Filter, FilterCondition, FilterParserError, Index};
+use roaring::RoaringBitmap;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::analytics::Analytics;
+use crate::error::MeilisearchHttpError;
+use crate::extractors::authentication::policies::*;
+use crate::extractors::authentication::GuardedData;
+use crate::extractors::json::ValidatedJson;
+use crate::extractors::sequential_extractor::SeqHandler;
+use crate::search::parse_filter;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("/validate").route(web::post().to(SeqHandler(validate_filter))));
+}
+
+#[derive(Debug, DeserializeFromValue)]
+#[deserr(error = DeserrError, rename_all = camelCase, deny_unknown_fields)]
+pub struct ValidateFilterQuery {
+    #[deserr(error = DeserrError<InvalidSearchFilter>)]
+    pub filter: Value,
+    #[deserr(error = DeserrError<InvalidFilterExplain>, default)]
+    pub explain: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FilterValidation {
+    pub valid: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<FilterValidationError>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normalized_filter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<FilterExplanation>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FilterValidationError {
+    pub message: String,
+    /// The filter expression the error was found in.
+    pub expression: String,
+    /// The position of the error in the expression, counted in characters.
+    pub start: usize,
+    pub end: usize,
+}
+
+impl From<&FilterParserError<'_>> for FilterValidationError {
+    fn from(error: &FilterParserError) -> Self {
+        let context = error.context();
+        let expression = context.extra;
+        let start = expression[..context.location_offset()].chars().count();
+        let end = start + context.fragment().chars().count();
+        FilterValidationError {
+            message: error.to_string(),
+            expression: expression.to_string(),
+            start,
+            end,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FilterExplanation {
+    pub filter: String,
+    pub matched_documents: u64,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub sub_clauses: Vec<FilterExplanation>,
+}
+
+pub async fn validate_filter(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::SEARCH }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+    params: ValidatedJson<ValidateFilterQuery, DeserrError>,
+    req: HttpRequest,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let query = params.into_inner();
+    debug!("called with params: {:?}", query);
+
+    analytics.publish(
+        "Filter Validated".to_string(),
+        json!({ "explain": query.explain }),
+        Some(&req),
+    );
+
+    // The documents matched by the explanation must be restricted by the tenant token.
+    let rules_filter = index_scheduler
+        .filters()
+        .search_rules
+        .get_index_search_rules(&index_uid)
+        .and_then(|rules| rules.filter);
+
+    // The filterable attributes and the documents differ between the indexes of an alias,
+    // a filter can only be validated against an alias pointing to a single index.
+    let index = match index_scheduler.resolve_indexes(&index_uid)?.as_slice() {
+        [(_, index)] => index.clone(),
+        _ => return Err(MeilisearchHttpError::FilterValidationOnMultiIndexAlias.into()),
+    };
+    let validation = tokio::task::spawn_blocking(move || {
+        perform_filter_validation(&index, query, rules_filter.as_ref())
+    })
+    .await??;
+
+    debug!("returns: {:?}", validation);
+    Ok(HttpResponse::Ok().json(validation))
+}
+
+fn perform_filter_validation(
+    index: &Index,
+    query: ValidateFilterQuery,
+    rules_filter: Option<&Value>,
+) -> Result<FilterValidation, MeilisearchHttpError> {
+    let ValidateFilterQuery { filter, explain } = query;
+    let invalid = |errors| FilterValidation {
+        valid: false,
+        errors,
+        normalized_filter: None,
+        explanation: None,
+    };
+
+    // We first parse every expression by ourselves to report all the syntax errors with
+    // their position, `parse_filter` would only return the first error as a string.
+    let expressions = filter_expressions(&filter)?;
+    let errors: Vec<_> = expressions
+        .iter()
+        .filter_map(|expression| FilterCondition::parse(expression).err())
+        .map(|error| FilterValidationError::from(&error))
+        .collect();
+    if !errors.is_empty() {
+        return Ok(invalid(errors));
+    }
+
+    let filter = match parse_filter(&filter)? {
+        Some(filter) => filter,
+        None => {
+            return Ok(FilterValidation {
+                valid: true,
+                errors: Vec::new(),
+                normalized_filter: None,
+                explanation: None,
+            })
+        }
+    };
+
+    let rtxn = index.read_txn()?;
+    let filterable_fields = index.filterable_fields(&rtxn)?;
+    let errors: Vec<_> =
+        filter.validate(&filterable_fields).iter().map(FilterValidationError::from).collect();
+    if !errors.is_empty() {
+        return Ok(invalid(errors));
+    }
+
+    let condition = FilterCondition::from(filter);
+    let explanation = if explain {
+        let universe = match rules_filter.map(parse_filter).transpose()?.flatten() {
+            Some(rules_filter) => Some(rules_filter.evaluate(&rtxn, index)?),
+            None => None,
+        };
+        let (_, explanation) = explain_condition(&rtxn, index, &condition, universe.as_ref())?;
+        Some(explanation)
+    } else {
+        None
+    };
+
+    Ok(FilterValidation {
+        valid: true,
+        errors: Vec::new(),
+        normalized_filter: Some(condition.to_normalized_string()),
+        explanation,
+    })
+}
+
+/// Returns all the string expressions contained in a filter, in the order they appear.
+fn filter_expressions(filter: &Value) -> Result<Vec<&str>, MeilisearchHttpError> {
+    match filter {
+        Value::String(expression) => Ok(vec![expression]),
+        Value::Array(array) => {
+            let mut expressions = Vec::new();
+            for value in array {
+                match value {
+                    Value::String(expression) => expressions.push(expression.as_str()),
+                    Value::Array(array) => {
+                        for value in array {
+                            match value {
+                                Value::String(expression) => expressions.push(expression.as_str()),
+                                v => {
+                                    return Err(MeilisearchHttpError::InvalidExpression(
+                                        &["String"],
+                                        v.clone(),
+                                    ))
+                                }
+                            }
+                        }
+                    }
+                    v => {
+                        return Err(MeilisearchHttpError::InvalidExpression(
+                            &["String", "[String]"],
+                            v.clone(),
+                        ))
+                    }
+                }
+            }
+            Ok(expressions)
+        }
+        v => Err(MeilisearchHttpError::InvalidExpression(&["String", "Array"], v.clone())),
+    }
+}
+
+/// Computes the documents matched by every clause of the filter. The `AND`, `OR` and `NOT`
+/// clauses are combined from the documents matched by their sub-clauses so that every
+/// condition is only evaluated once.
+fn explain_condition(
+    rtxn: &RoTxn,
+    index: &Index,
+    condition: &FilterCondition,
+    universe: Option<&RoaringBitmap>,
+) -> milli::Result<(RoaringBitmap, FilterExplanation)> {
+    let mut sub_clauses = Vec::new();
+    let docids = match condition {
+        FilterCondition::And(filters) | FilterCondition::Or(filters) => {
+            let is_and = matches!(condition, FilterCondition::And(_));
+            let mut docids: Option<RoaringBitmap> = None;
+            for filter in filters {
+                let (sub_docids, explanation) = explain_condition(rtxn, index, filter, universe)?;
+                sub_clauses.push(explanation);
+                docids = Some(match docids {
+                    Some(docids) if is_and => docids & sub_docids,
+                    Some(docids) => docids | sub_docids,
+                    None => sub_docids,
+                });
+            }
+            docids.unwrap_or_default()
+        }
+        FilterCondition::Not(filter) => {
+            let (sub_docids, explanation) = explain_condition(rtxn, index, filter, universe)?;
+            sub_clauses.push(explanation);
+            index.documents_ids(rtxn)? - sub_docids
+        }
+        condition => Filter::from(condition.clone()).evaluate(rtxn, index)?,
+    };
+
+    let matched_documents = match universe {
+        Some(universe) => docids.intersection_len(universe),
+        None => docids.len(),
+    };
+    let explanation = FilterExplanation {
+        filter: condition.to_normalized_string(),
+        matched_documents,
+        sub_clauses,
+    };
+
+    Ok((docids, explanation))
+}
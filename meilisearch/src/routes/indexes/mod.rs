@@ -7,11 +7,13 @@ use index_scheduler::IndexScheduler;
 use log::debug;
 use meilisearch_types::error::deserr_codes::*;
 use meilisearch_types::error::{unwrap_any, Code, DeserrError, ResponseError, TakeErrorMessage};
+use meilisearch_types::index_quotas::IndexQuota;
 use meilisearch_types::index_uid::IndexUid;
+use meilisearch_types::milli::update::PrimaryKeyDefinition;
 use meilisearch_types::milli::{self, FieldDistribution, Index};
 use meilisearch_types::tasks::KindWithContent;
 use serde::{Deserialize, Serialize};
-use serde_json::json;
+use serde_json::{json, Value};
 use time::OffsetDateTime;
 
 use self::search::parse_usize_take_error_message;
@@ -24,6 +26,7 @@ use crate::extractors::query_parameters::QueryParameter;
 use crate::extractors::sequential_extractor::SeqHandler;
 
 pub mod documents;
+pub mod filter;
 pub mod search;
 pub mod settings;
 
@@ -42,7 +45,16 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
                     .route(web::delete().to(SeqHandler(delete_index))),
             )
             .service(web::resource("/stats").route(web::get().to(SeqHandler(get_index_stats))))
+            .service(web::resource("/clone").route(web::post().to(SeqHandler(clone_index))))
+            .service(web::resource("/read-only").route(web::put().to(SeqHandler(update_read_only))))
+            .service(web::resource("/compact").route(web::post().to(SeqHandler(compact_index))))
+            .service(
+                web::resource("/quota")
+                    .route(web::get().to(SeqHandler(get_quota)))
+                    .route(web::put().to(SeqHandler(update_quota))),
+            )
             .service(web::scope("/documents").configure(documents::configure))
+            .service(web::scope("/filter").configure(filter::configure))
             .service(web::scope("/search").configure(search::configure))
             .service(web::scope("/settings").configure(settings::configure)),
     );
@@ -56,21 +68,52 @@ pub struct IndexView {
     pub created_at: OffsetDateTime,
     #[serde(with = "time::serde::rfc3339")]
     pub updated_at: OffsetDateTime,
-    pub primary_key: Option<String>,
+    /// A composite primary key is displayed as the list of its fields.
+    pub primary_key: Option<PrimaryKeyDefinition>,
+    pub read_only: bool,
 }
 
 impl IndexView {
-    fn new(uid: String, index: &Index) -> Result<IndexView, milli::Error> {
+    fn new(uid: String, index: &Index, read_only: bool) -> Result<IndexView, milli::Error> {
         let rtxn = index.read_txn()?;
         Ok(IndexView {
             uid,
             created_at: index.created_at(&rtxn)?,
             updated_at: index.updated_at(&rtxn)?,
-            primary_key: index.primary_key(&rtxn)?.map(String::from),
+            primary_key: index.primary_key_definition(&rtxn)?,
+            read_only,
         })
     }
 }
 
+/// A primary key is either the name of a field or the ordered list of the fields of a
+/// composite primary key.
+fn primary_key_from_value(value: Value) -> Result<PrimaryKeyDefinition, ResponseError> {
+    let invalid = |value: &Value| {
+        ResponseError::from_msg(
+            format!(
+                "`primaryKey` must be a string or a non-empty array of strings, but found `{value}`."
+            ),
+            Code::InvalidIndexPrimaryKey,
+        )
+    };
+
+    match value {
+        Value::String(primary_key) => Ok(PrimaryKeyDefinition::Field(primary_key)),
+        Value::Array(ref fields) if !fields.is_empty() => {
+            let mut primary_key = Vec::with_capacity(fields.len());
+            for field in fields {
+                match field {
+                    Value::String(field) => primary_key.push(field.clone()),
+                    _ => return Err(invalid(&value)),
+                }
+            }
+            Ok(PrimaryKeyDefinition::Composite(primary_key))
+        }
+        value => Err(invalid(&value)),
+    }
+}
+
 #[derive(DeserializeFromValue, Deserialize, Debug, Clone, Copy)]
 #[deserr(error = DeserrError, rename_all = camelCase, deny_unknown_fields)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
@@ -83,7 +126,7 @@ pub struct ListIndexes {
     pub limit: usize,
 }
 impl ListIndexes {
-    fn as_pagination(self) -> Pagination {
+    pub(super) fn as_pagination(self) -> Pagination {
         Pagination { offset: self.offset, limit: self.limit }
     }
 }
@@ -97,8 +140,11 @@ pub async fn list_indexes(
     let indexes = indexes
         .into_iter()
         .filter(|(name, _)| search_rules.is_index_authorized(name))
-        .map(|(name, index)| IndexView::new(name, &index))
-        .collect::<Result<Vec<_>, _>>()?;
+        .map(|(name, index)| {
+            let read_only = index_scheduler.is_index_read_only(&name)?;
+            Ok(IndexView::new(name, &index, read_only)?)
+        })
+        .collect::<Result<Vec<_>, ResponseError>>()?;
 
     let ret = paginate.as_pagination().auto_paginate_sized(indexes.into_iter());
 
@@ -112,7 +158,7 @@ pub struct IndexCreateRequest {
     #[deserr(error = DeserrError<InvalidIndexUid>, missing_field_error = DeserrError::missing_index_uid)]
     uid: String,
     #[deserr(error = DeserrError<InvalidIndexPrimaryKey>)]
-    primary_key: Option<String>,
+    primary_key: Option<Value>,
 }
 
 pub async fn create_index(
@@ -123,6 +169,7 @@ pub async fn create_index(
 ) -> Result<HttpResponse, ResponseError> {
     let IndexCreateRequest { primary_key, uid } = body.into_inner();
     let uid = IndexUid::try_from(uid)?.into_inner();
+    let primary_key = primary_key.map(primary_key_from_value).transpose()?;
 
     let allow_index_creation = index_scheduler.filters().search_rules.is_index_authorized(&uid);
     if allow_index_creation {
@@ -154,7 +201,6 @@ fn deny_immutable_fields_index(
     ));
 
     error.code = match field {
-        "uid" => Code::ImmutableIndexUid,
         "createdAt" => Code::ImmutableIndexCreatedAt,
         "updatedAt" => Code::ImmutableIndexUpdatedAt,
         _ => Code::BadRequest,
@@ -164,8 +210,13 @@ fn deny_immutable_fields_index(
 #[derive(DeserializeFromValue, Debug)]
 #[deserr(error = DeserrError, rename_all = camelCase, deny_unknown_fields = deny_immutable_fields_index)]
 pub struct UpdateIndexRequest {
+    #[deserr(error = DeserrError<InvalidIndexUid>)]
+    uid: Option<String>,
     #[deserr(error = DeserrError<InvalidIndexPrimaryKey>)]
-    primary_key: Option<String>,
+    primary_key: Option<Value>,
+    /// Whether the API keys restricted to the index should follow it when it's renamed.
+    #[deserr(error = DeserrError<InvalidIndexMigrateApiKeys>)]
+    migrate_api_keys: Option<bool>,
 }
 
 pub async fn get_index(
@@ -173,7 +224,8 @@ pub async fn get_index(
     index_uid: web::Path<String>,
 ) -> Result<HttpResponse, ResponseError> {
     let index = index_scheduler.index(&index_uid)?;
-    let index_view = IndexView::new(index_uid.into_inner(), &index)?;
+    let read_only = index_scheduler.is_index_read_only(&index_uid)?;
+    let index_view = IndexView::new(index_uid.into_inner(), &index, read_only)?;
 
     debug!("returns: {:?}", index_view);
 
@@ -188,16 +240,31 @@ pub async fn update_index(
     analytics: web::Data<dyn Analytics>,
 ) -> Result<HttpResponse, ResponseError> {
     debug!("called with params: {:?}", body);
-    let body = body.into_inner();
+    let UpdateIndexRequest { uid, primary_key, migrate_api_keys } = body.into_inner();
+    let primary_key = primary_key.map(primary_key_from_value).transpose()?;
+    let new_index_uid =
+        uid.map(|uid| IndexUid::try_from(uid).map(IndexUid::into_inner)).transpose()?;
+    if let Some(new_index_uid) = &new_index_uid {
+        if !index_scheduler.filters().search_rules.is_index_authorized(new_index_uid) {
+            return Err(AuthenticationError::InvalidToken.into());
+        }
+    }
     analytics.publish(
         "Index Updated".to_string(),
-        json!({ "primary_key": body.primary_key }),
+        json!({
+            "primary_key": primary_key,
+            "renamed": new_index_uid.is_some(),
+            "migrate_api_keys": migrate_api_keys.unwrap_or_default(),
+        }),
         Some(&req),
     );
 
+    let index_uid = path.into_inner();
     let task = KindWithContent::IndexUpdate {
-        index_uid: path.into_inner(),
-        primary_key: body.primary_key,
+        index_uid,
+        primary_key,
+        migrate_api_keys: new_index_uid.is_some() && migrate_api_keys.unwrap_or_default(),
+        new_index_uid,
     };
 
     let task: SummarizedTaskView =
@@ -218,6 +285,119 @@ pub async fn delete_index(
     Ok(HttpResponse::Accepted().json(task))
 }
 
+#[derive(DeserializeFromValue, Debug)]
+#[deserr(error = DeserrError, rename_all = camelCase, deny_unknown_fields)]
+pub struct IndexCloneRequest {
+    #[deserr(error = DeserrError<InvalidIndexUid>, missing_field_error = DeserrError::missing_index_uid)]
+    uid: String,
+}
+
+pub async fn clone_index(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::INDEXES_CREATE }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+    body: ValidatedJson<IndexCloneRequest, DeserrError>,
+    req: HttpRequest,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let IndexCloneRequest { uid } = body.into_inner();
+    let target_uid = IndexUid::try_from(uid)?.into_inner();
+
+    if !index_scheduler.filters().search_rules.is_index_authorized(&target_uid) {
+        return Err(AuthenticationError::InvalidToken.into());
+    }
+
+    analytics.publish("Index Cloned".to_string(), json!({}), Some(&req));
+
+    let task = KindWithContent::IndexClone { index_uid: index_uid.into_inner(), target_uid };
+    let task: SummarizedTaskView =
+        tokio::task::spawn_blocking(move || index_scheduler.register(task)).await??.into();
+
+    debug!("returns: {:?}", task);
+    Ok(HttpResponse::Accepted().json(task))
+}
+
+#[derive(DeserializeFromValue, Debug)]
+#[deserr(error = DeserrError, rename_all = camelCase, deny_unknown_fields)]
+pub struct UpdateReadOnlyRequest {
+    #[deserr(error = DeserrError<InvalidIndexReadOnly>, missing_field_error = DeserrError::missing_index_read_only)]
+    read_only: bool,
+}
+
+pub async fn update_read_only(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::INDEXES_UPDATE }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+    body: ValidatedJson<UpdateReadOnlyRequest, DeserrError>,
+    req: HttpRequest,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let UpdateReadOnlyRequest { read_only } = body.into_inner();
+
+    analytics.publish(
+        "Index Read-Only Updated".to_string(),
+        json!({ "read_only": read_only }),
+        Some(&req),
+    );
+
+    let task =
+        KindWithContent::IndexReadOnlyUpdate { index_uid: index_uid.into_inner(), read_only };
+    let task: SummarizedTaskView =
+        tokio::task::spawn_blocking(move || index_scheduler.register(task)).await??.into();
+
+    debug!("returns: {:?}", task);
+    Ok(HttpResponse::Accepted().json(task))
+}
+
+pub async fn compact_index(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::INDEXES_UPDATE }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+    req: HttpRequest,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    analytics.publish("Index Compacted".to_string(), json!({}), Some(&req));
+
+    let task = KindWithContent::IndexCompaction { index_uid: index_uid.into_inner() };
+    let task: SummarizedTaskView =
+        tokio::task::spawn_blocking(move || index_scheduler.register(task)).await??.into();
+
+    debug!("returns: {:?}", task);
+    Ok(HttpResponse::Accepted().json(task))
+}
+
+pub async fn get_quota(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::INDEXES_GET }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+) -> Result<HttpResponse, ResponseError> {
+    let quota = index_scheduler.index_quota(&index_uid)?;
+
+    debug!("returns: {:?}", quota);
+    Ok(HttpResponse::Ok().json(quota))
+}
+
+pub async fn update_quota(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::INDEXES_UPDATE }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+    body: ValidatedJson<IndexQuota, DeserrError>,
+    req: HttpRequest,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let quota = body.into_inner();
+
+    analytics.publish(
+        "Index Quota Updated".to_string(),
+        json!({
+            "max_documents": quota.max_documents.is_some(),
+            "max_size_bytes": quota.max_size_bytes.is_some(),
+        }),
+        Some(&req),
+    );
+
+    tokio::task::spawn_blocking(move || index_scheduler.update_index_quota(&index_uid, &quota))
+        .await??;
+
+    debug!("returns: {:?}", quota);
+    Ok(HttpResponse::Ok().json(quota))
+}
+
 pub async fn get_index_stats(
     index_scheduler: GuardedData<ActionPolicy<{ actions::STATS_GET }>, Data<IndexScheduler>>,
     index_uid: web::Path<String>,
@@ -238,6 +418,18 @@ pub struct IndexStats {
     pub number_of_documents: u64,
     pub is_indexing: bool,
     pub field_distribution: FieldDistribution,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quota: Option<IndexQuotaUsage>,
+}
+
+/// The usage of an index compared to its quota.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexQuotaUsage {
+    pub number_of_documents: u64,
+    pub max_documents: Option<u64>,
+    pub size_bytes: u64,
+    pub max_size_bytes: Option<u64>,
 }
 
 impl IndexStats {
@@ -245,14 +437,37 @@ impl IndexStats {
         index_scheduler: Data<IndexScheduler>,
         index_uid: String,
     ) -> Result<Self, ResponseError> {
-        // we check if there is currently a task processing associated with this index.
-        let is_processing = index_scheduler.is_index_processing(&index_uid)?;
-        let index = index_scheduler.index(&index_uid)?;
-        let rtxn = index.read_txn()?;
-        Ok(IndexStats {
-            number_of_documents: index.number_of_documents(&rtxn)?,
-            is_indexing: is_processing,
-            field_distribution: index.field_distribution(&rtxn)?,
-        })
+        // The stats of an alias are the sum of the stats of the indexes it points to.
+        let mut stats = IndexStats {
+            number_of_documents: 0,
+            is_indexing: false,
+            field_distribution: FieldDistribution::new(),
+            quota: None,
+        };
+        for (name, index) in index_scheduler.resolve_indexes(&index_uid)? {
+            // we check if there is currently a task processing associated with this index.
+            stats.is_indexing |= index_scheduler.is_index_processing(&name)?;
+            let rtxn = index.read_txn()?;
+            stats.number_of_documents += index.number_of_documents(&rtxn)?;
+            for (field, count) in index.field_distribution(&rtxn)? {
+                *stats.field_distribution.entry(field).or_default() += count;
+            }
+
+            // Only the indexes have a quota, the aliases don't.
+            if name == index_uid {
+                let quota = index_scheduler.index_quota(&name)?;
+                if !quota.is_unlimited() {
+                    stats.quota = Some(IndexQuotaUsage {
+                        number_of_documents: index.number_of_documents(&rtxn)?,
+                        max_documents: quota.max_documents,
+                        // The quota is enforced on the pages used by the index, not on the
+                        // size of its file which never shrinks.
+                        size_bytes: index.used_size()?,
+                        max_size_bytes: quota.max_size_bytes,
+                    });
+                }
+            }
+        }
+        Ok(stats)
     }
 }
@@ -18,7 +18,7 @@ use crate::extractors::query_parameters::QueryParameter;
 use crate::extractors::sequential_extractor::SeqHandler;
 use crate::routes::from_string_to_option_take_error_message;
 use crate::search::{
-    perform_search, MatchingStrategy, SearchQuery, DEFAULT_CROP_LENGTH, DEFAULT_CROP_MARKER,
+    perform_alias_search, MatchingStrategy, SearchQuery, DEFAULT_CROP_LENGTH, DEFAULT_CROP_MARKER,
     DEFAULT_HIGHLIGHT_POST_TAG, DEFAULT_HIGHLIGHT_PRE_TAG, DEFAULT_SEARCH_LIMIT,
     DEFAULT_SEARCH_OFFSET,
 };
@@ -178,8 +178,10 @@ pub async fn search_with_url_query(
 
     let mut aggregate = SearchAggregator::from_query(&query, &req);
 
-    let index = index_scheduler.index(&index_uid)?;
-    let search_result = tokio::task::spawn_blocking(move || perform_search(&index, query)).await?;
+    let indexes: Vec<_> =
+        index_scheduler.resolve_indexes(&index_uid)?.into_iter().map(|(_, index)| index).collect();
+    let search_result =
+        tokio::task::spawn_blocking(move || perform_alias_search(&indexes, query)).await?;
     if let Ok(ref search_result) = search_result {
         aggregate.succeed(search_result);
     }
@@ -210,8 +212,10 @@ pub async fn search_with_post(
 
     let mut aggregate = SearchAggregator::from_query(&query, &req);
 
-    let index = index_scheduler.index(&index_uid)?;
-    let search_result = tokio::task::spawn_blocking(move || perform_search(&index, query)).await?;
+    let indexes: Vec<_> =
+        index_scheduler.resolve_indexes(&index_uid)?.into_iter().map(|(_, index)| index).collect();
+    let search_result =
+        tokio::task::spawn_blocking(move || perform_alias_search(&indexes, query)).await?;
     if let Ok(ref search_result) = search_result {
         aggregate.succeed(search_result);
     }
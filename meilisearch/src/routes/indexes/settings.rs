@@ -4,14 +4,18 @@ use index_scheduler::IndexScheduler;
 use log::debug;
 use meilisearch_types::error::{DeserrError, ResponseError};
 use meilisearch_types::index_uid::IndexUid;
+use meilisearch_types::milli::update::{ReindexingSetting, SettingsImpact};
 use meilisearch_types::settings::{settings, RankingRuleView, Settings, Unchecked};
 use meilisearch_types::tasks::KindWithContent;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 
+use super::ListIndexes;
 use crate::analytics::Analytics;
 use crate::extractors::authentication::policies::*;
 use crate::extractors::authentication::GuardedData;
 use crate::extractors::json::ValidatedJson;
+use crate::extractors::query_parameters::QueryParameter;
 use crate::routes::SummarizedTaskView;
 
 #[macro_export]
@@ -49,6 +53,7 @@ macro_rules! make_setting_route {
                     new_settings: Box::new(new_settings),
                     is_deletion: true,
                     allow_index_creation,
+                    is_rollback: false,
                 };
                 let task: SummarizedTaskView =
                     tokio::task::spawn_blocking(move || index_scheduler.register(task))
@@ -88,6 +93,7 @@ macro_rules! make_setting_route {
                     new_settings: Box::new(new_settings),
                     is_deletion: false,
                     allow_index_creation,
+                    is_rollback: false,
                 };
                 let task: SummarizedTaskView =
                     tokio::task::spawn_blocking(move || index_scheduler.register(task))
@@ -430,6 +436,127 @@ make_setting_route!(
     }
 );
 
+make_setting_route!(
+    "/ttl-attribute",
+    put,
+    String,
+    meilisearch_types::error::DeserrError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsTtlAttribute,
+    >,
+    ttl_attribute,
+    "ttlAttribute",
+    analytics,
+    |ttl: &Option<String>, req: &HttpRequest| {
+        use serde_json::json;
+        analytics.publish(
+            "TtlAttribute Updated".to_string(),
+            json!({
+                "ttl_attribute": {
+                    "set": ttl.is_some(),
+                }
+            }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/default-ttl",
+    put,
+    u64,
+    meilisearch_types::error::DeserrError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsDefaultTtl,
+    >,
+    default_ttl,
+    "defaultTtl",
+    analytics,
+    |ttl: &Option<u64>, req: &HttpRequest| {
+        use serde_json::json;
+        analytics.publish(
+            "DefaultTtl Updated".to_string(),
+            json!({
+                "default_ttl": {
+                    "set": ttl.is_some(),
+                }
+            }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/primary-key-generation",
+    put,
+    meilisearch_types::settings::PrimaryKeyGenerationView,
+    meilisearch_types::error::DeserrError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsPrimaryKeyGeneration,
+    >,
+    primary_key_generation,
+    "primaryKeyGeneration",
+    analytics,
+    |generation: &Option<meilisearch_types::settings::PrimaryKeyGenerationView>, req: &HttpRequest| {
+        use serde_json::json;
+        analytics.publish(
+            "PrimaryKeyGeneration Updated".to_string(),
+            json!({
+                "primary_key_generation": {
+                    "generation": generation,
+                }
+            }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/schema",
+    put,
+    meilisearch_types::settings::DocumentSchemaView,
+    meilisearch_types::error::DeserrError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsSchema,
+    >,
+    schema,
+    "schema",
+    analytics,
+    |schema: &Option<meilisearch_types::settings::DocumentSchemaView>, req: &HttpRequest| {
+        use serde_json::json;
+        analytics.publish(
+            "Schema Updated".to_string(),
+            json!({
+                "schema": {
+                    "policy": schema.as_ref().map(|schema| schema.0.policy),
+                    "total_properties": schema.as_ref().map(|schema| schema.0.properties.len()),
+                }
+            }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/soft-deleted-purge-threshold",
+    put,
+    u8,
+    meilisearch_types::error::DeserrError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsSoftDeletedPurgeThreshold,
+    >,
+    soft_deleted_purge_threshold,
+    "softDeletedPurgeThreshold",
+    analytics,
+    |percentage: &Option<u8>, req: &HttpRequest| {
+        use serde_json::json;
+        analytics.publish(
+            "SoftDeletedPurgeThreshold Updated".to_string(),
+            json!({
+                "soft_deleted_purge_threshold": {
+                    "percentage": percentage,
+                }
+            }),
+            Some(req),
+        );
+    }
+);
+
 macro_rules! generate_configure {
     ($($mod:ident),*) => {
         pub fn configure(cfg: &mut web::ServiceConfig) {
@@ -439,6 +566,11 @@ macro_rules! generate_configure {
                 .route(web::patch().to(SeqHandler(update_all)))
                 .route(web::get().to(SeqHandler(get_all)))
                 .route(web::delete().to(SeqHandler(delete_all))))
+                .service(web::resource("/dry-run").route(web::post().to(SeqHandler(dry_run))))
+                .service(web::resource("/history").route(web::get().to(SeqHandler(get_history))))
+                .service(
+                    web::resource("/rollback/{version}").route(web::post().to(SeqHandler(rollback))),
+                )
                 $(.service($mod::resources()))*;
         }
     };
@@ -455,7 +587,12 @@ generate_configure!(
     ranking_rules,
     typo_tolerance,
     pagination,
-    faceting
+    faceting,
+    ttl_attribute,
+    default_ttl,
+    primary_key_generation,
+    schema,
+    soft_deleted_purge_threshold
 );
 
 pub async fn update_all(
@@ -547,6 +684,22 @@ pub async fn update_all(
             "synonyms": {
                 "total": new_settings.synonyms.as_ref().set().map(|synonyms| synonyms.len()),
             },
+            "ttl_attribute": {
+                "set": new_settings.ttl_attribute.as_ref().set().is_some()
+            },
+            "default_ttl": {
+                "set": new_settings.default_ttl.as_ref().set().is_some()
+            },
+            "primary_key_generation": {
+                "generation": new_settings.primary_key_generation.as_ref().set(),
+            },
+            "schema": {
+                "policy": new_settings.schema.as_ref().set().map(|schema| schema.0.policy),
+                "total_properties": new_settings.schema.as_ref().set().map(|schema| schema.0.properties.len()),
+            },
+            "soft_deleted_purge_threshold": {
+                "percentage": new_settings.soft_deleted_purge_threshold.as_ref().set(),
+            },
         }),
         Some(&req),
     );
@@ -558,6 +711,7 @@ pub async fn update_all(
         new_settings: Box::new(new_settings),
         is_deletion: false,
         allow_index_creation,
+        is_rollback: false,
     };
     let task: SummarizedTaskView =
         tokio::task::spawn_blocking(move || index_scheduler.register(task)).await??.into();
@@ -590,6 +744,121 @@ pub async fn delete_all(
         new_settings: Box::new(new_settings),
         is_deletion: true,
         allow_index_creation,
+        is_rollback: false,
+    };
+    let task: SummarizedTaskView =
+        tokio::task::spawn_blocking(move || index_scheduler.register(task)).await??.into();
+
+    debug!("returns: {:?}", task);
+    Ok(HttpResponse::Accepted().json(task))
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsDryRunView {
+    /// Whether applying the settings reindexes all the documents.
+    pub reindexing: bool,
+    pub reindexing_settings: Vec<&'static str>,
+    pub rebuilt_databases: Vec<&'static str>,
+    pub estimated_cost: EstimatedCostView,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EstimatedCostView {
+    pub documents_to_reindex: u64,
+    pub reindexed_size: u64,
+}
+
+impl From<SettingsImpact> for SettingsDryRunView {
+    fn from(impact: SettingsImpact) -> Self {
+        let reindexing_settings = impact
+            .reindexing_settings
+            .iter()
+            .map(|setting| match setting {
+                // The faceted attributes come from the filterable and sortable attributes,
+                // the distinct and ttl attributes and the sort ranking rules.
+                ReindexingSetting::FacetedFields => "facetedAttributes",
+                ReindexingSetting::StopWords => "stopWords",
+                ReindexingSetting::Synonyms => "synonyms",
+                ReindexingSetting::SearchableFields => "searchableAttributes",
+                ReindexingSetting::ExactAttributes => "typoTolerance.disableOnAttributes",
+            })
+            .collect();
+
+        SettingsDryRunView {
+            reindexing: impact.documents_to_reindex != 0,
+            reindexing_settings,
+            rebuilt_databases: impact.rebuilt_databases,
+            estimated_cost: EstimatedCostView {
+                documents_to_reindex: impact.documents_to_reindex,
+                reindexed_size: impact.reindexed_size,
+            },
+        }
+    }
+}
+
+pub async fn dry_run(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::SETTINGS_UPDATE }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+    body: ValidatedJson<Settings<Unchecked>, DeserrError>,
+    req: HttpRequest,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    analytics.publish("Settings Dry Run".to_string(), json!({}), Some(&req));
+
+    let new_settings = body.into_inner().check();
+    let impact = tokio::task::spawn_blocking(move || {
+        index_scheduler.settings_dry_run(&index_uid, &new_settings)
+    })
+    .await??;
+
+    let impact = SettingsDryRunView::from(impact);
+    debug!("returns: {:?}", impact);
+    Ok(HttpResponse::Ok().json(impact))
+}
+
+pub async fn get_history(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::SETTINGS_GET }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+    paginate: QueryParameter<ListIndexes, DeserrError>,
+) -> Result<HttpResponse, ResponseError> {
+    let history = index_scheduler.settings_history(&index_uid)?;
+    let ret = paginate.as_pagination().auto_paginate_sized(history.into_iter());
+
+    debug!("returns: {:?}", ret);
+    Ok(HttpResponse::Ok().json(ret))
+}
+
+#[derive(Deserialize)]
+pub struct SettingsVersionParam {
+    index_uid: String,
+    version: u32,
+}
+
+pub async fn rollback(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::SETTINGS_UPDATE }>, Data<IndexScheduler>>,
+    path: web::Path<SettingsVersionParam>,
+    req: HttpRequest,
+    analytics: web::Data<dyn Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let SettingsVersionParam { index_uid, version } = path.into_inner();
+    analytics.publish("Settings Rolled Back".to_string(), json!({}), Some(&req));
+
+    // The whole version is enqueued so that the settings updates processed before
+    // the rollback can't leave the index in a state that never existed, the settings
+    // it really changes are only known once it's processed.
+    let new_settings =
+        index_scheduler.settings_version(&index_uid, version)?.settings.check().into_unchecked();
+
+    let allow_index_creation = index_scheduler.filters().allow_index_creation;
+    let index_uid = IndexUid::try_from(index_uid)?.into_inner();
+    let task = KindWithContent::SettingsUpdate {
+        index_uid,
+        new_settings: Box::new(new_settings),
+        is_deletion: false,
+        allow_index_creation,
+        is_rollback: true,
     };
     let task: SummarizedTaskView =
         tokio::task::spawn_blocking(move || index_scheduler.register(task)).await??.into();
@@ -18,8 +18,10 @@ use crate::analytics::Analytics;
 use crate::extractors::authentication::policies::*;
 use crate::extractors::authentication::GuardedData;
 
+mod aliases;
 mod api_key;
 mod dump;
+mod index_templates;
 pub mod indexes;
 mod swap_indexes;
 pub mod tasks;
@@ -32,6 +34,8 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
         .service(web::resource("/stats").route(web::get().to(get_stats)))
         .service(web::resource("/version").route(web::get().to(get_version)))
         .service(web::scope("/indexes").configure(indexes::configure))
+        .service(web::scope("/aliases").configure(aliases::configure))
+        .service(web::scope("/index-templates").configure(index_templates::configure))
         .service(web::scope("/swap-indexes").configure(swap_indexes::configure));
 }
 
@@ -298,6 +302,7 @@ pub fn create_all_stats(
             number_of_documents: index.number_of_documents(&rtxn)?,
             is_indexing: processing_index.map_or(false, |index_name| name == index_name),
             field_distribution: index.field_distribution(&rtxn)?,
+            quota: None,
         };
 
         let updated_at = index.updated_at(&rtxn)?;
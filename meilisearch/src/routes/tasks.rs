@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::num::ParseIntError;
 use std::str::FromStr;
 
@@ -8,10 +9,12 @@ use index_scheduler::{IndexScheduler, Query, TaskId};
 use meilisearch_types::error::deserr_codes::*;
 use meilisearch_types::error::{DeserrError, ResponseError, TakeErrorMessage};
 use meilisearch_types::index_uid::IndexUid;
+use meilisearch_types::milli::update::PrimaryKeyDefinition;
+use meilisearch_types::milli::{Object, SchemaViolations};
 use meilisearch_types::settings::{Settings, Unchecked};
 use meilisearch_types::star_or::StarOr;
 use meilisearch_types::tasks::{
-    serialize_duration, Details, IndexSwap, Kind, KindWithContent, Status, Task,
+    serialize_duration, Details, ImportedFile, IndexSwap, Kind, KindWithContent, Status, Task,
 };
 use serde::{Deserialize, Serialize};
 use serde_cs::vec::CS;
@@ -89,7 +92,9 @@ pub struct DetailsView {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub indexed_documents: Option<Option<u64>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub primary_key: Option<Option<String>>,
+    pub primary_key: Option<Option<PrimaryKeyDefinition>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_index_uid: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub provided_ids: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -105,28 +110,77 @@ pub struct DetailsView {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dump_uid: Option<Option<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<Option<Object>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub function: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edited_documents: Option<Option<u64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purged_documents: Option<Option<u64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(flatten)]
     pub settings: Option<Box<Settings<Unchecked>>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_uid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloned_documents: Option<Option<u64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pre_compaction_size: Option<Option<u64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_compaction_size: Option<Option<u64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub swaps: Option<Vec<IndexSwap>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indexes: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub imported_files: Option<Vec<ImportedFile>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_conflicts: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_conflicts_count: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generated_ids: Option<BTreeMap<u64, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generated_ids_count: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_violations: Option<SchemaViolations>,
 }
 
 impl From<Details> for DetailsView {
     fn from(details: Details) -> Self {
         match details {
-            Details::DocumentAdditionOrUpdate { received_documents, indexed_documents } => {
-                DetailsView {
-                    received_documents: Some(received_documents),
-                    indexed_documents: Some(indexed_documents),
-                    ..DetailsView::default()
-                }
-            }
+            Details::DocumentAdditionOrUpdate {
+                received_documents,
+                indexed_documents,
+                imported_files,
+                version_conflicts,
+                version_conflicts_count,
+                generated_ids,
+                generated_ids_count,
+                schema_violations,
+            } => DetailsView {
+                received_documents: Some(received_documents),
+                indexed_documents: Some(indexed_documents),
+                imported_files,
+                version_conflicts,
+                version_conflicts_count,
+                generated_ids,
+                generated_ids_count,
+                schema_violations,
+                ..DetailsView::default()
+            },
             Details::SettingsUpdate { settings } => {
                 DetailsView { settings: Some(settings), ..DetailsView::default() }
             }
-            Details::IndexInfo { primary_key } => {
-                DetailsView { primary_key: Some(primary_key), ..DetailsView::default() }
-            }
+            Details::IndexInfo { primary_key, new_index_uid } => DetailsView {
+                primary_key: Some(primary_key),
+                new_index_uid,
+                ..DetailsView::default()
+            },
             Details::DocumentDeletion {
                 provided_ids: received_document_ids,
                 deleted_documents,
@@ -135,6 +189,28 @@ impl From<Details> for DetailsView {
                 deleted_documents: Some(deleted_documents),
                 ..DetailsView::default()
             },
+            Details::DocumentDeletionByFilter { original_filter, deleted_documents } => {
+                DetailsView {
+                    original_filter: Some(original_filter),
+                    deleted_documents: Some(deleted_documents),
+                    ..DetailsView::default()
+                }
+            }
+            Details::DocumentExpiration { deleted_documents } => {
+                DetailsView { deleted_documents: Some(deleted_documents), ..DetailsView::default() }
+            }
+            Details::DocumentPurge { purged_documents } => {
+                DetailsView { purged_documents: Some(purged_documents), ..DetailsView::default() }
+            }
+            Details::DocumentEdition { original_filter, context, function, edited_documents } => {
+                DetailsView {
+                    original_filter,
+                    context: Some(context),
+                    function: Some(function),
+                    edited_documents: Some(edited_documents),
+                    ..DetailsView::default()
+                }
+            }
             Details::ClearAll { deleted_documents } => {
                 DetailsView { deleted_documents: Some(deleted_documents), ..DetailsView::default() }
             }
@@ -157,9 +233,25 @@ impl From<Details> for DetailsView {
             Details::Dump { dump_uid } => {
                 DetailsView { dump_uid: Some(dump_uid), ..DetailsView::default() }
             }
+            Details::IndexClone { target_uid, cloned_documents } => DetailsView {
+                target_uid: Some(target_uid),
+                cloned_documents: Some(cloned_documents),
+                ..DetailsView::default()
+            },
+            Details::IndexReadOnlyUpdate { read_only } => {
+                DetailsView { read_only: Some(read_only), ..DetailsView::default() }
+            }
+            Details::IndexCompaction { pre_compaction_size, post_compaction_size } => DetailsView {
+                pre_compaction_size: Some(pre_compaction_size),
+                post_compaction_size: Some(post_compaction_size),
+                ..DetailsView::default()
+            },
             Details::IndexSwap { swaps } => {
                 DetailsView { swaps: Some(swaps), ..Default::default() }
             }
+            Details::AliasUpdate { alias, indexes } => {
+                DetailsView { alias: Some(alias), indexes: Some(indexes), ..Default::default() }
+            }
         }
     }
 }
@@ -709,9 +801,9 @@ mod tests {
     #[test]
     fn deserialize_task_filter_types() {
         {
-            let params = "types=documentAdditionOrUpdate,documentDeletion,settingsUpdate,indexCreation,indexDeletion,indexUpdate,indexSwap,taskCancelation,taskDeletion,dumpCreation,snapshotCreation";
+            let params = "types=documentAdditionOrUpdate,documentDeletion,settingsUpdate,indexCreation,indexDeletion,indexUpdate,indexClone,indexReadOnlyUpdate,indexCompaction,indexSwap,aliasUpdate,taskCancelation,taskDeletion,dumpCreation,snapshotCreation";
             let query = deserr_query_params::<TaskDeletionOrCancelationQuery>(params).unwrap();
-            snapshot!(format!("{:?}", query.types.unwrap()), @"[DocumentAdditionOrUpdate, DocumentDeletion, SettingsUpdate, IndexCreation, IndexDeletion, IndexUpdate, IndexSwap, TaskCancelation, TaskDeletion, DumpCreation, SnapshotCreation]");
+            snapshot!(format!("{:?}", query.types.unwrap()), @"[DocumentAdditionOrUpdate, DocumentDeletion, SettingsUpdate, IndexCreation, IndexDeletion, IndexUpdate, IndexClone, IndexReadOnlyUpdate, IndexCompaction, IndexSwap, AliasUpdate, TaskCancelation, TaskDeletion, DumpCreation, SnapshotCreation]");
         }
         {
             let params = "types=settingsUpdate";
@@ -721,7 +813,7 @@ mod tests {
         {
             let params = "types=createIndex";
             let err = deserr_query_params::<TaskDeletionOrCancelationQuery>(params).unwrap_err();
-            snapshot!(format!("{err}"), @"`createIndex` is not a type. Available types are `documentAdditionOrUpdate`, `documentDeletion`, `settingsUpdate`, `indexCreation`, `indexDeletion`, `indexUpdate`, `indexSwap`, `taskCancelation`, `taskDeletion`, `dumpCreation`, `snapshotCreation`. at `.types`.");
+            snapshot!(format!("{err}"), @"`createIndex` is not a type. Available types are `documentAdditionOrUpdate`, `documentDeletion`, `documentEdition`, `documentPurge`, `settingsUpdate`, `indexCreation`, `indexDeletion`, `indexUpdate`, `indexClone`, `indexReadOnlyUpdate`, `indexCompaction`, `indexSwap`, `aliasUpdate`, `taskCancelation`, `taskDeletion`, `dumpCreation`, `snapshotCreation`. at `.types`.");
         }
     }
     #[test]
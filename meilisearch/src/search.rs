@@ -331,6 +331,103 @@ pub fn perform_search(
     Ok(result)
 }
 
+/// Search the indexes an alias points to. The hits of the different indexes are
+/// interleaved, the best hit of every index coming first, and the totals and facet
+/// distributions are summed.
+///
+/// The hits of different indexes can't be ordered according to a `sort`, it is
+/// rejected when the alias points to several indexes.
+pub fn perform_alias_search(
+    indexes: &[Index],
+    query: SearchQuery,
+) -> Result<SearchResult, MeilisearchHttpError> {
+    if let [index] = indexes {
+        return perform_search(index, query);
+    }
+    if query.sort.is_some() {
+        return Err(MeilisearchHttpError::SortOnMultiIndexAlias);
+    }
+
+    let before_search = Instant::now();
+    let is_finite_pagination = query.is_finite_pagination();
+
+    let (offset, limit) = if is_finite_pagination {
+        let limit = query.hits_per_page.unwrap_or_else(DEFAULT_SEARCH_LIMIT);
+        let page = query.page.unwrap_or(1);
+        page.checked_sub(1).map_or((0, 0), |p| (limit * p, limit))
+    } else {
+        (query.offset, query.limit)
+    };
+
+    // Every index returns its best `offset + limit` hits, the requested
+    // page is only extracted once the hits have been interleaved.
+    let index_query = if is_finite_pagination {
+        SearchQuery {
+            page: Some(1),
+            hits_per_page: Some(offset.saturating_add(limit)),
+            ..query.clone()
+        }
+    } else {
+        SearchQuery { offset: 0, limit: offset.saturating_add(limit), ..query.clone() }
+    };
+
+    let mut total_hits = 0;
+    let mut facet_distribution: Option<BTreeMap<String, BTreeMap<String, u64>>> = None;
+    let mut hits_per_index = Vec::with_capacity(indexes.len());
+    for index in indexes {
+        let result = perform_search(index, index_query.clone())?;
+        total_hits += match result.hits_info {
+            HitsInfo::Pagination { total_hits, .. } => total_hits,
+            HitsInfo::OffsetLimit { estimated_total_hits, .. } => estimated_total_hits,
+        };
+        if let Some(distribution) = result.facet_distribution {
+            let merged = facet_distribution.get_or_insert_with(BTreeMap::new);
+            for (facet, values) in distribution {
+                let merged_values = merged.entry(facet).or_default();
+                for (value, count) in values {
+                    *merged_values.entry(value).or_default() += count;
+                }
+            }
+        }
+        hits_per_index.push(result.hits.into_iter());
+    }
+
+    let mut hits = Vec::new();
+    loop {
+        let hits_before = hits.len();
+        for index_hits in hits_per_index.iter_mut() {
+            hits.extend(index_hits.next());
+        }
+        if hits.len() == hits_before {
+            break;
+        }
+    }
+    let hits = hits.into_iter().skip(offset).take(limit).collect();
+
+    let hits_info = if is_finite_pagination {
+        let hits_per_page = query.hits_per_page.unwrap_or_else(DEFAULT_SEARCH_LIMIT);
+        let total_pages =
+            (total_hits + hits_per_page.saturating_sub(1)).checked_div(hits_per_page).unwrap_or(0);
+
+        HitsInfo::Pagination {
+            hits_per_page,
+            page: query.page.unwrap_or(1),
+            total_pages,
+            total_hits,
+        }
+    } else {
+        HitsInfo::OffsetLimit { limit, offset, estimated_total_hits: total_hits }
+    };
+
+    Ok(SearchResult {
+        hits,
+        hits_info,
+        query: query.q.unwrap_or_default(),
+        processing_time_ms: before_search.elapsed().as_millis(),
+        facet_distribution,
+    })
+}
+
 fn insert_geo_distance(sorts: &[String], document: &mut Document) {
     lazy_static::lazy_static! {
         static ref GEO_REGEX: Regex =
@@ -603,7 +700,7 @@ fn format_value<'a, A: AsRef<[u8]>>(
     }
 }
 
-fn parse_filter(facets: &Value) -> Result<Option<Filter>, MeilisearchHttpError> {
+pub fn parse_filter(facets: &Value) -> Result<Option<Filter>, MeilisearchHttpError> {
     match facets {
         Value::String(expr) => {
             let condition = Filter::from_str(expr)?;
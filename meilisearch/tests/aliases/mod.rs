@@ -0,0 +1,166 @@
+use actix_web::test;
+use serde_json::json;
+
+use crate::common::{GetAllDocumentsOptions, Server};
+
+#[actix_rt::test]
+async fn alias_is_resolved_and_repointed() {
+    let server = Server::new().await;
+    let movies = server.index("movies_v1");
+    movies.add_documents(json!([{ "id": 1, "title": "Carol" }]), None).await;
+    movies.wait_task(0).await;
+    let series = server.index("series");
+    series.add_documents(json!([{ "id": 2, "title": "Carol's series" }]), None).await;
+    series.wait_task(1).await;
+
+    let (response, code) = server.update_alias("movies", json!({ "indexes": ["movies_v1"] })).await;
+    assert_eq!(code, 202, "{response}");
+    let response = server.wait_task(2).await;
+    assert_eq!(response["status"], "succeeded", "{response}");
+    assert_eq!(response["type"], "aliasUpdate");
+    assert_eq!(response["details"], json!({ "alias": "movies", "indexes": ["movies_v1"] }));
+
+    let (response, code) = server.get_alias("movies").await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response, json!({ "uid": "movies" }));
+
+    let (response, code) = server.list_aliases().await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["results"], json!([{ "uid": "movies" }]));
+
+    let alias = server.index("movies");
+    let (response, code) = alias.search_post(json!({ "q": "carol" })).await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["hits"], json!([{ "id": 1, "title": "Carol" }]));
+
+    let (response, code) = alias.get_document(1, None).await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["title"], "Carol");
+
+    // The alias now points to both indexes.
+    server.update_alias("movies", json!({ "indexes": ["movies_v1", "series"] })).await;
+    server.wait_task(3).await;
+
+    let (response, code) = alias.search_post(json!({ "q": "carol" })).await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["hits"].as_array().unwrap().len(), 2);
+    assert_eq!(response["estimatedTotalHits"], 2);
+
+    let (response, code) = alias.search_post(json!({ "q": "carol", "sort": ["id:asc"] })).await;
+    assert_eq!(code, 400, "{response}");
+    assert_eq!(response["code"], "invalid_search_sort");
+
+    let (response, code) = alias.get_document(2, None).await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["title"], "Carol's series");
+
+    let (response, code) = alias.get_all_documents(GetAllDocumentsOptions::default()).await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["total"], 2);
+    assert_eq!(
+        response["results"],
+        json!([{ "id": 1, "title": "Carol" }, { "id": 2, "title": "Carol's series" }])
+    );
+    let options = GetAllDocumentsOptions { offset: Some(1), ..Default::default() };
+    let (response, code) = alias.get_all_documents(options).await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["results"], json!([{ "id": 2, "title": "Carol's series" }]));
+
+    let (response, code) = alias.stats().await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["numberOfDocuments"], 2);
+    assert_eq!(response["fieldDistribution"]["title"], 2);
+
+    // Deleting an index removes it from the aliases.
+    series.delete().await;
+    server.wait_task(4).await;
+    let (response, code) = alias.search_post(json!({ "q": "carol" })).await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["hits"], json!([{ "id": 1, "title": "Carol" }]));
+
+    let (response, code) = server.delete_alias("movies").await;
+    assert_eq!(code, 202, "{response}");
+    server.wait_task(5).await;
+    let (response, code) = alias.search_post(json!({ "q": "carol" })).await;
+    assert_eq!(code, 404, "{response}");
+}
+
+async fn export(server: &Server, query: &str) -> (String, u16) {
+    let app = server.init_web_app().await;
+    let uri = format!("/indexes/movies/documents/export?{query}");
+    let res = test::call_service(&app, test::TestRequest::get().uri(&uri).to_request()).await;
+    let status = res.status().as_u16();
+    let body = test::read_body(res).await;
+    (String::from_utf8(body.to_vec()).unwrap(), status)
+}
+
+#[actix_rt::test]
+async fn export_and_validate_filter_on_alias() {
+    let server = Server::new().await;
+    let movies = server.index("movies_v1");
+    movies.add_documents(json!([{ "id": 1, "title": "Carol" }]), None).await;
+    movies.wait_task(0).await;
+    let series = server.index("series");
+    series.add_documents(json!([{ "id": 2, "title": "Carol's series" }]), None).await;
+    series.wait_task(1).await;
+    server.update_alias("movies", json!({ "indexes": ["movies_v1"] })).await;
+    server.wait_task(2).await;
+
+    let alias = server.index("movies");
+    let (response, code) = alias.validate_filter(json!({ "filter": "id = 1" })).await;
+    assert_eq!(code, 200, "{response}");
+    let (body, status) = export(&server, "").await;
+    assert_eq!(status, 200, "{body}");
+    assert_eq!(body, "{\"id\":1,\"title\":\"Carol\"}\n");
+
+    // The alias now points to both indexes.
+    server.update_alias("movies", json!({ "indexes": ["movies_v1", "series"] })).await;
+    server.wait_task(3).await;
+
+    let (body, status) = export(&server, "").await;
+    assert_eq!(status, 200, "{body}");
+    assert_eq!(body, "{\"id\":1,\"title\":\"Carol\"}\n{\"id\":2,\"title\":\"Carol's series\"}\n");
+
+    let (body, status) = export(&server, "format=csv").await;
+    assert_eq!(status, 400, "{body}");
+    assert!(body.contains("invalid_document_export_format"), "{body}");
+
+    let (response, code) = alias.validate_filter(json!({ "filter": "id = 1" })).await;
+    assert_eq!(code, 400, "{response}");
+    assert_eq!(response["code"], "invalid_index_uid");
+}
+
+#[actix_rt::test]
+async fn error_alias_to_missing_index() {
+    let server = Server::new().await;
+
+    let (response, code) = server.update_alias("movies", json!({ "indexes": ["movies_v1"] })).await;
+    assert_eq!(code, 202, "{response}");
+    let response = server.wait_task(0).await;
+    assert_eq!(response["status"], "failed");
+    assert_eq!(
+        response["error"],
+        json!({
+            "message": "Index `movies_v1` not found.",
+            "code": "index_not_found",
+            "type": "invalid_request",
+            "link": "https://docs.meilisearch.com/errors#index-not-found"
+        })
+    );
+
+    let (response, code) = server.get_alias("movies").await;
+    assert_eq!(code, 404);
+    assert_eq!(
+        response,
+        json!({
+            "message": "Alias `movies` not found.",
+            "code": "alias_not_found",
+            "type": "invalid_request",
+            "link": "https://docs.meilisearch.com/errors#alias-not-found"
+        })
+    );
+
+    let (response, code) = server.update_alias("movies", json!({ "indexes": [] })).await;
+    assert_eq!(code, 400);
+    assert_eq!(response["code"], "invalid_alias_indexes");
+}
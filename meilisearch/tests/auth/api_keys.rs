@@ -1789,3 +1789,36 @@ async fn error_access_api_key_routes_no_master_key_set() {
     "###);
     meili_snap::snapshot!(code, @"401 Unauthorized");
 }
+
+#[actix_rt::test]
+async fn rename_index_migrates_api_keys() {
+    let mut server = Server::new_auth().await;
+    server.use_admin_key("MASTER_KEY").await;
+
+    let (key, code) = server
+        .add_api_key(json!({
+            "indexes": ["products"],
+            "actions": ["search"],
+            "expiresAt": null
+        }))
+        .await;
+    assert_eq!(code, 201, "{key}");
+
+    let index = server.index("products");
+    index.create(None).await;
+    index.wait_task(0).await;
+
+    let (response, code) =
+        index.update_raw(json!({ "uid": "articles", "migrateApiKeys": true })).await;
+    assert_eq!(code, 202, "{response}");
+    let response = index.wait_task(1).await;
+    assert_eq!(response["status"], "succeeded", "{response}");
+
+    let (response, code) = server.get_api_key(key["uid"].as_str().unwrap()).await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["indexes"], json!(["articles"]));
+
+    server.use_api_key(key["key"].as_str().unwrap());
+    let (response, code) = server.index("articles").search_post(json!({})).await;
+    assert_eq!(code, 200, "{response}");
+}
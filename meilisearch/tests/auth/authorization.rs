@@ -13,10 +13,18 @@ pub static AUTHORIZATIONS: Lazy<HashMap<(&'static str, &'static str), HashSet<&'
         let mut authorizations = hashmap! {
             ("POST",    "/indexes/products/search") =>                         hashset!{"search", "*"},
             ("GET",     "/indexes/products/search") =>                         hashset!{"search", "*"},
+            ("POST",    "/indexes/products/filter/validate") =>                hashset!{"search", "*"},
             ("POST",    "/indexes/products/documents") =>                      hashset!{"documents.add", "documents.*", "*"},
             ("GET",     "/indexes/products/documents") =>                      hashset!{"documents.get", "documents.*", "*"},
+            ("PATCH",   "/indexes/products/documents") =>                      hashset!{"documents.add", "documents.*", "*"},
             ("GET",     "/indexes/products/documents/0") =>                    hashset!{"documents.get", "documents.*", "*"},
+            ("POST",    "/indexes/products/documents/edit") =>                 hashset!{"documents.add", "documents.*", "*"},
+            ("POST",    "/indexes/products/documents/fetch") =>                hashset!{"documents.get", "documents.*", "*"},
+            ("GET",     "/indexes/products/documents/export") =>               hashset!{"documents.get", "documents.*", "*"},
+            ("POST",    "/indexes/products/documents/import") =>               hashset!{"documents.add", "documents.*", "*"},
             ("DELETE",  "/indexes/products/documents/0") =>                    hashset!{"documents.delete", "documents.*", "*"},
+            ("POST",    "/indexes/products/documents/delete") =>               hashset!{"documents.delete", "documents.*", "*"},
+            ("POST",    "/indexes/products/documents/purge") =>                hashset!{"documents.delete", "documents.*", "*"},
             ("GET",     "/tasks") =>                                           hashset!{"tasks.get", "tasks.*", "*"},
             ("DELETE",  "/tasks") =>                                           hashset!{"tasks.delete", "tasks.*", "*"},
             ("GET",     "/tasks?indexUid=products") =>                         hashset!{"tasks.get", "tasks.*", "*"},
@@ -25,8 +33,21 @@ pub static AUTHORIZATIONS: Lazy<HashMap<(&'static str, &'static str), HashSet<&'
             ("GET",     "/indexes/products/") =>                               hashset!{"indexes.get", "indexes.*", "*"},
             ("DELETE",  "/indexes/products/") =>                               hashset!{"indexes.delete", "indexes.*", "*"},
             ("POST",    "/indexes") =>                                         hashset!{"indexes.create", "indexes.*", "*"},
+            ("POST",    "/indexes/products/clone") =>                          hashset!{"indexes.create", "indexes.*", "*"},
+            ("PUT",     "/indexes/products/read-only") =>                      hashset!{"indexes.update", "indexes.*", "*"},
+            ("POST",    "/indexes/products/compact") =>                        hashset!{"indexes.update", "indexes.*", "*"},
+            ("GET",     "/indexes/products/quota") =>                          hashset!{"indexes.get", "indexes.*", "*"},
+            ("PUT",     "/indexes/products/quota") =>                          hashset!{"indexes.update", "indexes.*", "*"},
             ("GET",     "/indexes") =>                                         hashset!{"indexes.get", "indexes.*", "*"},
             ("POST",    "/swap-indexes") =>                                    hashset!{"indexes.swap", "indexes.*", "*"},
+            ("GET",     "/aliases") =>                                         hashset!{"indexes.get", "indexes.*", "*"},
+            ("GET",     "/aliases/products") =>                                hashset!{"indexes.get", "indexes.*", "*"},
+            ("PUT",     "/aliases/products") =>                                hashset!{"indexes.update", "indexes.*", "*"},
+            ("DELETE",  "/aliases/products") =>                                hashset!{"indexes.update", "indexes.*", "*"},
+            ("GET",     "/index-templates") =>                                 hashset!{"indexes.get", "indexes.*", "*"},
+            ("GET",     "/index-templates/products") =>                        hashset!{"indexes.get", "indexes.*", "*"},
+            ("PUT",     "/index-templates/products") =>                        hashset!{"indexes.create", "indexes.*", "*"},
+            ("DELETE",  "/index-templates/products") =>                        hashset!{"indexes.delete", "indexes.*", "*"},
             ("GET",     "/indexes/products/settings") =>                       hashset!{"settings.get", "settings.*", "*"},
             ("GET",     "/indexes/products/settings/displayed-attributes") =>  hashset!{"settings.get", "settings.*", "*"},
             ("GET",     "/indexes/products/settings/distinct-attribute") =>    hashset!{"settings.get", "settings.*", "*"},
@@ -36,8 +57,16 @@ pub static AUTHORIZATIONS: Lazy<HashMap<(&'static str, &'static str), HashSet<&'
             ("GET",     "/indexes/products/settings/sortable-attributes") =>   hashset!{"settings.get", "settings.*", "*"},
             ("GET",     "/indexes/products/settings/stop-words") =>            hashset!{"settings.get", "settings.*", "*"},
             ("GET",     "/indexes/products/settings/synonyms") =>              hashset!{"settings.get", "settings.*", "*"},
+            ("GET",     "/indexes/products/settings/ttl-attribute") =>         hashset!{"settings.get", "settings.*", "*"},
+            ("GET",     "/indexes/products/settings/default-ttl") =>           hashset!{"settings.get", "settings.*", "*"},
+            ("GET",     "/indexes/products/settings/primary-key-generation") => hashset!{"settings.get", "settings.*", "*"},
+            ("GET",     "/indexes/products/settings/schema") => hashset!{"settings.get", "settings.*", "*"},
+            ("GET",     "/indexes/products/settings/soft-deleted-purge-threshold") => hashset!{"settings.get", "settings.*", "*"},
             ("DELETE",  "/indexes/products/settings") =>                       hashset!{"settings.update", "settings.*", "*"},
             ("PATCH",   "/indexes/products/settings") =>                       hashset!{"settings.update", "settings.*", "*"},
+            ("POST",    "/indexes/products/settings/dry-run") =>               hashset!{"settings.update", "settings.*", "*"},
+            ("GET",     "/indexes/products/settings/history") =>               hashset!{"settings.get", "settings.*", "*"},
+            ("POST",    "/indexes/products/settings/rollback/1") =>            hashset!{"settings.update", "settings.*", "*"},
             ("PATCH",   "/indexes/products/settings/typo-tolerance") =>        hashset!{"settings.update", "settings.*", "*"},
             ("PUT",     "/indexes/products/settings/displayed-attributes") =>  hashset!{"settings.update", "settings.*", "*"},
             ("PUT",     "/indexes/products/settings/distinct-attribute") =>    hashset!{"settings.update", "settings.*", "*"},
@@ -47,6 +76,11 @@ pub static AUTHORIZATIONS: Lazy<HashMap<(&'static str, &'static str), HashSet<&'
             ("PUT",     "/indexes/products/settings/sortable-attributes") =>   hashset!{"settings.update", "settings.*", "*"},
             ("PUT",     "/indexes/products/settings/stop-words") =>            hashset!{"settings.update", "settings.*", "*"},
             ("PUT",     "/indexes/products/settings/synonyms") =>              hashset!{"settings.update", "settings.*", "*"},
+            ("PUT",     "/indexes/products/settings/ttl-attribute") =>         hashset!{"settings.update", "settings.*", "*"},
+            ("PUT",     "/indexes/products/settings/default-ttl") =>           hashset!{"settings.update", "settings.*", "*"},
+            ("PUT",     "/indexes/products/settings/primary-key-generation") => hashset!{"settings.update", "settings.*", "*"},
+            ("PUT",     "/indexes/products/settings/schema") => hashset!{"settings.update", "settings.*", "*"},
+            ("PUT",     "/indexes/products/settings/soft-deleted-purge-threshold") => hashset!{"settings.update", "settings.*", "*"},
             ("GET",     "/indexes/products/stats") =>                          hashset!{"stats.get", "stats.*", "*"},
             ("GET",     "/stats") =>                                           hashset!{"stats.get", "stats.*", "*"},
             ("POST",    "/dumps") =>                                           hashset!{"dumps.create", "dumps.*", "*"},
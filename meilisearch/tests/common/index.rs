@@ -72,6 +72,36 @@ impl Index<'_> {
         self.service.patch_encoded(url, body, self.encoder).await
     }
 
+    pub async fn update_raw(&self, body: Value) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}", urlencode(self.uid.as_ref()));
+        self.service.patch_encoded(url, body, self.encoder).await
+    }
+
+    pub async fn update_read_only(&self, read_only: bool) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/read-only", urlencode(self.uid.as_ref()));
+        self.service.put_encoded(url, json!({ "readOnly": read_only }), self.encoder).await
+    }
+
+    pub async fn compact(&self) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/compact", urlencode(self.uid.as_ref()));
+        self.service.post_encoded(url, json!({}), self.encoder).await
+    }
+
+    pub async fn quota(&self) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/quota", urlencode(self.uid.as_ref()));
+        self.service.get(url).await
+    }
+
+    pub async fn update_quota(&self, quota: Value) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/quota", urlencode(self.uid.as_ref()));
+        self.service.put_encoded(url, quota, self.encoder).await
+    }
+
+    pub async fn clone_index(&self, target_uid: &str) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/clone", urlencode(self.uid.as_ref()));
+        self.service.post_encoded(url, json!({ "uid": target_uid }), self.encoder).await
+    }
+
     pub async fn delete(&self) -> (Value, StatusCode) {
         let url = format!("/indexes/{}", urlencode(self.uid.as_ref()));
         self.service.delete(url).await
@@ -105,6 +135,28 @@ impl Index<'_> {
         self.service.put_encoded(url, documents, self.encoder).await
     }
 
+    pub async fn deep_merge_documents(
+        &self,
+        documents: Value,
+        primary_key: Option<&str>,
+        append_arrays: bool,
+    ) -> (Value, StatusCode) {
+        let url = match primary_key {
+            Some(key) => format!(
+                "/indexes/{}/documents?primaryKey={}&appendArrays={}",
+                urlencode(self.uid.as_ref()),
+                key,
+                append_arrays
+            ),
+            None => format!(
+                "/indexes/{}/documents?appendArrays={}",
+                urlencode(self.uid.as_ref()),
+                append_arrays
+            ),
+        };
+        self.service.patch_encoded(url, documents, self.encoder).await
+    }
+
     pub async fn wait_task(&self, update_id: u64) -> Value {
         // try several times to get status, or panic to not wait forever
         let url = format!("/tasks/{}", update_id);
@@ -169,9 +221,18 @@ impl Index<'_> {
             let _ = write!(url, "fields={}&", attributes_to_retrieve.join(","));
         }
 
+        if let Some(filter) = options.filter {
+            let _ = write!(url, "filter={}&", urlencode(filter));
+        }
+
         self.service.get(url).await
     }
 
+    pub async fn fetch_documents(&self, body: Value) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/documents/fetch", urlencode(self.uid.as_ref()));
+        self.service.post_encoded(url, body, self.encoder).await
+    }
+
     pub async fn delete_document(&self, id: u64) -> (Value, StatusCode) {
         let url = format!("/indexes/{}/documents/{}", urlencode(self.uid.as_ref()), id);
         self.service.delete(url).await
@@ -187,6 +248,26 @@ impl Index<'_> {
         self.service.post_encoded(url, serde_json::to_value(&ids).unwrap(), self.encoder).await
     }
 
+    pub async fn delete_document_by_filter(&self, body: Value) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/documents/delete", urlencode(self.uid.as_ref()));
+        self.service.post_encoded(url, body, self.encoder).await
+    }
+
+    pub async fn edit_documents(&self, body: Value) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/documents/edit", urlencode(self.uid.as_ref()));
+        self.service.post_encoded(url, body, self.encoder).await
+    }
+
+    pub async fn import_documents(&self, body: Value) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/documents/import", urlencode(self.uid.as_ref()));
+        self.service.post_encoded(url, body, self.encoder).await
+    }
+
+    pub async fn purge_documents(&self) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/documents/purge", urlencode(self.uid.as_ref()));
+        self.service.post_encoded(url, json!({}), self.encoder).await
+    }
+
     pub async fn settings(&self) -> (Value, StatusCode) {
         let url = format!("/indexes/{}/settings", urlencode(self.uid.as_ref()));
         self.service.get(url).await
@@ -197,6 +278,21 @@ impl Index<'_> {
         self.service.patch_encoded(url, settings, self.encoder).await
     }
 
+    pub async fn settings_dry_run(&self, settings: Value) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/settings/dry-run", urlencode(self.uid.as_ref()));
+        self.service.post_encoded(url, settings, self.encoder).await
+    }
+
+    pub async fn settings_history(&self) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/settings/history", urlencode(self.uid.as_ref()));
+        self.service.get(url).await
+    }
+
+    pub async fn rollback_settings(&self, version: u32) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/settings/rollback/{version}", urlencode(self.uid.as_ref()));
+        self.service.post_encoded(url, json!({}), self.encoder).await
+    }
+
     pub async fn update_settings_displayed_attributes(
         &self,
         settings: Value,
@@ -308,6 +404,11 @@ impl Index<'_> {
         self.service.get(url).await
     }
 
+    pub async fn validate_filter(&self, body: Value) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/filter/validate", urlencode(self.uid.as_ref()));
+        self.service.post_encoded(url, body, self.encoder).await
+    }
+
     pub async fn update_distinct_attribute(&self, value: Value) -> (Value, StatusCode) {
         let url =
             format!("/indexes/{}/settings/{}", urlencode(self.uid.as_ref()), "distinct-attribute");
@@ -330,4 +431,5 @@ pub struct GetAllDocumentsOptions {
     pub limit: Option<usize>,
     pub offset: Option<usize>,
     pub attributes_to_retrieve: Option<Vec<&'static str>>,
+    pub filter: Option<&'static str>,
 }
@@ -148,6 +148,34 @@ impl Server {
         self.service.post("/swap-indexes", value).await
     }
 
+    pub async fn update_alias(&self, alias: &str, value: Value) -> (Value, StatusCode) {
+        self.service.put(format!("/aliases/{alias}"), value).await
+    }
+
+    pub async fn list_aliases(&self) -> (Value, StatusCode) {
+        self.service.get("/aliases").await
+    }
+
+    pub async fn get_alias(&self, alias: &str) -> (Value, StatusCode) {
+        self.service.get(format!("/aliases/{alias}")).await
+    }
+
+    pub async fn delete_alias(&self, alias: &str) -> (Value, StatusCode) {
+        self.service.delete(format!("/aliases/{alias}")).await
+    }
+
+    pub async fn put_index_template(&self, pattern: &str, value: Value) -> (Value, StatusCode) {
+        self.service.put(format!("/index-templates/{pattern}"), value).await
+    }
+
+    pub async fn get_index_template(&self, pattern: &str) -> (Value, StatusCode) {
+        self.service.get(format!("/index-templates/{pattern}")).await
+    }
+
+    pub async fn delete_index_template(&self, pattern: &str) -> (Value, StatusCode) {
+        self.service.delete(format!("/index-templates/{pattern}")).await
+    }
+
     pub async fn cancel_tasks(&self, value: Value) -> (Value, StatusCode) {
         self.service
             .post(format!("/tasks/cancel?{}", yaup::to_string(&value).unwrap()), json!(null))
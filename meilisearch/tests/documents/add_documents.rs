@@ -188,7 +188,7 @@ async fn error_add_documents_test_bad_content_types() {
     assert_eq!(
         response["message"],
         json!(
-            r#"The Content-Type `text/plain` is invalid. Accepted values for the Content-Type header are: `application/json`, `application/x-ndjson`, `text/csv`"#
+            r#"The Content-Type `text/plain` is invalid. Accepted values for the Content-Type header are: `application/json`, `application/x-ndjson`, `text/csv`, `application/vnd.apache.parquet`, `application/vnd.apache.arrow.stream`"#
         )
     );
     assert_eq!(response["code"], "invalid_content_type");
@@ -209,7 +209,7 @@ async fn error_add_documents_test_bad_content_types() {
     assert_eq!(
         response["message"],
         json!(
-            r#"The Content-Type `text/plain` is invalid. Accepted values for the Content-Type header are: `application/json`, `application/x-ndjson`, `text/csv`"#
+            r#"The Content-Type `text/plain` is invalid. Accepted values for the Content-Type header are: `application/json`, `application/x-ndjson`, `text/csv`, `application/vnd.apache.parquet`, `application/vnd.apache.arrow.stream`"#
         )
     );
     assert_eq!(response["code"], "invalid_content_type");
@@ -243,7 +243,7 @@ async fn error_add_documents_test_no_content_type() {
     assert_eq!(
         response["message"],
         json!(
-            r#"A Content-Type header is missing. Accepted values for the Content-Type header are: `application/json`, `application/x-ndjson`, `text/csv`"#
+            r#"A Content-Type header is missing. Accepted values for the Content-Type header are: `application/json`, `application/x-ndjson`, `text/csv`, `application/vnd.apache.parquet`, `application/vnd.apache.arrow.stream`"#
         )
     );
     assert_eq!(response["code"], "missing_content_type");
@@ -263,7 +263,7 @@ async fn error_add_documents_test_no_content_type() {
     assert_eq!(
         response["message"],
         json!(
-            r#"A Content-Type header is missing. Accepted values for the Content-Type header are: `application/json`, `application/x-ndjson`, `text/csv`"#
+            r#"A Content-Type header is missing. Accepted values for the Content-Type header are: `application/json`, `application/x-ndjson`, `text/csv`, `application/vnd.apache.parquet`, `application/vnd.apache.arrow.stream`"#
         )
     );
     assert_eq!(response["code"], "missing_content_type");
@@ -321,6 +321,105 @@ async fn error_add_malformed_csv_documents() {
     assert_eq!(response["link"], json!("https://docs.meilisearch.com/errors#malformed-payload"));
 }
 
+#[actix_rt::test]
+async fn add_csv_documents_with_options() {
+    let document = "id:number;name;available:boolean;tags:array(|)\n1;'Bouvier; Bernois';true;big|fluffy\n2;Leonberg;false;";
+
+    let server = Server::new().await;
+    let app = server.init_web_app().await;
+
+    let req = test::TestRequest::post()
+        .uri("/indexes/dog/documents?csvDelimiter=;&csvQuote='")
+        .set_payload(document.to_string())
+        .insert_header(("content-type", "text/csv"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    let status_code = res.status();
+    let body = test::read_body(res).await;
+    let response: Value = serde_json::from_slice(&body).unwrap_or_default();
+    assert_eq!(status_code, 202, "{}", response);
+
+    let index = server.index("dog");
+    let response = index.wait_task(0).await;
+    assert_eq!(response["status"], "succeeded", "{}", response);
+
+    let (response, code) = index.get_all_documents(GetAllDocumentsOptions::default()).await;
+    assert_eq!(code, 200);
+    assert_eq!(
+        response["results"],
+        json!([
+            { "id": 1, "name": "Bouvier; Bernois", "available": true, "tags": ["big", "fluffy"] },
+            { "id": 2, "name": "Leonberg", "available": false, "tags": null },
+        ])
+    );
+}
+
+#[actix_rt::test]
+async fn error_add_csv_documents_with_options() {
+    let server = Server::new().await;
+    let app = server.init_web_app().await;
+
+    let req = test::TestRequest::post()
+        .uri("/indexes/dog/documents?csvDelimiter=;;")
+        .set_payload("id\n1".to_string())
+        .insert_header(("content-type", "text/csv"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    let status_code = res.status();
+    let body = test::read_body(res).await;
+    let response: Value = serde_json::from_slice(&body).unwrap_or_default();
+    assert_eq!(status_code, 400);
+    assert_eq!(response["code"], json!("invalid_document_csv_delimiter"));
+
+    let req = test::TestRequest::post()
+        .uri("/indexes/dog/documents?csvQuote=%C3%A9")
+        .set_payload("id\n1".to_string())
+        .insert_header(("content-type", "text/csv"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    let status_code = res.status();
+    let body = test::read_body(res).await;
+    let response: Value = serde_json::from_slice(&body).unwrap_or_default();
+    assert_eq!(status_code, 400);
+    assert_eq!(response["code"], json!("invalid_document_csv_quote"));
+
+    let req = test::TestRequest::put()
+        .uri("/indexes/dog/documents?csvDelimiter=;")
+        .set_payload(json!([{ "id": 1 }]).to_string())
+        .insert_header(("content-type", "application/json"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    let status_code = res.status();
+    let body = test::read_body(res).await;
+    let response: Value = serde_json::from_slice(&body).unwrap_or_default();
+    assert_eq!(status_code, 400);
+    assert_eq!(
+        response["message"],
+        json!(
+            r#"The `csvDelimiter` parameter can only be used with a `csv` payload, but a `json` payload was sent."#
+        )
+    );
+    assert_eq!(response["code"], json!("invalid_document_csv_delimiter"));
+
+    let req = test::TestRequest::post()
+        .uri("/indexes/dog/documents")
+        .set_payload("id,available:boolean\n1,maybe".to_string())
+        .insert_header(("content-type", "text/csv"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    let status_code = res.status();
+    let body = test::read_body(res).await;
+    let response: Value = serde_json::from_slice(&body).unwrap_or_default();
+    assert_eq!(status_code, 400);
+    assert_eq!(
+        response["message"],
+        json!(
+            r#"The `csv` payload provided is malformed: `Error parsing boolean "maybe" at line 1: provided string was not `true` or `false``."#
+        )
+    );
+    assert_eq!(response["code"], json!("malformed_payload"));
+}
+
 #[actix_rt::test]
 async fn error_add_malformed_json_documents() {
     let document = r#"[{"id": 1}, {id: 2}]"#;
@@ -883,7 +982,7 @@ async fn error_add_documents_payload_size() {
     );
     let documents: Vec<_> = (0..16000).into_iter().map(|_| document.clone()).collect();
     let documents = json!(documents);
-    let (response, code) = index.add_documents(documents, None).await;
+    let (response, code) = index.add_documents(documents.clone(), None).await;
 
     let expected_response = json!({
         "message": "The provided payload reached the size limit.",
@@ -894,6 +993,53 @@ async fn error_add_documents_payload_size() {
 
     assert_eq!(response, expected_response);
     assert_eq!(code, 413);
+
+    // the limit applies to the decompressed size of the payload
+    for encoder in [Encoder::Gzip, Encoder::Brotli, Encoder::Zstd] {
+        let index = server.index_with_encoder("test", encoder);
+        let (response, code) = index.add_documents(documents.clone(), None).await;
+        assert_eq!(response, expected_response);
+        assert_eq!(code, 413);
+    }
+}
+
+#[actix_rt::test]
+async fn error_add_documents_bad_content_encoding() {
+    let server = Server::new().await;
+    let app = server.init_web_app().await;
+
+    let req = test::TestRequest::post()
+        .uri("/indexes/dog/documents")
+        .set_payload(json!([{ "id": 1 }]).to_string())
+        .insert_header(("content-type", "application/json"))
+        .insert_header(("content-encoding", "lzma"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    let status_code = res.status();
+    let body = test::read_body(res).await;
+    let response: Value = serde_json::from_slice(&body).unwrap_or_default();
+    assert_eq!(status_code, 415);
+    assert_eq!(
+        response["message"],
+        json!(
+            r#"The Content-Encoding `lzma` is not supported. Accepted values for the Content-Encoding header are: `gzip`, `deflate`, `br`, `zstd`, `identity`."#
+        )
+    );
+    assert_eq!(response["code"], "invalid_content_encoding");
+
+    // a payload that is not properly encoded is malformed
+    let req = test::TestRequest::post()
+        .uri("/indexes/dog/documents")
+        .set_payload(json!([{ "id": 1 }]).to_string())
+        .insert_header(("content-type", "application/json"))
+        .insert_header(("content-encoding", "zstd"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    let status_code = res.status();
+    let body = test::read_body(res).await;
+    let response: Value = serde_json::from_slice(&body).unwrap_or_default();
+    assert_eq!(status_code, 400, "{}", response);
+    assert_eq!(response["code"], "malformed_payload");
 }
 
 #[actix_rt::test]
@@ -1090,3 +1236,240 @@ async fn batch_several_documents_addition() {
     assert_eq!(code, 200, "failed with `{}`", response);
     assert_eq!(response["results"].as_array().unwrap().len(), 120);
 }
+
+#[actix_rt::test]
+async fn add_documents_with_version() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let documents = json!([
+        { "id": 1, "content": "foo", "_version": 0 },
+        { "id": 2, "content": "bar" },
+    ]);
+    index.add_documents(documents, None).await;
+    let response = index.wait_task(0).await;
+    assert_eq!(response["status"], "succeeded", "{}", response);
+
+    let (response, code) = index.get_document(1, None).await;
+    assert_eq!(code, 200);
+    assert_eq!(response, json!({ "id": 1, "content": "foo", "_version": 1 }));
+
+    let documents = json!([
+        { "id": 1, "content": "baz", "_version": 0 },
+        { "id": 2, "content": "qux", "_version": 0 },
+    ]);
+    index.update_documents(documents, None).await;
+    let response = index.wait_task(1).await;
+    assert_eq!(response["status"], "succeeded", "{}", response);
+    assert_eq!(response["details"]["receivedDocuments"], 2);
+    assert_eq!(response["details"]["indexedDocuments"], 1);
+    assert_eq!(response["details"]["versionConflicts"], json!(["1"]));
+    assert_eq!(response["details"]["versionConflictsCount"], 1);
+
+    let (response, _) = index.get_document(1, None).await;
+    assert_eq!(response, json!({ "id": 1, "content": "foo", "_version": 1 }));
+    let (response, _) = index.get_document(2, None).await;
+    assert_eq!(response, json!({ "id": 2, "content": "qux", "_version": 1 }));
+}
+
+#[actix_rt::test]
+async fn error_add_documents_invalid_version() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    index.add_documents(json!([{ "id": 1, "_version": -1 }]), None).await;
+    let response = index.wait_task(0).await;
+    assert_eq!(response["status"], "failed", "{}", response);
+    assert_eq!(
+        response["error"],
+        json!({
+            "message": "The `_version` field in the document with the id: `\"1\"` is invalid. Was expecting a non-negative integer but instead got `-1`.",
+            "code": "invalid_document_version",
+            "type": "invalid_request",
+            "link": "https://docs.meilisearch.com/errors#invalid-document-version"
+        })
+    );
+}
+
+#[actix_rt::test]
+async fn add_documents_with_generated_ids() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    index.update_settings(json!({ "primaryKeyGeneration": "uuidv4" })).await;
+    index.wait_task(0).await;
+
+    let documents = json!([
+        { "level": "info", "message": "started" },
+        { "id": "log-1", "level": "warn", "message": "slow" },
+    ]);
+    index.add_documents(documents, None).await;
+    let response = index.wait_task(1).await;
+    assert_eq!(response["status"], "succeeded", "{}", response);
+    assert_eq!(response["details"]["indexedDocuments"], 2);
+
+    // The generated ids are keyed by the position of their document in the payload.
+    assert_eq!(response["details"]["generatedIdsCount"], 1, "{}", response);
+    let generated_ids = response["details"]["generatedIds"].as_object().unwrap();
+    assert_eq!(generated_ids.len(), 1, "{}", response);
+    let id = generated_ids["0"].as_str().unwrap();
+    assert_eq!(id.len(), 36);
+
+    let (response, code) = server.service.get(format!("/indexes/test/documents/{id}")).await;
+    assert_eq!(code, 200, "{}", response);
+    assert_eq!(response, json!({ "id": id, "level": "info", "message": "started" }));
+
+    let (response, code) = index.settings().await;
+    assert_eq!(code, 200);
+    assert_eq!(response["primaryKeyGeneration"], "uuidv4");
+}
+
+#[actix_rt::test]
+async fn add_documents_with_many_generated_ids() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    index.update_settings(json!({ "primaryKeyGeneration": "uuidv4" })).await;
+    index.wait_task(0).await;
+
+    let mut documents: Vec<_> = (0..30).map(|i| json!({ "message": format!("log {i}") })).collect();
+    documents[3] = json!({ "id": "log-3", "message": "log 3" });
+    index.add_documents(json!(documents), None).await;
+    let response = index.wait_task(1).await;
+    assert_eq!(response["status"], "succeeded", "{}", response);
+    assert_eq!(response["details"]["indexedDocuments"], 30);
+
+    // Every generated id is reported, the documents with an id are left out.
+    assert_eq!(response["details"]["generatedIdsCount"], 29, "{}", response);
+    let generated_ids = response["details"]["generatedIds"].as_object().unwrap();
+    assert_eq!(generated_ids.len(), 29, "{}", response);
+    assert!(!generated_ids.contains_key("3"), "{}", response);
+
+    let id = generated_ids["29"].as_str().unwrap();
+    let (response, code) = server.service.get(format!("/indexes/test/documents/{id}")).await;
+    assert_eq!(code, 200, "{}", response);
+    assert_eq!(response["message"], "log 29");
+}
+
+#[actix_rt::test]
+async fn error_invalid_primary_key_generation() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) = index.update_settings(json!({ "primaryKeyGeneration": "uuidv1" })).await;
+    assert_eq!(code, 400, "{}", response);
+    assert_eq!(response["code"], "invalid_settings_primary_key_generation");
+}
+
+#[actix_rt::test]
+async fn add_documents_checked_against_schema() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let schema = json!({
+        "policy": "skipDocument",
+        "required": ["title"],
+        "properties": { "price": { "type": "number", "minimum": 0 } },
+    });
+    index.update_settings(json!({ "schema": schema })).await;
+    index.wait_task(0).await;
+
+    let documents = json!([
+        { "id": 1, "title": "hat", "price": 12 },
+        { "id": 2, "title": "scarf", "price": "7.5" },
+        { "id": 3, "price": 3 },
+    ]);
+    index.add_documents(documents.clone(), None).await;
+    let response = index.wait_task(1).await;
+    assert_eq!(response["status"], "succeeded", "{}", response);
+    assert_eq!(response["details"]["receivedDocuments"], 3);
+    assert_eq!(response["details"]["indexedDocuments"], 1);
+    assert_eq!(
+        response["details"]["schemaViolations"],
+        json!({
+            "skippedDocuments": 2,
+            "coercedDocuments": 0,
+            "sample": [
+                { "documentId": "2", "reasons": ["`price` was expecting a number but instead got `\"7.5\"`"] },
+                { "documentId": "3", "reasons": ["`title` is required"] },
+            ],
+        })
+    );
+
+    index
+        .update_settings(
+            json!({ "schema": { "policy": "coerce", "properties": schema["properties"] } }),
+        )
+        .await;
+    index.wait_task(2).await;
+    index.add_documents(documents.clone(), None).await;
+    let response = index.wait_task(3).await;
+    assert_eq!(response["status"], "succeeded", "{}", response);
+    assert_eq!(response["details"]["indexedDocuments"], 3);
+    assert_eq!(response["details"]["schemaViolations"]["coercedDocuments"], 1);
+    let (response, code) = index.get_document(2, None).await;
+    assert_eq!(code, 200, "{}", response);
+    assert_eq!(response["price"], json!(7.5));
+
+    index.update_settings(json!({ "schema": { "properties": schema["properties"] } })).await;
+    index.wait_task(4).await;
+    index.add_documents(documents, None).await;
+    let response = index.wait_task(5).await;
+    assert_eq!(response["status"], "failed", "{}", response);
+    assert_eq!(response["error"]["code"], "document_schema_mismatch");
+
+    let (response, code) = index.settings().await;
+    assert_eq!(code, 200);
+    assert_eq!(response["schema"]["policy"], "rejectBatch");
+}
+
+#[actix_rt::test]
+async fn update_documents_without_their_stored_required_fields() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    index.update_settings(json!({ "schema": { "required": ["title"] } })).await;
+    index.wait_task(0).await;
+    index.add_documents(json!([{ "id": 1, "title": "hat", "price": 12 }]), None).await;
+    let response = index.wait_task(1).await;
+    assert_eq!(response["status"], "succeeded", "{}", response);
+
+    // the stored document already has a title
+    index.update_documents(json!([{ "id": 1, "price": 10 }]), None).await;
+    let response = index.wait_task(2).await;
+    assert_eq!(response["status"], "succeeded", "{}", response);
+    let (response, code) = index.get_document(1, None).await;
+    assert_eq!(code, 200, "{}", response);
+    assert_eq!(response, json!({ "id": 1, "title": "hat", "price": 10 }));
+
+    // but a replacement or a new document must have one
+    index.add_documents(json!([{ "id": 1, "price": 10 }]), None).await;
+    let response = index.wait_task(3).await;
+    assert_eq!(response["status"], "failed", "{}", response);
+    assert_eq!(response["error"]["code"], "document_schema_mismatch");
+    index.update_documents(json!([{ "id": 2, "price": 10 }]), None).await;
+    let response = index.wait_task(4).await;
+    assert_eq!(response["status"], "failed", "{}", response);
+    assert_eq!(response["error"]["code"], "document_schema_mismatch");
+}
+
+#[actix_rt::test]
+async fn error_invalid_schema() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) = index
+        .update_settings(json!({ "schema": { "properties": { "price": { "type": "float" } } } }))
+        .await;
+    assert_eq!(code, 400, "{}", response);
+    assert_eq!(response["code"], "invalid_settings_schema");
+
+    index
+        .update_settings(
+            json!({ "schema": { "properties": { "price": { "minimum": 10, "maximum": 1 } } } }),
+        )
+        .await;
+    let response = index.wait_task(0).await;
+    assert_eq!(response["status"], "failed", "{}", response);
+    assert_eq!(response["error"]["code"], "invalid_settings_schema");
+}
@@ -135,3 +135,94 @@ async fn delete_no_document_batch() {
     assert_eq!(code, 200);
     assert_eq!(response["results"].as_array().unwrap().len(), 3);
 }
+
+#[actix_rt::test]
+async fn delete_document_by_filter() {
+    let server = Server::new().await;
+    let index = server.index("doggo");
+    index.update_settings_filterable_attributes(json!(["color"])).await;
+    index
+        .add_documents(
+            json!([
+                { "id": 0, "color": "red" },
+                { "id": 1, "color": "blue" },
+                { "id": 2, "color": "blue" },
+                { "id": 3 },
+            ]),
+            Some("id"),
+        )
+        .await;
+    index.wait_task(1).await;
+
+    let (response, code) =
+        index.delete_document_by_filter(json!({ "filter": "color = blue" })).await;
+    assert_eq!(code, 202, "{}", response);
+
+    let response = index.wait_task(2).await;
+    assert_eq!(response["status"], "succeeded", "{}", response);
+    assert_eq!(response["type"], "documentDeletion");
+    assert_eq!(
+        response["details"],
+        json!({ "deletedDocuments": 2, "originalFilter": "\"color = blue\"" })
+    );
+
+    let (response, code) = index.get_all_documents(GetAllDocumentsOptions::default()).await;
+    assert_eq!(code, 200);
+    assert_eq!(response["results"], json!([{ "id": 0, "color": "red" }, { "id": 3 }]));
+
+    let (response, code) =
+        index.delete_document_by_filter(json!({ "filter": ["color NOT EXISTS"] })).await;
+    assert_eq!(code, 202, "{}", response);
+
+    let response = index.wait_task(3).await;
+    assert_eq!(response["status"], "succeeded", "{}", response);
+    assert_eq!(
+        response["details"],
+        json!({ "deletedDocuments": 1, "originalFilter": "[\"color NOT EXISTS\"]" })
+    );
+
+    let (response, code) = index.get_all_documents(GetAllDocumentsOptions::default()).await;
+    assert_eq!(code, 200);
+    assert_eq!(response["results"], json!([{ "id": 0, "color": "red" }]));
+}
+
+#[actix_rt::test]
+async fn error_delete_document_by_filter() {
+    let server = Server::new().await;
+    let index = server.index("doggo");
+
+    let (response, code) = index.delete_document_by_filter(json!({})).await;
+    assert_eq!(code, 400, "{}", response);
+    assert_eq!(response["code"], "missing_document_filter");
+
+    let (response, code) = index.delete_document_by_filter(json!({ "filter": 1 })).await;
+    assert_eq!(code, 400, "{}", response);
+    assert_eq!(response["code"], "invalid_document_filter");
+
+    let (response, code) = index.delete_document_by_filter(json!({ "filter": "hello" })).await;
+    assert_eq!(code, 400, "{}", response);
+    assert_eq!(response["code"], "invalid_document_filter");
+
+    let (response, code) = index.delete_document_by_filter(json!({ "filter": [] })).await;
+    assert_eq!(code, 400, "{}", response);
+    assert_eq!(response["code"], "invalid_document_filter");
+
+    // The index doesn't exist
+    let (response, code) =
+        index.delete_document_by_filter(json!({ "filter": "doggo = bernese" })).await;
+    assert_eq!(code, 202, "{}", response);
+    let response = index.wait_task(0).await;
+    assert_eq!(response["status"], "failed");
+    assert_eq!(response["error"]["code"], "index_not_found");
+    assert_eq!(response["details"]["deletedDocuments"], json!(0));
+
+    // The attribute is not filterable
+    index.create(None).await;
+    index.wait_task(1).await;
+    let (response, code) =
+        index.delete_document_by_filter(json!({ "filter": "doggo = bernese" })).await;
+    assert_eq!(code, 202, "{}", response);
+    let response = index.wait_task(2).await;
+    assert_eq!(response["status"], "failed");
+    assert_eq!(response["error"]["code"], "invalid_search_filter");
+}
@@ -0,0 +1,172 @@
+use serde_json::json;
+
+use crate::common::{GetAllDocumentsOptions, Server};
+
+#[actix_rt::test]
+async fn edit_documents_by_function() {
+    let server = Server::new().await;
+    let index = server.index("doggo");
+    index.update_settings_filterable_attributes(json!(["id"])).await;
+    index
+        .add_documents(
+            json!([
+                { "id": 0, "name": "kefir", "views": 0 },
+                { "id": 1, "name": "intel", "views": 1 },
+                { "id": 2, "name": "echo", "views": 2 },
+            ]),
+            Some("id"),
+        )
+        .await;
+    index.wait_task(1).await;
+
+    let function = "if doc.views > 0 { doc.views += context.step }";
+    let (response, code) = index
+        .edit_documents(json!({
+            "filter": "id < 2",
+            "context": { "step": 10 },
+            "function": function,
+        }))
+        .await;
+    assert_eq!(code, 202, "{}", response);
+
+    let response = index.wait_task(2).await;
+    assert_eq!(response["status"], "succeeded", "{}", response);
+    assert_eq!(response["type"], "documentEdition");
+    assert_eq!(
+        response["details"],
+        json!({
+            "originalFilter": "\"id < 2\"",
+            "context": { "step": 10 },
+            "function": function,
+            "editedDocuments": 1,
+        })
+    );
+
+    let (response, code) = index.get_all_documents(GetAllDocumentsOptions::default()).await;
+    assert_eq!(code, 200);
+    assert_eq!(
+        response["results"],
+        json!([
+            { "id": 0, "name": "kefir", "views": 0 },
+            { "id": 1, "name": "intel", "views": 11 },
+            { "id": 2, "name": "echo", "views": 2 },
+        ])
+    );
+
+    // without a filter the function is applied to every document
+    let (response, code) =
+        index.edit_documents(json!({ "function": "doc.name = doc.name.to_upper()" })).await;
+    assert_eq!(code, 202, "{}", response);
+
+    let response = index.wait_task(3).await;
+    assert_eq!(response["status"], "succeeded", "{}", response);
+    assert_eq!(
+        response["details"],
+        json!({
+            "context": null,
+            "function": "doc.name = doc.name.to_upper()",
+            "editedDocuments": 3,
+        })
+    );
+
+    let (response, code) = index.get_all_documents(GetAllDocumentsOptions::default()).await;
+    assert_eq!(code, 200);
+    assert_eq!(
+        response["results"],
+        json!([
+            { "id": 0, "name": "KEFIR", "views": 0 },
+            { "id": 1, "name": "INTEL", "views": 11 },
+            { "id": 2, "name": "ECHO", "views": 2 },
+        ])
+    );
+}
+
+#[actix_rt::test]
+async fn edit_documents_unexisting_index() {
+    let server = Server::new().await;
+    let index = server.index("doggo");
+
+    let (response, code) = index.edit_documents(json!({ "function": "doc.views += 1" })).await;
+    assert_eq!(code, 202, "{}", response);
+
+    let response = index.wait_task(0).await;
+    assert_eq!(response["status"], "failed", "{}", response);
+    assert_eq!(response["error"]["code"], "index_not_found");
+}
+
+#[actix_rt::test]
+async fn error_edit_documents() {
+    let server = Server::new().await;
+    let index = server.index("doggo");
+
+    let (response, code) = index.edit_documents(json!({})).await;
+    assert_eq!(code, 400, "{}", response);
+    assert_eq!(response["code"], "missing_document_edition_function");
+
+    let (response, code) = index.edit_documents(json!({ "function": 1 })).await;
+    assert_eq!(code, 400, "{}", response);
+    assert_eq!(response["code"], "invalid_document_edition_function");
+
+    let (response, code) =
+        index.edit_documents(json!({ "function": "doc.views += 1", "context": [1] })).await;
+    assert_eq!(code, 400, "{}", response);
+    assert_eq!(response["code"], "invalid_document_edition_context");
+
+    let (response, code) =
+        index.edit_documents(json!({ "function": "doc.views += 1", "filter": "id = " })).await;
+    assert_eq!(code, 400, "{}", response);
+    assert_eq!(response["code"], "invalid_document_filter");
+
+    index.add_documents(json!([{ "id": 0, "views": 0 }]), Some("id")).await;
+    index.wait_task(0).await;
+
+    // the function is only compiled when the task is processed
+    let (response, code) = index.edit_documents(json!({ "function": "doc.views +=" })).await;
+    assert_eq!(code, 202, "{}", response);
+    let response = index.wait_task(1).await;
+    assert_eq!(response["status"], "failed", "{}", response);
+    assert_eq!(response["error"]["code"], "invalid_document_edition_function");
+    assert_eq!(response["details"]["editedDocuments"], 0);
+
+    let (response, code) = index.edit_documents(json!({ "function": "doc.id = 42" })).await;
+    assert_eq!(code, 202, "{}", response);
+    let response = index.wait_task(2).await;
+    assert_eq!(response["status"], "failed", "{}", response);
+    assert_eq!(response["error"]["code"], "invalid_document_edition_function");
+    assert_eq!(
+        response["error"]["message"],
+        "The edition function cannot modify the primary key of the document `0`."
+    );
+
+    // an endless function is stopped by the operations limit
+    let (response, code) = index.edit_documents(json!({ "function": "loop { }" })).await;
+    assert_eq!(code, 202, "{}", response);
+    let response = index.wait_task(3).await;
+    assert_eq!(response["status"], "failed", "{}", response);
+    assert_eq!(response["error"]["code"], "invalid_document_edition_function");
+
+    let (response, code) = index.get_all_documents(GetAllDocumentsOptions::default()).await;
+    assert_eq!(code, 200);
+    assert_eq!(response["results"], json!([{ "id": 0, "views": 0 }]));
+}
+
+#[actix_rt::test]
+async fn error_edit_documents_nested_primary_key() {
+    let server = Server::new().await;
+    let index = server.index("doggo");
+    index.add_documents(json!([{ "doggo": { "id": 0 }, "views": 0 }]), Some("doggo.id")).await;
+    index.wait_task(0).await;
+
+    let (response, code) = index.edit_documents(json!({ "function": "doc.doggo.id = 42" })).await;
+    assert_eq!(code, 202, "{}", response);
+    let response = index.wait_task(1).await;
+    assert_eq!(response["status"], "failed", "{}", response);
+    assert_eq!(
+        response["error"]["message"],
+        "The edition function cannot modify the primary key of the document `0`."
+    );
+
+    let (response, code) = index.get_all_documents(GetAllDocumentsOptions::default()).await;
+    assert_eq!(code, 200);
+    assert_eq!(response["results"], json!([{ "doggo": { "id": 0 }, "views": 0 }]));
+}
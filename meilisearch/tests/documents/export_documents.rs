@@ -0,0 +1,112 @@
+use actix_web::test;
+use http::header::{ACCEPT_ENCODING, CONTENT_TYPE};
+use serde_json::{json, Value};
+
+use crate::common::encoder::Encoder;
+use crate::common::Server;
+
+async fn export(server: &Server, query: &str, encoding: Option<&str>) -> (String, String, u16) {
+    let app = server.init_web_app().await;
+    let mut req = test::TestRequest::get().uri(&format!("/indexes/test/documents/export?{query}"));
+    if let Some(encoding) = encoding {
+        req = req.insert_header((ACCEPT_ENCODING, encoding));
+    }
+
+    let res = test::call_service(&app, req.to_request()).await;
+    let status = res.status().as_u16();
+    let content_type = res
+        .headers()
+        .get(CONTENT_TYPE)
+        .map(|value| value.to_str().unwrap().to_string())
+        .unwrap_or_default();
+
+    let bytes = test::read_body(res).await;
+    let body = match encoding {
+        Some("gzip") => Encoder::Gzip.decode(bytes).into(),
+        _ => bytes,
+    };
+    (String::from_utf8(body.to_vec()).unwrap(), content_type, status)
+}
+
+#[actix_rt::test]
+async fn export_documents_ndjson() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    index.load_test_set().await;
+
+    let (body, content_type, status) = export(&server, "", None).await;
+    assert_eq!(status, 200, "{}", body);
+    assert_eq!(content_type, "application/x-ndjson");
+    let documents: Vec<Value> =
+        body.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+    assert_eq!(documents.len(), 77);
+    assert_eq!(documents[0]["id"], json!(0));
+    assert_eq!(documents[76]["id"], json!(76));
+
+    // The compressed export contains the same documents.
+    let (compressed, _, status) = export(&server, "", Some("gzip")).await;
+    assert_eq!(status, 200);
+    assert_eq!(compressed, body);
+}
+
+#[actix_rt::test]
+async fn export_documents_filter_and_fields() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    index.load_test_set().await;
+    index.update_settings_filterable_attributes(json!(["color"])).await;
+    index.wait_task(1).await;
+
+    let (body, _, status) =
+        export(&server, "filter=color%20%3D%20blue&fields=id,color", None).await;
+    assert_eq!(status, 200, "{}", body);
+    let documents: Vec<Value> =
+        body.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+    assert_eq!(documents.len(), 28);
+    assert_eq!(documents[0], json!({ "id": 2, "color": "blue" }));
+
+    let (body, _, status) = export(&server, "filter=age%20%3E%2020", None).await;
+    assert_eq!(status, 400, "{}", body);
+    let error: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(error["code"], json!("invalid_document_filter"));
+}
+
+#[actix_rt::test]
+async fn export_documents_csv() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    index
+        .add_documents(
+            json!([
+                { "id": 1, "title": "Carol", "tags": ["pop", "rock"], "sold": true },
+                { "id": 2, "title": "Hello, \"world\"" },
+            ]),
+            None,
+        )
+        .await;
+    index.wait_task(0).await;
+
+    let (body, content_type, status) = export(&server, "format=csv", None).await;
+    assert_eq!(status, 200, "{}", body);
+    assert_eq!(content_type, "text/csv");
+    assert_eq!(
+        body,
+        "id,title,tags,sold\n1,Carol,\"[\"\"pop\"\",\"\"rock\"\"]\",true\n2,\"Hello, \"\"world\"\"\",,\n"
+    );
+
+    let (body, _, status) = export(&server, "format=csv&fields=title", None).await;
+    assert_eq!(status, 200, "{}", body);
+    assert_eq!(body, "title\nCarol\n\"Hello, \"\"world\"\"\"\n");
+
+    let (body, _, status) = export(&server, "format=xml", None).await;
+    assert_eq!(status, 400, "{}", body);
+    let error: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(error["code"], json!("invalid_document_export_format"));
+}
+
+#[actix_rt::test]
+async fn export_documents_unexisting_index() {
+    let server = Server::new().await;
+    let (body, _, status) = export(&server, "", None).await;
+    assert_eq!(status, 404, "{}", body);
+}
@@ -293,6 +293,111 @@ async fn test_get_all_documents_attributes_to_retrieve() {
     }
 }
 
+#[actix_rt::test]
+async fn test_get_all_documents_filter() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    index.load_test_set().await;
+    let (response, code) =
+        index.update_settings_filterable_attributes(json!(["color", "gender"])).await;
+    assert_eq!(code, 202, "{}", response);
+    index.wait_task(1).await;
+
+    let (response, code) = index
+        .get_all_documents(GetAllDocumentsOptions {
+            filter: Some("color = blue"),
+            limit: Some(5),
+            ..Default::default()
+        })
+        .await;
+    assert_eq!(code, 200, "{}", response);
+    let ids: Vec<_> = response["results"].as_array().unwrap().iter().map(|d| &d["id"]).collect();
+    assert_eq!(ids, [&json!(2), &json!(3), &json!(6), &json!(9), &json!(10)]);
+    assert_eq!(response["offset"], json!(0));
+    assert_eq!(response["limit"], json!(5));
+    assert_eq!(response["total"], json!(28));
+
+    let (response, code) = index
+        .get_all_documents(GetAllDocumentsOptions {
+            filter: Some(r#"["color = blue", "gender = male"]"#),
+            offset: Some(10),
+            ..Default::default()
+        })
+        .await;
+    assert_eq!(code, 200, "{}", response);
+    assert_eq!(response["results"].as_array().unwrap().len(), 3);
+    assert_eq!(response["total"], json!(13));
+
+    let (response, code) = index
+        .get_all_documents(GetAllDocumentsOptions {
+            filter: Some("isActive = true"),
+            ..Default::default()
+        })
+        .await;
+    assert_eq!(code, 400, "{}", response);
+    assert_eq!(response["code"], json!("invalid_document_filter"));
+
+    let (response, code) = index
+        .get_all_documents(GetAllDocumentsOptions { filter: Some("color ="), ..Default::default() })
+        .await;
+    assert_eq!(code, 400, "{}", response);
+    assert_eq!(response["code"], json!("invalid_document_filter"));
+}
+
+#[actix_rt::test]
+async fn test_fetch_documents() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    index.load_test_set().await;
+    let (response, code) =
+        index.update_settings_filterable_attributes(json!(["color", "gender"])).await;
+    assert_eq!(code, 202, "{}", response);
+    index.wait_task(1).await;
+
+    let (response, code) = index.fetch_documents(json!({})).await;
+    assert_eq!(code, 200, "{}", response);
+    assert_eq!(response["results"].as_array().unwrap().len(), 20);
+    assert_eq!(response["total"], json!(77));
+
+    let (response, code) = index
+        .fetch_documents(json!({
+            "filter": [["color = blue", "color = green"], "gender = male"],
+            "fields": ["id", "color"],
+            "offset": 2,
+            "limit": 3,
+        }))
+        .await;
+    assert_eq!(code, 200, "{}", response);
+    for document in response["results"].as_array().unwrap() {
+        assert_eq!(document.as_object().unwrap().keys().count(), 2);
+        assert_eq!(document["gender"], json!(null));
+    }
+    assert_eq!(response["offset"], json!(2));
+    assert_eq!(response["limit"], json!(3));
+
+    let (all_response, code) = index
+        .fetch_documents(json!({
+            "filter": [["color = blue", "color = green"], "gender = male"],
+            "limit": 1000,
+        }))
+        .await;
+    assert_eq!(code, 200, "{}", all_response);
+    assert_eq!(all_response["total"], response["total"]);
+    assert_eq!(
+        all_response["results"].as_array().unwrap().len() as u64,
+        all_response["total"].as_u64().unwrap()
+    );
+    assert_eq!(all_response["results"][2]["id"], response["results"][0]["id"]);
+
+    let (response, code) = index.fetch_documents(json!({ "filter": 42 })).await;
+    assert_eq!(code, 400, "{}", response);
+    assert_eq!(response["code"], json!("invalid_document_filter"));
+
+    let (response, code) = index.fetch_documents(json!({ "offset": "a" })).await;
+    assert_eq!(code, 400, "{}", response);
+    assert_eq!(response["code"], json!("invalid_document_offset"));
+}
+
 #[actix_rt::test]
 async fn get_document_s_nested_attributes_to_retrieve() {
     let server = Server::new().await;
@@ -378,3 +483,60 @@ async fn get_documents_displayed_attributes_is_ignored() {
     assert_eq!(response.as_object().unwrap().keys().count(), 16);
     assert!(response.as_object().unwrap().get("gender").is_some());
 }
+
+#[actix_rt::test]
+async fn get_and_delete_documents_with_composite_primary_key() {
+    let server = Server::new().await;
+    let (response, code) = server
+        .service
+        .post("/indexes", json!({ "uid": "test", "primaryKey": ["store_id", "sku"] }))
+        .await;
+    assert_eq!(code, 202, "{}", response);
+    let index = server.index("test");
+    index.wait_task(0).await;
+
+    let (response, code) = index.get().await;
+    assert_eq!(code, 200);
+    assert_eq!(response["primaryKey"], json!(["store_id", "sku"]));
+
+    let documents = json!([
+        { "store_id": 1, "sku": "AB-3", "price": 10 },
+        { "store_id": 2, "sku": "AB-3", "price": 12 },
+        { "store_id": 1, "sku": "CD", "price": 3 },
+    ]);
+    index.add_documents(documents, None).await;
+    let response = index.wait_task(1).await;
+    assert_eq!(response["status"], "succeeded", "{}", response);
+
+    // with the composite string
+    let (response, code) = server.service.get("/indexes/test/documents/1-AB_-3").await;
+    assert_eq!(code, 200, "{}", response);
+    assert_eq!(response, json!({ "store_id": 1, "sku": "AB-3", "price": 10 }));
+
+    // with the object of the key parts
+    let parts = urlencode(r#"{"store_id":2,"sku":"AB-3"}"#);
+    let (response, code) = server.service.get(format!("/indexes/test/documents/{parts}")).await;
+    assert_eq!(code, 200, "{}", response);
+    assert_eq!(response, json!({ "store_id": 2, "sku": "AB-3", "price": 12 }));
+
+    let parts = urlencode(r#"{"store_id":2}"#);
+    let (response, code) = server.service.get(format!("/indexes/test/documents/{parts}")).await;
+    assert_eq!(code, 400, "{}", response);
+    assert_eq!(response["code"], "missing_document_id");
+
+    let (response, code) = server
+        .service
+        .post(
+            "/indexes/test/documents/delete-batch",
+            json!([{ "store_id": 1, "sku": "CD" }, "2-AB_-3"]),
+        )
+        .await;
+    assert_eq!(code, 202, "{}", response);
+    let response = index.wait_task(2).await;
+    assert_eq!(response["status"], "succeeded", "{}", response);
+    assert_eq!(response["details"]["deletedDocuments"], 2);
+
+    let (response, code) = index.get_all_documents(GetAllDocumentsOptions::default()).await;
+    assert_eq!(code, 200);
+    assert_eq!(response["results"], json!([{ "store_id": 1, "sku": "AB-3", "price": 10 }]));
+}
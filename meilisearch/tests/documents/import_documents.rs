@@ -0,0 +1,192 @@
+use std::fs;
+use std::io::Write;
+
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use meilisearch::Opt;
+use serde_json::json;
+use tempfile::TempDir;
+
+use crate::common::server::default_settings;
+use crate::common::{GetAllDocumentsOptions, Server};
+
+async fn server_with_import_dir() -> (Server, TempDir, TempDir) {
+    let db_dir = TempDir::new().unwrap();
+    let import_dir = TempDir::new().unwrap();
+    let options =
+        Opt { import_dir: Some(import_dir.path().to_owned()), ..default_settings(db_dir.path()) };
+    let server = Server::new_with_options(options).await.unwrap();
+    (server, db_dir, import_dir)
+}
+
+#[actix_rt::test]
+async fn import_documents() {
+    let (server, _db_dir, import_dir) = server_with_import_dir().await;
+    let feed = import_dir.path().join("feed");
+    fs::create_dir(&feed).unwrap();
+
+    fs::write(feed.join("1.ndjson"), "{\"id\": 1, \"name\": \"kefir\"}\n").unwrap();
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(b"{\"id\": 2, \"name\": \"intel\"}\n{\"id\": 3, \"name\": \"echo\"}\n")
+        .unwrap();
+    fs::write(feed.join("2.ndjson.gz"), encoder.finish().unwrap()).unwrap();
+    fs::write(feed.join("3.ndjson"), "{\"id\": 4,").unwrap();
+    fs::write(feed.join("ignored.json"), "[{\"id\": 5}]").unwrap();
+
+    let index = server.index("test");
+    let (response, code) = index
+        .import_documents(
+            json!({ "path": "feed/*.ndjson*", "format": "ndjson", "primaryKey": "id" }),
+        )
+        .await;
+    assert_eq!(code, 202, "{}", response);
+
+    let response = index.wait_task(0).await;
+    assert_eq!(response["status"], "succeeded", "{}", response);
+    assert_eq!(response["type"], "documentAdditionOrUpdate");
+    assert_eq!(response["details"]["receivedDocuments"], 3);
+    assert_eq!(response["details"]["indexedDocuments"], 3);
+
+    let feed = feed.canonicalize().unwrap();
+    let imported_files = response["details"]["importedFiles"].as_array().unwrap();
+    assert_eq!(imported_files.len(), 3, "{}", response);
+    assert_eq!(
+        imported_files[0],
+        json!({ "path": feed.join("1.ndjson").display().to_string(), "receivedDocuments": 1 })
+    );
+    assert_eq!(
+        imported_files[1],
+        json!({ "path": feed.join("2.ndjson.gz").display().to_string(), "receivedDocuments": 2 })
+    );
+    assert_eq!(imported_files[2]["path"], feed.join("3.ndjson").display().to_string());
+    assert!(imported_files[2]["error"].is_string(), "{}", response);
+
+    let (response, code) = index.get_all_documents(GetAllDocumentsOptions::default()).await;
+    assert_eq!(code, 200);
+    assert_eq!(
+        response["results"],
+        json!([
+            { "id": 1, "name": "kefir" },
+            { "id": 2, "name": "intel" },
+            { "id": 3, "name": "echo" },
+        ])
+    );
+
+    // absolute paths are accepted as long as they are inside the import directory
+    let path = feed.join("ignored.json");
+    let (response, code) = index
+        .import_documents(json!({ "path": path.display().to_string(), "format": "json" }))
+        .await;
+    assert_eq!(code, 202, "{}", response);
+
+    let response = index.wait_task(1).await;
+    assert_eq!(response["status"], "succeeded", "{}", response);
+    assert_eq!(response["details"]["receivedDocuments"], 1);
+}
+
+#[actix_rt::test]
+async fn import_csv_documents_with_options() {
+    let (server, _db_dir, import_dir) = server_with_import_dir().await;
+    fs::write(import_dir.path().join("documents.csv"), "id;name\n1;'kefir; the dog'\n2;intel\n")
+        .unwrap();
+
+    let index = server.index("test");
+    let (response, code) = index
+        .import_documents(json!({
+            "path": "documents.csv",
+            "format": "csv",
+            "csvDelimiter": ";",
+            "csvQuote": "'",
+        }))
+        .await;
+    assert_eq!(code, 202, "{}", response);
+
+    let response = index.wait_task(0).await;
+    assert_eq!(response["status"], "succeeded", "{}", response);
+    assert_eq!(response["details"]["receivedDocuments"], 2);
+
+    let (response, code) = index.get_all_documents(GetAllDocumentsOptions::default()).await;
+    assert_eq!(code, 200);
+    assert_eq!(
+        response["results"],
+        json!([
+            { "id": "1", "name": "kefir; the dog" },
+            { "id": "2", "name": "intel" },
+        ])
+    );
+
+    let (response, code) = index
+        .import_documents(
+            json!({ "path": "documents.csv", "format": "ndjson", "csvDelimiter": ";" }),
+        )
+        .await;
+    assert_eq!(code, 400, "{}", response);
+    assert_eq!(response["code"], "invalid_document_csv_delimiter");
+}
+
+#[actix_rt::test]
+async fn error_import_documents_disabled() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) =
+        index.import_documents(json!({ "path": "*.ndjson", "format": "ndjson" })).await;
+    assert_eq!(code, 400, "{}", response);
+    assert_eq!(
+        response,
+        json!({
+            "message": "Importing documents requires the `--import-dir` option to be set.",
+            "code": "document_import_disabled",
+            "type": "invalid_request",
+            "link": "https://docs.meilisearch.com/errors#document-import-disabled"
+        })
+    );
+}
+
+#[actix_rt::test]
+async fn error_import_documents_bad_path() {
+    let (server, _db_dir, import_dir) = server_with_import_dir().await;
+    let index = server.index("test");
+
+    let (response, code) =
+        index.import_documents(json!({ "path": "../*.ndjson", "format": "ndjson" })).await;
+    assert_eq!(code, 400, "{}", response);
+    assert_eq!(
+        response,
+        json!({
+            "message": "The import path `../*.ndjson` is outside of the import directory.",
+            "code": "invalid_document_import_path",
+            "type": "invalid_request",
+            "link": "https://docs.meilisearch.com/errors#invalid-document-import-path"
+        })
+    );
+
+    let (response, code) =
+        index.import_documents(json!({ "path": "/etc/passwd", "format": "csv" })).await;
+    assert_eq!(code, 400, "{}", response);
+    assert_eq!(response["code"], "invalid_document_import_path");
+
+    let (response, code) =
+        index.import_documents(json!({ "path": "*.ndjson", "format": "ndjson" })).await;
+    assert_eq!(code, 400, "{}", response);
+    assert_eq!(
+        response,
+        json!({
+            "message": "No file matches the import path `*.ndjson`.",
+            "code": "invalid_document_import_path",
+            "type": "invalid_request",
+            "link": "https://docs.meilisearch.com/errors#invalid-document-import-path"
+        })
+    );
+
+    fs::write(import_dir.path().join("documents.ndjson"), "{\"id\": 1}\n").unwrap();
+    let (response, code) =
+        index.import_documents(json!({ "path": "documents.ndjson", "format": "xml" })).await;
+    assert_eq!(code, 400, "{}", response);
+    assert_eq!(response["code"], "invalid_document_import_format");
+
+    let (response, code) = index.import_documents(json!({ "format": "ndjson" })).await;
+    assert_eq!(code, 400, "{}", response);
+    assert_eq!(response["code"], "invalid_document_import_path");
+}
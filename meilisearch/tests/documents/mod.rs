@@ -1,4 +1,8 @@
 mod add_documents;
 mod delete_documents;
+mod edit_documents;
+mod export_documents;
 mod get_documents;
+mod import_documents;
+mod purge_documents;
 mod update_documents;
@@ -0,0 +1,69 @@
+use serde_json::json;
+
+use crate::common::Server;
+
+#[actix_rt::test]
+async fn purge_soft_deleted_documents() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    let documents: Vec<_> =
+        (0..100).map(|id| json!({ "id": id, "title": format!("The document {id}") })).collect();
+    index.add_documents(json!(documents), None).await;
+    index.wait_task(0).await;
+    index.delete_batch((0..10).collect()).await;
+    index.wait_task(1).await;
+
+    let (response, code) = index.purge_documents().await;
+    assert_eq!(code, 202, "{response}");
+    let response = index.wait_task(2).await;
+    assert_eq!(response["status"], "succeeded", "{response}");
+    assert_eq!(response["type"], "documentPurge");
+    assert_eq!(response["indexUid"], "test");
+    assert_eq!(response["details"], json!({ "purgedDocuments": 10 }));
+
+    // The remaining documents are left untouched.
+    let (response, code) = index.search_post(json!({ "q": "document" })).await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["estimatedTotalHits"], 90);
+
+    // Nothing is left to purge.
+    index.purge_documents().await;
+    let response = index.wait_task(3).await;
+    assert_eq!(response["status"], "succeeded", "{response}");
+    assert_eq!(response["details"], json!({ "purgedDocuments": 0 }));
+}
+
+#[actix_rt::test]
+async fn error_purge_unexisting_index() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) = index.purge_documents().await;
+    assert_eq!(code, 202, "{response}");
+    let response = index.wait_task(0).await;
+    assert_eq!(response["status"], "failed", "{response}");
+    assert_eq!(response["error"]["code"], "index_not_found");
+    assert_eq!(response["details"], json!({ "purgedDocuments": 0 }));
+}
+
+#[actix_rt::test]
+async fn error_invalid_soft_deleted_purge_threshold() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) =
+        index.update_settings(json!({ "softDeletedPurgeThreshold": "half" })).await;
+    assert_eq!(code, 400, "{response}");
+    assert_eq!(response["code"], "invalid_settings_soft_deleted_purge_threshold");
+
+    index.update_settings(json!({ "softDeletedPurgeThreshold": 101 })).await;
+    let response = index.wait_task(0).await;
+    assert_eq!(response["status"], "failed", "{response}");
+    assert_eq!(response["error"]["code"], "invalid_settings_soft_deleted_purge_threshold");
+
+    index.update_settings(json!({ "softDeletedPurgeThreshold": 20 })).await;
+    let response = index.wait_task(1).await;
+    assert_eq!(response["status"], "succeeded", "{response}");
+    let (response, _) = index.settings().await;
+    assert_eq!(response["softDeletedPurgeThreshold"], 20);
+}
@@ -196,3 +196,68 @@ async fn error_update_documents_missing_document_id() {
         "https://docs.meilisearch.com/errors#missing-document-id"
     );
 }
+
+#[actix_rt::test]
+async fn deep_merge_documents() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let documents = json!([
+        {
+            "id": 1,
+            "address": { "city": "Paris", "street": "rivoli", "zip": 75001 },
+            "tags": ["a", "b"],
+        }
+    ]);
+    let (_response, code) = index.add_documents(documents, Some("id")).await;
+    assert_eq!(code, 202);
+    index.wait_task(0).await;
+
+    // nested objects are merged and `null` removes a key
+    let documents = json!([
+        {
+            "id": 1,
+            "address": { "city": "Lyon", "zip": null },
+            "tags": ["c"],
+        }
+    ]);
+    let (response, code) = index.deep_merge_documents(documents, None, false).await;
+    assert_eq!(code, 202, "response: {}", response);
+
+    let response = index.wait_task(1).await;
+    assert_eq!(response["status"], "succeeded", "{}", response);
+    assert_eq!(response["type"], "documentAdditionOrUpdate");
+
+    let (response, code) = index.get_document(1, None).await;
+    assert_eq!(code, 200);
+    assert_eq!(
+        response,
+        json!({ "id": 1, "address": { "city": "Lyon", "street": "rivoli" }, "tags": ["c"] })
+    );
+
+    // arrays are appended on demand
+    let documents = json!([{ "id": 1, "tags": ["d"] }]);
+    let (response, code) = index.deep_merge_documents(documents, None, true).await;
+    assert_eq!(code, 202, "response: {}", response);
+    index.wait_task(2).await;
+
+    let (response, code) = index.get_document(1, None).await;
+    assert_eq!(code, 200);
+    assert_eq!(
+        response,
+        json!({ "id": 1, "address": { "city": "Lyon", "street": "rivoli" }, "tags": ["c", "d"] })
+    );
+}
+
+#[actix_rt::test]
+async fn error_deep_merge_documents_bad_append_arrays() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) = index
+        .service
+        .patch("/indexes/test/documents?appendArrays=maybe", json!([{ "id": 1 }]))
+        .await;
+    assert_eq!(code, 400, "response: {}", response);
+    assert_eq!(response["code"], "invalid_document_append_arrays");
+}
@@ -35,7 +35,7 @@ async fn import_dump_v1_movie_raw() {
     assert_eq!(code, 200);
     assert_eq!(
         settings,
-        json!({"displayedAttributes": ["*"], "searchableAttributes": ["*"], "filterableAttributes": [], "sortableAttributes": [], "rankingRules": ["typo", "words", "proximity", "attribute", "exactness"], "stopWords": [], "synonyms": {}, "distinctAttribute": null, "typoTolerance": {"enabled": true, "minWordSizeForTypos": {"oneTypo": 5, "twoTypos": 9}, "disableOnWords": [], "disableOnAttributes": [] }, "faceting": { "maxValuesPerFacet": 100 }, "pagination": { "maxTotalHits": 1000 } })
+        json!({"displayedAttributes": ["*"], "searchableAttributes": ["*"], "filterableAttributes": [], "sortableAttributes": [], "rankingRules": ["typo", "words", "proximity", "attribute", "exactness"], "stopWords": [], "synonyms": {}, "distinctAttribute": null, "typoTolerance": {"enabled": true, "minWordSizeForTypos": {"oneTypo": 5, "twoTypos": 9}, "disableOnWords": [], "disableOnAttributes": [] }, "faceting": { "maxValuesPerFacet": 100 }, "pagination": { "maxTotalHits": 1000 }, "ttlAttribute": null, "defaultTtl": null, "primaryKeyGeneration": null, "schema": null, "softDeletedPurgeThreshold": null })
     );
 
     let (tasks, code) = index.list_tasks().await;
@@ -98,7 +98,7 @@ async fn import_dump_v1_movie_with_settings() {
     assert_eq!(code, 200);
     assert_eq!(
         settings,
-        json!({ "displayedAttributes": ["genres", "id", "overview", "poster", "release_date", "title"], "searchableAttributes": ["title", "overview"], "filterableAttributes": ["genres"], "sortableAttributes": [], "rankingRules": ["typo", "words", "proximity", "attribute", "exactness"], "stopWords": ["of", "the"], "synonyms": {}, "distinctAttribute": null, "typoTolerance": {"enabled": true, "minWordSizeForTypos": { "oneTypo": 5, "twoTypos": 9 }, "disableOnWords": [], "disableOnAttributes": [] }, "faceting": { "maxValuesPerFacet": 100 }, "pagination": { "maxTotalHits": 1000 } })
+        json!({ "displayedAttributes": ["genres", "id", "overview", "poster", "release_date", "title"], "searchableAttributes": ["title", "overview"], "filterableAttributes": ["genres"], "sortableAttributes": [], "rankingRules": ["typo", "words", "proximity", "attribute", "exactness"], "stopWords": ["of", "the"], "synonyms": {}, "distinctAttribute": null, "typoTolerance": {"enabled": true, "minWordSizeForTypos": { "oneTypo": 5, "twoTypos": 9 }, "disableOnWords": [], "disableOnAttributes": [] }, "faceting": { "maxValuesPerFacet": 100 }, "pagination": { "maxTotalHits": 1000 }, "ttlAttribute": null, "defaultTtl": null, "primaryKeyGeneration": null, "schema": null, "softDeletedPurgeThreshold": null })
     );
 
     let (tasks, code) = index.list_tasks().await;
@@ -161,7 +161,7 @@ async fn import_dump_v1_rubygems_with_settings() {
     assert_eq!(code, 200);
     assert_eq!(
         settings,
-        json!({"displayedAttributes": ["description", "id", "name", "summary", "total_downloads", "version"], "searchableAttributes": ["name", "summary"], "filterableAttributes": ["version"], "sortableAttributes": [], "rankingRules": ["typo", "words", "fame:desc", "proximity", "attribute", "exactness", "total_downloads:desc"], "stopWords": [], "synonyms": {}, "distinctAttribute": null, "typoTolerance": {"enabled": true, "minWordSizeForTypos": {"oneTypo": 5, "twoTypos": 9}, "disableOnWords": [], "disableOnAttributes": [] }, "faceting": { "maxValuesPerFacet": 100 }, "pagination": { "maxTotalHits": 1000 }})
+        json!({"displayedAttributes": ["description", "id", "name", "summary", "total_downloads", "version"], "searchableAttributes": ["name", "summary"], "filterableAttributes": ["version"], "sortableAttributes": [], "rankingRules": ["typo", "words", "fame:desc", "proximity", "attribute", "exactness", "total_downloads:desc"], "stopWords": [], "synonyms": {}, "distinctAttribute": null, "typoTolerance": {"enabled": true, "minWordSizeForTypos": {"oneTypo": 5, "twoTypos": 9}, "disableOnWords": [], "disableOnAttributes": [] }, "faceting": { "maxValuesPerFacet": 100 }, "pagination": { "maxTotalHits": 1000 }, "ttlAttribute": null, "defaultTtl": null, "primaryKeyGeneration": null, "schema": null, "softDeletedPurgeThreshold": null})
     );
 
     let (tasks, code) = index.list_tasks().await;
@@ -222,7 +222,7 @@ async fn import_dump_v2_movie_raw() {
     assert_eq!(code, 200);
     assert_eq!(
         settings,
-        json!({"displayedAttributes": ["*"], "searchableAttributes": ["*"], "filterableAttributes": [], "sortableAttributes": [], "rankingRules": ["words", "typo", "proximity", "attribute", "exactness"], "stopWords": [], "synonyms": {}, "distinctAttribute": null, "typoTolerance": {"enabled": true, "minWordSizeForTypos": {"oneTypo": 5, "twoTypos": 9}, "disableOnWords": [], "disableOnAttributes": [] }, "faceting": { "maxValuesPerFacet": 100 }, "pagination": { "maxTotalHits": 1000 } })
+        json!({"displayedAttributes": ["*"], "searchableAttributes": ["*"], "filterableAttributes": [], "sortableAttributes": [], "rankingRules": ["words", "typo", "proximity", "attribute", "exactness"], "stopWords": [], "synonyms": {}, "distinctAttribute": null, "typoTolerance": {"enabled": true, "minWordSizeForTypos": {"oneTypo": 5, "twoTypos": 9}, "disableOnWords": [], "disableOnAttributes": [] }, "faceting": { "maxValuesPerFacet": 100 }, "pagination": { "maxTotalHits": 1000 }, "ttlAttribute": null, "defaultTtl": null, "primaryKeyGeneration": null, "schema": null, "softDeletedPurgeThreshold": null })
     );
 
     let (tasks, code) = index.list_tasks().await;
@@ -285,7 +285,7 @@ async fn import_dump_v2_movie_with_settings() {
     assert_eq!(code, 200);
     assert_eq!(
         settings,
-        json!({ "displayedAttributes": ["title", "genres", "overview", "poster", "release_date"], "searchableAttributes": ["title", "overview"], "filterableAttributes": ["genres"], "sortableAttributes": [], "rankingRules": ["words", "typo", "proximity", "attribute", "exactness"], "stopWords": ["of", "the"], "synonyms": {}, "distinctAttribute": null, "typoTolerance": {"enabled": true, "minWordSizeForTypos": { "oneTypo": 5, "twoTypos": 9 }, "disableOnWords": [], "disableOnAttributes": [] }, "faceting": { "maxValuesPerFacet": 100 }, "pagination": { "maxTotalHits": 1000 } })
+        json!({ "displayedAttributes": ["title", "genres", "overview", "poster", "release_date"], "searchableAttributes": ["title", "overview"], "filterableAttributes": ["genres"], "sortableAttributes": [], "rankingRules": ["words", "typo", "proximity", "attribute", "exactness"], "stopWords": ["of", "the"], "synonyms": {}, "distinctAttribute": null, "typoTolerance": {"enabled": true, "minWordSizeForTypos": { "oneTypo": 5, "twoTypos": 9 }, "disableOnWords": [], "disableOnAttributes": [] }, "faceting": { "maxValuesPerFacet": 100 }, "pagination": { "maxTotalHits": 1000 }, "ttlAttribute": null, "defaultTtl": null, "primaryKeyGeneration": null, "schema": null, "softDeletedPurgeThreshold": null })
     );
 
     let (tasks, code) = index.list_tasks().await;
@@ -348,7 +348,7 @@ async fn import_dump_v2_rubygems_with_settings() {
     assert_eq!(code, 200);
     assert_eq!(
         settings,
-        json!({"displayedAttributes": ["name", "summary", "description", "version", "total_downloads"], "searchableAttributes": ["name", "summary"], "filterableAttributes": ["version"], "sortableAttributes": [], "rankingRules": ["typo", "words", "fame:desc", "proximity", "attribute", "exactness", "total_downloads:desc"], "stopWords": [], "synonyms": {}, "distinctAttribute": null, "typoTolerance": {"enabled": true, "minWordSizeForTypos": {"oneTypo": 5, "twoTypos": 9}, "disableOnWords": [], "disableOnAttributes": [] }, "faceting": { "maxValuesPerFacet": 100 }, "pagination": { "maxTotalHits": 1000 }})
+        json!({"displayedAttributes": ["name", "summary", "description", "version", "total_downloads"], "searchableAttributes": ["name", "summary"], "filterableAttributes": ["version"], "sortableAttributes": [], "rankingRules": ["typo", "words", "fame:desc", "proximity", "attribute", "exactness", "total_downloads:desc"], "stopWords": [], "synonyms": {}, "distinctAttribute": null, "typoTolerance": {"enabled": true, "minWordSizeForTypos": {"oneTypo": 5, "twoTypos": 9}, "disableOnWords": [], "disableOnAttributes": [] }, "faceting": { "maxValuesPerFacet": 100 }, "pagination": { "maxTotalHits": 1000 }, "ttlAttribute": null, "defaultTtl": null, "primaryKeyGeneration": null, "schema": null, "softDeletedPurgeThreshold": null})
     );
 
     let (tasks, code) = index.list_tasks().await;
@@ -409,7 +409,7 @@ async fn import_dump_v3_movie_raw() {
     assert_eq!(code, 200);
     assert_eq!(
         settings,
-        json!({"displayedAttributes": ["*"], "searchableAttributes": ["*"], "filterableAttributes": [], "sortableAttributes": [], "rankingRules": ["words", "typo", "proximity", "attribute", "exactness"], "stopWords": [], "synonyms": {}, "distinctAttribute": null, "typoTolerance": {"enabled": true, "minWordSizeForTypos": {"oneTypo": 5, "twoTypos": 9}, "disableOnWords": [], "disableOnAttributes": [] }, "faceting": { "maxValuesPerFacet": 100 }, "pagination": { "maxTotalHits": 1000 } })
+        json!({"displayedAttributes": ["*"], "searchableAttributes": ["*"], "filterableAttributes": [], "sortableAttributes": [], "rankingRules": ["words", "typo", "proximity", "attribute", "exactness"], "stopWords": [], "synonyms": {}, "distinctAttribute": null, "typoTolerance": {"enabled": true, "minWordSizeForTypos": {"oneTypo": 5, "twoTypos": 9}, "disableOnWords": [], "disableOnAttributes": [] }, "faceting": { "maxValuesPerFacet": 100 }, "pagination": { "maxTotalHits": 1000 }, "ttlAttribute": null, "defaultTtl": null, "primaryKeyGeneration": null, "schema": null, "softDeletedPurgeThreshold": null })
     );
 
     let (tasks, code) = index.list_tasks().await;
@@ -472,7 +472,7 @@ async fn import_dump_v3_movie_with_settings() {
     assert_eq!(code, 200);
     assert_eq!(
         settings,
-        json!({ "displayedAttributes": ["title", "genres", "overview", "poster", "release_date"], "searchableAttributes": ["title", "overview"], "filterableAttributes": ["genres"], "sortableAttributes": [], "rankingRules": ["words", "typo", "proximity", "attribute", "exactness"], "stopWords": ["of", "the"], "synonyms": {}, "distinctAttribute": null, "typoTolerance": {"enabled": true, "minWordSizeForTypos": { "oneTypo": 5, "twoTypos": 9 }, "disableOnWords": [], "disableOnAttributes": [] }, "faceting": { "maxValuesPerFacet": 100 }, "pagination": { "maxTotalHits": 1000 } })
+        json!({ "displayedAttributes": ["title", "genres", "overview", "poster", "release_date"], "searchableAttributes": ["title", "overview"], "filterableAttributes": ["genres"], "sortableAttributes": [], "rankingRules": ["words", "typo", "proximity", "attribute", "exactness"], "stopWords": ["of", "the"], "synonyms": {}, "distinctAttribute": null, "typoTolerance": {"enabled": true, "minWordSizeForTypos": { "oneTypo": 5, "twoTypos": 9 }, "disableOnWords": [], "disableOnAttributes": [] }, "faceting": { "maxValuesPerFacet": 100 }, "pagination": { "maxTotalHits": 1000 }, "ttlAttribute": null, "defaultTtl": null, "primaryKeyGeneration": null, "schema": null, "softDeletedPurgeThreshold": null })
     );
 
     let (tasks, code) = index.list_tasks().await;
@@ -535,7 +535,7 @@ async fn import_dump_v3_rubygems_with_settings() {
     assert_eq!(code, 200);
     assert_eq!(
         settings,
-        json!({"displayedAttributes": ["name", "summary", "description", "version", "total_downloads"], "searchableAttributes": ["name", "summary"], "filterableAttributes": ["version"], "sortableAttributes": [], "rankingRules": ["typo", "words", "fame:desc", "proximity", "attribute", "exactness", "total_downloads:desc"], "stopWords": [], "synonyms": {}, "distinctAttribute": null, "typoTolerance": {"enabled": true, "minWordSizeForTypos": {"oneTypo": 5, "twoTypos": 9}, "disableOnWords": [], "disableOnAttributes": [] }, "faceting": { "maxValuesPerFacet": 100 }, "pagination": { "maxTotalHits": 1000 } })
+        json!({"displayedAttributes": ["name", "summary", "description", "version", "total_downloads"], "searchableAttributes": ["name", "summary"], "filterableAttributes": ["version"], "sortableAttributes": [], "rankingRules": ["typo", "words", "fame:desc", "proximity", "attribute", "exactness", "total_downloads:desc"], "stopWords": [], "synonyms": {}, "distinctAttribute": null, "typoTolerance": {"enabled": true, "minWordSizeForTypos": {"oneTypo": 5, "twoTypos": 9}, "disableOnWords": [], "disableOnAttributes": [] }, "faceting": { "maxValuesPerFacet": 100 }, "pagination": { "maxTotalHits": 1000 }, "ttlAttribute": null, "defaultTtl": null, "primaryKeyGeneration": null, "schema": null, "softDeletedPurgeThreshold": null })
     );
 
     let (tasks, code) = index.list_tasks().await;
@@ -596,7 +596,7 @@ async fn import_dump_v4_movie_raw() {
     assert_eq!(code, 200);
     assert_eq!(
         settings,
-        json!({ "displayedAttributes": ["*"], "searchableAttributes": ["*"], "filterableAttributes": [], "sortableAttributes": [], "rankingRules": ["words", "typo", "proximity", "attribute", "exactness"], "stopWords": [], "synonyms": {}, "distinctAttribute": null, "typoTolerance": {"enabled": true, "minWordSizeForTypos": {"oneTypo": 5, "twoTypos": 9}, "disableOnWords": [], "disableOnAttributes": [] }, "faceting": { "maxValuesPerFacet": 100 }, "pagination": { "maxTotalHits": 1000 } })
+        json!({ "displayedAttributes": ["*"], "searchableAttributes": ["*"], "filterableAttributes": [], "sortableAttributes": [], "rankingRules": ["words", "typo", "proximity", "attribute", "exactness"], "stopWords": [], "synonyms": {}, "distinctAttribute": null, "typoTolerance": {"enabled": true, "minWordSizeForTypos": {"oneTypo": 5, "twoTypos": 9}, "disableOnWords": [], "disableOnAttributes": [] }, "faceting": { "maxValuesPerFacet": 100 }, "pagination": { "maxTotalHits": 1000 }, "ttlAttribute": null, "defaultTtl": null, "primaryKeyGeneration": null, "schema": null, "softDeletedPurgeThreshold": null })
     );
 
     let (tasks, code) = index.list_tasks().await;
@@ -659,7 +659,7 @@ async fn import_dump_v4_movie_with_settings() {
     assert_eq!(code, 200);
     assert_eq!(
         settings,
-        json!({ "displayedAttributes": ["title", "genres", "overview", "poster", "release_date"], "searchableAttributes": ["title", "overview"], "filterableAttributes": ["genres"], "sortableAttributes": [], "rankingRules": ["words", "typo", "proximity", "attribute", "exactness"], "stopWords": ["of", "the"], "synonyms": {}, "distinctAttribute": null, "typoTolerance": {"enabled": true, "minWordSizeForTypos": { "oneTypo": 5, "twoTypos": 9 }, "disableOnWords": [], "disableOnAttributes": [] }, "faceting": { "maxValuesPerFacet": 100 }, "pagination": { "maxTotalHits": 1000 } })
+        json!({ "displayedAttributes": ["title", "genres", "overview", "poster", "release_date"], "searchableAttributes": ["title", "overview"], "filterableAttributes": ["genres"], "sortableAttributes": [], "rankingRules": ["words", "typo", "proximity", "attribute", "exactness"], "stopWords": ["of", "the"], "synonyms": {}, "distinctAttribute": null, "typoTolerance": {"enabled": true, "minWordSizeForTypos": { "oneTypo": 5, "twoTypos": 9 }, "disableOnWords": [], "disableOnAttributes": [] }, "faceting": { "maxValuesPerFacet": 100 }, "pagination": { "maxTotalHits": 1000 }, "ttlAttribute": null, "defaultTtl": null, "primaryKeyGeneration": null, "schema": null, "softDeletedPurgeThreshold": null })
     );
 
     let (tasks, code) = index.list_tasks().await;
@@ -722,7 +722,7 @@ async fn import_dump_v4_rubygems_with_settings() {
     assert_eq!(code, 200);
     assert_eq!(
         settings,
-        json!({ "displayedAttributes": ["name", "summary", "description", "version", "total_downloads"], "searchableAttributes": ["name", "summary"], "filterableAttributes": ["version"], "sortableAttributes": [], "rankingRules": ["typo", "words", "fame:desc", "proximity", "attribute", "exactness", "total_downloads:desc"], "stopWords": [], "synonyms": {}, "distinctAttribute": null, "typoTolerance": {"enabled": true, "minWordSizeForTypos": {"oneTypo": 5, "twoTypos": 9}, "disableOnWords": [], "disableOnAttributes": [] }, "faceting": { "maxValuesPerFacet": 100 }, "pagination": { "maxTotalHits": 1000 } })
+        json!({ "displayedAttributes": ["name", "summary", "description", "version", "total_downloads"], "searchableAttributes": ["name", "summary"], "filterableAttributes": ["version"], "sortableAttributes": [], "rankingRules": ["typo", "words", "fame:desc", "proximity", "attribute", "exactness", "total_downloads:desc"], "stopWords": [], "synonyms": {}, "distinctAttribute": null, "typoTolerance": {"enabled": true, "minWordSizeForTypos": {"oneTypo": 5, "twoTypos": 9}, "disableOnWords": [], "disableOnAttributes": [] }, "faceting": { "maxValuesPerFacet": 100 }, "pagination": { "maxTotalHits": 1000 }, "ttlAttribute": null, "defaultTtl": null, "primaryKeyGeneration": null, "schema": null, "softDeletedPurgeThreshold": null })
     );
 
     let (tasks, code) = index.list_tasks().await;
@@ -817,3 +817,132 @@ async fn import_dump_v5() {
 
     assert_eq!(key["name"], "my key");
 }
+
+#[actix_rt::test]
+#[cfg_attr(target_os = "windows", ignore)]
+async fn dump_and_import_aliases() {
+    let temp = tempfile::tempdir().unwrap();
+    let server = Server::new_with_options(default_settings(temp.path())).await.unwrap();
+    let index = server.index("movies_v1");
+    index.add_documents(json!([{ "id": 1, "title": "Carol" }]), None).await;
+    index.wait_task(0).await;
+    server.update_alias("movies", json!({ "indexes": ["movies_v1"] })).await;
+    server.wait_task(1).await;
+
+    let (task, code) = server.create_dump().await;
+    assert_eq!(code, 202, "{task}");
+    let task = server.wait_task(task["taskUid"].as_u64().unwrap()).await;
+    assert_eq!(task["status"], "succeeded", "{task}");
+    let dump_uid = task["details"]["dumpUid"].as_str().unwrap();
+    let dump_path = temp.path().join("dumps").join(format!("{dump_uid}.dump"));
+
+    let imported = tempfile::tempdir().unwrap();
+    let options = Opt { import_dump: Some(dump_path), ..default_settings(imported.path()) };
+    let server = Server::new_with_options(options).await.unwrap();
+    let (response, code) = server.get_alias("movies").await;
+    assert_eq!(code, 200, "{response}");
+    let (document, code) = server.index("movies").get_document(1, None).await;
+    assert_eq!(code, 200, "{document}");
+    assert_eq!(document["title"], "Carol");
+}
+
+#[actix_rt::test]
+#[cfg_attr(target_os = "windows", ignore)]
+async fn dump_and_import_quota_and_read_only() {
+    let temp = tempfile::tempdir().unwrap();
+    let server = Server::new_with_options(default_settings(temp.path())).await.unwrap();
+    let index = server.index("test");
+    index.add_documents(json!([{ "id": 1 }]), None).await;
+    index.wait_task(0).await;
+    index.update_quota(json!({ "maxDocuments": 10 })).await;
+    index.update_read_only(true).await;
+    index.wait_task(1).await;
+
+    let (task, code) = server.create_dump().await;
+    assert_eq!(code, 202, "{task}");
+    let task = server.wait_task(task["taskUid"].as_u64().unwrap()).await;
+    assert_eq!(task["status"], "succeeded", "{task}");
+    let dump_uid = task["details"]["dumpUid"].as_str().unwrap();
+    let dump_path = temp.path().join("dumps").join(format!("{dump_uid}.dump"));
+
+    let imported = tempfile::tempdir().unwrap();
+    let options = Opt { import_dump: Some(dump_path), ..default_settings(imported.path()) };
+    let server = Server::new_with_options(options).await.unwrap();
+    let index = server.index("test");
+    let (response, code) = index.quota().await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response, json!({ "maxDocuments": 10, "maxSizeBytes": null }));
+    let (response, code) = index.get().await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["readOnly"], true);
+}
+
+#[actix_rt::test]
+#[cfg_attr(target_os = "windows", ignore)]
+async fn dump_and_import_settings_history() {
+    let temp = tempfile::tempdir().unwrap();
+    let server = Server::new_with_options(default_settings(temp.path())).await.unwrap();
+    let index = server.index("test");
+    index.update_settings(json!({ "stopWords": ["the"] })).await;
+    index.wait_task(0).await;
+    index.update_settings(json!({ "stopWords": ["a"] })).await;
+    index.wait_task(1).await;
+    let (history, _) = index.settings_history().await;
+    assert_eq!(history["total"], 3, "{history}");
+
+    let (task, code) = server.create_dump().await;
+    assert_eq!(code, 202, "{task}");
+    let task = server.wait_task(task["taskUid"].as_u64().unwrap()).await;
+    assert_eq!(task["status"], "succeeded", "{task}");
+    let dump_uid = task["details"]["dumpUid"].as_str().unwrap();
+    let dump_path = temp.path().join("dumps").join(format!("{dump_uid}.dump"));
+
+    let imported = tempfile::tempdir().unwrap();
+    let options = Opt { import_dump: Some(dump_path), ..default_settings(imported.path()) };
+    let server = Server::new_with_options(options).await.unwrap();
+    let index = server.index("test");
+    let (response, code) = index.settings_history().await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response, history);
+
+    // The versions recorded after the import follow the imported ones.
+    let (task, _) = index.update_settings(json!({ "stopWords": ["an"] })).await;
+    index.wait_task(task["taskUid"].as_u64().unwrap()).await;
+    let (response, _) = index.settings_history().await;
+    assert_eq!(response["total"], 4, "{response}");
+    assert_eq!(response["results"][3]["version"], 4);
+
+    let (response, code) = index.rollback_settings(2).await;
+    assert_eq!(code, 202, "{response}");
+    let response = index.wait_task(response["taskUid"].as_u64().unwrap()).await;
+    assert_eq!(response["status"], "succeeded", "{response}");
+    let (response, _) = index.settings().await;
+    assert_eq!(response["stopWords"], json!(["the"]));
+}
+
+#[actix_rt::test]
+#[cfg_attr(target_os = "windows", ignore)]
+async fn dump_and_import_versioned_documents() {
+    let temp = tempfile::tempdir().unwrap();
+    let server = Server::new_with_options(default_settings(temp.path())).await.unwrap();
+    let index = server.index("test");
+    index.add_documents(json!([{ "id": 1, "content": "foo", "_version": 0 }]), None).await;
+    index.wait_task(0).await;
+    index.add_documents(json!([{ "id": 1, "content": "bar", "_version": 1 }]), None).await;
+    index.wait_task(1).await;
+
+    let (task, code) = server.create_dump().await;
+    assert_eq!(code, 202, "{task}");
+    let task = server.wait_task(task["taskUid"].as_u64().unwrap()).await;
+    assert_eq!(task["status"], "succeeded", "{task}");
+    let dump_uid = task["details"]["dumpUid"].as_str().unwrap();
+    let dump_path = temp.path().join("dumps").join(format!("{dump_uid}.dump"));
+
+    // The documents are restored with their version instead of being rejected as conflicts.
+    let imported = tempfile::tempdir().unwrap();
+    let options = Opt { import_dump: Some(dump_path), ..default_settings(imported.path()) };
+    let server = Server::new_with_options(options).await.unwrap();
+    let (document, code) = server.index("test").get_document(1, None).await;
+    assert_eq!(code, 200, "{document}");
+    assert_eq!(document, json!({ "id": 1, "content": "bar", "_version": 2 }));
+}
@@ -0,0 +1,75 @@
+use serde_json::json;
+
+use crate::common::Server;
+
+#[actix_rt::test]
+async fn clone_index_with_settings_and_documents() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    index.update_settings(json!({ "filterableAttributes": ["color"] })).await;
+    index
+        .add_documents(json!([{ "id": 1, "color": "blue" }, { "id": 2, "color": "red" }]), None)
+        .await;
+    index.wait_task(1).await;
+
+    let (response, code) = index.clone_index("test_copy").await;
+    assert_eq!(code, 202, "{response}");
+    let response = index.wait_task(2).await;
+    assert_eq!(response["status"], "succeeded", "{response}");
+    assert_eq!(response["type"], "indexClone");
+    assert_eq!(response["indexUid"], "test");
+    assert_eq!(response["details"], json!({ "targetUid": "test_copy", "clonedDocuments": 2 }));
+
+    let copy = server.index("test_copy");
+    let (response, code) = copy.get().await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["primaryKey"], "id");
+
+    let (response, _) = copy.settings().await;
+    assert_eq!(response["filterableAttributes"], json!(["color"]));
+
+    let (response, code) = copy.search_post(json!({ "filter": "color = red" })).await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["hits"], json!([{ "id": 2, "color": "red" }]));
+
+    // The copy is independent from the original index.
+    copy.add_documents(json!([{ "id": 3, "color": "green" }]), None).await;
+    copy.wait_task(3).await;
+    let (response, _) = index.stats().await;
+    assert_eq!(response["numberOfDocuments"], 2);
+    let (response, _) = copy.stats().await;
+    assert_eq!(response["numberOfDocuments"], 3);
+}
+
+#[actix_rt::test]
+async fn error_clone_into_existing_index() {
+    let server = Server::new().await;
+    server.index("test").create(None).await;
+    server.index("other").create(None).await;
+    server.wait_task(1).await;
+
+    let (_, code) = server.index("test").clone_index("other").await;
+    assert_eq!(code, 202);
+    let response = server.wait_task(2).await;
+    assert_eq!(response["status"], "failed");
+    assert_eq!(
+        response["error"],
+        json!({
+            "message": "Index `other` already exists.",
+            "code": "index_already_exists",
+            "type": "invalid_request",
+            "link": "https://docs.meilisearch.com/errors#index-already-exists"
+        })
+    );
+}
+
+#[actix_rt::test]
+async fn error_clone_unexisting_index() {
+    let server = Server::new().await;
+
+    let (_, code) = server.index("test").clone_index("test_copy").await;
+    assert_eq!(code, 202);
+    let response = server.wait_task(0).await;
+    assert_eq!(response["status"], "failed");
+    assert_eq!(response["error"]["code"], "index_not_found");
+}
@@ -0,0 +1,53 @@
+use serde_json::json;
+
+use crate::common::Server;
+
+#[actix_rt::test]
+async fn compact_index_after_deletions() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    let documents: Vec<_> =
+        (0..1000).map(|id| json!({ "id": id, "title": format!("The document {id}") })).collect();
+    index.add_documents(json!(documents), None).await;
+    index.wait_task(0).await;
+    index.update_quota(json!({ "maxDocuments": 1000 })).await;
+    index.delete_batch((0..990).collect()).await;
+    index.wait_task(1).await;
+
+    let (response, code) = index.compact().await;
+    assert_eq!(code, 202, "{response}");
+    let response = index.wait_task(2).await;
+    assert_eq!(response["status"], "succeeded", "{response}");
+    assert_eq!(response["type"], "indexCompaction");
+    assert_eq!(response["indexUid"], "test");
+    let pre_compaction_size = response["details"]["preCompactionSize"].as_u64().unwrap();
+    let post_compaction_size = response["details"]["postCompactionSize"].as_u64().unwrap();
+    assert!(post_compaction_size <= pre_compaction_size, "{response}");
+
+    // The compacted index keeps its documents, settings and quota.
+    let (response, code) = index.search_post(json!({ "q": "document" })).await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["estimatedTotalHits"], 10);
+    let (response, _) = index.quota().await;
+    assert_eq!(response["maxDocuments"], 1000);
+
+    index.add_documents(json!([{ "id": 1000, "title": "Carol" }]), None).await;
+    let response = index.wait_task(3).await;
+    assert_eq!(response["status"], "succeeded", "{response}");
+}
+
+#[actix_rt::test]
+async fn error_compact_unexisting_index() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) = index.compact().await;
+    assert_eq!(code, 202, "{response}");
+    let response = index.wait_task(0).await;
+    assert_eq!(response["status"], "failed", "{response}");
+    assert_eq!(response["error"]["code"], "index_not_found");
+    assert_eq!(
+        response["details"],
+        json!({ "preCompactionSize": null, "postCompactionSize": null })
+    );
+}
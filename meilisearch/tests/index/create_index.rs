@@ -198,3 +198,79 @@ async fn error_create_with_invalid_index_uid() {
     assert_eq!(response, expected_response);
     assert_eq!(code, 400);
 }
+
+#[actix_rt::test]
+async fn create_index_with_composite_primary_key() {
+    let server = Server::new().await;
+    let (_response, code) = server
+        .service
+        .post("/indexes", json!({ "uid": "test", "primaryKey": ["store_id", "sku"] }))
+        .await;
+    assert_eq!(code, 202);
+
+    let index = server.index("test");
+    let response = index.wait_task(0).await;
+    assert_eq!(response["status"], "succeeded");
+    assert_eq!(response["details"]["primaryKey"], json!(["store_id", "sku"]));
+
+    let (response, code) = index.get().await;
+    assert_eq!(code, 200);
+    assert_eq!(response["primaryKey"], json!(["store_id", "sku"]));
+}
+
+#[actix_rt::test]
+async fn create_index_with_a_primary_key_containing_a_comma() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    let (_response, code) = index.create(Some("store,id")).await;
+    assert_eq!(code, 202);
+    index.wait_task(0).await;
+
+    let (response, code) = index.get().await;
+    assert_eq!(code, 200);
+    assert_eq!(response["primaryKey"], json!("store,id"));
+
+    let documents = json!([{ "store,id": 1, "store": 2, "id": 3 }]);
+    index.add_documents(documents, None).await;
+    let response = index.wait_task(1).await;
+    assert_eq!(response["status"], "succeeded", "{}", response);
+
+    let (_response, code) = index.get_document(1, None).await;
+    assert_eq!(code, 200);
+}
+
+#[actix_rt::test]
+async fn error_create_with_nested_composite_primary_key() {
+    let server = Server::new().await;
+    let (_response, code) = server
+        .service
+        .post("/indexes", json!({ "uid": "test", "primaryKey": ["store.id", "sku"] }))
+        .await;
+    assert_eq!(code, 202);
+
+    let response = server.index("test").wait_task(0).await;
+    assert_eq!(response["status"], "failed");
+    assert_eq!(response["error"]["code"], "invalid_index_primary_key");
+}
+
+#[actix_rt::test]
+async fn error_create_with_invalid_composite_primary_key() {
+    let server = Server::new().await;
+    let (response, code) =
+        server.service.post("/indexes", json!({ "uid": "test", "primaryKey": ["a", 1] })).await;
+
+    let expected_response = json!({
+        "message": "`primaryKey` must be a string or a non-empty array of strings, but found `[\"a\",1]`.",
+        "code": "invalid_index_primary_key",
+        "type": "invalid_request",
+        "link": "https://docs.meilisearch.com/errors#invalid-index-primary-key"
+    });
+
+    assert_eq!(response, expected_response);
+    assert_eq!(code, 400);
+
+    let (response, code) =
+        server.service.post("/indexes", json!({ "uid": "test", "primaryKey": [] })).await;
+    assert_eq!(code, 400, "{}", response);
+    assert_eq!(response["code"], "invalid_index_primary_key");
+}
@@ -20,7 +20,7 @@ async fn create_and_get_index() {
     assert!(response.get("updatedAt").is_some());
     assert_eq!(response["createdAt"], response["updatedAt"]);
     assert_eq!(response["primaryKey"], Value::Null);
-    assert_eq!(response.as_object().unwrap().len(), 4);
+    assert_eq!(response.as_object().unwrap().len(), 5);
 }
 
 #[actix_rt::test]
@@ -1,5 +1,9 @@
+mod clone_index;
+mod compact_index;
 mod create_index;
 mod delete_index;
 mod get_index;
+mod quota;
+mod read_only;
 mod stats;
 mod update_index;
@@ -0,0 +1,148 @@
+use serde_json::json;
+
+use crate::common::Server;
+
+#[actix_rt::test]
+async fn max_documents_quota() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    index.create(None).await;
+    index.wait_task(0).await;
+
+    let (response, code) = index.quota().await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response, json!({ "maxDocuments": null, "maxSizeBytes": null }));
+    let (response, _) = index.stats().await;
+    assert!(response.get("quota").is_none(), "{response}");
+
+    let (response, code) = index.update_quota(json!({ "maxDocuments": 2 })).await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response, json!({ "maxDocuments": 2, "maxSizeBytes": null }));
+
+    index.add_documents(json!([{ "id": 1 }, { "id": 2 }]), None).await;
+    let response = index.wait_task(1).await;
+    assert_eq!(response["status"], "succeeded", "{response}");
+
+    index.add_documents(json!([{ "id": 3 }]), None).await;
+    let response = index.wait_task(2).await;
+    assert_eq!(response["status"], "failed", "{response}");
+    assert_eq!(
+        response["error"],
+        json!({
+            "message": "Index `test` would contain 3 documents but its quota is 2 documents.",
+            "code": "index_quota_exceeded",
+            "type": "invalid_request",
+            "link": "https://docs.meilisearch.com/errors#index-quota-exceeded"
+        })
+    );
+
+    // Replacing the existing documents doesn't grow the index.
+    index.add_documents(json!([{ "id": 2, "title": "Carol" }]), None).await;
+    let response = index.wait_task(3).await;
+    assert_eq!(response["status"], "succeeded", "{response}");
+
+    let (response, code) = index.stats().await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["numberOfDocuments"], 2);
+    assert_eq!(response["quota"]["numberOfDocuments"], 2);
+    assert_eq!(response["quota"]["maxDocuments"], 2);
+    assert!(response["quota"]["sizeBytes"].as_u64().unwrap() > 0);
+    assert_eq!(response["quota"]["maxSizeBytes"], json!(null));
+
+    // Removing the quota lifts the limit.
+    index.update_quota(json!({ "maxDocuments": null })).await;
+    index.add_documents(json!([{ "id": 3 }]), None).await;
+    let response = index.wait_task(4).await;
+    assert_eq!(response["status"], "succeeded", "{response}");
+}
+
+#[actix_rt::test]
+async fn max_size_quota() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    index.create(None).await;
+    index.wait_task(0).await;
+
+    index.add_documents(json!([{ "id": 1 }]), None).await;
+    let response = index.wait_task(1).await;
+    assert_eq!(response["status"], "succeeded", "{response}");
+
+    let (response, code) = index.update_quota(json!({ "maxSizeBytes": 1 })).await;
+    assert_eq!(code, 200, "{response}");
+
+    index.add_documents(json!([{ "id": 2 }]), None).await;
+    let response = index.wait_task(2).await;
+    assert_eq!(response["status"], "failed", "{response}");
+    assert_eq!(response["error"]["code"], "index_quota_exceeded");
+
+    // Updating the existing documents can grow the index too.
+    index.add_documents(json!([{ "id": 1, "title": "Carol" }]), None).await;
+    let response = index.wait_task(3).await;
+    assert_eq!(response["status"], "failed", "{response}");
+    assert_eq!(response["error"]["code"], "index_quota_exceeded");
+
+    // The deletions are accepted even though the index is above its quota.
+    index.delete_document(1).await;
+    let response = index.wait_task(4).await;
+    assert_eq!(response["status"], "succeeded", "{response}");
+}
+
+#[actix_rt::test]
+async fn max_size_quota_rejects_the_batch_crossing_it() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    index.create(None).await;
+    index.wait_task(0).await;
+
+    let (response, code) = index.update_quota(json!({ "maxSizeBytes": 1_000_000 })).await;
+    assert_eq!(code, 200, "{response}");
+
+    // The index is still empty when this batch is processed, it must be rejected
+    // before being committed rather than making the following batches fail.
+    let documents: Vec<_> =
+        (0..200).map(|id| json!({ "id": id, "text": "a".repeat(10_000) })).collect();
+    index.add_documents(json!(documents), None).await;
+    let response = index.wait_task(1).await;
+    assert_eq!(response["status"], "failed", "{response}");
+    assert_eq!(response["error"]["code"], "index_quota_exceeded");
+
+    let (response, _) = index.stats().await;
+    assert_eq!(response["numberOfDocuments"], 0, "{response}");
+
+    index.add_documents(json!([{ "id": 1 }]), None).await;
+    let response = index.wait_task(2).await;
+    assert_eq!(response["status"], "succeeded", "{response}");
+
+    // The reported size is the one the quota is enforced on.
+    let (response, _) = index.stats().await;
+    assert!(response["quota"]["sizeBytes"].as_u64().unwrap() <= 1_000_000, "{response}");
+}
+
+#[actix_rt::test]
+async fn error_quota() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) = index.quota().await;
+    assert_eq!(code, 404, "{response}");
+    assert_eq!(response["code"], "index_not_found");
+
+    let (response, code) = index.update_quota(json!({})).await;
+    assert_eq!(code, 404, "{response}");
+    assert_eq!(response["code"], "index_not_found");
+
+    index.create(None).await;
+    index.wait_task(0).await;
+
+    let (response, code) = index.update_quota(json!({ "maxDocuments": -1 })).await;
+    assert_eq!(code, 400, "{response}");
+    assert_eq!(response["code"], "invalid_index_max_documents");
+
+    let (response, code) = index.update_quota(json!({ "maxSizeBytes": "big" })).await;
+    assert_eq!(code, 400, "{response}");
+    assert_eq!(response["code"], "invalid_index_max_size_bytes");
+
+    let (response, code) = index.update_quota(json!({ "maxRam": 1 })).await;
+    assert_eq!(code, 400, "{response}");
+    assert_eq!(response["code"], "bad_request");
+}
@@ -0,0 +1,73 @@
+use serde_json::json;
+
+use crate::common::Server;
+
+#[actix_rt::test]
+async fn read_only_index_rejects_writes() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    index.add_documents(json!([{ "id": 1, "title": "Carol" }]), None).await;
+    index.wait_task(0).await;
+
+    let (response, code) = index.update_read_only(true).await;
+    assert_eq!(code, 202, "{response}");
+    let response = index.wait_task(1).await;
+    assert_eq!(response["status"], "succeeded", "{response}");
+    assert_eq!(response["type"], "indexReadOnlyUpdate");
+    assert_eq!(response["details"], json!({ "readOnly": true }));
+
+    let (response, code) = index.get().await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["readOnly"], true);
+    let (response, _) = server.list_indexes(None, None).await;
+    assert_eq!(response["results"][0]["readOnly"], true);
+
+    let expected_error = json!({
+        "message": "Index `test` is read-only.",
+        "code": "index_read_only",
+        "type": "invalid_request",
+        "link": "https://docs.meilisearch.com/errors#index-read-only"
+    });
+    let (response, code) = index.add_documents(json!([{ "id": 2 }]), None).await;
+    assert_eq!(code, 403, "{response}");
+    assert_eq!(response, expected_error);
+    let (response, code) = index.update_settings(json!({ "stopWords": ["the"] })).await;
+    assert_eq!(code, 403, "{response}");
+    assert_eq!(response, expected_error);
+    let (response, code) = index.delete_document(1).await;
+    assert_eq!(code, 403, "{response}");
+    let (response, code) = index.delete().await;
+    assert_eq!(code, 403, "{response}");
+
+    // The search keeps working.
+    let (response, code) = index.search_post(json!({ "q": "carol" })).await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["hits"], json!([{ "id": 1, "title": "Carol" }]));
+
+    let (response, code) = index.update_read_only(false).await;
+    assert_eq!(code, 202, "{response}");
+    index.wait_task(2).await;
+    let (response, _) = index.get().await;
+    assert_eq!(response["readOnly"], false);
+
+    let (response, code) = index.add_documents(json!([{ "id": 2 }]), None).await;
+    assert_eq!(code, 202, "{response}");
+    let response = index.wait_task(3).await;
+    assert_eq!(response["status"], "succeeded", "{response}");
+}
+
+#[actix_rt::test]
+async fn error_update_read_only() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) = index.update_read_only(true).await;
+    assert_eq!(code, 202, "{response}");
+    let response = index.wait_task(0).await;
+    assert_eq!(response["status"], "failed");
+    assert_eq!(response["error"]["code"], "index_not_found");
+
+    let (response, code) = server.service.put("/indexes/test/read-only", json!({})).await;
+    assert_eq!(code, 400, "{response}");
+    assert_eq!(response["code"], "missing_index_read_only");
+}
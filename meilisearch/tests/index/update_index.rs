@@ -34,7 +34,7 @@ async fn update_primary_key() {
     assert!(created_at < updated_at);
 
     assert_eq!(response["primaryKey"], "primary");
-    assert_eq!(response.as_object().unwrap().len(), 4);
+    assert_eq!(response.as_object().unwrap().len(), 5);
 }
 
 #[actix_rt::test]
@@ -122,3 +122,76 @@ async fn error_update_unexisting_index() {
 
     assert_eq!(response["error"], expected_response);
 }
+
+#[actix_rt::test]
+async fn rename_index() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    index.add_documents(json!([{ "id": 1, "title": "Carol" }]), None).await;
+    index.wait_task(0).await;
+
+    let (response, code) = index.update_raw(json!({ "uid": "movies" })).await;
+    assert_eq!(code, 202, "{response}");
+    let response = index.wait_task(1).await;
+    assert_eq!(response["status"], "succeeded", "{response}");
+    assert_eq!(response["type"], "indexUpdate");
+    assert_eq!(response["details"], json!({ "primaryKey": null, "newIndexUid": "movies" }));
+
+    let (_, code) = index.get().await;
+    assert_eq!(code, 404);
+
+    let movies = server.index("movies");
+    let (response, code) = movies.get().await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["primaryKey"], "id");
+    let (response, code) = movies.get_document(1, None).await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["title"], "Carol");
+
+    // The tasks that were processed before the rename now refer to the new uid.
+    let (response, code) = server.tasks_filter(json!({ "indexUids": "movies" })).await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["results"].as_array().unwrap().len(), 2);
+    assert_eq!(response["results"][1]["indexUid"], "movies");
+}
+
+#[actix_rt::test]
+async fn error_rename_index_to_existing_index() {
+    let mut server = Server::new_auth().await;
+    server.use_admin_key("MASTER_KEY").await;
+    let (key, code) = server
+        .add_api_key(json!({ "indexes": ["test"], "actions": ["search"], "expiresAt": null }))
+        .await;
+    assert_eq!(code, 201, "{key}");
+
+    let index = server.index("test");
+    index.create(None).await;
+    server.index("movies").create(None).await;
+
+    let (_, code) = index
+        .update_raw(json!({ "uid": "movies", "primaryKey": "id", "migrateApiKeys": true }))
+        .await;
+    assert_eq!(code, 202);
+    let response = index.wait_task(2).await;
+    assert_eq!(response["status"], "failed");
+    assert_eq!(
+        response["error"],
+        json!({
+            "message": "Index `movies` already exists.",
+            "code": "index_already_exists",
+            "type": "invalid_request",
+            "link": "https://docs.meilisearch.com/errors#index-already-exists"
+        })
+    );
+
+    // The primary key is not updated and the API keys are not migrated when the rename fails.
+    let (response, _) = index.get().await;
+    assert_eq!(response["primaryKey"], json!(null));
+    let (response, code) = server.get_api_key(key["uid"].as_str().unwrap()).await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["indexes"], json!(["test"]));
+
+    let (response, code) = index.update_raw(json!({ "uid": "bad uid" })).await;
+    assert_eq!(code, 400, "{response}");
+    assert_eq!(response["code"], "invalid_index_uid");
+}
@@ -0,0 +1,108 @@
+use serde_json::json;
+
+use crate::common::Server;
+
+#[actix_rt::test]
+async fn template_is_applied_to_new_indexes() {
+    let server = Server::new().await;
+
+    let (response, code) = server
+        .put_index_template(
+            "customer-*",
+            json!({ "filterableAttributes": ["country"], "stopWords": ["the"] }),
+        )
+        .await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(
+        response,
+        json!({
+            "pattern": "customer-*",
+            "settings": { "filterableAttributes": ["country"], "stopWords": ["the"] }
+        })
+    );
+
+    let (response, code) = server.get_index_template("customer-*").await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["settings"]["filterableAttributes"], json!(["country"]));
+
+    // Explicit creation.
+    let index = server.index("customer-1");
+    index.create(None).await;
+    index.wait_task(0).await;
+    let (response, code) = index.settings().await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["filterableAttributes"], json!(["country"]));
+    assert_eq!(response["stopWords"], json!(["the"]));
+
+    // Implicit creation through a document addition.
+    let index = server.index("customer-2");
+    index.add_documents(json!([{ "id": 1, "country": "France" }]), None).await;
+    let response = index.wait_task(1).await;
+    assert_eq!(response["status"], "succeeded", "{response}");
+    let (response, code) = index.search_post(json!({ "filter": "country = France" })).await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["hits"], json!([{ "id": 1, "country": "France" }]));
+
+    // The indexes that don't match keep the default settings.
+    let index = server.index("movies");
+    index.create(None).await;
+    index.wait_task(2).await;
+    let (response, _) = index.settings().await;
+    assert_eq!(response["filterableAttributes"], json!([]));
+
+    let (response, code) = server.delete_index_template("customer-*").await;
+    assert_eq!(code, 204, "{response}");
+    let index = server.index("customer-3");
+    index.create(None).await;
+    index.wait_task(3).await;
+    let (response, _) = index.settings().await;
+    assert_eq!(response["filterableAttributes"], json!([]));
+}
+
+#[actix_rt::test]
+async fn most_specific_template_wins() {
+    let server = Server::new().await;
+    server.put_index_template("*", json!({ "stopWords": ["a"] })).await;
+    server.put_index_template("customer-*", json!({ "stopWords": ["the"] })).await;
+
+    let index = server.index("customer-1");
+    index.create(None).await;
+    index.wait_task(0).await;
+    let (response, _) = index.settings().await;
+    assert_eq!(response["stopWords"], json!(["the"]));
+
+    let (response, code) = server.service.get("/index-templates").await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["total"], 2);
+}
+
+#[actix_rt::test]
+async fn error_index_template() {
+    let server = Server::new().await;
+
+    let (response, code) = server.put_index_template("customer.1", json!({})).await;
+    assert_eq!(code, 400, "{response}");
+    assert_eq!(response["code"], "invalid_index_template_pattern");
+
+    // The templates that can't be applied to a new index are rejected.
+    let (response, code) =
+        server.put_index_template("customer-*", json!({ "defaultTtl": 60 })).await;
+    assert_eq!(code, 400, "{response}");
+    assert_eq!(response["code"], "invalid_settings_default_ttl");
+
+    let (response, code) = server.get_index_template("customer-*").await;
+    assert_eq!(code, 404, "{response}");
+    assert_eq!(
+        response,
+        json!({
+            "message": "Index template `customer-*` not found.",
+            "code": "index_template_not_found",
+            "type": "invalid_request",
+            "link": "https://docs.meilisearch.com/errors#index-template-not-found"
+        })
+    );
+
+    let (response, code) = server.delete_index_template("customer-*").await;
+    assert_eq!(code, 404, "{response}");
+    assert_eq!(response["code"], "index_template_not_found");
+}
@@ -1,9 +1,11 @@
+mod aliases;
 mod auth;
 mod common;
 mod dashboard;
 mod documents;
 mod dumps;
 mod index;
+mod index_templates;
 mod search;
 mod settings;
 mod snapshot;
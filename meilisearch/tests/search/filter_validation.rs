@@ -0,0 +1,113 @@
+use serde_json::json;
+
+use super::NESTED_DOCUMENTS;
+use crate::common::Server;
+
+#[actix_rt::test]
+async fn validate_filter_normalizes_and_explains() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    index.update_settings(json!({"filterableAttributes": ["father", "mother", "id"]})).await;
+    index.add_documents(NESTED_DOCUMENTS.clone(), None).await;
+    index.wait_task(1).await;
+
+    let (response, code) = index
+        .validate_filter(
+            json!({ "filter": "father   = jean AND (mother = 'michelle' OR id > 900)" }),
+        )
+        .await;
+    assert_eq!(code, 200, "{}", response);
+    assert_eq!(
+        response,
+        json!({
+            "valid": true,
+            "normalizedFilter": "father = jean AND (mother = michelle OR id > 900)",
+        })
+    );
+
+    let (response, code) = index
+        .validate_filter(json!({
+            "filter": ["father = jean", ["mother = michelle", "id > 900"]],
+            "explain": true,
+        }))
+        .await;
+    assert_eq!(code, 200, "{}", response);
+    assert_eq!(
+        response,
+        json!({
+            "valid": true,
+            "normalizedFilter": "father = jean AND (mother = michelle OR id > 900)",
+            "explanation": {
+                "filter": "father = jean AND (mother = michelle OR id > 900)",
+                "matchedDocuments": 1,
+                "subClauses": [
+                    { "filter": "father = jean", "matchedDocuments": 1 },
+                    {
+                        "filter": "mother = michelle OR id > 900",
+                        "matchedDocuments": 3,
+                        "subClauses": [
+                            { "filter": "mother = michelle", "matchedDocuments": 2 },
+                            { "filter": "id > 900", "matchedDocuments": 1 },
+                        ]
+                    },
+                ]
+            }
+        })
+    );
+}
+
+#[actix_rt::test]
+async fn validate_filter_reports_errors() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    index.update_settings(json!({"filterableAttributes": ["price"]})).await;
+    index.wait_task(0).await;
+
+    let (response, code) =
+        index.validate_filter(json!({ "filter": ["price > 10", "title & Glass"] })).await;
+    assert_eq!(code, 200, "{}", response);
+    assert_eq!(
+        response,
+        json!({
+            "valid": false,
+            "errors": [
+                {
+                    "message": "Was expecting an operation `=`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, or `_geoRadius` at `title & Glass`.\n1:14 title & Glass",
+                    "expression": "title & Glass",
+                    "start": 0,
+                    "end": 13,
+                },
+            ]
+        })
+    );
+
+    let (response, code) =
+        index.validate_filter(json!({ "filter": "title = foo AND price > bar" })).await;
+    assert_eq!(code, 200, "{}", response);
+    assert_eq!(
+        response,
+        json!({
+            "valid": false,
+            "errors": [
+                {
+                    "message": "Attribute `title` is not filterable. Available filterable attributes are: `price`.\n1:6 title = foo AND price > bar",
+                    "expression": "title = foo AND price > bar",
+                    "start": 0,
+                    "end": 5,
+                },
+                {
+                    "message": "invalid float literal\n25:28 title = foo AND price > bar",
+                    "expression": "title = foo AND price > bar",
+                    "start": 24,
+                    "end": 27,
+                },
+            ]
+        })
+    );
+
+    let (response, code) = index.validate_filter(json!({ "filter": true })).await;
+    assert_eq!(code, 400, "{}", response);
+    assert_eq!(response["code"], "invalid_search_filter");
+}
@@ -2,6 +2,7 @@
 // should be tested in its own module to isolate tests and keep the tests readable.
 
 mod errors;
+mod filter_validation;
 mod formatted;
 mod pagination;
 
@@ -0,0 +1,99 @@
+use serde_json::json;
+
+use crate::common::Server;
+
+#[actix_rt::test]
+async fn dry_run_without_reindexing() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    index.add_documents(json!([{ "id": 1, "title": "Carol" }]), None).await;
+    index.wait_task(0).await;
+
+    let (response, code) = index
+        .settings_dry_run(json!({ "displayedAttributes": ["title"], "rankingRules": ["words"] }))
+        .await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(
+        response,
+        json!({
+            "reindexing": false,
+            "reindexingSettings": [],
+            "rebuiltDatabases": [],
+            "estimatedCost": { "documentsToReindex": 0, "reindexedSize": 0 },
+        })
+    );
+}
+
+#[actix_rt::test]
+async fn dry_run_with_reindexing() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    let documents: Vec<_> =
+        (0..10).map(|id| json!({ "id": id, "title": format!("The document {id}") })).collect();
+    index.add_documents(json!(documents), None).await;
+    index.wait_task(0).await;
+
+    let (response, code) = index
+        .settings_dry_run(
+            json!({ "searchableAttributes": ["title"], "filterableAttributes": ["_geo"] }),
+        )
+        .await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["reindexing"], true);
+    assert_eq!(
+        response["reindexingSettings"],
+        json!(["facetedAttributes", "searchableAttributes"])
+    );
+    let databases = response["rebuiltDatabases"].as_array().unwrap();
+    assert!(databases.contains(&json!("word-docids")), "{response}");
+    assert!(databases.contains(&json!("word-prefix-docids")), "{response}");
+    assert!(databases.contains(&json!("facet-id-f64-docids")), "{response}");
+    assert!(databases.contains(&json!("geo-rtree")), "{response}");
+    assert_eq!(response["estimatedCost"]["documentsToReindex"], 10);
+    assert!(response["estimatedCost"]["reindexedSize"].as_u64().unwrap() > 0);
+
+    // Nothing has been applied nor enqueued.
+    let (response, _) = index.settings().await;
+    assert_eq!(response["searchableAttributes"], json!(["*"]));
+    assert_eq!(response["filterableAttributes"], json!([]));
+    let (response, _) = index.list_tasks().await;
+    assert_eq!(response["results"].as_array().unwrap().len(), 1);
+}
+
+#[actix_rt::test]
+async fn error_dry_run_unexisting_index() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) = index.settings_dry_run(json!({ "stopWords": ["the"] })).await;
+    assert_eq!(code, 404, "{response}");
+    assert_eq!(response["code"], "index_not_found");
+}
+
+#[actix_rt::test]
+async fn error_dry_run_invalid_settings() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    index.create(None).await;
+    index.wait_task(0).await;
+
+    let (response, code) = index.settings_dry_run(json!({ "rankingRules": ["manyTheFish"] })).await;
+    assert_eq!(code, 400, "{response}");
+    assert_eq!(response["code"], "invalid_settings_ranking_rules");
+
+    let (response, code) =
+        index.settings_dry_run(json!({ "softDeletedPurgeThreshold": 101 })).await;
+    assert_eq!(code, 400, "{response}");
+    assert_eq!(response["code"], "invalid_settings_soft_deleted_purge_threshold");
+
+    // The settings are also checked against the current settings of the index.
+    let (response, code) = index.settings_dry_run(json!({ "defaultTtl": 3600 })).await;
+    assert_eq!(code, 400, "{response}");
+    assert_eq!(response["code"], "invalid_settings_default_ttl");
+
+    let (response, code) = index
+        .settings_dry_run(json!({ "typoTolerance": { "minWordSizeForTypos": { "oneTypo": 10 } } }))
+        .await;
+    assert_eq!(code, 400, "{response}");
+    assert_eq!(response["code"], "invalid_min_word_length_for_typo");
+}
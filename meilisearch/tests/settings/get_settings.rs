@@ -11,6 +11,11 @@ static DEFAULT_SETTINGS_VALUES: Lazy<HashMap<&'static str, Value>> = Lazy::new(|
     map.insert("searchable_attributes", json!(["*"]));
     map.insert("filterable_attributes", json!([]));
     map.insert("distinct_attribute", json!(Value::Null));
+    map.insert("ttl_attribute", json!(Value::Null));
+    map.insert("default_ttl", json!(Value::Null));
+    map.insert("primary_key_generation", json!(Value::Null));
+    map.insert("schema", json!(Value::Null));
+    map.insert("soft_deleted_purge_threshold", json!(Value::Null));
     map.insert(
         "ranking_rules",
         json!(["words", "typo", "proximity", "attribute", "sort", "exactness"]),
@@ -48,7 +53,7 @@ async fn get_settings() {
     let (response, code) = index.settings().await;
     assert_eq!(code, 200);
     let settings = response.as_object().unwrap();
-    assert_eq!(settings.keys().len(), 11);
+    assert_eq!(settings.keys().len(), 16);
     assert_eq!(settings["displayedAttributes"], json!(["*"]));
     assert_eq!(settings["searchableAttributes"], json!(["*"]));
     assert_eq!(settings["filterableAttributes"], json!([]));
@@ -71,6 +76,11 @@ async fn get_settings() {
             "maxTotalHits": 1000,
         })
     );
+    assert_eq!(settings["ttlAttribute"], json!(null));
+    assert_eq!(settings["defaultTtl"], json!(null));
+    assert_eq!(settings["primaryKeyGeneration"], json!(null));
+    assert_eq!(settings["schema"], json!(null));
+    assert_eq!(settings["softDeletedPurgeThreshold"], json!(null));
 }
 
 #[actix_rt::test]
@@ -269,7 +279,12 @@ test_setting_routes!(
     ranking_rules put,
     synonyms put,
     pagination patch,
-    faceting patch
+    faceting patch,
+    ttl_attribute put,
+    default_ttl put,
+    primary_key_generation put,
+    schema put,
+    soft_deleted_purge_threshold put
 );
 
 #[actix_rt::test]
@@ -0,0 +1,107 @@
+use serde_json::json;
+
+use crate::common::Server;
+
+#[actix_rt::test]
+async fn settings_history_and_rollback() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    index.add_documents(json!([{ "id": 1, "title": "Carol" }]), None).await;
+    index.wait_task(0).await;
+
+    // The documents additions don't produce a new version of the settings.
+    let (response, code) = index.settings_history().await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["results"], json!([]));
+
+    index.update_settings(json!({ "stopWords": ["the"] })).await;
+    index.wait_task(1).await;
+    index.update_settings(json!({ "stopWords": ["a"], "displayedAttributes": ["title"] })).await;
+    index.wait_task(2).await;
+
+    let (response, code) = index.settings_history().await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["total"], 3);
+    let versions = response["results"].as_array().unwrap();
+    // The settings from before the first settings update are recorded as the first version.
+    assert_eq!(versions[0]["version"], 1);
+    assert_eq!(versions[0]["taskUids"], json!([]));
+    assert_eq!(versions[0]["settings"]["stopWords"], json!([]));
+    assert!(versions[0]["appliedAt"].is_string(), "{response}");
+    assert_eq!(versions[1]["version"], 2);
+    assert_eq!(versions[1]["taskUids"], json!([1]));
+    assert_eq!(versions[1]["settings"]["stopWords"], json!(["the"]));
+    assert_eq!(versions[1]["settings"]["displayedAttributes"], json!(["*"]));
+    assert!(versions[1]["appliedAt"].is_string(), "{response}");
+    assert_eq!(versions[2]["version"], 3);
+    assert_eq!(versions[2]["taskUids"], json!([2]));
+    assert_eq!(versions[2]["settings"]["stopWords"], json!(["a"]));
+    assert_eq!(versions[2]["settings"]["displayedAttributes"], json!(["title"]));
+    let initial_version = versions[0]["settings"].clone();
+    let second_version = versions[1]["settings"].clone();
+
+    let (response, code) = index.rollback_settings(2).await;
+    assert_eq!(code, 202, "{response}");
+    assert_eq!(response["type"], "settingsUpdate");
+    let response = index.wait_task(3).await;
+    assert_eq!(response["status"], "succeeded", "{response}");
+    // Only the settings that differ from the current ones are reported.
+    assert_eq!(
+        response["details"],
+        json!({ "stopWords": ["the"], "displayedAttributes": ["*"] }),
+        "{response}"
+    );
+
+    let (response, _) = index.settings().await;
+    assert_eq!(response, second_version);
+
+    // The rollback is a settings update like any other and produces a new version.
+    let (response, _) = index.settings_history().await;
+    assert_eq!(response["total"], 4);
+    assert_eq!(response["results"][3]["version"], 4);
+    assert_eq!(response["results"][3]["taskUids"], json!([3]));
+    assert_eq!(response["results"][3]["settings"], second_version);
+
+    // The settings the index had before its first settings update can be restored.
+    let (response, code) = index.rollback_settings(1).await;
+    assert_eq!(code, 202, "{response}");
+    let response = index.wait_task(4).await;
+    assert_eq!(response["status"], "succeeded", "{response}");
+
+    let (response, _) = index.settings().await;
+    assert_eq!(response, initial_version);
+}
+
+#[actix_rt::test]
+async fn error_rollback_unknown_version() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    index.update_settings(json!({ "stopWords": ["the"] })).await;
+    index.wait_task(0).await;
+
+    let (response, code) = index.rollback_settings(3).await;
+    assert_eq!(code, 404, "{response}");
+    assert_eq!(
+        response,
+        json!({
+            "message": "Version `3` of the settings of index `test` not found.",
+            "code": "settings_version_not_found",
+            "type": "invalid_request",
+            "link": "https://docs.meilisearch.com/errors#settings-version-not-found"
+        })
+    );
+}
+
+#[actix_rt::test]
+async fn error_settings_history_unexisting_index() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) = index.settings_history().await;
+    assert_eq!(code, 404, "{response}");
+    assert_eq!(response["code"], "index_not_found");
+
+    let (response, code) = index.rollback_settings(1).await;
+    assert_eq!(code, 404, "{response}");
+    assert_eq!(response["code"], "index_not_found");
+}
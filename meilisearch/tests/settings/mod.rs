@@ -1,3 +1,6 @@
 mod distinct;
+mod dry_run;
 mod errors;
 mod get_settings;
+mod history;
+mod ttl;
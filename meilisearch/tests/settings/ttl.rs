@@ -0,0 +1,81 @@
+use serde_json::json;
+
+use crate::common::{GetAllDocumentsOptions, Server};
+
+#[actix_rt::test]
+async fn set_and_reset_ttl_settings() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (_response, _code) =
+        index.update_settings(json!({ "ttlAttribute": "expiresAt", "defaultTtl": 3600 })).await;
+    let response = index.wait_task(0).await;
+    assert_eq!(response["status"], "succeeded", "{}", response);
+
+    let (response, _) = index.settings().await;
+    assert_eq!(response["ttlAttribute"], "expiresAt");
+    assert_eq!(response["defaultTtl"], 3600);
+
+    index.update_settings(json!({ "ttlAttribute": null, "defaultTtl": null })).await;
+    index.wait_task(1).await;
+
+    let (response, _) = index.settings().await;
+    assert_eq!(response["ttlAttribute"], json!(null));
+    assert_eq!(response["defaultTtl"], json!(null));
+}
+
+#[actix_rt::test]
+async fn error_default_ttl_without_ttl_attribute() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    index.update_settings(json!({ "defaultTtl": 3600 })).await;
+    let response = index.wait_task(0).await;
+    assert_eq!(response["status"], "failed", "{}", response);
+    assert_eq!(
+        response["error"],
+        json!({
+            "message": "The `defaultTtl` setting requires the `ttlAttribute` setting to be set.",
+            "code": "invalid_settings_default_ttl",
+            "type": "invalid_request",
+            "link": "https://docs.meilisearch.com/errors#invalid-settings-default-ttl"
+        })
+    );
+}
+
+#[actix_rt::test]
+async fn expired_documents_are_hidden() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    index.update_settings(json!({ "ttlAttribute": "expiresAt" })).await;
+    index.wait_task(0).await;
+
+    let documents = json!([
+        { "id": 1, "title": "kefir", "expiresAt": 1000 },
+        { "id": 2, "title": "intel", "expiresAt": "2999-01-01T00:00:00Z" },
+        { "id": 3, "title": "echo", "expiresAt": "2000-01-01T00:00:00Z" },
+        { "id": 4, "title": "mimi" },
+    ]);
+    index.add_documents(documents, None).await;
+    let response = index.wait_task(1).await;
+    assert_eq!(response["status"], "succeeded", "{}", response);
+
+    let (response, code) = index.get_document(1, None).await;
+    assert_eq!(code, 404, "{}", response);
+    assert_eq!(response["code"], "document_not_found");
+    let (response, code) = index.get_document(2, None).await;
+    assert_eq!(code, 200, "{}", response);
+
+    let (response, code) = index.get_all_documents(GetAllDocumentsOptions::default()).await;
+    assert_eq!(code, 200, "{}", response);
+    assert_eq!(response["total"], 2);
+    let ids: Vec<_> = response["results"].as_array().unwrap().iter().map(|d| &d["id"]).collect();
+    assert_eq!(ids, [&json!(2), &json!(4)]);
+
+    let (response, code) = index.search_post(json!({ "q": "" })).await;
+    assert_eq!(code, 200, "{}", response);
+    assert_eq!(response["estimatedTotalHits"], 2);
+    let ids: Vec<_> = response["hits"].as_array().unwrap().iter().map(|d| &d["id"]).collect();
+    assert_eq!(ids, [&json!(2), &json!(4)]);
+}
@@ -87,7 +87,7 @@ async fn task_bad_types() {
     snapshot!(code, @"400 Bad Request");
     snapshot!(json_string!(response), @r###"
     {
-      "message": "`doggo` is not a type. Available types are `documentAdditionOrUpdate`, `documentDeletion`, `settingsUpdate`, `indexCreation`, `indexDeletion`, `indexUpdate`, `indexSwap`, `taskCancelation`, `taskDeletion`, `dumpCreation`, `snapshotCreation`. at `.types`.",
+      "message": "`doggo` is not a type. Available types are `documentAdditionOrUpdate`, `documentDeletion`, `documentEdition`, `documentPurge`, `settingsUpdate`, `indexCreation`, `indexDeletion`, `indexUpdate`, `indexClone`, `indexReadOnlyUpdate`, `indexCompaction`, `indexSwap`, `aliasUpdate`, `taskCancelation`, `taskDeletion`, `dumpCreation`, `snapshotCreation`. at `.types`.",
       "code": "invalid_task_types",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#invalid-task-types"
@@ -98,7 +98,7 @@ async fn task_bad_types() {
     snapshot!(code, @"400 Bad Request");
     snapshot!(json_string!(response), @r###"
     {
-      "message": "`doggo` is not a type. Available types are `documentAdditionOrUpdate`, `documentDeletion`, `settingsUpdate`, `indexCreation`, `indexDeletion`, `indexUpdate`, `indexSwap`, `taskCancelation`, `taskDeletion`, `dumpCreation`, `snapshotCreation`. at `.types`.",
+      "message": "`doggo` is not a type. Available types are `documentAdditionOrUpdate`, `documentDeletion`, `documentEdition`, `documentPurge`, `settingsUpdate`, `indexCreation`, `indexDeletion`, `indexUpdate`, `indexClone`, `indexReadOnlyUpdate`, `indexCompaction`, `indexSwap`, `aliasUpdate`, `taskCancelation`, `taskDeletion`, `dumpCreation`, `snapshotCreation`. at `.types`.",
       "code": "invalid_task_types",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#invalid-task-types"
@@ -109,7 +109,7 @@ async fn task_bad_types() {
     snapshot!(code, @"400 Bad Request");
     snapshot!(json_string!(response), @r###"
     {
-      "message": "`doggo` is not a type. Available types are `documentAdditionOrUpdate`, `documentDeletion`, `settingsUpdate`, `indexCreation`, `indexDeletion`, `indexUpdate`, `indexSwap`, `taskCancelation`, `taskDeletion`, `dumpCreation`, `snapshotCreation`. at `.types`.",
+      "message": "`doggo` is not a type. Available types are `documentAdditionOrUpdate`, `documentDeletion`, `documentEdition`, `documentPurge`, `settingsUpdate`, `indexCreation`, `indexDeletion`, `indexUpdate`, `indexClone`, `indexReadOnlyUpdate`, `indexCompaction`, `indexSwap`, `aliasUpdate`, `taskCancelation`, `taskDeletion`, `dumpCreation`, `snapshotCreation`. at `.types`.",
       "code": "invalid_task_types",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#invalid-task-types"
@@ -0,0 +1,283 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Number, Value};
+
+use crate::documents::DocumentsBatchIndex;
+use crate::error::{InternalError, UserError};
+use crate::{FieldId, Result};
+
+/// The maximum number of offending documents kept in a [`SchemaViolations`] report.
+pub const SCHEMA_VIOLATIONS_SAMPLE_SIZE: usize = 20;
+
+/// A JSON-Schema-like description of the documents of an index, checked
+/// when documents are added or updated.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct DocumentSchema {
+    /// What to do with the documents that don't match the schema.
+    #[serde(default)]
+    pub policy: SchemaPolicy,
+    /// The fields that must be present in every document.
+    #[serde(default)]
+    pub required: BTreeSet<String>,
+    /// The constraints on the values of the top-level fields.
+    #[serde(default)]
+    pub properties: BTreeMap<String, FieldSchema>,
+}
+
+/// What to do with the documents that don't match the schema of an index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SchemaPolicy {
+    /// The whole document addition fails.
+    RejectBatch,
+    /// The offending documents are not indexed.
+    SkipDocument,
+    /// The values are converted to the expected types when possible,
+    /// the documents that still don't match are not indexed.
+    Coerce,
+}
+
+impl Default for SchemaPolicy {
+    fn default() -> Self {
+        SchemaPolicy::RejectBatch
+    }
+}
+
+/// The constraints on the value of a field.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct FieldSchema {
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub field_type: Option<FieldType>,
+    #[serde(default, rename = "enum", skip_serializing_if = "Option::is_none")]
+    pub allowed_values: Option<Vec<Value>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minimum: Option<Number>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maximum: Option<Number>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FieldType {
+    String,
+    Number,
+    Integer,
+    Boolean,
+    Array,
+    Object,
+}
+
+impl FieldType {
+    fn name(&self) -> &'static str {
+        match self {
+            FieldType::String => "a string",
+            FieldType::Number => "a number",
+            FieldType::Integer => "an integer",
+            FieldType::Boolean => "a boolean",
+            FieldType::Array => "an array",
+            FieldType::Object => "an object",
+        }
+    }
+
+    fn matches(&self, value: &Value) -> bool {
+        match (self, value) {
+            (FieldType::String, Value::String(_)) => true,
+            (FieldType::Number, Value::Number(_)) => true,
+            (FieldType::Integer, Value::Number(n)) => n.is_i64() || n.is_u64(),
+            (FieldType::Boolean, Value::Bool(_)) => true,
+            (FieldType::Array, Value::Array(_)) => true,
+            (FieldType::Object, Value::Object(_)) => true,
+            _ => false,
+        }
+    }
+
+    /// Converts the value to this type when it is possible without losing information.
+    fn coerce(&self, value: &Value) -> Option<Value> {
+        match (self, value) {
+            (FieldType::String, Value::Number(n)) => Some(Value::String(n.to_string())),
+            (FieldType::String, Value::Bool(b)) => Some(Value::String(b.to_string())),
+            (FieldType::Number, Value::String(s)) => {
+                s.trim().parse::<f64>().ok().and_then(Number::from_f64).map(Value::Number)
+            }
+            (FieldType::Integer, Value::String(s)) => {
+                s.trim().parse::<i64>().ok().map(|i| Value::Number(i.into()))
+            }
+            (FieldType::Boolean, Value::String(s)) => match s.trim() {
+                "true" => Some(Value::Bool(true)),
+                "false" => Some(Value::Bool(false)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl FieldSchema {
+    /// Returns the reasons why this value doesn't match the constraints of the field.
+    fn violations(&self, field: &str, value: &Value) -> Vec<String> {
+        let mut reasons = Vec::new();
+
+        if let Some(field_type) = self.field_type {
+            if !field_type.matches(value) {
+                reasons.push(format!(
+                    "`{field}` was expecting {} but instead got `{value}`",
+                    field_type.name()
+                ));
+                return reasons;
+            }
+        }
+
+        if let Some(allowed_values) = &self.allowed_values {
+            if !allowed_values.contains(value) {
+                reasons.push(format!(
+                    "`{field}` must be one of {} but instead got `{value}`",
+                    Value::from(allowed_values.clone())
+                ));
+            }
+        }
+
+        if let Some(number) = value.as_f64() {
+            if let Some(minimum) = self.minimum.as_ref().and_then(Number::as_f64) {
+                if number < minimum {
+                    reasons.push(format!("`{field}` must be greater than or equal to {minimum}"));
+                }
+            }
+            if let Some(maximum) = self.maximum.as_ref().and_then(Number::as_f64) {
+                if number > maximum {
+                    reasons.push(format!("`{field}` must be less than or equal to {maximum}"));
+                }
+            }
+        }
+
+        if let (Some(max_length), Value::String(s)) = (self.max_length, value) {
+            if s.chars().count() > max_length {
+                reasons.push(format!("`{field}` must not be longer than {max_length} characters"));
+            }
+        }
+
+        reasons
+    }
+}
+
+/// The result of checking a document against a [`DocumentSchema`].
+#[derive(Debug, Default)]
+pub struct SchemaCheck {
+    /// The reasons why the document doesn't match the schema, empty when it does.
+    pub violations: Vec<String>,
+    /// The values that were converted to the expected types, by field id of the batch.
+    pub coerced: Vec<(FieldId, Value)>,
+}
+
+impl DocumentSchema {
+    /// Checks that the constraints of this schema can be satisfied.
+    pub fn validate(&self) -> Result<()> {
+        for (field, schema) in &self.properties {
+            if let (Some(minimum), Some(maximum)) = (&schema.minimum, &schema.maximum) {
+                if minimum.as_f64() > maximum.as_f64() {
+                    return Err(UserError::InvalidDocumentSchemaRange {
+                        field: field.clone(),
+                        minimum: minimum.clone(),
+                        maximum: maximum.clone(),
+                    }
+                    .into());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks a document of a batch against this schema, the values are only
+    /// converted to the expected types when the policy is [`SchemaPolicy::Coerce`].
+    ///
+    /// When the document is merged into the one already stored, `is_stored` tells
+    /// whether the stored document has a field, the required fields it has don't
+    /// have to be part of the document.
+    pub fn check(
+        &self,
+        document: &obkv::KvReader<FieldId>,
+        fields_index: &DocumentsBatchIndex,
+        is_stored: impl Fn(&str) -> bool,
+    ) -> Result<SchemaCheck> {
+        let mut check = SchemaCheck::default();
+
+        for field in &self.required {
+            if fields_index.id(field).and_then(|fid| document.get(fid)).is_none()
+                && !is_stored(field)
+            {
+                check.violations.push(format!("`{field}` is required"));
+            }
+        }
+
+        for (field, schema) in &self.properties {
+            let (field_id, bytes) = match fields_index.id(field) {
+                Some(field_id) => match document.get(field_id) {
+                    Some(bytes) => (field_id, bytes),
+                    None => continue,
+                },
+                None => continue,
+            };
+            let value: Value = serde_json::from_slice(bytes).map_err(InternalError::SerdeJson)?;
+
+            let mut reasons = schema.violations(field, &value);
+            if !reasons.is_empty() && self.policy == SchemaPolicy::Coerce {
+                let coerced = schema.field_type.and_then(|field_type| field_type.coerce(&value));
+                if let Some(coerced) = coerced {
+                    reasons = schema.violations(field, &coerced);
+                    if reasons.is_empty() {
+                        check.coerced.push((field_id, coerced));
+                    }
+                }
+            }
+            check.violations.extend(reasons);
+        }
+
+        Ok(check)
+    }
+}
+
+/// A document that didn't match the schema of the index.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaViolation {
+    pub document_id: String,
+    pub reasons: Vec<String>,
+}
+
+/// The documents of an addition that didn't match the schema of the index,
+/// only a bounded sample of them is kept.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaViolations {
+    pub skipped_documents: u64,
+    pub coerced_documents: u64,
+    pub sample: Vec<SchemaViolation>,
+}
+
+impl SchemaViolations {
+    pub fn is_empty(&self) -> bool {
+        self.skipped_documents == 0 && self.coerced_documents == 0
+    }
+
+    pub fn push_skipped(&mut self, document_id: &str, reasons: Vec<String>) {
+        self.skipped_documents += 1;
+        self.push_sample(document_id, reasons);
+    }
+
+    pub fn push_coerced(&mut self, document_id: &str, fields: Vec<String>) {
+        self.coerced_documents += 1;
+        let reasons = fields.into_iter().map(|field| format!("`{field}` was converted")).collect();
+        self.push_sample(document_id, reasons);
+    }
+
+    fn push_sample(&mut self, document_id: &str, reasons: Vec<String>) {
+        if self.sample.len() < SCHEMA_VIOLATIONS_SAMPLE_SIZE {
+            self.sample.push(SchemaViolation { document_id: document_id.to_string(), reasons });
+        }
+    }
+}
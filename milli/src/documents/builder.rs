@@ -142,6 +142,33 @@ impl<W: Write> DocumentsBatchBuilder<W> {
                             to_writer(&mut self.value_buffer, value)?;
                         }
                     }
+                    AllowedType::Boolean => {
+                        if value.trim().is_empty() {
+                            to_writer(&mut self.value_buffer, &Value::Null)?;
+                        } else {
+                            match parse_bool_ignore_case(value.trim()) {
+                                Ok(boolean) => {
+                                    to_writer(&mut self.value_buffer, &boolean)?;
+                                }
+                                Err(error) => {
+                                    return Err(Error::ParseBool {
+                                        error,
+                                        line,
+                                        value: value.to_string(),
+                                    });
+                                }
+                            }
+                        }
+                    }
+                    AllowedType::Array(separator) => {
+                        if value.trim().is_empty() {
+                            to_writer(&mut self.value_buffer, &Value::Null)?;
+                        } else {
+                            let values: Vec<_> =
+                                value.split(separator.as_str()).map(str::trim).collect();
+                            to_writer(&mut self.value_buffer, &values)?;
+                        }
+                    }
                 }
 
                 // We insert into the obkv writer the value buffer that has been filled just above.
@@ -174,14 +201,38 @@ impl<W: Write> DocumentsBatchBuilder<W> {
 enum AllowedType {
     String,
     Number,
+    Boolean,
+    /// A list of strings separated by the given separator.
+    Array(String),
+}
+
+/// Parse a boolean regardless of its case, e.g. `TRUE` or `False`.
+fn parse_bool_ignore_case(value: &str) -> Result<bool, std::str::ParseBoolError> {
+    if value.eq_ignore_ascii_case("true") {
+        Ok(true)
+    } else if value.eq_ignore_ascii_case("false") {
+        Ok(false)
+    } else {
+        value.parse()
+    }
 }
 
 fn parse_csv_header(header: &str) -> (&str, AllowedType) {
+    // the array type is checked first as its separator can contain a colon.
+    if let Some((field_name, separator)) =
+        header.strip_suffix(')').and_then(|header| header.rsplit_once(":array("))
+    {
+        if !separator.is_empty() {
+            return (field_name, AllowedType::Array(separator.to_string()));
+        }
+    }
+
     // if there are several separators we only split on the last one.
     match header.rsplit_once(':') {
         Some((field_name, field_type)) => match field_type {
             "string" => (field_name, AllowedType::String),
             "number" => (field_name, AllowedType::Number),
+            "boolean" => (field_name, AllowedType::Boolean),
             // if the pattern isn't reconized, we keep the whole field.
             _otherwise => (header, AllowedType::String),
         },
@@ -546,6 +597,64 @@ mod test {
         );
     }
 
+    #[test]
+    fn boolean_and_array_in_header() {
+        let csv_content = r#"id:number,available:boolean,tags:array(|),ratios:array(:)
+1,true,a| b |c,1:2
+2, FALSE , ,"3""#;
+        let csv = csv::Reader::from_reader(Cursor::new(csv_content));
+
+        let mut builder = DocumentsBatchBuilder::new(Vec::new());
+        builder.append_csv(csv).unwrap();
+        let vector = builder.into_inner().unwrap();
+
+        let (mut cursor, index) = DocumentsBatchReader::from_reader(Cursor::new(vector))
+            .unwrap()
+            .into_cursor_and_fields_index();
+
+        let doc = cursor.next_document().unwrap().unwrap();
+        let val = obkv_to_object(&doc, &index).map(Value::from).unwrap();
+        assert_eq!(
+            val,
+            json!({ "id": 1, "available": true, "tags": ["a", "b", "c"], "ratios": ["1", "2"] })
+        );
+
+        let doc = cursor.next_document().unwrap().unwrap();
+        let val = obkv_to_object(&doc, &index).map(Value::from).unwrap();
+        assert_eq!(val, json!({ "id": 2, "available": false, "tags": null, "ratios": ["3"] }));
+
+        assert!(cursor.next_document().unwrap().is_none());
+    }
+
+    #[test]
+    fn bad_boolean_in_field() {
+        let csv_content = r#"city,available:boolean
+"Boston","yes""#;
+        let csv = csv::Reader::from_reader(Cursor::new(csv_content));
+
+        let mut builder = DocumentsBatchBuilder::new(Vec::new());
+        assert!(matches!(builder.append_csv(csv), Err(Error::ParseBool { line: 1, .. })));
+    }
+
+    #[test]
+    fn empty_array_separator_in_header() {
+        let csv_content = r#"tags:array()
+"a|b""#;
+        let csv = csv::Reader::from_reader(Cursor::new(csv_content));
+
+        let mut builder = DocumentsBatchBuilder::new(Vec::new());
+        builder.append_csv(csv).unwrap();
+        let vector = builder.into_inner().unwrap();
+
+        let (mut cursor, index) = DocumentsBatchReader::from_reader(Cursor::new(vector))
+            .unwrap()
+            .into_cursor_and_fields_index();
+
+        let doc = cursor.next_document().unwrap().unwrap();
+        let val = obkv_to_object(&doc, &index).map(Value::from).unwrap();
+        assert_eq!(val, json!({ "tags:array()": "a|b" }));
+    }
+
     #[test]
     fn bad_type_in_header() {
         let csv_content = r#"city,country:number,pop
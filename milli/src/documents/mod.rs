@@ -90,6 +90,7 @@ impl DocumentsBatchIndex {
 #[derive(Debug)]
 pub enum Error {
     ParseFloat { error: std::num::ParseFloatError, line: usize, value: String },
+    ParseBool { error: std::str::ParseBoolError, line: usize, value: String },
     InvalidDocumentFormat,
     InvalidEnrichedData,
     InvalidUtf8(Utf8Error),
@@ -136,6 +137,9 @@ impl fmt::Display for Error {
             Error::ParseFloat { error, line, value } => {
                 write!(f, "Error parsing number {:?} at line {}: {}", value, line, error)
             }
+            Error::ParseBool { error, line, value } => {
+                write!(f, "Error parsing boolean {:?} at line {}: {}", value, line, error)
+            }
             Error::InvalidDocumentFormat => {
                 f.write_str("Invalid document addition format, missing the documents batch index.")
             }
@@ -94,12 +94,26 @@ pub enum UserError {
     CriterionError(#[from] CriterionError),
     #[error("Maximum number of documents reached.")]
     DocumentLimitReached,
+    #[error("The document with the id: `{document_id}` doesn't match the schema of the index: {reason}.")]
+    DocumentSchemaMismatch { document_id: Value, reason: String },
+    #[error("The schema of the `{field}` field is invalid: its `minimum` ({minimum}) is greater than its `maximum` ({maximum}).")]
+    InvalidDocumentSchemaRange {
+        field: String,
+        minimum: serde_json::Number,
+        maximum: serde_json::Number,
+    },
+    #[error("The `defaultTtl` setting requires the `ttlAttribute` setting to be set.")]
+    DefaultTtlWithoutTtlField,
+    #[error("The `softDeletedPurgeThreshold` setting must be a percentage between 0 and 100, found `{0}`.")]
+    InvalidSoftDeletedPurgeThreshold(u8),
     #[error(
         "Document identifier `{}` is invalid. \
 A document identifier can be of type integer or string, \
 only composed of alphanumeric characters (a-z A-Z 0-9), hyphens (-) and underscores (_).", .document_id.to_string()
     )]
     InvalidDocumentId { document_id: Value },
+    #[error("The `_version` field in the document with the id: `{document_id}` is invalid. Was expecting a non-negative integer but instead got `{version}`.")]
+    InvalidDocumentVersion { document_id: Value, version: Value },
     #[error("Invalid facet distribution, the fields `{}` are not set as filterable.",
         .invalid_facets_name.iter().map(AsRef::as_ref).collect::<Vec<&str>>().join(", ")
      )]
@@ -108,6 +122,8 @@ only composed of alphanumeric characters (a-z A-Z 0-9), hyphens (-) and undersco
     InvalidGeoField(#[from] GeoError),
     #[error("{0}")]
     InvalidFilter(String),
+    #[error("Invalid type for filter subexpression: `expected {}, found: {1}`.", .0.join(", "))]
+    InvalidFilterExpression(&'static [&'static str], Value),
     #[error("Attribute `{}` is not sortable. {}",
         .field,
         match .valid_fields.is_empty() {
@@ -138,6 +154,8 @@ only composed of alphanumeric characters (a-z A-Z 0-9), hyphens (-) and undersco
     NoSpaceLeftOnDevice,
     #[error("Index already has a primary key: `{0}`.")]
     PrimaryKeyCannotBeChanged(String),
+    #[error("The primary key `{0}` is invalid. A composite primary key must be made of at least one non-empty top-level field, nested fields are not supported.")]
+    InvalidCompositePrimaryKey(String),
     #[error(transparent)]
     SerdeJson(serde_json::Error),
     #[error(transparent)]
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::mem::size_of;
+use std::ops::Bound;
 use std::path::Path;
 
 use heed::flags::Flags;
@@ -19,22 +20,28 @@ use crate::heed_codec::facet::{
     FieldIdCodec, OrderedF64Codec,
 };
 use crate::heed_codec::StrRefCodec;
+use crate::search::facet::find_docids_of_facet_within_bounds;
+use crate::update::{PrimaryKeyDefinition, PrimaryKeyGeneration};
 use crate::{
     default_criteria, BEU32StrCodec, BoRoaringBitmapCodec, CboRoaringBitmapCodec, Criterion,
-    DocumentId, ExternalDocumentsIds, FacetDistribution, FieldDistribution, FieldId,
-    FieldIdWordCountCodec, GeoPoint, ObkvCodec, Result, RoaringBitmapCodec, RoaringBitmapLenCodec,
-    Search, StrBEU32Codec, U8StrStrCodec, BEU16, BEU32,
+    DocumentId, DocumentSchema, ExternalDocumentsIds, FacetDistribution, FieldDistribution,
+    FieldId, FieldIdWordCountCodec, GeoPoint, ObkvCodec, Result, RoaringBitmapCodec,
+    RoaringBitmapLenCodec, Search, StrBEU32Codec, U8StrStrCodec, BEU16, BEU32,
 };
 
 pub const DEFAULT_MIN_WORD_LEN_ONE_TYPO: u8 = 5;
 pub const DEFAULT_MIN_WORD_LEN_TWO_TYPOS: u8 = 9;
 
 pub mod main_key {
+    pub const COMPOSITE_PRIMARY_KEY_KEY: &str = "composite-primary-key";
     pub const CRITERIA_KEY: &str = "criteria";
     pub const DISPLAYED_FIELDS_KEY: &str = "displayed-fields";
     pub const DISTINCT_FIELD_KEY: &str = "distinct-field-key";
+    pub const DEFAULT_TTL_KEY: &str = "default-ttl";
     pub const DOCUMENTS_IDS_KEY: &str = "documents-ids";
+    pub const DOCUMENT_SCHEMA_KEY: &str = "document-schema";
     pub const SOFT_DELETED_DOCUMENTS_IDS_KEY: &str = "soft-deleted-documents-ids";
+    pub const SOFT_DELETED_PURGE_THRESHOLD_KEY: &str = "soft-deleted-purge-threshold";
     pub const HIDDEN_FACETED_FIELDS_KEY: &str = "hidden-faceted-fields";
     pub const FILTERABLE_FIELDS_KEY: &str = "filterable-fields";
     pub const SORTABLE_FIELDS_KEY: &str = "sortable-fields";
@@ -45,12 +52,14 @@ pub mod main_key {
     pub const HARD_EXTERNAL_DOCUMENTS_IDS_KEY: &str = "hard-external-documents-ids";
     pub const NUMBER_FACETED_DOCUMENTS_IDS_PREFIX: &str = "number-faceted-documents-ids";
     pub const PRIMARY_KEY_KEY: &str = "primary-key";
+    pub const PRIMARY_KEY_GENERATION_KEY: &str = "primary-key-generation";
     pub const SEARCHABLE_FIELDS_KEY: &str = "searchable-fields";
     pub const USER_DEFINED_SEARCHABLE_FIELDS_KEY: &str = "user-defined-searchable-fields";
     pub const SOFT_EXTERNAL_DOCUMENTS_IDS_KEY: &str = "soft-external-documents-ids";
     pub const STOP_WORDS_KEY: &str = "stop-words";
     pub const STRING_FACETED_DOCUMENTS_IDS_PREFIX: &str = "string-faceted-documents-ids";
     pub const SYNONYMS_KEY: &str = "synonyms";
+    pub const TTL_FIELD_KEY: &str = "ttl-field";
     pub const WORDS_FST_KEY: &str = "words-fst";
     pub const WORDS_PREFIXES_FST_KEY: &str = "words-prefixes-fst";
     pub const CREATED_AT_KEY: &str = "created-at";
@@ -327,6 +336,42 @@ impl Index {
             .unwrap_or_default())
     }
 
+    /// Returns the number of soft deleted documents, they are still stored in the index
+    /// until they are purged.
+    pub fn number_of_soft_deleted_documents(&self, rtxn: &RoTxn) -> Result<u64> {
+        let count = self
+            .main
+            .get::<_, Str, RoaringBitmapLenCodec>(rtxn, main_key::SOFT_DELETED_DOCUMENTS_IDS_KEY)?;
+        Ok(count.unwrap_or_default())
+    }
+
+    /* soft deleted purge threshold */
+
+    pub(crate) fn put_soft_deleted_purge_threshold(
+        &self,
+        wtxn: &mut RwTxn,
+        percentage: u8,
+    ) -> heed::Result<()> {
+        self.main.put::<_, Str, OwnedType<u8>>(
+            wtxn,
+            main_key::SOFT_DELETED_PURGE_THRESHOLD_KEY,
+            &percentage,
+        )
+    }
+
+    /// Returns the percentage of soft deleted documents above which
+    /// they must be purged, `None` if they are never purged automatically.
+    pub fn soft_deleted_purge_threshold(&self, rtxn: &RoTxn) -> heed::Result<Option<u8>> {
+        self.main.get::<_, Str, OwnedType<u8>>(rtxn, main_key::SOFT_DELETED_PURGE_THRESHOLD_KEY)
+    }
+
+    pub(crate) fn delete_soft_deleted_purge_threshold(
+        &self,
+        wtxn: &mut RwTxn,
+    ) -> heed::Result<bool> {
+        self.main.delete::<_, Str>(wtxn, main_key::SOFT_DELETED_PURGE_THRESHOLD_KEY)
+    }
+
     /* primary key */
 
     /// Writes the documents primary key, this is the field name that is used to store the id.
@@ -345,6 +390,100 @@ impl Index {
         self.main.get::<_, Str, Str>(rtxn, main_key::PRIMARY_KEY_KEY)
     }
 
+    /* composite primary key */
+
+    /// Writes the fields of a composite primary key, in the order their values make the
+    /// documents ids. The primary key itself only stores the name of the composite key.
+    pub(crate) fn put_composite_primary_key(
+        &self,
+        wtxn: &mut RwTxn,
+        fields: &[String],
+    ) -> heed::Result<()> {
+        self.main.put::<_, Str, SerdeJson<&[String]>>(
+            wtxn,
+            main_key::COMPOSITE_PRIMARY_KEY_KEY,
+            &fields,
+        )
+    }
+
+    pub(crate) fn delete_composite_primary_key(&self, wtxn: &mut RwTxn) -> heed::Result<bool> {
+        self.main.delete::<_, Str>(wtxn, main_key::COMPOSITE_PRIMARY_KEY_KEY)
+    }
+
+    /// Returns the fields of the composite primary key, `None` if the primary key
+    /// isn't a composite one.
+    pub fn composite_primary_key(&self, rtxn: &RoTxn) -> heed::Result<Option<Vec<String>>> {
+        self.main.get::<_, Str, SerdeJson<Vec<String>>>(rtxn, main_key::COMPOSITE_PRIMARY_KEY_KEY)
+    }
+
+    /// Returns the definition of the primary key, the fields of a composite primary key
+    /// or the single field of any other, `None` if it hasn't been defined.
+    pub fn primary_key_definition(
+        &self,
+        rtxn: &RoTxn,
+    ) -> heed::Result<Option<PrimaryKeyDefinition>> {
+        match self.composite_primary_key(rtxn)? {
+            Some(fields) => Ok(Some(PrimaryKeyDefinition::Composite(fields))),
+            None => {
+                Ok(self.primary_key(rtxn)?.map(|name| PrimaryKeyDefinition::from(name.to_string())))
+            }
+        }
+    }
+
+    /* primary key generation */
+
+    pub(crate) fn put_primary_key_generation(
+        &self,
+        wtxn: &mut RwTxn,
+        generation: PrimaryKeyGeneration,
+    ) -> heed::Result<()> {
+        self.main.put::<_, Str, SerdeJson<PrimaryKeyGeneration>>(
+            wtxn,
+            main_key::PRIMARY_KEY_GENERATION_KEY,
+            &generation,
+        )
+    }
+
+    /// Returns the kind of ids generated for the documents without a primary key value,
+    /// `None` if those documents must be rejected.
+    pub fn primary_key_generation(
+        &self,
+        rtxn: &RoTxn,
+    ) -> heed::Result<Option<PrimaryKeyGeneration>> {
+        self.main.get::<_, Str, SerdeJson<PrimaryKeyGeneration>>(
+            rtxn,
+            main_key::PRIMARY_KEY_GENERATION_KEY,
+        )
+    }
+
+    pub(crate) fn delete_primary_key_generation(&self, wtxn: &mut RwTxn) -> heed::Result<bool> {
+        self.main.delete::<_, Str>(wtxn, main_key::PRIMARY_KEY_GENERATION_KEY)
+    }
+
+    /* document schema */
+
+    pub(crate) fn put_document_schema(
+        &self,
+        wtxn: &mut RwTxn,
+        schema: &DocumentSchema,
+    ) -> heed::Result<()> {
+        self.main.put::<_, Str, SerdeJson<DocumentSchema>>(
+            wtxn,
+            main_key::DOCUMENT_SCHEMA_KEY,
+            schema,
+        )
+    }
+
+    /// Returns the schema the documents are checked against when they are added or updated,
+    /// `None` if the documents aren't checked.
+    pub fn document_schema(&self, rtxn: &RoTxn) -> heed::Result<Option<DocumentSchema>> {
+        self.main.get::<_, Str, SerdeJson<DocumentSchema>>(rtxn, main_key::DOCUMENT_SCHEMA_KEY)
+    }
+
+    pub(crate) fn delete_document_schema(&self, wtxn: &mut RwTxn) -> heed::Result<bool> {
+        self.main.delete::<_, Str>(wtxn, main_key::DOCUMENT_SCHEMA_KEY)
+    }
+
     /* external documents ids */
 
     /// Writes the external documents ids and internal ids (i.e. `u32`).
@@ -750,7 +889,7 @@ impl Index {
 
     /// Returns the user defined faceted fields names.
     ///
-    /// The user faceted fields are the union of all the filterable, sortable, distinct, and Asc/Desc fields.
+    /// The user faceted fields are the union of all the filterable, sortable, distinct, ttl, and Asc/Desc fields.
     pub fn user_defined_faceted_fields(&self, rtxn: &RoTxn) -> Result<HashSet<String>> {
         let filterable_fields = self.filterable_fields(rtxn)?;
         let sortable_fields = self.sortable_fields(rtxn)?;
@@ -761,12 +900,17 @@ impl Index {
                 _otherwise => None,
             });
 
+        let ttl_field = self.ttl_field(rtxn)?;
+
         let mut faceted_fields = filterable_fields;
         faceted_fields.extend(sortable_fields);
         faceted_fields.extend(asc_desc_fields);
         if let Some(field) = distinct_field {
             faceted_fields.insert(field.to_owned());
         }
+        if let Some(field) = ttl_field {
+            faceted_fields.insert(field.to_owned());
+        }
 
         Ok(faceted_fields)
     }
@@ -856,6 +1000,65 @@ impl Index {
         self.main.delete::<_, Str>(wtxn, main_key::DISTINCT_FIELD_KEY)
     }
 
+    /* ttl field */
+
+    pub(crate) fn put_ttl_field(&self, wtxn: &mut RwTxn, ttl_field: &str) -> heed::Result<()> {
+        self.main.put::<_, Str, Str>(wtxn, main_key::TTL_FIELD_KEY, ttl_field)
+    }
+
+    /// Returns the name of the field containing the expiration date of the documents.
+    pub fn ttl_field<'a>(&self, rtxn: &'a RoTxn) -> heed::Result<Option<&'a str>> {
+        self.main.get::<_, Str, Str>(rtxn, main_key::TTL_FIELD_KEY)
+    }
+
+    pub(crate) fn delete_ttl_field(&self, wtxn: &mut RwTxn) -> heed::Result<bool> {
+        self.main.delete::<_, Str>(wtxn, main_key::TTL_FIELD_KEY)
+    }
+
+    /* default ttl */
+
+    pub(crate) fn put_default_ttl(&self, wtxn: &mut RwTxn, seconds: u64) -> heed::Result<()> {
+        self.main.put::<_, Str, OwnedType<u64>>(wtxn, main_key::DEFAULT_TTL_KEY, &seconds)
+    }
+
+    /// Returns the number of seconds after which the documents that
+    /// don't have a value for the ttl field expire.
+    pub fn default_ttl(&self, rtxn: &RoTxn) -> heed::Result<Option<u64>> {
+        self.main.get::<_, Str, OwnedType<u64>>(rtxn, main_key::DEFAULT_TTL_KEY)
+    }
+
+    pub(crate) fn delete_default_ttl(&self, wtxn: &mut RwTxn) -> heed::Result<bool> {
+        self.main.delete::<_, Str>(wtxn, main_key::DEFAULT_TTL_KEY)
+    }
+
+    /// Returns the ids of the documents whose expiration date, stored in the ttl field
+    /// as a Unix timestamp in seconds or as an RFC 3339 date, is before or at `now`.
+    pub fn expired_documents_ids(
+        &self,
+        rtxn: &RoTxn,
+        now: OffsetDateTime,
+    ) -> Result<RoaringBitmap> {
+        let field_id = match self.ttl_field(rtxn)? {
+            Some(field) => match self.fields_ids_map(rtxn)?.id(field) {
+                Some(field_id) => field_id,
+                None => return Ok(RoaringBitmap::new()),
+            },
+            None => return Ok(RoaringBitmap::new()),
+        };
+
+        let mut expired = RoaringBitmap::new();
+        find_docids_of_facet_within_bounds::<OrderedF64Codec>(
+            rtxn,
+            self.facet_id_f64_docids,
+            field_id,
+            &Bound::Unbounded,
+            &Bound::Included(now.unix_timestamp() as f64),
+            &mut expired,
+        )?;
+
+        Ok(expired & self.documents_ids(rtxn)?)
+    }
+
     /* criteria */
 
     pub(crate) fn put_criteria(
@@ -1198,9 +1401,10 @@ pub(crate) mod tests {
     use heed::{EnvOpenOptions, RwTxn};
     use maplit::hashset;
     use tempfile::TempDir;
+    use time::OffsetDateTime;
 
     use crate::documents::DocumentsBatchReader;
-    use crate::error::{Error, InternalError};
+    use crate::error::{Error, InternalError, UserError};
     use crate::index::{DEFAULT_MIN_WORD_LEN_ONE_TYPO, DEFAULT_MIN_WORD_LEN_TWO_TYPOS};
     use crate::update::{
         self, DeleteDocuments, DeletionStrategy, IndexDocuments, IndexDocumentsConfig,
@@ -2292,4 +2496,59 @@ pub(crate) mod tests {
             assert!(all_ids.insert(id));
         }
     }
+
+    #[test]
+    fn expired_documents_are_hidden_from_search() {
+        let index = TempIndex::new();
+        index
+            .update_settings(|settings| {
+                settings.set_ttl_field(S("expires_at"));
+            })
+            .unwrap();
+
+        index
+            .add_documents(documents!([
+                { "id": 0, "expires_at": 1000 },
+                { "id": 1, "expires_at": "2999-01-01T00:00:00Z" },
+                { "id": 2, "expires_at": "2000-01-01T00:00:00Z" },
+                { "id": 3 },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let expired = index.expired_documents_ids(&rtxn, OffsetDateTime::now_utc()).unwrap();
+        assert_eq!(expired.into_iter().collect::<Vec<_>>(), vec![0, 2]);
+
+        let SearchResult { mut documents_ids, .. } = Search::new(&rtxn, &index).execute().unwrap();
+        documents_ids.sort_unstable();
+        assert_eq!(documents_ids, vec![1, 3]);
+        drop(rtxn);
+
+        // the documents without an expiration date are stamped with the default ttl
+        index
+            .update_settings(|settings| {
+                settings.set_default_ttl(3600);
+            })
+            .unwrap();
+        index.add_documents(documents!([{ "id": 4 }, { "id": 5, "expires_at": 1000 }])).unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let expired = index.expired_documents_ids(&rtxn, OffsetDateTime::now_utc()).unwrap();
+        assert_eq!(expired.into_iter().collect::<Vec<_>>(), vec![0, 2, 5]);
+
+        let in_one_hour = OffsetDateTime::now_utc() + time::Duration::hours(1);
+        let expired = index.expired_documents_ids(&rtxn, in_one_hour).unwrap();
+        assert_eq!(expired.into_iter().collect::<Vec<_>>(), vec![0, 2, 4, 5]);
+    }
+
+    #[test]
+    fn default_ttl_requires_a_ttl_field() {
+        let index = TempIndex::new();
+        let err = index
+            .update_settings(|settings| {
+                settings.set_default_ttl(3600);
+            })
+            .unwrap_err();
+        assert!(matches!(err, Error::UserError(UserError::DefaultTtlWithoutTtlField)));
+    }
 }
@@ -4,6 +4,7 @@ pub mod documents;
 
 mod asc_desc;
 mod criterion;
+mod document_schema;
 mod error;
 mod external_documents_ids;
 pub mod facet;
@@ -22,7 +23,7 @@ use std::collections::{BTreeMap, HashMap};
 use std::convert::{TryFrom, TryInto};
 use std::hash::BuildHasherDefault;
 
-pub use filter_parser::{Condition, FilterCondition, Span, Token};
+pub use filter_parser::{Condition, Error as FilterParserError, FilterCondition, Span, Token};
 use fxhash::{FxHasher32, FxHasher64};
 pub use grenad::CompressionType;
 use serde_json::Value;
@@ -30,6 +31,10 @@ pub use {charabia as tokenizer, heed};
 
 pub use self::asc_desc::{AscDesc, AscDescError, Member, SortError};
 pub use self::criterion::{default_criteria, Criterion, CriterionError};
+pub use self::document_schema::{
+    DocumentSchema, FieldSchema, FieldType, SchemaCheck, SchemaPolicy, SchemaViolation,
+    SchemaViolations, SCHEMA_VIOLATIONS_SAMPLE_SIZE,
+};
 pub use self::error::{
     Error, FieldIdMapMissingEntry, InternalError, SerializationError, UserError,
 };
@@ -88,6 +93,10 @@ pub const MAX_WORD_LENGTH: usize = MAX_LMDB_KEY_LENGTH / 2;
 
 pub const MAX_POSITION_PER_ATTRIBUTE: u32 = u16::MAX as u32 + 1;
 
+/// The field storing the version of a document, a document sent with this field is
+/// only written if its version matches the one of the document stored in the index.
+pub const DOCUMENT_VERSION_FIELD: &str = "_version";
+
 // Convert an absolute word position into a relative position.
 // Return the field id of the attribute related to the absolute position
 // and the relative position in the attribute.
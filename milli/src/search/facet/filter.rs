@@ -5,6 +5,7 @@ use std::ops::Bound::{self, Excluded, Included};
 use either::Either;
 pub use filter_parser::{Condition, Error as FPError, FilterCondition, Span, Token};
 use roaring::RoaringBitmap;
+use serde_json::Value;
 
 use super::facet_range_search;
 use crate::error::{Error, UserError};
@@ -82,6 +83,51 @@ impl<'a> From<Filter<'a>> for FilterCondition<'a> {
 }
 
 impl<'a> Filter<'a> {
+    /// Parses a filter given in its JSON form: either a string expression, or an array of
+    /// string expressions and arrays of string expressions, like in a search request.
+    pub fn from_json(facets: &'a Value) -> Result<Option<Self>> {
+        match facets {
+            Value::String(expr) => Filter::from_str(expr),
+            Value::Array(arr) => Self::parse_filter_array(arr),
+            v => Err(Error::UserError(UserError::InvalidFilterExpression(
+                &["String", "Array"],
+                v.clone(),
+            ))),
+        }
+    }
+
+    fn parse_filter_array(arr: &'a [Value]) -> Result<Option<Self>> {
+        let mut ands = Vec::new();
+        for value in arr {
+            match value {
+                Value::String(s) => ands.push(Either::Right(s.as_str())),
+                Value::Array(arr) => {
+                    let mut ors = Vec::new();
+                    for value in arr {
+                        match value {
+                            Value::String(s) => ors.push(s.as_str()),
+                            v => {
+                                return Err(Error::UserError(UserError::InvalidFilterExpression(
+                                    &["String"],
+                                    v.clone(),
+                                )))
+                            }
+                        }
+                    }
+                    ands.push(Either::Left(ors));
+                }
+                v => {
+                    return Err(Error::UserError(UserError::InvalidFilterExpression(
+                        &["String", "[String]"],
+                        v.clone(),
+                    )))
+                }
+            }
+        }
+
+        Filter::from_array(ands)
+    }
+
     pub fn from_array<I, J>(array: I) -> Result<Option<Self>>
     where
         I: IntoIterator<Item = Either<J, &'a str>>,
@@ -144,6 +190,94 @@ impl<'a> Filter<'a> {
 }
 
 impl<'a> Filter<'a> {
+    /// Checks the attributes and values of the filter against the filterable fields of the
+    /// index without evaluating it. Unlike [`Filter::evaluate`], which stops at the first
+    /// error, every error found in the filter is returned.
+    pub fn validate(&self, filterable_fields: &HashSet<String>) -> Vec<FPError> {
+        let mut errors = Vec::new();
+        Self::inner_validate(&self.condition, filterable_fields, &mut errors);
+        errors
+    }
+
+    fn inner_validate<'b>(
+        condition: &'b FilterCondition<'a>,
+        filterable_fields: &HashSet<String>,
+        errors: &mut Vec<FPError<'b>>,
+    ) {
+        match condition {
+            FilterCondition::Not(f) => Self::inner_validate(f, filterable_fields, errors),
+            FilterCondition::Or(subfilters) | FilterCondition::And(subfilters) => {
+                for f in subfilters {
+                    Self::inner_validate(f, filterable_fields, errors);
+                }
+            }
+            FilterCondition::In { fid, .. } => {
+                if !crate::is_faceted(fid.value(), filterable_fields) {
+                    errors.push(fid.as_external_error(FilterError::AttributeNotFilterable {
+                        attribute: fid.value(),
+                        filterable_fields: filterable_fields.clone(),
+                    }));
+                }
+            }
+            FilterCondition::Condition { fid, op } => {
+                if !crate::is_faceted(fid.value(), filterable_fields) {
+                    let error = match fid.lexeme() {
+                        attribute @ "_geo" => FilterError::BadGeo(attribute),
+                        attribute if attribute.starts_with("_geoPoint(") => {
+                            FilterError::BadGeo("_geoPoint")
+                        }
+                        attribute @ "_geoDistance" => FilterError::Reserved(attribute),
+                        attribute => FilterError::AttributeNotFilterable {
+                            attribute,
+                            filterable_fields: filterable_fields.clone(),
+                        },
+                    };
+                    errors.push(fid.as_external_error(error));
+                }
+
+                let numbers = match op {
+                    Condition::GreaterThan(val)
+                    | Condition::GreaterThanOrEqual(val)
+                    | Condition::LowerThan(val)
+                    | Condition::LowerThanOrEqual(val) => vec![val],
+                    Condition::Between { from, to } => vec![from, to],
+                    Condition::Equal(_) | Condition::NotEqual(_) | Condition::Exists => vec![],
+                };
+                for number in numbers {
+                    if let Err(error) = number.parse_finite_float() {
+                        errors.push(error);
+                    }
+                }
+            }
+            FilterCondition::GeoLowerThan { point, radius } => {
+                if !filterable_fields.contains("_geo") {
+                    errors.push(point[0].as_external_error(FilterError::AttributeNotFilterable {
+                        attribute: "_geo",
+                        filterable_fields: filterable_fields.clone(),
+                    }));
+                }
+
+                match point[0].parse_finite_float() {
+                    Ok(lat) if !(-90.0..=90.0).contains(&lat) => {
+                        errors.push(point[0].as_external_error(FilterError::BadGeoLat(lat)))
+                    }
+                    Ok(_) => (),
+                    Err(error) => errors.push(error),
+                }
+                match point[1].parse_finite_float() {
+                    Ok(lng) if !(-180.0..=180.0).contains(&lng) => {
+                        errors.push(point[1].as_external_error(FilterError::BadGeoLng(lng)))
+                    }
+                    Ok(_) => (),
+                    Err(error) => errors.push(error),
+                }
+                if let Err(error) = radius.parse_finite_float() {
+                    errors.push(error);
+                }
+            }
+        }
+    }
+
     pub fn evaluate(&self, rtxn: &heed::RoTxn, index: &Index) -> Result<RoaringBitmap> {
         // to avoid doing this for each recursive call we're going to do it ONCE ahead of time
         let soft_deleted_documents = index.soft_deleted_documents_ids(rtxn)?;
@@ -491,6 +625,40 @@ mod tests {
         assert_eq!(condition, expected);
     }
 
+    #[test]
+    fn from_json() {
+        let filter = serde_json::json!("channel = mv");
+        let condition = Filter::from_json(&filter).unwrap().unwrap();
+        let expected = Filter::from_str("channel = mv").unwrap().unwrap();
+        assert_eq!(condition, expected);
+
+        let filter =
+            serde_json::json!(["channel = gotaga", ["timestamp = 44", "channel != ponce"]]);
+        let condition = Filter::from_json(&filter).unwrap().unwrap();
+        let expected =
+            Filter::from_str("channel = gotaga AND (timestamp = 44 OR channel != ponce)")
+                .unwrap()
+                .unwrap();
+        assert_eq!(condition, expected);
+
+        let filter = serde_json::json!([]);
+        assert!(Filter::from_json(&filter).unwrap().is_none());
+
+        let filter = serde_json::json!(["channel = gotaga", [42]]);
+        let error = Filter::from_json(&filter).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid type for filter subexpression: `expected String, found: 42`."
+        );
+
+        let filter = serde_json::json!(true);
+        let error = Filter::from_json(&filter).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid type for filter subexpression: `expected String, Array, found: true`."
+        );
+    }
+
     #[test]
     fn not_filterable() {
         let index = TempIndex::new();
@@ -625,6 +793,31 @@ mod tests {
         assert_eq!(documents_ids, vec![0]);
     }
 
+    #[test]
+    fn validate_reports_every_error() {
+        let filterable_fields = hashset! { S("price") };
+
+        let filter = Filter::from_str("price > 10 AND price < 100").unwrap().unwrap();
+        assert!(filter.validate(&filterable_fields).is_empty());
+
+        let filter = Filter::from_str("title = foo AND price > bar OR _geoRadius(-100, 10, 5)")
+            .unwrap()
+            .unwrap();
+        let errors: Vec<_> =
+            filter.validate(&filterable_fields).iter().map(|e| e.to_string()).collect();
+        assert_eq!(errors.len(), 4, "{:?}", errors);
+        assert!(errors[0].starts_with(
+            "Attribute `title` is not filterable. Available filterable attributes are: `price`."
+        ));
+        assert!(errors[1].ends_with("25:28 title = foo AND price > bar OR _geoRadius(-100, 10, 5)"));
+        assert!(errors[2].starts_with(
+            "Attribute `_geo` is not filterable. Available filterable attributes are: `price`."
+        ));
+        assert!(errors[3].starts_with(
+            "Bad latitude `-100`. Latitude must be contained between -90 and 90 degrees."
+        ));
+    }
+
     #[test]
     fn geo_radius_error() {
         let index = TempIndex::new();
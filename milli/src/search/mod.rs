@@ -14,6 +14,7 @@ use levenshtein_automata::{LevenshteinAutomatonBuilder as LevBuilder, DFA};
 use log::debug;
 use once_cell::sync::Lazy;
 use roaring::bitmap::RoaringBitmap;
+use time::OffsetDateTime;
 
 pub use self::facet::{FacetDistribution, Filter, DEFAULT_VALUES_PER_FACET};
 use self::fst_utils::{Complement, Intersection, StartsWith, Union};
@@ -250,6 +251,9 @@ impl<'a> Search<'a> {
         let mut offset = self.offset;
         let mut initial_candidates = InitialCandidates::Estimated(RoaringBitmap::new());
         let mut excluded_candidates = self.index.soft_deleted_documents_ids(self.rtxn)?;
+        // The expired documents are hidden even before they are deleted.
+        excluded_candidates |=
+            self.index.expired_documents_ids(self.rtxn, OffsetDateTime::now_utc())?;
         let mut documents_ids = Vec::new();
 
         while let Some(FinalResult { candidates, initial_candidates: ic, .. }) =
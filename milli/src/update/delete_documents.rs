@@ -224,296 +224,336 @@ impl<'t, 'u, 'i> DeleteDocuments<'t, 'u, 'i> {
         }
 
         self.to_delete_docids = soft_deleted_docids;
+        purge_documents(self.wtxn, self.index, &self.to_delete_docids)?;
 
-        let Index {
-            env: _env,
-            main: _main,
-            word_docids,
-            exact_word_docids,
-            word_prefix_docids,
-            exact_word_prefix_docids,
-            docid_word_positions,
-            word_pair_proximity_docids,
-            field_id_word_count_docids,
-            word_prefix_pair_proximity_docids,
-            prefix_word_pair_proximity_docids,
-            word_position_docids,
-            word_prefix_position_docids,
-            facet_id_f64_docids: _,
-            facet_id_string_docids: _,
-            field_id_docid_facet_f64s: _,
-            field_id_docid_facet_strings: _,
-            facet_id_exists_docids,
-            documents,
-        } = self.index;
-
-        // Retrieve the words contained in the documents.
-        let mut words = Vec::new();
-        for docid in &self.to_delete_docids {
-            documents.delete(self.wtxn, &BEU32::new(docid))?;
-
-            // We iterate through the words positions of the document id, retrieve the word and delete the positions.
-            // We create an iterator to be able to get the content and delete the key-value itself.
-            // It's faster to acquire a cursor to get and delete, as we avoid traversing the LMDB B-Tree two times but only once.
-            let mut iter = docid_word_positions.prefix_iter_mut(self.wtxn, &(docid, ""))?;
-            while let Some(result) = iter.next() {
-                let ((_docid, word), _positions) = result?;
-                // This boolean will indicate if we must remove this word from the words FST.
-                words.push((SmallString32::from(word), false));
-                // safety: we don't keep references from inside the LMDB database.
-                unsafe { iter.del_current()? };
-            }
+        Ok(DetailedDocumentDeletionResult {
+            deleted_documents: self.to_delete_docids.len(),
+            remaining_documents: documents_ids.len(),
+            soft_deletion_used: false,
+        })
+    }
+}
+
+/// Physically removes soft-deleted documents from all the databases of an index.
+///
+/// The soft-deleted documents are purged by chunks of at most `max_documents` documents,
+/// so that a big purge can be split across several transactions.
+pub struct PurgeSoftDeletedDocuments<'t, 'u, 'i> {
+    wtxn: &'t mut heed::RwTxn<'i, 'u>,
+    index: &'i Index,
+    max_documents: Option<u64>,
+}
+
+impl<'t, 'u, 'i> PurgeSoftDeletedDocuments<'t, 'u, 'i> {
+    pub fn new(
+        wtxn: &'t mut heed::RwTxn<'i, 'u>,
+        index: &'i Index,
+    ) -> PurgeSoftDeletedDocuments<'t, 'u, 'i> {
+        PurgeSoftDeletedDocuments { wtxn, index, max_documents: None }
+    }
+
+    pub fn max_documents(&mut self, max_documents: u64) {
+        self.max_documents = Some(max_documents);
+    }
+
+    /// Returns the number of purged documents.
+    pub fn execute(self) -> Result<u64> {
+        let soft_deleted_docids = self.index.soft_deleted_documents_ids(self.wtxn)?;
+        if soft_deleted_docids.is_empty() {
+            return Ok(0);
         }
-        // We acquire the current external documents ids map...
-        // Note that its soft-deleted document ids field will be equal to the `to_delete_docids`
-        let mut new_external_documents_ids = self.index.external_documents_ids(self.wtxn)?;
-        // We then remove the soft-deleted docids from it
-        new_external_documents_ids.delete_soft_deleted_documents_ids_from_fsts()?;
-        // and write it back to the main database.
-        let new_external_documents_ids = new_external_documents_ids.into_static();
-        self.index.put_external_documents_ids(self.wtxn, &new_external_documents_ids)?;
-
-        // Maybe we can improve the get performance of the words
-        // if we sort the words first, keeping the LMDB pages in cache.
-        words.sort_unstable();
-
-        // We iterate over the words and delete the documents ids
-        // from the word docids database.
-        for (word, must_remove) in &mut words {
-            remove_from_word_docids(
-                self.wtxn,
-                word_docids,
-                word.as_str(),
-                must_remove,
-                &self.to_delete_docids,
-            )?;
 
-            remove_from_word_docids(
-                self.wtxn,
-                exact_word_docids,
-                word.as_str(),
-                must_remove,
-                &self.to_delete_docids,
-            )?;
+        // An index only containing soft-deleted documents can be cleared in one go.
+        if self.index.number_of_documents(self.wtxn)? == 0 {
+            ClearDocuments::new(self.wtxn, self.index).execute()?;
+            return Ok(soft_deleted_docids.len());
         }
 
-        // We construct an FST set that contains the words to delete from the words FST.
-        let words_to_delete =
-            words.iter().filter_map(
-                |(word, must_remove)| {
-                    if *must_remove {
-                        Some(word.as_str())
-                    } else {
-                        None
-                    }
-                },
-            );
-        let words_to_delete = fst::Set::from_iter(words_to_delete)?;
+        let to_purge = match self.max_documents {
+            Some(max_documents) => {
+                soft_deleted_docids.iter().take(max_documents as usize).collect()
+            }
+            None => soft_deleted_docids,
+        };
+        purge_documents(self.wtxn, self.index, &to_purge)?;
 
-        let new_words_fst = {
-            // We retrieve the current words FST from the database.
-            let words_fst = self.index.words_fst(self.wtxn)?;
-            let difference = words_fst.op().add(&words_to_delete).difference();
+        Ok(to_purge.len())
+    }
+}
 
-            // We stream the new external ids that does no more contains the to-delete external ids.
-            let mut new_words_fst_builder = fst::SetBuilder::memory();
-            new_words_fst_builder.extend_stream(difference.into_stream())?;
+/// Removes the given soft-deleted documents from all the databases of the index,
+/// the other soft-deleted documents stay soft-deleted.
+fn purge_documents(
+    wtxn: &mut heed::RwTxn,
+    index: &Index,
+    to_delete_docids: &RoaringBitmap,
+) -> Result<()> {
+    let Index {
+        env: _env,
+        main: _main,
+        word_docids,
+        exact_word_docids,
+        word_prefix_docids,
+        exact_word_prefix_docids,
+        docid_word_positions,
+        word_pair_proximity_docids,
+        field_id_word_count_docids,
+        word_prefix_pair_proximity_docids,
+        prefix_word_pair_proximity_docids,
+        word_position_docids,
+        word_prefix_position_docids,
+        facet_id_f64_docids: _,
+        facet_id_string_docids: _,
+        field_id_docid_facet_f64s: _,
+        field_id_docid_facet_strings: _,
+        facet_id_exists_docids,
+        documents,
+    } = index;
+
+    // Retrieve the words contained in the documents.
+    let mut words = Vec::new();
+    for docid in to_delete_docids {
+        documents.delete(wtxn, &BEU32::new(docid))?;
+
+        // We iterate through the words positions of the document id, retrieve the word and delete the positions.
+        // We create an iterator to be able to get the content and delete the key-value itself.
+        // It's faster to acquire a cursor to get and delete, as we avoid traversing the LMDB B-Tree two times but only once.
+        let mut iter = docid_word_positions.prefix_iter_mut(wtxn, &(docid, ""))?;
+        while let Some(result) = iter.next() {
+            let ((_docid, word), _positions) = result?;
+            // This boolean will indicate if we must remove this word from the words FST.
+            words.push((SmallString32::from(word), false));
+            // safety: we don't keep references from inside the LMDB database.
+            unsafe { iter.del_current()? };
+        }
+    }
+    // We acquire the current external documents ids map...
+    // Note that its soft-deleted document ids field contains all the soft-deleted documents,
+    // not only the `to_delete_docids`, the external ids of the documents that stay soft-deleted
+    // are dropped too, they can't be retrieved anyway.
+    let mut new_external_documents_ids = index.external_documents_ids(wtxn)?;
+    // We then remove the soft-deleted docids from it
+    new_external_documents_ids.delete_soft_deleted_documents_ids_from_fsts()?;
+    // and write it back to the main database.
+    let new_external_documents_ids = new_external_documents_ids.into_static();
+    index.put_external_documents_ids(wtxn, &new_external_documents_ids)?;
+
+    // Maybe we can improve the get performance of the words
+    // if we sort the words first, keeping the LMDB pages in cache.
+    words.sort_unstable();
+
+    // We iterate over the words and delete the documents ids
+    // from the word docids database.
+    for (word, must_remove) in &mut words {
+        remove_from_word_docids(wtxn, word_docids, word.as_str(), must_remove, to_delete_docids)?;
+
+        remove_from_word_docids(
+            wtxn,
+            exact_word_docids,
+            word.as_str(),
+            must_remove,
+            to_delete_docids,
+        )?;
+    }
 
-            // We create an words FST set from the above builder.
-            new_words_fst_builder.into_set()
-        };
+    // We construct an FST set that contains the words to delete from the words FST.
+    let words_to_delete =
+        words.iter().filter_map(
+            |(word, must_remove)| {
+                if *must_remove {
+                    Some(word.as_str())
+                } else {
+                    None
+                }
+            },
+        );
+    let words_to_delete = fst::Set::from_iter(words_to_delete)?;
 
-        // We write the new words FST into the main database.
-        self.index.put_words_fst(self.wtxn, &new_words_fst)?;
+    let new_words_fst = {
+        // We retrieve the current words FST from the database.
+        let words_fst = index.words_fst(wtxn)?;
+        let difference = words_fst.op().add(&words_to_delete).difference();
 
-        let prefixes_to_delete =
-            remove_from_word_prefix_docids(self.wtxn, word_prefix_docids, &self.to_delete_docids)?;
+        // We stream the new external ids that does no more contains the to-delete external ids.
+        let mut new_words_fst_builder = fst::SetBuilder::memory();
+        new_words_fst_builder.extend_stream(difference.into_stream())?;
 
-        let exact_prefix_to_delete = remove_from_word_prefix_docids(
-            self.wtxn,
-            exact_word_prefix_docids,
-            &self.to_delete_docids,
-        )?;
+        // We create an words FST set from the above builder.
+        new_words_fst_builder.into_set()
+    };
 
-        let all_prefixes_to_delete = prefixes_to_delete.op().add(&exact_prefix_to_delete).union();
+    // We write the new words FST into the main database.
+    index.put_words_fst(wtxn, &new_words_fst)?;
 
-        // We compute the new prefix FST and write it only if there is a change.
-        if !prefixes_to_delete.is_empty() || !exact_prefix_to_delete.is_empty() {
-            let new_words_prefixes_fst = {
-                // We retrieve the current words prefixes FST from the database.
-                let words_prefixes_fst = self.index.words_prefixes_fst(self.wtxn)?;
-                let difference =
-                    words_prefixes_fst.op().add(all_prefixes_to_delete.into_stream()).difference();
+    let prefixes_to_delete =
+        remove_from_word_prefix_docids(wtxn, word_prefix_docids, to_delete_docids)?;
 
-                // We stream the new external ids that does no more contains the to-delete external ids.
-                let mut new_words_prefixes_fst_builder = fst::SetBuilder::memory();
-                new_words_prefixes_fst_builder.extend_stream(difference.into_stream())?;
+    let exact_prefix_to_delete =
+        remove_from_word_prefix_docids(wtxn, exact_word_prefix_docids, to_delete_docids)?;
 
-                // We create an words FST set from the above builder.
-                new_words_prefixes_fst_builder.into_set()
-            };
+    let all_prefixes_to_delete = prefixes_to_delete.op().add(&exact_prefix_to_delete).union();
 
-            // We write the new words prefixes FST into the main database.
-            self.index.put_words_prefixes_fst(self.wtxn, &new_words_prefixes_fst)?;
-        }
+    // We compute the new prefix FST and write it only if there is a change.
+    if !prefixes_to_delete.is_empty() || !exact_prefix_to_delete.is_empty() {
+        let new_words_prefixes_fst = {
+            // We retrieve the current words prefixes FST from the database.
+            let words_prefixes_fst = index.words_prefixes_fst(wtxn)?;
+            let difference =
+                words_prefixes_fst.op().add(all_prefixes_to_delete.into_stream()).difference();
 
-        for db in [word_prefix_pair_proximity_docids, prefix_word_pair_proximity_docids] {
-            // We delete the documents ids from the word prefix pair proximity database docids
-            // and remove the empty pairs too.
-            let db = db.remap_key_type::<ByteSlice>();
-            let mut iter = db.iter_mut(self.wtxn)?;
-            while let Some(result) = iter.next() {
-                let (key, mut docids) = result?;
-                let previous_len = docids.len();
-                docids -= &self.to_delete_docids;
-                if docids.is_empty() {
-                    // safety: we don't keep references from inside the LMDB database.
-                    unsafe { iter.del_current()? };
-                } else if docids.len() != previous_len {
-                    let key = key.to_owned();
-                    // safety: we don't keep references from inside the LMDB database.
-                    unsafe { iter.put_current(&key, &docids)? };
-                }
-            }
-        }
+            // We stream the new external ids that does no more contains the to-delete external ids.
+            let mut new_words_prefixes_fst_builder = fst::SetBuilder::memory();
+            new_words_prefixes_fst_builder.extend_stream(difference.into_stream())?;
+
+            // We create an words FST set from the above builder.
+            new_words_prefixes_fst_builder.into_set()
+        };
+
+        // We write the new words prefixes FST into the main database.
+        index.put_words_prefixes_fst(wtxn, &new_words_prefixes_fst)?;
+    }
 
-        // We delete the documents ids that are under the pairs of words,
-        // it is faster and use no memory to iterate over all the words pairs than
-        // to compute the cartesian product of every words of the deleted documents.
-        let mut iter =
-            word_pair_proximity_docids.remap_key_type::<ByteSlice>().iter_mut(self.wtxn)?;
+    for db in [word_prefix_pair_proximity_docids, prefix_word_pair_proximity_docids] {
+        // We delete the documents ids from the word prefix pair proximity database docids
+        // and remove the empty pairs too.
+        let db = db.remap_key_type::<ByteSlice>();
+        let mut iter = db.iter_mut(wtxn)?;
         while let Some(result) = iter.next() {
-            let (bytes, mut docids) = result?;
+            let (key, mut docids) = result?;
             let previous_len = docids.len();
-            docids -= &self.to_delete_docids;
+            docids -= to_delete_docids;
             if docids.is_empty() {
                 // safety: we don't keep references from inside the LMDB database.
                 unsafe { iter.del_current()? };
             } else if docids.len() != previous_len {
-                let bytes = bytes.to_owned();
+                let key = key.to_owned();
                 // safety: we don't keep references from inside the LMDB database.
-                unsafe { iter.put_current(&bytes, &docids)? };
+                unsafe { iter.put_current(&key, &docids)? };
             }
         }
+    }
 
-        drop(iter);
+    // We delete the documents ids that are under the pairs of words,
+    // it is faster and use no memory to iterate over all the words pairs than
+    // to compute the cartesian product of every words of the deleted documents.
+    let mut iter = word_pair_proximity_docids.remap_key_type::<ByteSlice>().iter_mut(wtxn)?;
+    while let Some(result) = iter.next() {
+        let (bytes, mut docids) = result?;
+        let previous_len = docids.len();
+        docids -= to_delete_docids;
+        if docids.is_empty() {
+            // safety: we don't keep references from inside the LMDB database.
+            unsafe { iter.del_current()? };
+        } else if docids.len() != previous_len {
+            let bytes = bytes.to_owned();
+            // safety: we don't keep references from inside the LMDB database.
+            unsafe { iter.put_current(&bytes, &docids)? };
+        }
+    }
 
-        // We delete the documents ids that are under the word level position docids.
-        let mut iter = word_position_docids.iter_mut(self.wtxn)?.remap_key_type::<ByteSlice>();
-        while let Some(result) = iter.next() {
-            let (bytes, mut docids) = result?;
-            let previous_len = docids.len();
-            docids -= &self.to_delete_docids;
-            if docids.is_empty() {
-                // safety: we don't keep references from inside the LMDB database.
-                unsafe { iter.del_current()? };
-            } else if docids.len() != previous_len {
-                let bytes = bytes.to_owned();
-                // safety: we don't keep references from inside the LMDB database.
-                unsafe { iter.put_current(&bytes, &docids)? };
-            }
+    drop(iter);
+
+    // We delete the documents ids that are under the word level position docids.
+    let mut iter = word_position_docids.iter_mut(wtxn)?.remap_key_type::<ByteSlice>();
+    while let Some(result) = iter.next() {
+        let (bytes, mut docids) = result?;
+        let previous_len = docids.len();
+        docids -= to_delete_docids;
+        if docids.is_empty() {
+            // safety: we don't keep references from inside the LMDB database.
+            unsafe { iter.del_current()? };
+        } else if docids.len() != previous_len {
+            let bytes = bytes.to_owned();
+            // safety: we don't keep references from inside the LMDB database.
+            unsafe { iter.put_current(&bytes, &docids)? };
         }
+    }
 
-        drop(iter);
+    drop(iter);
 
-        // We delete the documents ids that are under the word prefix level position docids.
-        let mut iter =
-            word_prefix_position_docids.iter_mut(self.wtxn)?.remap_key_type::<ByteSlice>();
-        while let Some(result) = iter.next() {
-            let (bytes, mut docids) = result?;
-            let previous_len = docids.len();
-            docids -= &self.to_delete_docids;
-            if docids.is_empty() {
-                // safety: we don't keep references from inside the LMDB database.
-                unsafe { iter.del_current()? };
-            } else if docids.len() != previous_len {
-                let bytes = bytes.to_owned();
-                // safety: we don't keep references from inside the LMDB database.
-                unsafe { iter.put_current(&bytes, &docids)? };
-            }
+    // We delete the documents ids that are under the word prefix level position docids.
+    let mut iter = word_prefix_position_docids.iter_mut(wtxn)?.remap_key_type::<ByteSlice>();
+    while let Some(result) = iter.next() {
+        let (bytes, mut docids) = result?;
+        let previous_len = docids.len();
+        docids -= to_delete_docids;
+        if docids.is_empty() {
+            // safety: we don't keep references from inside the LMDB database.
+            unsafe { iter.del_current()? };
+        } else if docids.len() != previous_len {
+            let bytes = bytes.to_owned();
+            // safety: we don't keep references from inside the LMDB database.
+            unsafe { iter.put_current(&bytes, &docids)? };
         }
+    }
 
-        drop(iter);
+    drop(iter);
 
-        // Remove the documents ids from the field id word count database.
-        let mut iter = field_id_word_count_docids.iter_mut(self.wtxn)?;
-        while let Some((key, mut docids)) = iter.next().transpose()? {
-            let previous_len = docids.len();
-            docids -= &self.to_delete_docids;
-            if docids.is_empty() {
-                // safety: we don't keep references from inside the LMDB database.
-                unsafe { iter.del_current()? };
-            } else if docids.len() != previous_len {
-                let key = key.to_owned();
-                // safety: we don't keep references from inside the LMDB database.
-                unsafe { iter.put_current(&key, &docids)? };
-            }
+    // Remove the documents ids from the field id word count database.
+    let mut iter = field_id_word_count_docids.iter_mut(wtxn)?;
+    while let Some((key, mut docids)) = iter.next().transpose()? {
+        let previous_len = docids.len();
+        docids -= to_delete_docids;
+        if docids.is_empty() {
+            // safety: we don't keep references from inside the LMDB database.
+            unsafe { iter.del_current()? };
+        } else if docids.len() != previous_len {
+            let key = key.to_owned();
+            // safety: we don't keep references from inside the LMDB database.
+            unsafe { iter.put_current(&key, &docids)? };
         }
+    }
 
-        drop(iter);
+    drop(iter);
 
-        if let Some(mut rtree) = self.index.geo_rtree(self.wtxn)? {
-            let mut geo_faceted_doc_ids = self.index.geo_faceted_documents_ids(self.wtxn)?;
+    if let Some(mut rtree) = index.geo_rtree(wtxn)? {
+        let mut geo_faceted_doc_ids = index.geo_faceted_documents_ids(wtxn)?;
 
-            let (points_to_remove, docids_to_remove): (Vec<_>, RoaringBitmap) = rtree
-                .iter()
-                .filter(|&point| self.to_delete_docids.contains(point.data.0))
-                .cloned()
-                .map(|point| (point, point.data.0))
-                .unzip();
-            points_to_remove.iter().for_each(|point| {
-                rtree.remove(point);
-            });
-            geo_faceted_doc_ids -= docids_to_remove;
+        let (points_to_remove, docids_to_remove): (Vec<_>, RoaringBitmap) = rtree
+            .iter()
+            .filter(|&point| to_delete_docids.contains(point.data.0))
+            .cloned()
+            .map(|point| (point, point.data.0))
+            .unzip();
+        points_to_remove.iter().for_each(|point| {
+            rtree.remove(point);
+        });
+        geo_faceted_doc_ids -= docids_to_remove;
 
-            self.index.put_geo_rtree(self.wtxn, &rtree)?;
-            self.index.put_geo_faceted_documents_ids(self.wtxn, &geo_faceted_doc_ids)?;
-        }
+        index.put_geo_rtree(wtxn, &rtree)?;
+        index.put_geo_faceted_documents_ids(wtxn, &geo_faceted_doc_ids)?;
+    }
 
-        for facet_type in [FacetType::Number, FacetType::String] {
-            let mut affected_facet_values = HashMap::new();
-            for field_id in self.index.faceted_fields_ids(self.wtxn)? {
-                // Remove docids from the number faceted documents ids
-                let mut docids =
-                    self.index.faceted_documents_ids(self.wtxn, field_id, facet_type)?;
-                docids -= &self.to_delete_docids;
-                self.index.put_faceted_documents_ids(self.wtxn, field_id, facet_type, &docids)?;
-
-                let facet_values = remove_docids_from_field_id_docid_facet_value(
-                    self.index,
-                    self.wtxn,
-                    facet_type,
-                    field_id,
-                    &self.to_delete_docids,
-                )?;
-                if !facet_values.is_empty() {
-                    affected_facet_values.insert(field_id, facet_values);
-                }
-            }
-            FacetsDelete::new(
-                self.index,
+    for facet_type in [FacetType::Number, FacetType::String] {
+        let mut affected_facet_values = HashMap::new();
+        for field_id in index.faceted_fields_ids(wtxn)? {
+            // Remove docids from the number faceted documents ids
+            let mut docids = index.faceted_documents_ids(wtxn, field_id, facet_type)?;
+            docids -= to_delete_docids;
+            index.put_faceted_documents_ids(wtxn, field_id, facet_type, &docids)?;
+
+            let facet_values = remove_docids_from_field_id_docid_facet_value(
+                index,
+                wtxn,
                 facet_type,
-                affected_facet_values,
-                &self.to_delete_docids,
-            )
-            .execute(self.wtxn)?;
+                field_id,
+                to_delete_docids,
+            )?;
+            if !facet_values.is_empty() {
+                affected_facet_values.insert(field_id, facet_values);
+            }
         }
+        FacetsDelete::new(index, facet_type, affected_facet_values, to_delete_docids)
+            .execute(wtxn)?;
+    }
 
-        // We delete the documents ids that are under the facet field id values.
-        remove_docids_from_facet_id_exists_docids(
-            self.wtxn,
-            facet_id_exists_docids,
-            &self.to_delete_docids,
-        )?;
+    // We delete the documents ids that are under the facet field id values.
+    remove_docids_from_facet_id_exists_docids(wtxn, facet_id_exists_docids, to_delete_docids)?;
 
-        self.index.put_soft_deleted_documents_ids(self.wtxn, &RoaringBitmap::new())?;
+    let soft_deleted_docids = index.soft_deleted_documents_ids(wtxn)? - to_delete_docids;
+    index.put_soft_deleted_documents_ids(wtxn, &soft_deleted_docids)?;
 
-        Ok(DetailedDocumentDeletionResult {
-            deleted_documents: self.to_delete_docids.len(),
-            remaining_documents: documents_ids.len(),
-            soft_deletion_used: false,
-        })
-    }
+    Ok(())
 }
 
 fn remove_from_word_prefix_docids(
@@ -1166,4 +1206,49 @@ mod tests {
         stats_should_not_return_deleted_documents_(DeletionStrategy::AlwaysHard);
         stats_should_not_return_deleted_documents_(DeletionStrategy::AlwaysSoft);
     }
+
+    #[test]
+    fn purge_soft_deleted_documents_by_chunks() {
+        let index = TempIndex::new();
+
+        let mut wtxn = index.write_txn().unwrap();
+        index
+            .update_settings_using_wtxn(&mut wtxn, |settings| {
+                settings.set_primary_key(S("id"));
+                settings.set_filterable_fields(hashset! { S("color") });
+            })
+            .unwrap();
+        index
+            .add_documents_using_wtxn(
+                &mut wtxn,
+                documents!([
+                    { "id": 0, "name": "kevin", "color": "blue" },
+                    { "id": 1, "name": "kevina", "color": "red" },
+                    { "id": 2, "name": "benoit", "color": "blue" },
+                    { "id": 3, "name": "tamo", "color": "green" },
+                    { "id": 4, "name": "loic", "color": "red" }
+                ]),
+            )
+            .unwrap();
+        delete_documents(&mut wtxn, &index, &["0", "1", "2"], DeletionStrategy::AlwaysSoft);
+        assert_eq!(index.soft_deleted_documents_ids(&wtxn).unwrap().len(), 3);
+
+        let mut builder = PurgeSoftDeletedDocuments::new(&mut wtxn, &index);
+        builder.max_documents(2);
+        assert_eq!(builder.execute().unwrap(), 2);
+        assert_eq!(index.soft_deleted_documents_ids(&wtxn).unwrap().len(), 1);
+
+        // The purged documents are gone from the databases, the others are still there.
+        assert_eq!(index.number_of_documents(&wtxn).unwrap(), 2);
+        let results = index.search(&wtxn).query("kevin").execute().unwrap();
+        assert!(results.documents_ids.is_empty());
+        let filter = Filter::from_str("color = red").unwrap().unwrap();
+        let results = index.search(&wtxn).filter(filter).execute().unwrap();
+        assert_eq!(results.documents_ids, vec![4]);
+
+        assert_eq!(PurgeSoftDeletedDocuments::new(&mut wtxn, &index).execute().unwrap(), 1);
+        assert!(index.soft_deleted_documents_ids(&wtxn).unwrap().is_empty());
+        assert!(index.documents.get(&wtxn, &BEU32::new(2)).unwrap().is_none());
+        assert_eq!(PurgeSoftDeletedDocuments::new(&mut wtxn, &index).execute().unwrap(), 0);
+    }
 }
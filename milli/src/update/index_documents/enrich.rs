@@ -1,5 +1,7 @@
+use std::collections::HashSet;
 use std::io::{Read, Seek};
 use std::result::Result as StdResult;
+use std::time::{SystemTime, UNIX_EPOCH};
 use std::{fmt, iter};
 
 use serde::{Deserialize, Serialize};
@@ -7,20 +9,32 @@ use serde_json::Value;
 
 use crate::documents::{DocumentsBatchIndex, DocumentsBatchReader, EnrichedDocumentsBatchReader};
 use crate::error::{GeoError, InternalError, UserError};
-use crate::update::index_documents::{obkv_to_object, writer_into_reader};
-use crate::{FieldId, Index, Object, Result};
+use crate::update::index_documents::{obkv_to_object, writer_into_reader, IndexDocumentsMethod};
+use crate::{FieldId, Index, Object, Result, SchemaPolicy, BEU32, DOCUMENT_VERSION_FIELD};
 
 /// The symbol used to define levels in a nested primary key.
 const PRIMARY_KEY_SPLIT_SYMBOL: char = '.';
 
+/// The symbol used to separate the fields of a composite primary key in its name.
+const COMPOSITE_PRIMARY_KEY_SEPARATOR: &str = ",";
+
+/// The symbol used to separate the values of the fields of a composite primary key
+/// in the external document id, the occurrences of it in the values are escaped.
+const COMPOSITE_DOCUMENT_ID_SEPARATOR: char = '-';
+
+/// The symbol used to escape the separator in the values of a composite document id.
+const COMPOSITE_DOCUMENT_ID_ESCAPE: char = '_';
+
 /// The default primary that is used when not specified.
 const DEFAULT_PRIMARY_KEY: &str = "id";
 
 /// This function validates and enrich the documents by checking that:
-///  - we can infer a primary key,
+///  - we can infer a primary key or generate the missing ones,
 ///  - all the documents id exist and are extracted,
 ///  - the validity of them but also,
-///  - the validity of the `_geo` field depending on the settings.
+///  - the validity of the `_geo` field depending on the settings,
+///  - the validity of the `_version` field,
+///  - the documents match the schema of the index when it rejects the whole batch.
 ///
 /// # Panics
 ///
@@ -29,6 +43,7 @@ pub fn enrich_documents_batch<R: Read + Seek>(
     rtxn: &heed::RoTxn,
     index: &Index,
     autogenerate_docids: bool,
+    index_documents_method: IndexDocumentsMethod,
     reader: DocumentsBatchReader<R>,
 ) -> Result<StdResult<EnrichedDocumentsBatchReader<R>, UserError>> {
     let (mut cursor, mut documents_batch_index) = reader.into_cursor_and_fields_index();
@@ -36,15 +51,25 @@ pub fn enrich_documents_batch<R: Read + Seek>(
     let mut external_ids = tempfile::tempfile().map(grenad::Writer::new)?;
     let mut uuid_buffer = [0; uuid::fmt::Hyphenated::LENGTH];
 
+    // The kind of ids generated for the documents that don't have one, the setting of
+    // the index takes precedence over the `autogenerate_docids` indexing option.
+    let generation = match index.primary_key_generation(rtxn)? {
+        Some(generation) => Some(generation),
+        None if autogenerate_docids => Some(PrimaryKeyGeneration::UuidV4),
+        None => None,
+    };
+
     // The primary key *field id* that has already been set for this index or the one
     // we will guess by searching for the first key that contains "id" as a substring.
-    let primary_key = match index.primary_key(rtxn)? {
-        Some(primary_key) if primary_key.contains(PRIMARY_KEY_SPLIT_SYMBOL) => {
+    let composite_primary_key = index.composite_primary_key(rtxn)?;
+    let primary_key = match (index.primary_key(rtxn)?, composite_primary_key.as_deref()) {
+        (Some(primary_key), Some(fields)) => PrimaryKey::composite(primary_key, fields),
+        (Some(primary_key), None) if primary_key.contains(PRIMARY_KEY_SPLIT_SYMBOL) => {
             PrimaryKey::nested(primary_key)
         }
-        Some(primary_key) => match documents_batch_index.id(primary_key) {
+        (Some(primary_key), None) => match documents_batch_index.id(primary_key) {
             Some(id) => PrimaryKey::flat(primary_key, id),
-            None if autogenerate_docids => {
+            None if generation.is_some() => {
                 PrimaryKey::flat(primary_key, documents_batch_index.insert(primary_key))
             }
             None => {
@@ -57,7 +82,7 @@ pub fn enrich_documents_batch<R: Read + Seek>(
                 };
             }
         },
-        None => {
+        (None, _) => {
             let mut guesses: Vec<(u16, &str)> = documents_batch_index
                 .iter()
                 .filter(|(_, name)| name.to_lowercase().ends_with(DEFAULT_PRIMARY_KEY))
@@ -74,7 +99,7 @@ pub fn enrich_documents_batch<R: Read + Seek>(
             });
 
             match guesses.as_slice() {
-                [] if autogenerate_docids => PrimaryKey::flat(
+                [] if generation.is_some() => PrimaryKey::flat(
                     DEFAULT_PRIMARY_KEY,
                     documents_batch_index.insert(DEFAULT_PRIMARY_KEY),
                 ),
@@ -102,13 +127,28 @@ pub fn enrich_documents_batch<R: Read + Seek>(
         _otherwise => None,
     };
 
+    let version_field_id = documents_batch_index.id(DOCUMENT_VERSION_FIELD);
+
+    // The documents that don't match the schema are skipped or coerced by the transform,
+    // only the policy that rejects the whole batch must be enforced here.
+    let schema =
+        index.document_schema(rtxn)?.filter(|schema| schema.policy == SchemaPolicy::RejectBatch);
+
+    // A partial update only has to contain the required fields that the stored document
+    // doesn't already have. A document seen earlier in this batch was already checked.
+    let merge_documents = !matches!(index_documents_method, IndexDocumentsMethod::ReplaceDocuments)
+        && schema.as_ref().map_or(false, |schema| !schema.required.is_empty());
+    let external_documents_ids = index.external_documents_ids(rtxn)?;
+    let fields_ids_map = index.fields_ids_map(rtxn)?;
+    let mut seen_documents_ids = HashSet::new();
+
     let mut count = 0;
     while let Some(document) = cursor.next_document()? {
         let document_id = match fetch_or_generate_document_id(
             &document,
             &documents_batch_index,
             primary_key,
-            autogenerate_docids,
+            generation,
             &mut uuid_buffer,
             count,
         )? {
@@ -122,6 +162,34 @@ pub fn enrich_documents_batch<R: Read + Seek>(
             }
         }
 
+        if let Some(version) = version_field_id.and_then(|fid| document.get(fid)) {
+            if let Err(user_error) = validate_document_version(&document_id, version)? {
+                return Ok(Err(user_error));
+            }
+        }
+
+        if let Some(schema) = &schema {
+            let external_id = document_id.value();
+            let seen_in_batch =
+                merge_documents && !seen_documents_ids.insert(external_id.to_string());
+            let stored_document = match external_documents_ids.get(external_id) {
+                Some(docid) if merge_documents => index.documents.get(rtxn, &BEU32::new(docid))?,
+                _ => None,
+            };
+            let check = schema.check(&document, &documents_batch_index, |field| {
+                seen_in_batch
+                    || stored_document.as_ref().map_or(false, |obkv| {
+                        fields_ids_map.id(field).and_then(|fid| obkv.get(fid)).is_some()
+                    })
+            })?;
+            if !check.violations.is_empty() {
+                return Ok(Err(UserError::DocumentSchemaMismatch {
+                    document_id: Value::from(external_id),
+                    reason: check.violations.join(", "),
+                }));
+            }
+        }
+
         let document_id = serde_json::to_vec(&document_id).map_err(InternalError::SerdeJson)?;
         external_ids.insert(count.to_be_bytes(), document_id)?;
 
@@ -145,7 +213,7 @@ fn fetch_or_generate_document_id(
     document: &obkv::KvReader<FieldId>,
     documents_batch_index: &DocumentsBatchIndex,
     primary_key: PrimaryKey,
-    autogenerate_docids: bool,
+    generation: Option<PrimaryKeyGeneration>,
     uuid_buffer: &mut [u8; uuid::fmt::Hyphenated::LENGTH],
     count: u32,
 ) -> Result<StdResult<DocumentId, UserError>> {
@@ -160,14 +228,16 @@ fn fetch_or_generate_document_id(
                         Err(user_error) => Ok(Err(user_error)),
                     }
                 }
-                None if autogenerate_docids => {
-                    let uuid = uuid::Uuid::new_v4().as_hyphenated().encode_lower(uuid_buffer);
-                    Ok(Ok(DocumentId::generated(uuid.to_string(), count)))
-                }
-                None => Ok(Err(UserError::MissingDocumentId {
-                    primary_key: primary_key.to_string(),
-                    document: obkv_to_object(document, documents_batch_index)?,
-                })),
+                None => match generation {
+                    Some(generation) => {
+                        let uuid = generation.generate().as_hyphenated().encode_lower(uuid_buffer);
+                        Ok(Ok(DocumentId::generated(uuid.to_string(), count)))
+                    }
+                    None => Ok(Err(UserError::MissingDocumentId {
+                        primary_key: primary_key.to_string(),
+                        document: obkv_to_object(document, documents_batch_index)?,
+                    })),
+                },
             }
         }
         nested @ PrimaryKey::Nested { .. } => {
@@ -200,15 +270,158 @@ fn fetch_or_generate_document_id(
                 })),
             }
         }
+        PrimaryKey::Composite { fields, .. } => {
+            let mut values = Vec::new();
+            for field in fields {
+                match documents_batch_index.id(field).and_then(|field_id| document.get(field_id)) {
+                    Some(value_bytes) => values.push(
+                        serde_json::from_slice(value_bytes).map_err(InternalError::SerdeJson)?,
+                    ),
+                    None => {
+                        return Ok(Err(UserError::MissingDocumentId {
+                            primary_key: field.to_string(),
+                            document: obkv_to_object(document, documents_batch_index)?,
+                        }))
+                    }
+                }
+            }
+
+            match composite_document_id(values)? {
+                Ok(document_id) => Ok(Ok(DocumentId::retrieved(document_id))),
+                Err(user_error) => Ok(Err(user_error)),
+            }
+        }
+    }
+}
+
+/// The primary key of an index as it is defined by the user, a single field, that may be
+/// nested, or the list of the top-level fields whose values make the documents ids.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PrimaryKeyDefinition {
+    Field(String),
+    Composite(Vec<String>),
+}
+
+impl PrimaryKeyDefinition {
+    /// Returns the name of the primary key, the fields of a composite primary key
+    /// are joined by a comma.
+    pub fn name(&self) -> String {
+        match self {
+            PrimaryKeyDefinition::Field(field) => field.clone(),
+            PrimaryKeyDefinition::Composite(fields) => fields.join(COMPOSITE_PRIMARY_KEY_SEPARATOR),
+        }
+    }
+
+    /// Returns the fields the primary key is made of, in order.
+    pub fn fields(&self) -> &[String] {
+        match self {
+            PrimaryKeyDefinition::Field(field) => std::slice::from_ref(field),
+            PrimaryKeyDefinition::Composite(fields) => fields,
+        }
+    }
+
+    /// Checks that the fields of a composite primary key are non-empty top-level fields.
+    pub fn validate(&self) -> StdResult<(), UserError> {
+        match self {
+            PrimaryKeyDefinition::Composite(fields)
+                if fields.is_empty()
+                    || fields.iter().any(|field| {
+                        field.is_empty() || field.contains(PRIMARY_KEY_SPLIT_SYMBOL)
+                    }) =>
+            {
+                Err(UserError::InvalidCompositePrimaryKey(self.name()))
+            }
+            _ => Ok(()),
+        }
     }
 }
 
+impl From<String> for PrimaryKeyDefinition {
+    fn from(field: String) -> Self {
+        PrimaryKeyDefinition::Field(field)
+    }
+}
+
+impl fmt::Debug for PrimaryKeyDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PrimaryKeyDefinition::Field(field) => fmt::Debug::fmt(field, f),
+            PrimaryKeyDefinition::Composite(fields) => fmt::Debug::fmt(fields, f),
+        }
+    }
+}
+
+/// Derives the external id of a document from the values of the fields of its
+/// primary key, given in the order of [`PrimaryKeyDefinition::fields`].
+///
+/// Each value must be a valid document id by itself, a single value is kept as is.
+/// Otherwise, the values are joined by a `-`, and the `-` and `_` they contain are
+/// escaped by a `_`. This way the derived id is a valid document id too, and two
+/// different lists of values never give the same id.
+pub fn composite_document_id(values: Vec<Value>) -> Result<StdResult<String, UserError>> {
+    if values.len() == 1 {
+        return validate_document_id_value(values.into_iter().next().unwrap());
+    }
+
+    let mut document_id = String::new();
+    for (i, value) in values.into_iter().enumerate() {
+        let value = match validate_document_id_value(value)? {
+            Ok(value) => value,
+            Err(user_error) => return Ok(Err(user_error)),
+        };
+
+        if i != 0 {
+            document_id.push(COMPOSITE_DOCUMENT_ID_SEPARATOR);
+        }
+        for c in value.chars() {
+            if c == COMPOSITE_DOCUMENT_ID_SEPARATOR || c == COMPOSITE_DOCUMENT_ID_ESCAPE {
+                document_id.push(COMPOSITE_DOCUMENT_ID_ESCAPE);
+            }
+            document_id.push(c);
+        }
+    }
+
+    Ok(Ok(document_id))
+}
+
+/// The kind of UUID generated for the documents that don't have a value for the primary key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PrimaryKeyGeneration {
+    /// A fully random UUID.
+    UuidV4,
+    /// A UUID starting with a Unix timestamp, the generated ids are sorted by creation date.
+    UuidV7,
+}
+
+impl PrimaryKeyGeneration {
+    fn generate(self) -> uuid::Uuid {
+        match self {
+            PrimaryKeyGeneration::UuidV4 => uuid::Uuid::new_v4(),
+            PrimaryKeyGeneration::UuidV7 => new_uuid_v7(),
+        }
+    }
+}
+
+/// Generates a UUID version 7, a big-endian 48 bits Unix timestamp in milliseconds
+/// followed by random bits. The version of the `uuid` crate we use doesn't expose it yet.
+fn new_uuid_v7() -> uuid::Uuid {
+    let millis = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis();
+    // The variant bits are already set by the random UUID generation.
+    let mut bytes = *uuid::Uuid::new_v4().as_bytes();
+    bytes[..6].copy_from_slice(&(millis as u64).to_be_bytes()[2..]);
+    bytes[6] = 0x70 | (bytes[6] & 0x0f);
+    uuid::Uuid::from_bytes(bytes)
+}
+
 /// A type that represent the type of primary key that has been set
-/// for this index, a classic flat one or a nested one.
+/// for this index, a classic flat one, a nested one or a composite one.
 #[derive(Debug, Clone, Copy)]
 enum PrimaryKey<'a> {
     Flat { name: &'a str, field_id: FieldId },
     Nested { name: &'a str },
+    Composite { name: &'a str, fields: &'a [String] },
 }
 
 impl PrimaryKey<'_> {
@@ -220,10 +433,15 @@ impl PrimaryKey<'_> {
         PrimaryKey::Nested { name }
     }
 
+    fn composite<'a>(name: &'a str, fields: &'a [String]) -> PrimaryKey<'a> {
+        PrimaryKey::Composite { name, fields }
+    }
+
     fn name(&self) -> &str {
         match self {
             PrimaryKey::Flat { name, .. } => name,
             PrimaryKey::Nested { name } => name,
+            PrimaryKey::Composite { name, .. } => name,
         }
     }
 
@@ -387,3 +605,17 @@ pub fn validate_geo_from_json(id: &DocumentId, bytes: &[u8]) -> Result<StdResult
         value => Ok(Err(NotAnObject { document_id: debug_id(), value })),
     }
 }
+
+/// Parses the `_version` of a document, which must be a non-negative integer.
+pub fn validate_document_version(
+    id: &DocumentId,
+    bytes: &[u8],
+) -> Result<StdResult<u64, UserError>> {
+    match serde_json::from_slice(bytes).map_err(InternalError::SerdeJson)? {
+        Value::Number(number) if number.is_u64() => Ok(Ok(number.as_u64().unwrap())),
+        version => Ok(Err(UserError::InvalidDocumentVersion {
+            document_id: Value::from(id.value()),
+            version,
+        })),
+    }
+}
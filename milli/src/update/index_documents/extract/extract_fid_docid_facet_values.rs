@@ -9,6 +9,8 @@ use heed::zerocopy::AsBytes;
 use heed::BytesEncode;
 use roaring::RoaringBitmap;
 use serde_json::Value;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
 
 use super::helpers::{create_sorter, keep_first, sorter_into_reader, GrenadParameters};
 use crate::error::InternalError;
@@ -18,6 +20,8 @@ use crate::{CboRoaringBitmapCodec, DocumentId, FieldId, Result, BEU32, MAX_FACET
 
 /// Extracts the facet values of each faceted field of each document.
 ///
+/// The RFC 3339 dates of the ttl field are also extracted as numbers.
+///
 /// Returns the generated grenad reader containing the docid the fid and the orginal value as key
 /// and the normalized value as value extracted from the given chunk of documents.
 #[logging_timer::time]
@@ -25,6 +29,7 @@ pub fn extract_fid_docid_facet_values<R: io::Read + io::Seek>(
     obkv_documents: grenad::Reader<R>,
     indexer: GrenadParameters,
     faceted_fields: &HashSet<FieldId>,
+    ttl_field_id: Option<FieldId>,
 ) -> Result<(grenad::Reader<File>, grenad::Reader<File>, grenad::Reader<File>)> {
     let max_memory = indexer.max_memory_by_thread();
 
@@ -73,7 +78,17 @@ pub fn extract_fid_docid_facet_values<R: io::Read + io::Seek>(
                 let value =
                     serde_json::from_slice(field_bytes).map_err(InternalError::SerdeJson)?;
 
-                let (numbers, strings) = extract_facet_values(&value);
+                let (mut numbers, strings) = extract_facet_values(&value);
+
+                // The expiration dates can also be written in the RFC 3339 format,
+                // they are converted to timestamps to be compared with the current date.
+                if Some(field_id) == ttl_field_id {
+                    if let Value::String(date) = &value {
+                        if let Ok(date) = OffsetDateTime::parse(date, &Rfc3339) {
+                            numbers.push(date.unix_timestamp() as f64);
+                        }
+                    }
+                }
 
                 // insert facet numbers in sorter
                 for number in numbers {
@@ -43,6 +43,7 @@ pub(crate) fn data_from_obkv_documents(
     faceted_fields: HashSet<FieldId>,
     primary_key_id: FieldId,
     geo_fields_ids: Option<(FieldId, FieldId)>,
+    ttl_field_id: Option<FieldId>,
     stop_words: Option<fst::Set<&[u8]>>,
     max_positions_per_attributes: Option<u32>,
     exact_attributes: HashSet<FieldId>,
@@ -66,6 +67,7 @@ pub(crate) fn data_from_obkv_documents(
                 &faceted_fields,
                 primary_key_id,
                 geo_fields_ids,
+                ttl_field_id,
                 &stop_words,
                 max_positions_per_attributes,
             )
@@ -229,6 +231,7 @@ fn send_and_extract_flattened_documents_data(
     faceted_fields: &HashSet<FieldId>,
     primary_key_id: FieldId,
     geo_fields_ids: Option<(FieldId, FieldId)>,
+    ttl_field_id: Option<FieldId>,
     stop_words: &Option<fst::Set<&[u8]>>,
     max_positions_per_attributes: Option<u32>,
 ) -> Result<(
@@ -285,6 +288,7 @@ fn send_and_extract_flattened_documents_data(
                     flattened_documents_chunk.clone(),
                     indexer,
                     faceted_fields,
+                    ttl_field_id,
                 )?;
 
                 // send docid_fid_facet_numbers_chunk to DB writer
@@ -3,10 +3,11 @@ use std::io;
 use std::result::Result as StdResult;
 
 use roaring::RoaringBitmap;
+use serde_json::{Map, Value};
 
 use super::read_u32_ne_bytes;
 use crate::heed_codec::CboRoaringBitmapCodec;
-use crate::Result;
+use crate::{InternalError, Result};
 
 pub type MergeFn = for<'a> fn(&[u8], &[Cow<'a, [u8]>]) -> Result<Cow<'a, [u8]>>;
 
@@ -88,6 +89,118 @@ pub fn merge_two_obkvs(base: obkv::KvReaderU16, update: obkv::KvReaderU16, buffe
     writer.finish().unwrap();
 }
 
+/// Deep merge all the obkvs in the order we see them, replacing the arrays.
+pub fn deep_merge_obkvs<'a>(_key: &[u8], obkvs: &[Cow<'a, [u8]>]) -> Result<Cow<'a, [u8]>> {
+    deep_merge_all_obkvs(obkvs, false)
+}
+
+/// Deep merge all the obkvs in the order we see them, appending the arrays.
+pub fn deep_merge_obkvs_appending_arrays<'a>(
+    _key: &[u8],
+    obkvs: &[Cow<'a, [u8]>],
+) -> Result<Cow<'a, [u8]>> {
+    deep_merge_all_obkvs(obkvs, true)
+}
+
+fn deep_merge_all_obkvs<'a>(obkvs: &[Cow<'a, [u8]>], append_arrays: bool) -> Result<Cow<'a, [u8]>> {
+    let mut merged = obkvs[0].clone();
+    for current in &obkvs[1..] {
+        let mut buffer = Vec::new();
+        deep_merge_two_obkvs(
+            obkv::KvReader::new(&merged),
+            obkv::KvReader::new(current),
+            append_arrays,
+            &mut buffer,
+        )?;
+        merged = Cow::from(buffer);
+    }
+    Ok(merged)
+}
+
+/// Deep merge the `update` obkv into the `base` one, see [`deep_merge_values`].
+pub fn deep_merge_two_obkvs(
+    base: obkv::KvReaderU16,
+    update: obkv::KvReaderU16,
+    append_arrays: bool,
+    buffer: &mut Vec<u8>,
+) -> Result<()> {
+    use itertools::merge_join_by;
+    use itertools::EitherOrBoth::{Both, Left, Right};
+
+    buffer.clear();
+
+    let mut writer = obkv::KvWriter::new(buffer);
+    for eob in merge_join_by(base.iter(), update.iter(), |(b, _), (u, _)| b.cmp(u)) {
+        let (key, merged) = match eob {
+            Left((k, v)) => {
+                writer.insert(k, v)?;
+                continue;
+            }
+            Right((k, v)) => {
+                let update = serde_json::from_slice(v).map_err(InternalError::SerdeJson)?;
+                (k, deep_merge_values(None, update, append_arrays))
+            }
+            Both((k, b), (_, u)) => {
+                let base = serde_json::from_slice(b).map_err(InternalError::SerdeJson)?;
+                let update = serde_json::from_slice(u).map_err(InternalError::SerdeJson)?;
+                (k, deep_merge_values(Some(base), update, append_arrays))
+            }
+        };
+
+        if let Some(merged) = merged {
+            let merged = serde_json::to_vec(&merged).map_err(InternalError::SerdeJson)?;
+            writer.insert(key, merged)?;
+        }
+    }
+
+    writer.finish()?;
+    Ok(())
+}
+
+/// Recursively merge the `update` value into the `base` one.
+///
+/// - Objects are merged key by key, keeping the base order and appending the new keys.
+/// - A `null` update removes the value, in which case `None` is returned.
+/// - Arrays are replaced by the update, unless `append_arrays` is set.
+/// - Any other value is replaced by the update.
+pub fn deep_merge_values(base: Option<Value>, update: Value, append_arrays: bool) -> Option<Value> {
+    match (base, update) {
+        (_, Value::Null) => None,
+        (Some(Value::Object(base)), Value::Object(update)) => {
+            let mut merged = Map::new();
+            for (key, base_value) in base {
+                let value = match update.get(&key) {
+                    Some(update_value) => {
+                        deep_merge_values(Some(base_value), update_value.clone(), append_arrays)
+                    }
+                    None => Some(base_value),
+                };
+                if let Some(value) = value {
+                    merged.insert(key, value);
+                }
+            }
+            for (key, update_value) in update {
+                if merged.contains_key(&key) {
+                    continue;
+                }
+                if let Some(value) = deep_merge_values(None, update_value, append_arrays) {
+                    merged.insert(key, value);
+                }
+            }
+            Some(Value::Object(merged))
+        }
+        (Some(Value::Array(mut base)), Value::Array(update)) if append_arrays => {
+            base.extend(update);
+            Some(Value::Array(base))
+        }
+        // There is nothing to merge the object into but we must still remove its null values.
+        (_, Value::Object(update)) => {
+            deep_merge_values(Some(Value::Object(Map::new())), Value::Object(update), append_arrays)
+        }
+        (_, update) => Some(update),
+    }
+}
+
 pub fn merge_cbo_roaring_bitmaps<'a>(
     _key: &[u8],
     values: &[Cow<'a, [u8]>],
@@ -21,8 +21,9 @@ use typed_chunk::{write_typed_chunk_into_index, TypedChunk};
 
 use self::enrich::enrich_documents_batch;
 pub use self::enrich::{
-    extract_finite_float_from_value, validate_document_id, validate_document_id_value,
-    validate_geo_from_json, DocumentId,
+    composite_document_id, extract_finite_float_from_value, fetch_matching_values,
+    validate_document_id, validate_document_id_value, validate_document_version,
+    validate_geo_from_json, DocumentId, PrimaryKeyDefinition, PrimaryKeyGeneration,
 };
 pub use self::helpers::{
     as_cloneable_grenad, create_sorter, create_writer, fst_stream_into_hashset,
@@ -30,7 +31,10 @@ pub use self::helpers::{
     sorter_into_lmdb_database, valid_lmdb_key, writer_into_reader, ClonableMmap, MergeFn,
 };
 use self::helpers::{grenad_obkv_into_chunks, GrenadParameters};
-pub use self::transform::{Transform, TransformOutput};
+pub use self::transform::{
+    GeneratedDocumentsIds, Transform, TransformOutput, VersionConflicts,
+    VERSION_CONFLICTS_SAMPLE_SIZE,
+};
 use crate::documents::{obkv_to_object, DocumentsBatchReader};
 use crate::error::{Error, InternalError, UserError};
 pub use crate::update::index_documents::helpers::CursorClonableMmap;
@@ -38,7 +42,7 @@ use crate::update::{
     self, DeletionStrategy, IndexerConfig, PrefixWordPairsProximityDocids, UpdateIndexingStep,
     WordPrefixDocids, WordPrefixPositionDocids, WordsPrefixesFst,
 };
-use crate::{Index, Result, RoaringBitmapCodec};
+use crate::{Index, Result, RoaringBitmapCodec, SchemaViolations};
 
 static MERGED_DATABASE_COUNT: usize = 7;
 static PREFIX_DATABASE_COUNT: usize = 5;
@@ -62,6 +66,11 @@ pub enum IndexDocumentsMethod {
     /// Merge the previous version of the document with the new version,
     /// replacing old attributes values with the new ones and add the new attributes.
     UpdateDocuments,
+
+    /// Recursively merge the previous version of the document with the new version,
+    /// merging the nested objects instead of replacing them and removing the attributes
+    /// set to `null`. Arrays are replaced unless `append_arrays` is set.
+    DeepMergeDocuments { append_arrays: bool },
 }
 
 impl Default for IndexDocumentsMethod {
@@ -90,6 +99,8 @@ pub struct IndexDocumentsConfig {
     pub update_method: IndexDocumentsMethod,
     pub deletion_strategy: DeletionStrategy,
     pub autogenerate_docids: bool,
+    /// Write the documents regardless of their `_version`, keeping the one they are sent with.
+    pub skip_version_check: bool,
 }
 
 impl<'t, 'u, 'i, 'a, FP, FA> IndexDocuments<'t, 'u, 'i, 'a, FP, FA>
@@ -111,6 +122,7 @@ where
             indexer_config,
             config.update_method,
             config.autogenerate_docids,
+            config.skip_version_check,
         )?);
 
         Ok(IndexDocuments {
@@ -147,6 +159,7 @@ where
             self.wtxn,
             self.index,
             self.config.autogenerate_docids,
+            self.config.update_method,
             reader,
         )? {
             Ok(reader) => reader,
@@ -166,6 +179,30 @@ where
         Ok((self, Ok(indexed_documents)))
     }
 
+    /// Returns the documents skipped since the last call to this method
+    /// because their `_version` didn't match the one of the stored documents.
+    pub fn take_version_conflicts(&mut self) -> VersionConflicts {
+        self.transform
+            .as_mut()
+            .map_or_else(VersionConflicts::default, |transform| transform.take_version_conflicts())
+    }
+
+    /// Returns the external ids generated since the last call to this method
+    /// for the documents that didn't have a value for the primary key.
+    pub fn take_generated_documents_ids(&mut self) -> GeneratedDocumentsIds {
+        self.transform.as_mut().map_or_else(GeneratedDocumentsIds::default, |transform| {
+            transform.take_generated_documents_ids()
+        })
+    }
+
+    /// Returns the documents skipped or coerced since the last call to this method
+    /// because they didn't match the schema of the index.
+    pub fn take_schema_violations(&mut self) -> SchemaViolations {
+        self.transform
+            .as_mut()
+            .map_or_else(SchemaViolations::default, |transform| transform.take_schema_violations())
+    }
+
     #[logging_timer::time("IndexDocuments::{}")]
     pub fn execute(mut self) -> Result<DocumentAdditionResult> {
         if self.added_documents == 0 {
@@ -279,6 +316,12 @@ where
             None => None,
         };
 
+        // get the fid of the field containing the expiration date of the documents.
+        let ttl_field_id = match self.index.ttl_field(self.wtxn)? {
+            Some(field) => self.index.fields_ids_map(self.wtxn)?.id(field),
+            None => None,
+        };
+
         let stop_words = self.index.stop_words(self.wtxn)?;
         let exact_attributes = self.index.exact_attributes_ids(self.wtxn)?;
 
@@ -314,6 +357,7 @@ where
                     faceted_fields,
                     primary_key_id,
                     geo_fields_ids,
+                    ttl_field_id,
                     stop_words,
                     max_positions_per_attributes,
                     exact_attributes,
@@ -740,6 +784,72 @@ mod tests {
         drop(rtxn);
     }
 
+    #[test]
+    fn deep_merge_documents() {
+        let mut index = TempIndex::new();
+        index
+            .update_settings(|settings| {
+                settings.set_filterable_fields(hashset!(S("address.street")));
+            })
+            .unwrap();
+
+        index
+            .add_documents(documents!([
+                { "id": 1, "address": { "city": "Lyon", "street": "rivoli" }, "tags": ["a"] },
+                { "id": 2, "address": { "city": "Paris", "street": "rivoli" }, "tags": ["b"] },
+            ]))
+            .unwrap();
+
+        index.index_documents_config.update_method =
+            IndexDocumentsMethod::DeepMergeDocuments { append_arrays: false };
+        index
+            .add_documents(documents!([
+                // the nested objects are merged and the arrays replaced
+                { "id": 1, "address": { "city": "Paris" }, "tags": ["c"] },
+                // a null value removes the key
+                { "id": 2, "address": { "street": null }, "tags": null },
+                // a new document is merged into an empty one
+                { "id": 3, "address": { "city": "Nice", "street": null } },
+                { "id": 3, "address": { "street": "rivoli" } },
+            ]))
+            .unwrap();
+
+        index.index_documents_config.update_method =
+            IndexDocumentsMethod::DeepMergeDocuments { append_arrays: true };
+        index.add_documents(documents!([{ "id": 1, "tags": ["d"] }])).unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let fields_ids_map = index.fields_ids_map(&rtxn).unwrap();
+        let mut documents: Vec<_> = index
+            .all_documents(&rtxn)
+            .unwrap()
+            .map(|result| crate::all_obkv_to_json(result.unwrap().1, &fields_ids_map).unwrap())
+            .map(serde_json::Value::Object)
+            .collect();
+        documents.sort_by_key(|document| document["id"].as_u64());
+        assert_eq!(
+            documents,
+            vec![
+                serde_json::json!({
+                    "id": 1,
+                    "address": { "city": "Paris", "street": "rivoli" },
+                    "tags": ["c", "d"]
+                }),
+                serde_json::json!({ "id": 2, "address": { "city": "Paris" } }),
+                serde_json::json!({ "id": 3, "address": { "city": "Nice", "street": "rivoli" } }),
+            ]
+        );
+
+        // the nested fields of the merged documents are indexed
+        let filter = crate::Filter::from_str("address.street = rivoli").unwrap().unwrap();
+        let candidates = filter.evaluate(&rtxn, &index).unwrap();
+        let external_ids = index.external_documents_ids(&rtxn).unwrap();
+        let mut expected = RoaringBitmap::new();
+        expected.insert(external_ids.get("1").unwrap());
+        expected.insert(external_ids.get("3").unwrap());
+        assert_eq!(candidates, expected);
+    }
+
     #[test]
     fn not_auto_generated_documents_ids() {
         let index = TempIndex::new();
@@ -1907,4 +2017,298 @@ mod tests {
 
         index.add_documents(doc1).unwrap();
     }
+
+    #[test]
+    fn documents_with_a_mismatching_version_are_skipped() {
+        let index = TempIndex::new();
+
+        index
+            .add_documents(documents!([
+                { "id": 1, "name": "kevin", "_version": 0 },
+                { "id": 2, "name": "bob" },
+            ]))
+            .unwrap();
+
+        let mut wtxn = index.write_txn().unwrap();
+        let builder = IndexDocuments::new(
+            &mut wtxn,
+            &index,
+            &index.indexer_config,
+            index.index_documents_config.clone(),
+            |_| (),
+            || false,
+        )
+        .unwrap();
+        let (mut builder, user_error) = builder
+            .add_documents(documents!([
+                { "id": 1, "name": "kevina", "_version": 0 },
+                { "id": 2, "name": "bobby", "_version": 0 },
+                { "id": 3, "name": "jean", "_version": 5 },
+            ]))
+            .unwrap();
+        assert_eq!(user_error.unwrap(), 1);
+        assert_eq!(
+            builder.take_version_conflicts(),
+            VersionConflicts { count: 2, sample: vec![S("1"), S("3")] }
+        );
+        builder.execute().unwrap();
+        wtxn.commit().unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let fields_ids_map = index.fields_ids_map(&rtxn).unwrap();
+        let all_fields: Vec<_> = fields_ids_map.iter().map(|(id, _)| id).collect();
+        let documents: Vec<_> = index
+            .all_documents(&rtxn)
+            .unwrap()
+            .map(|result| {
+                let (_, obkv) = result.unwrap();
+                serde_json::Value::Object(
+                    crate::obkv_to_json(&all_fields, &fields_ids_map, obkv).unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            documents,
+            vec![
+                serde_json::json!({ "id": 1, "name": "kevin", "_version": 1 }),
+                serde_json::json!({ "id": 2, "name": "bobby", "_version": 1 }),
+            ]
+        );
+        drop(rtxn);
+
+        // a document sent without version still increments the version of the stored document
+        index.add_documents(documents!([{ "id": 1, "name": "kevina" }])).unwrap();
+        let rtxn = index.read_txn().unwrap();
+        let fields_ids_map = index.fields_ids_map(&rtxn).unwrap();
+        let version_field_id = fields_ids_map.id("_version").unwrap();
+        let docid = index.external_documents_ids(&rtxn).unwrap().get("1").unwrap();
+        let (_, obkv) = index.documents(&rtxn, Some(docid)).unwrap().remove(0);
+        assert_eq!(obkv.get(version_field_id), Some(&b"2"[..]));
+
+        // and a version that isn't a non-negative integer is rejected
+        let err = index
+            .add_documents(documents!([{ "id": 1, "name": "kevin", "_version": "2" }]))
+            .unwrap_err();
+        assert!(matches!(err, Error::UserError(UserError::InvalidDocumentVersion { .. })));
+    }
+
+    #[test]
+    fn primary_key_generation_setting() {
+        let index = TempIndex::new();
+        index
+            .update_settings(|settings| {
+                settings.set_primary_key_generation(PrimaryKeyGeneration::UuidV7);
+            })
+            .unwrap();
+
+        // there is no field ending with `id` to infer the primary key from
+        index.add_documents(documents!([{ "level": "info" }, { "level": "warn" }])).unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.primary_key(&rtxn).unwrap(), Some("id"));
+        let fields_ids_map = index.fields_ids_map(&rtxn).unwrap();
+        let id_field_id = fields_ids_map.id("id").unwrap();
+        for result in index.all_documents(&rtxn).unwrap() {
+            let (_, obkv) = result.unwrap();
+            let id: String = serde_json::from_slice(obkv.get(id_field_id).unwrap()).unwrap();
+            let uuid = uuid::Uuid::parse_str(&id).unwrap();
+            assert_eq!(uuid.get_version_num(), 7);
+        }
+        drop(rtxn);
+
+        // the documents with an id are left untouched
+        index.add_documents(documents!([{ "id": "log-1", "level": "error" }])).unwrap();
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.external_documents_ids(&rtxn).unwrap().get("log-1").is_some());
+        assert_eq!(index.number_of_documents(&rtxn).unwrap(), 3);
+        drop(rtxn);
+
+        index
+            .update_settings(|settings| {
+                settings.reset_primary_key_generation();
+            })
+            .unwrap();
+        let err = index.add_documents(documents!([{ "level": "debug" }])).unwrap_err();
+        assert!(matches!(err, Error::UserError(UserError::MissingDocumentId { .. })));
+    }
+
+    #[test]
+    fn index_documents_with_composite_primary_key() {
+        let index = TempIndex::new();
+        index
+            .update_settings(|settings| {
+                settings.set_primary_key(PrimaryKeyDefinition::Composite(vec![
+                    S("store_id"),
+                    S("sku"),
+                ]));
+            })
+            .unwrap();
+
+        index
+            .add_documents(documents!([
+                { "store_id": 1, "sku": "AB-3_x", "price": 10 },
+                { "store_id": 2, "sku": "AB-3_x", "price": 12 },
+                { "store_id": 1, "sku": "AB", "price": 3 },
+            ]))
+            .unwrap();
+        // a document with the same key parts replaces the previous one
+        index.add_documents(documents!([{ "store_id": 1, "sku": "AB", "price": 4 }])).unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.primary_key(&rtxn).unwrap(), Some("store_id,sku"));
+        assert_eq!(
+            index.composite_primary_key(&rtxn).unwrap(),
+            Some(vec![S("store_id"), S("sku")])
+        );
+        assert_eq!(index.number_of_documents(&rtxn).unwrap(), 3);
+        let fields_ids_map = index.fields_ids_map(&rtxn).unwrap();
+        assert!(fields_ids_map.id("store_id,sku").is_none());
+        let external_documents_ids = index.external_documents_ids(&rtxn).unwrap();
+        assert!(external_documents_ids.get("1-AB_-3__x").is_some());
+        assert!(external_documents_ids.get("2-AB_-3__x").is_some());
+        assert!(external_documents_ids.get("1-AB").is_some());
+        drop(rtxn);
+
+        let err = index.add_documents(documents!([{ "store_id": 3, "price": 4 }])).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::UserError(UserError::MissingDocumentId { primary_key, .. }) if primary_key == "sku"
+        ));
+        let err = index.add_documents(documents!([{ "store_id": 3, "sku": "a b" }])).unwrap_err();
+        assert!(matches!(err, Error::UserError(UserError::InvalidDocumentId { .. })));
+    }
+
+    #[test]
+    fn invalid_composite_primary_keys() {
+        let index = TempIndex::new();
+        let err = index
+            .update_settings(|settings| {
+                settings.set_primary_key(PrimaryKeyDefinition::Composite(vec![
+                    S("store.id"),
+                    S("sku"),
+                ]));
+            })
+            .unwrap_err();
+        assert!(matches!(err, Error::UserError(UserError::InvalidCompositePrimaryKey(_))));
+        let err = index
+            .update_settings(|settings| {
+                settings
+                    .set_primary_key(PrimaryKeyDefinition::Composite(vec![S("store_id"), S("")]));
+            })
+            .unwrap_err();
+        assert!(matches!(err, Error::UserError(UserError::InvalidCompositePrimaryKey(_))));
+    }
+
+    #[test]
+    fn flat_primary_keys_containing_a_comma() {
+        // a flat primary key containing a comma is inferred like any other field
+        let index = TempIndex::new();
+        index.add_documents(documents!([{ "store,id": 1, "store": 2, "id": 3 }])).unwrap();
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.primary_key(&rtxn).unwrap(), Some("store,id"));
+        assert_eq!(index.composite_primary_key(&rtxn).unwrap(), None);
+        assert!(index.external_documents_ids(&rtxn).unwrap().get("1").is_some());
+        drop(rtxn);
+
+        // and is never interpreted as a composite primary key when set explicitly
+        let index = TempIndex::new();
+        index
+            .update_settings(|settings| {
+                settings.set_primary_key(S("store,id"));
+            })
+            .unwrap();
+        index.add_documents(documents!([{ "store,id": 1, "store": 2, "id": 3 }])).unwrap();
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.external_documents_ids(&rtxn).unwrap().get("1").is_some());
+    }
+
+    #[test]
+    fn composite_document_ids_are_escaped() {
+        let id = |values: serde_json::Value| match values {
+            serde_json::Value::Array(values) => composite_document_id(values).unwrap().unwrap(),
+            _ => unreachable!(),
+        };
+
+        assert_eq!(id(serde_json::json!(["a-b"])), "a-b");
+        assert_eq!(id(serde_json::json!([1, "a"])), "1-a");
+        assert_eq!(id(serde_json::json!(["a-b", "c"])), "a_-b-c");
+        assert_eq!(id(serde_json::json!(["a", "b-c"])), "a-b_-c");
+        assert_eq!(id(serde_json::json!(["a_", "b"])), "a__-b");
+        assert_eq!(id(serde_json::json!(["a", "_b"])), "a-__b");
+    }
+
+    #[test]
+    fn documents_are_checked_against_the_schema() {
+        let index = TempIndex::new();
+        let schema = |policy: &str| -> crate::DocumentSchema {
+            serde_json::from_value(serde_json::json!({
+                "policy": policy,
+                "required": ["title"],
+                "properties": {
+                    "price": { "type": "number", "minimum": 0 },
+                    "color": { "enum": ["red", "blue"] },
+                },
+            }))
+            .unwrap()
+        };
+        let documents = || {
+            documents!([
+                { "id": 1, "title": "hat", "price": 12, "color": "red" },
+                { "id": 2, "title": "scarf", "price": "7.5" },
+                { "id": 3, "price": 3 },
+                { "id": 4, "title": "boots", "price": -1, "color": "green" },
+            ])
+        };
+
+        index
+            .update_settings(|settings| settings.set_document_schema(schema("rejectBatch")))
+            .unwrap();
+        let err = index.add_documents(documents()).unwrap_err();
+        assert!(matches!(err, Error::UserError(UserError::DocumentSchemaMismatch { .. })));
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.number_of_documents(&rtxn).unwrap(), 0);
+        drop(rtxn);
+
+        for (policy, indexed, skipped, coerced) in
+            [("skipDocument", vec![S("1")], 3, 0), ("coerce", vec![S("1"), S("2")], 2, 1)]
+        {
+            index.update_settings(|settings| settings.set_document_schema(schema(policy))).unwrap();
+
+            let mut wtxn = index.write_txn().unwrap();
+            let builder = IndexDocuments::new(
+                &mut wtxn,
+                &index,
+                &index.indexer_config,
+                index.index_documents_config.clone(),
+                |_| (),
+                || false,
+            )
+            .unwrap();
+            let (mut builder, user_error) = builder.add_documents(documents()).unwrap();
+            assert_eq!(user_error.unwrap(), indexed.len() as u64);
+            let violations = builder.take_schema_violations();
+            assert_eq!(violations.skipped_documents, skipped);
+            assert_eq!(violations.coerced_documents, coerced);
+            assert_eq!(violations.sample.len(), skipped as usize + coerced as usize);
+            builder.execute().unwrap();
+            wtxn.commit().unwrap();
+
+            let rtxn = index.read_txn().unwrap();
+            let external_documents_ids = index.external_documents_ids(&rtxn).unwrap();
+            for id in ["1", "2", "3", "4"] {
+                let expected = indexed.iter().any(|indexed| indexed == id);
+                assert_eq!(external_documents_ids.get(id).is_some(), expected, "{policy} {id}");
+            }
+        }
+
+        // the coerced value is stored with the expected type
+        let rtxn = index.read_txn().unwrap();
+        let fields_ids_map = index.fields_ids_map(&rtxn).unwrap();
+        let price_field_id = fields_ids_map.id("price").unwrap();
+        let docid = index.external_documents_ids(&rtxn).unwrap().get("2").unwrap();
+        let (_, obkv) = index.documents(&rtxn, Some(docid)).unwrap().into_iter().next().unwrap();
+        let price: serde_json::Value =
+            serde_json::from_slice(obkv.get(price_field_id).unwrap()).unwrap();
+        assert_eq!(price, serde_json::json!(7.5));
+    }
 }
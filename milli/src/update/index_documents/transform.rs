@@ -1,8 +1,9 @@
 use std::borrow::Cow;
 use std::collections::hash_map::Entry;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs::File;
 use std::io::{Read, Seek, SeekFrom};
+use std::mem;
 
 use fxhash::FxHashMap;
 use heed::RoTxn;
@@ -11,16 +12,20 @@ use obkv::{KvReader, KvWriter};
 use roaring::RoaringBitmap;
 use serde_json::Value;
 use smartstring::SmartString;
+use time::OffsetDateTime;
 
-use super::helpers::{create_sorter, create_writer, keep_latest_obkv, merge_obkvs, MergeFn};
-use super::{IndexDocumentsMethod, IndexerConfig};
+use super::helpers::{
+    create_sorter, create_writer, deep_merge_obkvs, deep_merge_obkvs_appending_arrays,
+    deep_merge_two_obkvs, keep_latest_obkv, merge_obkvs, MergeFn,
+};
+use super::{validate_document_version, IndexDocumentsMethod, IndexerConfig};
 use crate::documents::{DocumentsBatchIndex, EnrichedDocument, EnrichedDocumentsBatchReader};
 use crate::error::{Error, InternalError, UserError};
 use crate::index::{db_name, main_key};
 use crate::update::{AvailableDocumentsIds, ClearDocuments, UpdateIndexingStep};
 use crate::{
     ExternalDocumentsIds, FieldDistribution, FieldId, FieldIdMapMissingEntry, FieldsIdsMap, Index,
-    Result, BEU32,
+    Result, SchemaPolicy, SchemaViolations, BEU32, DOCUMENT_VERSION_FIELD,
 };
 
 pub struct TransformOutput {
@@ -35,6 +40,47 @@ pub struct TransformOutput {
     pub flattened_documents: File,
 }
 
+/// The ids generated for the documents that didn't have one, keyed by the position
+/// of the document in its payload so that every id can be matched with its document.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GeneratedDocumentsIds {
+    pub ids: BTreeMap<u64, String>,
+}
+
+impl GeneratedDocumentsIds {
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    fn push(&mut self, position: u64, external_id: &str) {
+        self.ids.insert(position, external_id.to_string());
+    }
+}
+
+/// The maximum number of ids kept in a [`VersionConflicts`] report.
+pub const VERSION_CONFLICTS_SAMPLE_SIZE: usize = 20;
+
+/// The documents skipped because their `_version` didn't match the one of the stored
+/// documents, only a bounded sample of their ids is kept.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VersionConflicts {
+    pub count: u64,
+    pub sample: Vec<String>,
+}
+
+impl VersionConflicts {
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    fn push(&mut self, external_id: &str) {
+        self.count += 1;
+        if self.sample.len() < VERSION_CONFLICTS_SAMPLE_SIZE {
+            self.sample.push(external_id.to_string());
+        }
+    }
+}
+
 /// Extract the external ids, deduplicate and compute the new internal documents ids
 /// and fields ids, writing all the documents under their internal ids into a final file.
 ///
@@ -47,6 +93,7 @@ pub struct Transform<'a, 'i> {
 
     indexer_settings: &'a IndexerConfig,
     pub autogenerate_docids: bool,
+    pub skip_version_check: bool,
     pub index_documents_method: IndexDocumentsMethod,
     available_documents_ids: AvailableDocumentsIds,
 
@@ -56,6 +103,14 @@ pub struct Transform<'a, 'i> {
     new_documents_ids: RoaringBitmap,
     // To increase the cache locality and decrease the heap usage we use compact smartstring.
     new_external_documents_ids_builder: FxHashMap<SmartString<smartstring::Compact>, u64>,
+    // The versions written in this batch, they are not yet visible in the index.
+    documents_versions: FxHashMap<SmartString<smartstring::Compact>, u64>,
+    // The documents skipped because of a version mismatch.
+    version_conflicts: VersionConflicts,
+    // The external ids generated for the documents that didn't have one.
+    generated_documents_ids: GeneratedDocumentsIds,
+    // The documents skipped or coerced because they didn't match the schema of the index.
+    schema_violations: SchemaViolations,
     documents_count: usize,
 }
 
@@ -89,12 +144,17 @@ impl<'a, 'i> Transform<'a, 'i> {
         indexer_settings: &'a IndexerConfig,
         index_documents_method: IndexDocumentsMethod,
         autogenerate_docids: bool,
+        skip_version_check: bool,
     ) -> Result<Self> {
         // We must choose the appropriate merge function for when two or more documents
         // with the same user id must be merged or fully replaced in the same batch.
         let merge_function = match index_documents_method {
             IndexDocumentsMethod::ReplaceDocuments => keep_latest_obkv,
             IndexDocumentsMethod::UpdateDocuments => merge_obkvs,
+            IndexDocumentsMethod::DeepMergeDocuments { append_arrays: false } => deep_merge_obkvs,
+            IndexDocumentsMethod::DeepMergeDocuments { append_arrays: true } => {
+                deep_merge_obkvs_appending_arrays
+            }
         };
 
         // We initialize the sorter with the user indexing settings.
@@ -124,6 +184,7 @@ impl<'a, 'i> Transform<'a, 'i> {
             fields_ids_map: index.fields_ids_map(wtxn)?,
             indexer_settings,
             autogenerate_docids,
+            skip_version_check,
             available_documents_ids: AvailableDocumentsIds::from_documents_ids(
                 &documents_ids,
                 &soft_deleted_documents_ids,
@@ -134,6 +195,10 @@ impl<'a, 'i> Transform<'a, 'i> {
             replaced_documents_ids: RoaringBitmap::new(),
             new_documents_ids: RoaringBitmap::new(),
             new_external_documents_ids_builder: FxHashMap::default(),
+            documents_versions: FxHashMap::default(),
+            version_conflicts: VersionConflicts::default(),
+            generated_documents_ids: GeneratedDocumentsIds::default(),
+            schema_violations: SchemaViolations::default(),
             documents_count: 0,
         })
     }
@@ -156,21 +221,157 @@ impl<'a, 'i> Transform<'a, 'i> {
 
         let mapping = create_fields_mapping(&mut self.fields_ids_map, &fields_index)?;
 
+        // The ids of the documents are never generated for a composite primary key, its name
+        // isn't a field of the documents and must not be registered as one.
         let primary_key = cursor.primary_key().to_string();
-        let primary_key_id =
-            self.fields_ids_map.insert(&primary_key).ok_or(UserError::AttributeLimitReached)?;
+        let primary_key_id = match self.index.composite_primary_key(wtxn)? {
+            Some(_) => None,
+            None => Some(
+                self.fields_ids_map.insert(&primary_key).ok_or(UserError::AttributeLimitReached)?,
+            ),
+        };
+
+        let deep_merge = match self.index_documents_method {
+            IndexDocumentsMethod::DeepMergeDocuments { append_arrays } => Some(append_arrays),
+            _ => None,
+        };
+
+        // When a default ttl is defined, the documents that don't specify an expiration date
+        // are stamped with one. The fields that are, or contain, the ttl field are kept to
+        // know if a document already specifies its expiration date.
+        let default_expiration = match (self.index.ttl_field(wtxn)?, self.index.default_ttl(wtxn)?)
+        {
+            (Some(ttl_field), Some(default_ttl)) => {
+                let ttl_field_id = self
+                    .fields_ids_map
+                    .insert(ttl_field)
+                    .ok_or(UserError::AttributeLimitReached)?;
+                let ttl_fields_ids: HashSet<FieldId> = self
+                    .fields_ids_map
+                    .iter()
+                    .filter(|(_, name)| {
+                        *name == ttl_field
+                            || ttl_field.strip_prefix(name).map_or(false, |s| s.starts_with('.'))
+                    })
+                    .map(|(id, _)| id)
+                    .collect();
+                let expiration =
+                    OffsetDateTime::now_utc().unix_timestamp().saturating_add(default_ttl as i64);
+                let expiration =
+                    serde_json::to_vec(&expiration).map_err(InternalError::SerdeJson)?;
+                Some((ttl_field_id, ttl_fields_ids, expiration))
+            }
+            _ => None,
+        };
+
+        // The batches are rejected by the `enrich_documents_batch` function when the schema
+        // requires it, here the offending documents are either skipped or coerced.
+        let schema = self
+            .index
+            .document_schema(wtxn)?
+            .filter(|schema| schema.policy != SchemaPolicy::RejectBatch);
+
+        // A partial update only has to contain the required fields that the stored document
+        // doesn't already have. A document seen earlier in this batch was already checked.
+        let merge_documents =
+            !matches!(self.index_documents_method, IndexDocumentsMethod::ReplaceDocuments);
+
+        let version_field_id = self.fields_ids_map.id(DOCUMENT_VERSION_FIELD);
+        let batch_version_field_id = fields_index.id(DOCUMENT_VERSION_FIELD);
 
         let mut obkv_buffer = Vec::new();
+        let mut merge_buffer = Vec::new();
         let mut documents_count = 0;
+        let mut document_position = 0;
         let mut docid_buffer: Vec<u8> = Vec::new();
+        let mut version_buffer: Vec<u8> = Vec::new();
         let mut field_buffer: Vec<(u16, Cow<[u8]>)> = Vec::new();
         while let Some(enriched_document) = cursor.next_enriched_document()? {
             let EnrichedDocument { document, document_id } = enriched_document;
+            // The skipped documents don't count in `documents_count` but keep their position.
+            let position = document_position;
+            document_position += 1;
 
             if should_abort() {
                 return Err(Error::InternalError(InternalError::AbortedIndexation));
             }
 
+            let mut coerced_values = Vec::new();
+            if let Some(schema) = &schema {
+                let external_id = document_id.value();
+                let seen_in_batch = merge_documents
+                    && self.new_external_documents_ids_builder.contains_key(external_id);
+                let stored_document = match external_documents_ids.get(external_id) {
+                    Some(docid) if merge_documents && !schema.required.is_empty() => {
+                        self.index.documents.get(wtxn, &BEU32::new(docid))?
+                    }
+                    _ => None,
+                };
+                let fields_ids_map = &self.fields_ids_map;
+                let check = schema.check(&document, &fields_index, |field| {
+                    seen_in_batch
+                        || stored_document.as_ref().map_or(false, |obkv| {
+                            fields_ids_map.id(field).and_then(|fid| obkv.get(fid)).is_some()
+                        })
+                })?;
+                if !check.violations.is_empty() {
+                    self.schema_violations.push_skipped(document_id.value(), check.violations);
+                    continue;
+                }
+                if !check.coerced.is_empty() {
+                    let fields = check
+                        .coerced
+                        .iter()
+                        .filter_map(|(k, _)| fields_index.name(*k))
+                        .map(str::to_string)
+                        .collect();
+                    self.schema_violations.push_coerced(document_id.value(), fields);
+                    for (k, value) in check.coerced {
+                        let value = serde_json::to_vec(&value).map_err(InternalError::SerdeJson)?;
+                        coerced_values.push((k, value));
+                    }
+                }
+            }
+
+            // A document sent with a `_version` is only written if it matches the version of
+            // the stored document, a missing document being at version 0. The version of the
+            // documents that are versioned is incremented each time they are written.
+            // When the check is skipped, e.g. to import a dump, the versions are kept as they are.
+            let new_version = match version_field_id {
+                Some(_) if self.skip_version_check => None,
+                Some(version_field_id) => {
+                    let external_id = document_id.value();
+                    let current_version = match self.documents_versions.get(external_id) {
+                        Some(version) => Some(*version),
+                        None => match external_documents_ids.get(external_id) {
+                            Some(docid) => self
+                                .index
+                                .documents
+                                .get(wtxn, &BEU32::new(docid))?
+                                .and_then(|obkv| obkv.get(version_field_id))
+                                .and_then(|version| serde_json::from_slice(version).ok()),
+                            None => None,
+                        },
+                    };
+                    let expected_version = match batch_version_field_id
+                        .and_then(|field_id| document.get(field_id))
+                    {
+                        Some(version) => Some(validate_document_version(&document_id, version)??),
+                        None => None,
+                    };
+
+                    match (expected_version, current_version) {
+                        (Some(expected), current) if expected != current.unwrap_or(0) => {
+                            self.version_conflicts.push(&external_id);
+                            continue;
+                        }
+                        (None, None) => None,
+                        (_, current) => Some(current.unwrap_or(0) + 1),
+                    }
+                }
+                None => None,
+            };
+
             // drop_and_reuse is called instead of .clear() to communicate to the compiler that field_buffer
             // does not keep references from the cursor between loop iterations
             let mut field_buffer_cache = drop_and_reuse(field_buffer);
@@ -183,16 +384,37 @@ impl<'a, 'i> Transform<'a, 'i> {
             // When the document id has been auto-generated by the `enrich_documents_batch`
             // we must insert this document id into the remaped document.
             let external_id = document_id.value();
-            if document_id.is_generated() {
+            if let Some(primary_key_id) = primary_key_id.filter(|_| document_id.is_generated()) {
                 serde_json::to_writer(&mut docid_buffer, external_id)
                     .map_err(InternalError::SerdeJson)?;
                 field_buffer_cache.push((primary_key_id, Cow::from(&docid_buffer)));
+                self.generated_documents_ids.push(position, external_id);
             }
 
             for (k, v) in document.iter() {
                 let mapped_id =
                     *mapping.get(&k).ok_or(InternalError::FieldIdMappingMissingEntry { key: k })?;
-                field_buffer_cache.push((mapped_id, Cow::from(v)));
+                match coerced_values.iter().position(|(id, _)| *id == k) {
+                    Some(pos) => {
+                        let (_, value) = coerced_values.swap_remove(pos);
+                        field_buffer_cache.push((mapped_id, Cow::Owned(value)));
+                    }
+                    None => field_buffer_cache.push((mapped_id, Cow::from(v))),
+                }
+            }
+
+            if let Some((ttl_field_id, ttl_fields_ids, expiration)) = &default_expiration {
+                if !field_buffer_cache.iter().any(|(id, _)| ttl_fields_ids.contains(id)) {
+                    field_buffer_cache.push((*ttl_field_id, Cow::from(expiration)));
+                }
+            }
+
+            if let (Some(version_field_id), Some(version)) = (version_field_id, new_version) {
+                field_buffer_cache.retain(|(id, _)| *id != version_field_id);
+                serde_json::to_writer(&mut version_buffer, &version)
+                    .map_err(InternalError::SerdeJson)?;
+                field_buffer_cache.push((version_field_id, Cow::from(&version_buffer)));
+                self.documents_versions.insert(external_id.into(), version);
             }
 
             // Insertion in a obkv need to be done with keys ordered. For now they are ordered
@@ -207,9 +429,13 @@ impl<'a, 'i> Transform<'a, 'i> {
             }
 
             let mut original_docid = None;
+            let mut seen_in_batch = false;
 
             let docid = match self.new_external_documents_ids_builder.entry((*external_id).into()) {
-                Entry::Occupied(entry) => *entry.get() as u32,
+                Entry::Occupied(entry) => {
+                    seen_in_batch = true;
+                    *entry.get() as u32
+                }
                 Entry::Vacant(entry) => {
                     // If the document was already in the db we mark it as a replaced document.
                     // It'll be deleted later. We keep its original docid to insert it in the grenad.
@@ -239,6 +465,18 @@ impl<'a, 'i> Transform<'a, 'i> {
                         key: None,
                     })?;
 
+                // When deep merging, we directly merge the new document into the base one
+                // so that the comparison below is done on the final version of the document.
+                if let Some(append_arrays) = deep_merge {
+                    deep_merge_two_obkvs(
+                        KvReader::new(base_obkv),
+                        KvReader::new(&obkv_buffer),
+                        append_arrays,
+                        &mut merge_buffer,
+                    )?;
+                    mem::swap(&mut obkv_buffer, &mut merge_buffer);
+                }
+
                 // we check if the two documents are exactly equal. If it's the case we can skip this document entirely
                 if base_obkv == obkv_buffer {
                     // we're not replacing anything
@@ -246,16 +484,29 @@ impl<'a, 'i> Transform<'a, 'i> {
                     // and we need to put back the original id as it was before
                     self.new_external_documents_ids_builder.remove(external_id);
                     skip_insertion = true;
-                } else {
+                } else if deep_merge.is_none() {
                     // we associate the base document with the new key, everything will get merged later.
                     self.original_sorter.insert(docid.to_be_bytes(), base_obkv)?;
-                    match self.flatten_from_fields_ids_map(KvReader::new(base_obkv))? {
+                    match Self::flatten_from_fields_ids_map(
+                        &mut self.fields_ids_map,
+                        KvReader::new(base_obkv),
+                    )? {
                         Some(buffer) => {
                             self.flattened_sorter.insert(docid.to_be_bytes(), &buffer)?
                         }
                         None => self.flattened_sorter.insert(docid.to_be_bytes(), base_obkv)?,
                     }
                 }
+            } else if let (Some(append_arrays), false) = (deep_merge, seen_in_batch) {
+                // A new document is merged into an empty one to remove its null values. The
+                // documents seen earlier in this batch will be merged by the sorter instead.
+                deep_merge_two_obkvs(
+                    KvReader::new(&[]),
+                    KvReader::new(&obkv_buffer),
+                    append_arrays,
+                    &mut merge_buffer,
+                )?;
+                mem::swap(&mut obkv_buffer, &mut merge_buffer);
             }
 
             if !skip_insertion {
@@ -263,10 +514,18 @@ impl<'a, 'i> Transform<'a, 'i> {
                 // We use the extracted/generated user id as the key for this document.
                 self.original_sorter.insert(docid.to_be_bytes(), obkv_buffer.clone())?;
 
-                match self.flatten_from_fields_ids_map(KvReader::new(&obkv_buffer))? {
-                    Some(buffer) => self.flattened_sorter.insert(docid.to_be_bytes(), &buffer)?,
-                    None => {
-                        self.flattened_sorter.insert(docid.to_be_bytes(), obkv_buffer.clone())?
+                // The deep merged documents are flattened once merged, when generating the output.
+                if deep_merge.is_none() {
+                    match Self::flatten_from_fields_ids_map(
+                        &mut self.fields_ids_map,
+                        KvReader::new(&obkv_buffer),
+                    )? {
+                        Some(buffer) => {
+                            self.flattened_sorter.insert(docid.to_be_bytes(), &buffer)?
+                        }
+                        None => self
+                            .flattened_sorter
+                            .insert(docid.to_be_bytes(), obkv_buffer.clone())?,
                     }
                 }
             }
@@ -278,6 +537,7 @@ impl<'a, 'i> Transform<'a, 'i> {
 
             field_buffer = drop_and_reuse(field_buffer_cache);
             docid_buffer.clear();
+            version_buffer.clear();
             obkv_buffer.clear();
         }
 
@@ -293,9 +553,30 @@ impl<'a, 'i> Transform<'a, 'i> {
         Ok(documents_count)
     }
 
+    /// Returns the documents that were skipped because their `_version` didn't match
+    /// the one of the stored documents, since the last call to this method.
+    pub fn take_version_conflicts(&mut self) -> VersionConflicts {
+        mem::take(&mut self.version_conflicts)
+    }
+
+    /// Returns the external ids generated for the documents that didn't have one,
+    /// since the last call to this method.
+    pub fn take_generated_documents_ids(&mut self) -> GeneratedDocumentsIds {
+        mem::take(&mut self.generated_documents_ids)
+    }
+
+    /// Returns the documents that were skipped or coerced because they didn't match
+    /// the schema of the index, since the last call to this method.
+    pub fn take_schema_violations(&mut self) -> SchemaViolations {
+        mem::take(&mut self.schema_violations)
+    }
+
     // Flatten a document from the fields ids map contained in self and insert the new
     // created fields. Returns `None` if the document doesn't need to be flattened.
-    fn flatten_from_fields_ids_map(&mut self, obkv: KvReader<FieldId>) -> Result<Option<Vec<u8>>> {
+    fn flatten_from_fields_ids_map(
+        fields_ids_map: &mut FieldsIdsMap,
+        obkv: KvReader<FieldId>,
+    ) -> Result<Option<Vec<u8>>> {
         if obkv
             .iter()
             .all(|(_, value)| !json_depth_checker::should_flatten_from_unchecked_slice(value))
@@ -316,7 +597,7 @@ impl<'a, 'i> Transform<'a, 'i> {
         // all the raw values get inserted directly in the `key_value` vec.
         for (key, value) in obkv.iter() {
             if json_depth_checker::should_flatten_from_unchecked_slice(value) {
-                let key = self.fields_ids_map.name(key).ok_or(FieldIdMapMissingEntry::FieldId {
+                let key = fields_ids_map.name(key).ok_or(FieldIdMapMissingEntry::FieldId {
                     field_id: key,
                     process: "Flatten from fields ids map.",
                 })?;
@@ -334,7 +615,7 @@ impl<'a, 'i> Transform<'a, 'i> {
         // Once we have the flattened version we insert all the new generated fields_ids
         // (if any) in the fields ids map and serialize the value.
         for (key, value) in flattened.into_iter() {
-            let fid = self.fields_ids_map.insert(&key).ok_or(UserError::AttributeLimitReached)?;
+            let fid = fields_ids_map.insert(&key).ok_or(UserError::AttributeLimitReached)?;
             let value = serde_json::to_vec(&value).map_err(InternalError::SerdeJson)?;
             key_value.push((fid, value.into()));
         }
@@ -449,7 +730,7 @@ impl<'a, 'i> Transform<'a, 'i> {
     /// format like CSV, JSON or JSON stream. This sorter must contain a key that is the document
     /// id for the user side and the value must be an obkv where keys are valid fields ids.
     pub(crate) fn output_from_sorter<F>(
-        self,
+        mut self,
         wtxn: &mut heed::RwTxn,
         progress_callback: F,
     ) -> Result<TransformOutput>
@@ -481,6 +762,17 @@ impl<'a, 'i> Transform<'a, 'i> {
 
         self.remove_deleted_documents_from_field_distribution(wtxn, &mut field_distribution)?;
 
+        // We create a final writer to write the flattened documents in order.
+        let mut flattened_writer = create_writer(
+            self.indexer_settings.chunk_compression_type,
+            self.indexer_settings.chunk_compression_level,
+            tempfile::tempfile()?,
+        );
+
+        // The deep merged documents can only be flattened once they are fully merged.
+        let deep_merge =
+            matches!(self.index_documents_method, IndexDocumentsMethod::DeepMergeDocuments { .. });
+
         // Here we are going to do the document count + field distribution + `write_into_stream_writer`
         let mut iter = self.original_sorter.into_stream_merger_iter()?;
         // used only for the callback
@@ -506,22 +798,25 @@ impl<'a, 'i> Transform<'a, 'i> {
                 *field_distribution.entry(name.to_string()).or_insert(0) += 1;
             }
             writer.insert(key, val)?;
+
+            if deep_merge {
+                match Self::flatten_from_fields_ids_map(&mut self.fields_ids_map, obkv)? {
+                    Some(buffer) => flattened_writer.insert(key, &buffer)?,
+                    None => flattened_writer.insert(key, val)?,
+                }
+            }
         }
 
         let mut original_documents = writer.into_inner()?;
         // We then extract the file and reset the seek to be able to read it again.
         original_documents.seek(SeekFrom::Start(0))?;
 
-        // We create a final writer to write the new documents in order from the sorter.
-        let mut writer = create_writer(
-            self.indexer_settings.chunk_compression_type,
-            self.indexer_settings.chunk_compression_level,
-            tempfile::tempfile()?,
-        );
         // Once we have written all the documents into the final sorter, we write the documents
         // into this writer, extract the file and reset the seek to be able to read it again.
-        self.flattened_sorter.write_into_stream_writer(&mut writer)?;
-        let mut flattened_documents = writer.into_inner()?;
+        if !deep_merge {
+            self.flattened_sorter.write_into_stream_writer(&mut flattened_writer)?;
+        }
+        let mut flattened_documents = flattened_writer.into_inner()?;
         flattened_documents.seek(SeekFrom::Start(0))?;
 
         let mut new_external_documents_ids_builder: Vec<_> =
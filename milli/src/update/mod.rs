@@ -1,17 +1,21 @@
 pub use self::available_documents_ids::AvailableDocumentsIds;
 pub use self::clear_documents::ClearDocuments;
-pub use self::delete_documents::{DeleteDocuments, DeletionStrategy, DocumentDeletionResult};
+pub use self::delete_documents::{
+    DeleteDocuments, DeletionStrategy, DocumentDeletionResult, PurgeSoftDeletedDocuments,
+};
 pub use self::facet::bulk::FacetsUpdateBulk;
 pub use self::facet::incremental::FacetsUpdateIncrementalInner;
 pub use self::index_documents::{
-    DocumentAdditionResult, DocumentId, IndexDocuments, IndexDocumentsConfig, IndexDocumentsMethod,
+    composite_document_id, fetch_matching_values, DocumentAdditionResult, DocumentId,
+    GeneratedDocumentsIds, IndexDocuments, IndexDocumentsConfig, IndexDocumentsMethod,
+    PrimaryKeyDefinition, PrimaryKeyGeneration, VersionConflicts, VERSION_CONFLICTS_SAMPLE_SIZE,
 };
 pub use self::indexer_config::IndexerConfig;
 pub use self::prefix_word_pairs::{
     PrefixWordPairsProximityDocids, MAX_LENGTH_FOR_PREFIX_PROXIMITY_DB,
     MAX_PROXIMITY_FOR_PREFIX_PROXIMITY_DB,
 };
-pub use self::settings::{Setting, Settings};
+pub use self::settings::{ReindexingSetting, Setting, Settings, SettingsChanges, SettingsImpact};
 pub use self::update_step::UpdateIndexingStep;
 pub use self::word_prefix_docids::WordPrefixDocids;
 pub use self::words_prefix_position_docids::WordPrefixPositionDocids;
@@ -1,4 +1,5 @@
 use std::collections::{BTreeSet, HashMap, HashSet};
+use std::ops::{Deref, DerefMut};
 use std::result::Result as StdResult;
 
 use charabia::{Tokenizer, TokenizerBuilder};
@@ -7,14 +8,16 @@ use itertools::Itertools;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use time::OffsetDateTime;
 
-use super::index_documents::{IndexDocumentsConfig, Transform};
+use super::index_documents::{IndexDocumentsConfig, PrimaryKeyDefinition, Transform};
 use super::IndexerConfig;
 use crate::criterion::Criterion;
 use crate::error::UserError;
-use crate::index::{DEFAULT_MIN_WORD_LEN_ONE_TYPO, DEFAULT_MIN_WORD_LEN_TWO_TYPOS};
+use crate::index::{
+    db_name, main_key, DEFAULT_MIN_WORD_LEN_ONE_TYPO, DEFAULT_MIN_WORD_LEN_TWO_TYPOS,
+};
 use crate::update::index_documents::IndexDocumentsMethod;
-use crate::update::{IndexDocuments, UpdateIndexingStep};
-use crate::{FieldsIdsMap, Index, Result};
+use crate::update::{IndexDocuments, PrimaryKeyGeneration, UpdateIndexingStep};
+use crate::{DocumentSchema, FieldsIdsMap, Index, Result};
 
 #[derive(Debug, Clone, PartialEq, Eq, Copy)]
 pub enum Setting<T> {
@@ -103,12 +106,58 @@ impl<'de, T: Deserialize<'de>> Deserialize<'de> for Setting<T> {
     }
 }
 
-pub struct Settings<'a, 't, 'u, 'i> {
-    wtxn: &'t mut heed::RwTxn<'i, 'u>,
-    index: &'i Index,
+/// The databases cleared and filled again when all the documents are reindexed.
+const REINDEXED_DATABASES: &[&str] = &[
+    db_name::WORD_DOCIDS,
+    db_name::EXACT_WORD_DOCIDS,
+    db_name::WORD_PREFIX_DOCIDS,
+    db_name::EXACT_WORD_PREFIX_DOCIDS,
+    db_name::DOCID_WORD_POSITIONS,
+    db_name::WORD_PAIR_PROXIMITY_DOCIDS,
+    db_name::WORD_PREFIX_PAIR_PROXIMITY_DOCIDS,
+    db_name::PREFIX_WORD_PAIR_PROXIMITY_DOCIDS,
+    db_name::WORD_POSITION_DOCIDS,
+    db_name::WORD_PREFIX_POSITION_DOCIDS,
+    db_name::FIELD_ID_WORD_COUNT_DOCIDS,
+    db_name::FACET_ID_F64_DOCIDS,
+    db_name::FACET_ID_EXISTS_DOCIDS,
+    db_name::FACET_ID_STRING_DOCIDS,
+    db_name::FIELD_ID_DOCID_FACET_F64S,
+    db_name::FIELD_ID_DOCID_FACET_STRINGS,
+    db_name::DOCUMENTS,
+    main_key::WORDS_FST_KEY,
+    main_key::WORDS_PREFIXES_FST_KEY,
+];
+
+/// A setting whose update makes [`Settings::execute`] reindex all the documents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReindexingSetting {
+    FacetedFields,
+    StopWords,
+    Synonyms,
+    SearchableFields,
+    ExactAttributes,
+}
 
-    indexer_config: &'a IndexerConfig,
+/// The work [`Settings::execute`] does to apply the settings, see [`SettingsChanges::impact`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SettingsImpact {
+    /// The updated settings that require to reindex all the documents.
+    pub reindexing_settings: Vec<ReindexingSetting>,
+    /// The databases rebuilt by the reindexing, empty if there is no document to reindex.
+    pub rebuilt_databases: Vec<&'static str>,
+    /// The number of documents to reindex.
+    pub documents_to_reindex: u64,
+    /// The space used by the index when a reindexing is needed, most of it is rewritten.
+    pub reindexed_size: u64,
+}
 
+/// The settings to apply to an index, the ones left `NotSet` are kept as they are.
+///
+/// They are applied by [`Settings`] which dereferences to them, [`SettingsChanges::impact`]
+/// tells what applying them does without writing to the index.
+#[derive(Debug, Default, Clone)]
+pub struct SettingsChanges {
     searchable_fields: Setting<Vec<String>>,
     displayed_fields: Setting<Vec<String>>,
     filterable_fields: Setting<HashSet<String>>,
@@ -116,8 +165,13 @@ pub struct Settings<'a, 't, 'u, 'i> {
     criteria: Setting<Vec<Criterion>>,
     stop_words: Setting<BTreeSet<String>>,
     distinct_field: Setting<String>,
+    ttl_field: Setting<String>,
+    default_ttl: Setting<u64>,
     synonyms: Setting<HashMap<String, Vec<String>>>,
-    primary_key: Setting<String>,
+    primary_key: Setting<PrimaryKeyDefinition>,
+    primary_key_generation: Setting<PrimaryKeyGeneration>,
+    document_schema: Setting<DocumentSchema>,
+    soft_deleted_purge_threshold: Setting<u8>,
     authorize_typos: Setting<bool>,
     min_word_len_two_typos: Setting<u8>,
     min_word_len_one_typo: Setting<u8>,
@@ -128,35 +182,30 @@ pub struct Settings<'a, 't, 'u, 'i> {
     pagination_max_total_hits: Setting<usize>,
 }
 
-impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
-    pub fn new(
-        wtxn: &'t mut heed::RwTxn<'i, 'u>,
-        index: &'i Index,
-        indexer_config: &'a IndexerConfig,
-    ) -> Settings<'a, 't, 'u, 'i> {
-        Settings {
-            wtxn,
-            index,
-            searchable_fields: Setting::NotSet,
-            displayed_fields: Setting::NotSet,
-            filterable_fields: Setting::NotSet,
-            sortable_fields: Setting::NotSet,
-            criteria: Setting::NotSet,
-            stop_words: Setting::NotSet,
-            distinct_field: Setting::NotSet,
-            synonyms: Setting::NotSet,
-            primary_key: Setting::NotSet,
-            authorize_typos: Setting::NotSet,
-            exact_words: Setting::NotSet,
-            min_word_len_two_typos: Setting::NotSet,
-            min_word_len_one_typo: Setting::NotSet,
-            exact_attributes: Setting::NotSet,
-            max_values_per_facet: Setting::NotSet,
-            pagination_max_total_hits: Setting::NotSet,
-            indexer_config,
-        }
+pub struct Settings<'a, 't, 'u, 'i> {
+    wtxn: &'t mut heed::RwTxn<'i, 'u>,
+    index: &'i Index,
+
+    indexer_config: &'a IndexerConfig,
+
+    changes: SettingsChanges,
+}
+
+impl Deref for Settings<'_, '_, '_, '_> {
+    type Target = SettingsChanges;
+
+    fn deref(&self) -> &Self::Target {
+        &self.changes
     }
+}
+
+impl DerefMut for Settings<'_, '_, '_, '_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.changes
+    }
+}
 
+impl SettingsChanges {
     pub fn reset_searchable_fields(&mut self) {
         self.searchable_fields = Setting::Reset;
     }
@@ -214,6 +263,22 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
         self.distinct_field = Setting::Set(distinct_field);
     }
 
+    pub fn reset_ttl_field(&mut self) {
+        self.ttl_field = Setting::Reset;
+    }
+
+    pub fn set_ttl_field(&mut self, ttl_field: String) {
+        self.ttl_field = Setting::Set(ttl_field);
+    }
+
+    pub fn reset_default_ttl(&mut self) {
+        self.default_ttl = Setting::Reset;
+    }
+
+    pub fn set_default_ttl(&mut self, seconds: u64) {
+        self.default_ttl = Setting::Set(seconds);
+    }
+
     pub fn reset_synonyms(&mut self) {
         self.synonyms = Setting::Reset;
     }
@@ -226,8 +291,32 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
         self.primary_key = Setting::Reset;
     }
 
-    pub fn set_primary_key(&mut self, primary_key: String) {
-        self.primary_key = Setting::Set(primary_key);
+    pub fn set_primary_key(&mut self, primary_key: impl Into<PrimaryKeyDefinition>) {
+        self.primary_key = Setting::Set(primary_key.into());
+    }
+
+    pub fn reset_primary_key_generation(&mut self) {
+        self.primary_key_generation = Setting::Reset;
+    }
+
+    pub fn set_primary_key_generation(&mut self, generation: PrimaryKeyGeneration) {
+        self.primary_key_generation = Setting::Set(generation);
+    }
+
+    pub fn reset_document_schema(&mut self) {
+        self.document_schema = Setting::Reset;
+    }
+
+    pub fn set_document_schema(&mut self, schema: DocumentSchema) {
+        self.document_schema = Setting::Set(schema);
+    }
+
+    pub fn reset_soft_deleted_purge_threshold(&mut self) {
+        self.soft_deleted_purge_threshold = Setting::Reset;
+    }
+
+    pub fn set_soft_deleted_purge_threshold(&mut self, percentage: u8) {
+        self.soft_deleted_purge_threshold = Setting::Set(percentage);
     }
 
     pub fn set_autorize_typos(&mut self, val: bool) {
@@ -286,6 +375,286 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
         self.pagination_max_total_hits = Setting::Reset;
     }
 
+    /// Computes the work [`Settings::execute`] does to apply these settings to the index,
+    /// only reading the index.
+    pub fn impact(&self, index: &Index, rtxn: &heed::RoTxn) -> Result<SettingsImpact> {
+        // If there is new faceted fields we indicate that we must reindex as we must
+        // index new fields as facets. It means that the distinct attribute, the ttl attribute,
+        // an Asc/Desc criterion or a filtered attribute as be added or removed.
+        let old_faceted_fields = index.user_defined_faceted_fields(rtxn)?;
+        let new_faceted_fields = self.faceted_fields(index, rtxn)?;
+        let reindexing_settings =
+            self.reindexing_settings(index, rtxn, old_faceted_fields != new_faceted_fields)?;
+
+        let mut impact = SettingsImpact { reindexing_settings, ..SettingsImpact::default() };
+        // Like in `reindex`, an index without documents has nothing to rebuild.
+        let number_of_documents = index.number_of_documents(rtxn)?;
+        if !impact.reindexing_settings.is_empty() && number_of_documents != 0 {
+            impact.rebuilt_databases = REINDEXED_DATABASES.to_vec();
+            if old_faceted_fields.contains("_geo") || new_faceted_fields.contains("_geo") {
+                impact.rebuilt_databases.push(main_key::GEO_RTREE_KEY);
+            }
+            impact.documents_to_reindex = number_of_documents;
+            impact.reindexed_size = index.used_size()?;
+        }
+
+        Ok(impact)
+    }
+
+    /// Checks that [`Settings::execute`] would accept these settings, without writing to
+    /// the index. When `index` is `None` they are checked against a new index, which has
+    /// the default settings.
+    pub fn validate(&self, index: Option<(&Index, &heed::RoTxn)>) -> Result<()> {
+        if let Setting::Set(primary_key) = &self.primary_key {
+            primary_key.validate()?;
+        }
+        if let Setting::Set(schema) = &self.document_schema {
+            schema.validate()?;
+        }
+        if let Setting::Set(percentage) = self.soft_deleted_purge_threshold {
+            if percentage > 100 {
+                return Err(UserError::InvalidSoftDeletedPurgeThreshold(percentage).into());
+            }
+        }
+
+        // Like in `update_min_typo_word_len`, the lengths are only checked when one changes.
+        let one = self.min_word_len_one_typo.or_reset(DEFAULT_MIN_WORD_LEN_ONE_TYPO);
+        let two = self.min_word_len_two_typos.or_reset(DEFAULT_MIN_WORD_LEN_TWO_TYPOS);
+        if !one.is_not_set() || !two.is_not_set() {
+            let one = match (one, index) {
+                (Setting::Set(one), _) => one,
+                (_, Some((index, rtxn))) => index.min_word_len_one_typo(rtxn)?,
+                (_, None) => DEFAULT_MIN_WORD_LEN_ONE_TYPO,
+            };
+            let two = match (two, index) {
+                (Setting::Set(two), _) => two,
+                (_, Some((index, rtxn))) => index.min_word_len_two_typos(rtxn)?,
+                (_, None) => DEFAULT_MIN_WORD_LEN_TWO_TYPOS,
+            };
+            if one > two {
+                return Err(UserError::InvalidMinTypoWordLenSetting(one, two).into());
+            }
+        }
+
+        let has_default_ttl = match (&self.default_ttl, index) {
+            (Setting::Set(_), _) => true,
+            (Setting::NotSet, Some((index, rtxn))) => index.default_ttl(rtxn)?.is_some(),
+            _ => false,
+        };
+        let has_ttl_field = match (&self.ttl_field, index) {
+            (Setting::Set(_), _) => true,
+            (Setting::NotSet, Some((index, rtxn))) => index.ttl_field(rtxn)?.is_some(),
+            _ => false,
+        };
+        if has_default_ttl && !has_ttl_field {
+            return Err(UserError::DefaultTtlWithoutTtlField.into());
+        }
+
+        Ok(())
+    }
+
+    /// The updated settings that require to reindex all the documents, they must be
+    /// computed before the settings are written in the index.
+    fn reindexing_settings(
+        &self,
+        index: &Index,
+        rtxn: &heed::RoTxn,
+        faceted_fields_change: bool,
+    ) -> Result<Vec<ReindexingSetting>> {
+        let mut reindexing_settings = Vec::new();
+        if faceted_fields_change {
+            reindexing_settings.push(ReindexingSetting::FacetedFields);
+        }
+        if self.stop_words_change(index, rtxn)? {
+            reindexing_settings.push(ReindexingSetting::StopWords);
+        }
+        if self.synonyms_change(index, rtxn)? {
+            reindexing_settings.push(ReindexingSetting::Synonyms);
+        }
+        if self.searchable_fields_change(index, rtxn)? {
+            reindexing_settings.push(ReindexingSetting::SearchableFields);
+        }
+        if self.exact_attributes_change(index, rtxn)? {
+            reindexing_settings.push(ReindexingSetting::ExactAttributes);
+        }
+        Ok(reindexing_settings)
+    }
+
+    /// The faceted fields of the index once these settings are applied, it's only a
+    /// prediction used by the dry run, [`Settings::execute`] compares the written ones.
+    fn faceted_fields(&self, index: &Index, rtxn: &heed::RoTxn) -> Result<HashSet<String>> {
+        let mut faceted_fields = match &self.filterable_fields {
+            Setting::Set(fields) => fields.clone(),
+            Setting::Reset => HashSet::new(),
+            Setting::NotSet => index.filterable_fields(rtxn)?,
+        };
+        match &self.sortable_fields {
+            Setting::Set(fields) => faceted_fields.extend(fields.iter().cloned()),
+            Setting::Reset => (),
+            Setting::NotSet => faceted_fields.extend(index.sortable_fields(rtxn)?),
+        }
+        // The default criteria don't contain any Asc/Desc criterion.
+        let criteria = match &self.criteria {
+            Setting::Set(criteria) => criteria.clone(),
+            Setting::Reset => Vec::new(),
+            Setting::NotSet => index.criteria(rtxn)?,
+        };
+        faceted_fields.extend(criteria.into_iter().filter_map(|criterion| match criterion {
+            Criterion::Asc(field) | Criterion::Desc(field) => Some(field),
+            _otherwise => None,
+        }));
+        for (setting, current) in [
+            (&self.distinct_field, index.distinct_field(rtxn)?),
+            (&self.ttl_field, index.ttl_field(rtxn)?),
+        ] {
+            match setting {
+                Setting::Set(field) => faceted_fields.insert(field.clone()),
+                Setting::Reset => false,
+                Setting::NotSet => {
+                    current.map_or(false, |field| faceted_fields.insert(field.into()))
+                }
+            };
+        }
+
+        Ok(faceted_fields)
+    }
+
+    /// The stop words of the index once these settings are applied.
+    fn new_stop_words(
+        &self,
+        index: &Index,
+        rtxn: &heed::RoTxn,
+    ) -> Result<Option<fst::Set<Vec<u8>>>> {
+        match &self.stop_words {
+            Setting::Set(stop_words) => Ok(Some(fst::Set::from_iter(stop_words)?)),
+            Setting::Reset => Ok(None),
+            Setting::NotSet => match index.stop_words(rtxn)? {
+                Some(stop_words) => Ok(Some(stop_words.map_data(|bytes| bytes.to_vec())?)),
+                None => Ok(None),
+            },
+        }
+    }
+
+    fn stop_words_change(&self, index: &Index, rtxn: &heed::RoTxn) -> Result<bool> {
+        match &self.stop_words {
+            Setting::Set(_) => {
+                let current = index.stop_words(rtxn)?;
+                // since we can't compare a BTreeSet with an FST we are going to convert the
+                // BTreeSet to an FST and then compare bytes per bytes the two FSTs.
+                let new = self.new_stop_words(index, rtxn)?;
+                Ok(current.map(|set| set.as_fst().as_bytes().to_vec())
+                    != new.map(|set| set.as_fst().as_bytes().to_vec()))
+            }
+            Setting::Reset => Ok(index.stop_words(rtxn)?.is_some()),
+            Setting::NotSet => Ok(false),
+        }
+    }
+
+    /// The synonyms to set, normalized with the stop words of the index once these
+    /// settings are applied.
+    fn normalized_synonyms(
+        &self,
+        index: &Index,
+        rtxn: &heed::RoTxn,
+    ) -> Result<Option<HashMap<Vec<String>, Vec<Vec<String>>>>> {
+        fn normalize(tokenizer: &Tokenizer<&[u8]>, text: &str) -> Vec<String> {
+            tokenizer
+                .tokenize(text)
+                .filter_map(
+                    |token| {
+                        if token.is_word() {
+                            Some(token.lemma().to_string())
+                        } else {
+                            None
+                        }
+                    },
+                )
+                .collect::<Vec<_>>()
+        }
+
+        let synonyms = match &self.synonyms {
+            Setting::Set(synonyms) => synonyms,
+            Setting::Reset | Setting::NotSet => return Ok(None),
+        };
+
+        let mut builder = TokenizerBuilder::new();
+        let stop_words = self.new_stop_words(index, rtxn)?;
+        if let Some(ref stop_words) = stop_words {
+            builder.stop_words(stop_words);
+        }
+        let tokenizer = builder.build();
+
+        let mut new_synonyms = HashMap::new();
+        for (word, synonyms) in synonyms {
+            // Normalize both the word and associated synonyms.
+            let normalized_word = normalize(&tokenizer, word);
+            let normalized_synonyms = synonyms.iter().map(|synonym| normalize(&tokenizer, synonym));
+
+            // Store the normalized synonyms under the normalized word,
+            // merging the possible duplicate words.
+            let entry = new_synonyms.entry(normalized_word).or_insert_with(Vec::new);
+            entry.extend(normalized_synonyms);
+        }
+
+        // Make sure that we don't have duplicate synonyms.
+        new_synonyms.iter_mut().for_each(|(_, synonyms)| {
+            synonyms.sort_unstable();
+            synonyms.dedup();
+        });
+
+        Ok(Some(new_synonyms))
+    }
+
+    fn synonyms_change(&self, index: &Index, rtxn: &heed::RoTxn) -> Result<bool> {
+        match &self.synonyms {
+            Setting::Set(_) => {
+                let new_synonyms = self.normalized_synonyms(index, rtxn)?;
+                Ok(new_synonyms.as_ref() != Some(&index.synonyms(rtxn)?))
+            }
+            Setting::Reset => Ok(!index.synonyms(rtxn)?.is_empty()),
+            Setting::NotSet => Ok(false),
+        }
+    }
+
+    fn searchable_fields_change(&self, index: &Index, rtxn: &heed::RoTxn) -> Result<bool> {
+        match &self.searchable_fields {
+            Setting::Set(fields) => match index.searchable_fields(rtxn)? {
+                // If old_fields is Some, let's check to see if the fields actually changed
+                Some(old_fields) => {
+                    let new_fields = fields.iter().map(String::as_str).collect::<Vec<_>>();
+                    Ok(new_fields != old_fields)
+                }
+                // If old_fields is None, the fields have changed (because they are being set)
+                None => Ok(true),
+            },
+            Setting::Reset => Ok(index.searchable_fields(rtxn)?.is_some()
+                || index.user_defined_searchable_fields(rtxn)?.is_some()),
+            Setting::NotSet => Ok(false),
+        }
+    }
+
+    fn exact_attributes_change(&self, index: &Index, rtxn: &heed::RoTxn) -> Result<bool> {
+        match &self.exact_attributes {
+            Setting::Set(attrs) => {
+                let old_attrs = index.exact_attributes(rtxn)?;
+                let old_attrs = old_attrs.into_iter().map(String::from).collect::<HashSet<_>>();
+                Ok(attrs != &old_attrs)
+            }
+            Setting::Reset => Ok(!index.exact_attributes(rtxn)?.is_empty()),
+            Setting::NotSet => Ok(false),
+        }
+    }
+}
+
+impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
+    pub fn new(
+        wtxn: &'t mut heed::RwTxn<'i, 'u>,
+        index: &'i Index,
+        indexer_config: &'a IndexerConfig,
+    ) -> Settings<'a, 't, 'u, 'i> {
+        Settings { wtxn, index, indexer_config, changes: SettingsChanges::default() }
+    }
+
     fn reindex<FP, FA>(
         &mut self,
         progress_callback: &FP,
@@ -309,6 +678,7 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
             self.indexer_config,
             IndexDocumentsMethod::ReplaceDocuments,
             false,
+            false,
         )?;
 
         // We clear the databases and remap the documents fields based on the new `FieldsIdsMap`.
@@ -334,7 +704,7 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
     }
 
     fn update_displayed(&mut self) -> Result<bool> {
-        match self.displayed_fields {
+        match self.changes.displayed_fields {
             Setting::Set(ref fields) => {
                 // fields are deduplicated, only the first occurrence is taken into account
                 let names: Vec<_> = fields.iter().unique().map(String::as_str).collect();
@@ -349,7 +719,7 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
     }
 
     fn update_distinct_field(&mut self) -> Result<bool> {
-        match self.distinct_field {
+        match self.changes.distinct_field {
             Setting::Set(ref attr) => {
                 self.index.put_distinct_field(self.wtxn, attr)?;
             }
@@ -361,26 +731,42 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
         Ok(true)
     }
 
+    fn update_ttl_field(&mut self) -> Result<bool> {
+        match self.changes.ttl_field {
+            Setting::Set(ref attr) => {
+                self.index.put_ttl_field(self.wtxn, attr)?;
+            }
+            Setting::Reset => {
+                self.index.delete_ttl_field(self.wtxn)?;
+            }
+            Setting::NotSet => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn update_default_ttl(&mut self) -> Result<()> {
+        match self.changes.default_ttl {
+            Setting::Set(seconds) => {
+                self.index.put_default_ttl(self.wtxn, seconds)?;
+            }
+            Setting::Reset => {
+                self.index.delete_default_ttl(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+
+        Ok(())
+    }
+
     /// Updates the index's searchable attributes. This causes the field map to be recomputed to
     /// reflect the order of the searchable attributes.
-    fn update_searchable(&mut self) -> Result<bool> {
-        match self.searchable_fields {
-            Setting::Set(ref fields) => {
-                // Check to see if the searchable fields changed before doing anything else
-                let old_fields = self.index.searchable_fields(self.wtxn)?;
-                let did_change = match old_fields {
-                    // If old_fields is Some, let's check to see if the fields actually changed
-                    Some(old_fields) => {
-                        let new_fields = fields.iter().map(String::as_str).collect::<Vec<_>>();
-                        new_fields != old_fields
-                    }
-                    // If old_fields is None, the fields have changed (because they are being set)
-                    None => true,
-                };
-                if !did_change {
-                    return Ok(false);
-                }
+    fn update_searchable(&mut self) -> Result<()> {
+        if !self.changes.searchable_fields_change(self.index, self.wtxn)? {
+            return Ok(());
+        }
 
+        match self.changes.searchable_fields {
+            Setting::Set(ref fields) => {
                 // every time the searchable attributes are updated, we need to update the
                 // ids for any settings that uses the facets. (distinct_fields, filterable_fields).
                 let old_fields_ids_map = self.index.fields_ids_map(self.wtxn)?;
@@ -405,114 +791,64 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
                     &new_fields_ids_map,
                 )?;
                 self.index.put_fields_ids_map(self.wtxn, &new_fields_ids_map)?;
-                Ok(true)
             }
-            Setting::Reset => Ok(self.index.delete_all_searchable_fields(self.wtxn)?),
-            Setting::NotSet => Ok(false),
+            Setting::Reset => {
+                self.index.delete_all_searchable_fields(self.wtxn)?;
+            }
+            Setting::NotSet => (),
         }
+        Ok(())
     }
 
-    fn update_stop_words(&mut self) -> Result<bool> {
-        match self.stop_words {
-            Setting::Set(ref stop_words) => {
-                let current = self.index.stop_words(self.wtxn)?;
-                // since we can't compare a BTreeSet with an FST we are going to convert the
-                // BTreeSet to an FST and then compare bytes per bytes the two FSTs.
-                let fst = fst::Set::from_iter(stop_words)?;
-
-                // Does the new FST differ from the previous one?
-                if current
-                    .map_or(true, |current| current.as_fst().as_bytes() != fst.as_fst().as_bytes())
-                {
-                    // we want to re-create our FST.
-                    self.index.put_stop_words(self.wtxn, &fst)?;
-                    Ok(true)
-                } else {
-                    Ok(false)
-                }
+    fn update_stop_words(&mut self) -> Result<()> {
+        if !self.changes.stop_words_change(self.index, self.wtxn)? {
+            return Ok(());
+        }
+
+        match self.changes.new_stop_words(self.index, self.wtxn)? {
+            Some(stop_words) => self.index.put_stop_words(self.wtxn, &stop_words)?,
+            None => {
+                self.index.delete_stop_words(self.wtxn)?;
             }
-            Setting::Reset => Ok(self.index.delete_stop_words(self.wtxn)?),
-            Setting::NotSet => Ok(false),
         }
+        Ok(())
     }
 
-    fn update_synonyms(&mut self) -> Result<bool> {
-        match self.synonyms {
-            Setting::Set(ref synonyms) => {
-                fn normalize(tokenizer: &Tokenizer<&[u8]>, text: &str) -> Vec<String> {
-                    tokenizer
-                        .tokenize(text)
-                        .filter_map(|token| {
-                            if token.is_word() {
-                                Some(token.lemma().to_string())
-                            } else {
-                                None
-                            }
-                        })
-                        .collect::<Vec<_>>()
-                }
-
-                let mut builder = TokenizerBuilder::new();
-                let stop_words = self.index.stop_words(self.wtxn)?;
-                if let Some(ref stop_words) = stop_words {
-                    builder.stop_words(stop_words);
-                }
-                let tokenizer = builder.build();
-
-                let mut new_synonyms = HashMap::new();
-                for (word, synonyms) in synonyms {
-                    // Normalize both the word and associated synonyms.
-                    let normalized_word = normalize(&tokenizer, word);
-                    let normalized_synonyms =
-                        synonyms.iter().map(|synonym| normalize(&tokenizer, synonym));
-
-                    // Store the normalized synonyms under the normalized word,
-                    // merging the possible duplicate words.
-                    let entry = new_synonyms.entry(normalized_word).or_insert_with(Vec::new);
-                    entry.extend(normalized_synonyms);
-                }
-
-                // Make sure that we don't have duplicate synonyms.
-                new_synonyms.iter_mut().for_each(|(_, synonyms)| {
-                    synonyms.sort_unstable();
-                    synonyms.dedup();
-                });
-
-                let old_synonyms = self.index.synonyms(self.wtxn)?;
+    /// Must be called after the stop words are written, the synonyms are normalized with them.
+    fn update_synonyms(&mut self) -> Result<()> {
+        if !self.changes.synonyms_change(self.index, self.wtxn)? {
+            return Ok(());
+        }
 
-                if new_synonyms != old_synonyms {
-                    self.index.put_synonyms(self.wtxn, &new_synonyms)?;
-                    Ok(true)
-                } else {
-                    Ok(false)
-                }
+        match self.changes.normalized_synonyms(self.index, self.wtxn)? {
+            Some(synonyms) => self.index.put_synonyms(self.wtxn, &synonyms)?,
+            None => {
+                self.index.delete_synonyms(self.wtxn)?;
             }
-            Setting::Reset => Ok(self.index.delete_synonyms(self.wtxn)?),
-            Setting::NotSet => Ok(false),
         }
+        Ok(())
     }
 
-    fn update_exact_attributes(&mut self) -> Result<bool> {
-        match self.exact_attributes {
-            Setting::Set(ref attrs) => {
-                let old_attrs = self.index.exact_attributes(self.wtxn)?;
-                let old_attrs = old_attrs.into_iter().map(String::from).collect::<HashSet<_>>();
+    fn update_exact_attributes(&mut self) -> Result<()> {
+        if !self.changes.exact_attributes_change(self.index, self.wtxn)? {
+            return Ok(());
+        }
 
-                if attrs != &old_attrs {
-                    let attrs = attrs.iter().map(String::as_str).collect::<Vec<_>>();
-                    self.index.put_exact_attributes(self.wtxn, &attrs)?;
-                    Ok(true)
-                } else {
-                    Ok(false)
-                }
+        match self.changes.exact_attributes {
+            Setting::Set(ref attrs) => {
+                let attrs = attrs.iter().map(String::as_str).collect::<Vec<_>>();
+                self.index.put_exact_attributes(self.wtxn, &attrs)?;
             }
-            Setting::Reset => Ok(self.index.delete_exact_attributes(self.wtxn)?),
-            Setting::NotSet => Ok(false),
+            Setting::Reset => {
+                self.index.delete_exact_attributes(self.wtxn)?;
+            }
+            Setting::NotSet => (),
         }
+        Ok(())
     }
 
     fn update_filterable(&mut self) -> Result<()> {
-        match self.filterable_fields {
+        match self.changes.filterable_fields {
             Setting::Set(ref fields) => {
                 let mut new_facets = HashSet::new();
                 for name in fields {
@@ -529,7 +865,7 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
     }
 
     fn update_sortable(&mut self) -> Result<()> {
-        match self.sortable_fields {
+        match self.changes.sortable_fields {
             Setting::Set(ref fields) => {
                 let mut new_fields = HashSet::new();
                 for name in fields {
@@ -546,7 +882,7 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
     }
 
     fn update_criteria(&mut self) -> Result<()> {
-        match &self.criteria {
+        match &self.changes.criteria {
             Setting::Set(criteria) => {
                 self.index.put_criteria(self.wtxn, criteria)?;
             }
@@ -559,13 +895,23 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
     }
 
     fn update_primary_key(&mut self) -> Result<()> {
-        match self.primary_key {
+        match self.changes.primary_key {
             Setting::Set(ref primary_key) => {
+                primary_key.validate()?;
                 if self.index.number_of_documents(self.wtxn)? == 0 {
-                    let mut fields_ids_map = self.index.fields_ids_map(self.wtxn)?;
-                    fields_ids_map.insert(primary_key).ok_or(UserError::AttributeLimitReached)?;
-                    self.index.put_fields_ids_map(self.wtxn, &fields_ids_map)?;
-                    self.index.put_primary_key(self.wtxn, primary_key)?;
+                    // The fields of a composite primary key are registered with the documents.
+                    match primary_key {
+                        PrimaryKeyDefinition::Field(field) => {
+                            let mut fields_ids_map = self.index.fields_ids_map(self.wtxn)?;
+                            fields_ids_map.insert(field).ok_or(UserError::AttributeLimitReached)?;
+                            self.index.put_fields_ids_map(self.wtxn, &fields_ids_map)?;
+                            self.index.delete_composite_primary_key(self.wtxn)?;
+                        }
+                        PrimaryKeyDefinition::Composite(fields) => {
+                            self.index.put_composite_primary_key(self.wtxn, fields)?;
+                        }
+                    }
+                    self.index.put_primary_key(self.wtxn, &primary_key.name())?;
                     Ok(())
                 } else {
                     let primary_key = self.index.primary_key(self.wtxn)?.unwrap();
@@ -575,6 +921,7 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
             Setting::Reset => {
                 if self.index.number_of_documents(self.wtxn)? == 0 {
                     self.index.delete_primary_key(self.wtxn)?;
+                    self.index.delete_composite_primary_key(self.wtxn)?;
                     Ok(())
                 } else {
                     let primary_key = self.index.primary_key(self.wtxn)?.unwrap();
@@ -585,8 +932,54 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
         }
     }
 
+    fn update_primary_key_generation(&mut self) -> Result<()> {
+        match self.changes.primary_key_generation {
+            Setting::Set(generation) => {
+                self.index.put_primary_key_generation(self.wtxn, generation)?;
+            }
+            Setting::Reset => {
+                self.index.delete_primary_key_generation(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+
+        Ok(())
+    }
+
+    fn update_document_schema(&mut self) -> Result<()> {
+        match self.changes.document_schema {
+            Setting::Set(ref schema) => {
+                schema.validate()?;
+                self.index.put_document_schema(self.wtxn, schema)?;
+            }
+            Setting::Reset => {
+                self.index.delete_document_schema(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+
+        Ok(())
+    }
+
+    fn update_soft_deleted_purge_threshold(&mut self) -> Result<()> {
+        match self.changes.soft_deleted_purge_threshold {
+            Setting::Set(percentage) if percentage > 100 => {
+                return Err(UserError::InvalidSoftDeletedPurgeThreshold(percentage).into());
+            }
+            Setting::Set(percentage) => {
+                self.index.put_soft_deleted_purge_threshold(self.wtxn, percentage)?;
+            }
+            Setting::Reset => {
+                self.index.delete_soft_deleted_purge_threshold(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+
+        Ok(())
+    }
+
     fn update_authorize_typos(&mut self) -> Result<()> {
-        match self.authorize_typos {
+        match self.changes.authorize_typos {
             Setting::Set(flag) => {
                 self.index.put_authorize_typos(self.wtxn, flag)?;
                 Ok(())
@@ -600,8 +993,8 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
     }
 
     fn update_min_typo_word_len(&mut self) -> Result<()> {
-        let one = self.min_word_len_one_typo.or_reset(DEFAULT_MIN_WORD_LEN_ONE_TYPO);
-        let two = self.min_word_len_two_typos.or_reset(DEFAULT_MIN_WORD_LEN_TWO_TYPOS);
+        let one = self.changes.min_word_len_one_typo.or_reset(DEFAULT_MIN_WORD_LEN_ONE_TYPO);
+        let two = self.changes.min_word_len_two_typos.or_reset(DEFAULT_MIN_WORD_LEN_TWO_TYPOS);
         match (one, two) {
             (Setting::Set(one), Setting::Set(two)) => {
                 if one > two {
@@ -634,7 +1027,7 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
     }
 
     fn update_exact_words(&mut self) -> Result<()> {
-        match self.exact_words {
+        match self.changes.exact_words {
             Setting::Set(ref mut words) => {
                 fn normalize(tokenizer: &Tokenizer<&[u8]>, text: &str) -> String {
                     tokenizer.tokenize(text).map(|token| token.lemma().to_string()).collect()
@@ -666,7 +1059,7 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
     }
 
     fn update_max_values_per_facet(&mut self) -> Result<()> {
-        match self.max_values_per_facet {
+        match self.changes.max_values_per_facet {
             Setting::Set(max) => {
                 self.index.put_max_values_per_facet(self.wtxn, max)?;
             }
@@ -680,7 +1073,7 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
     }
 
     fn update_pagination_max_total_hits(&mut self) -> Result<()> {
-        match self.pagination_max_total_hits {
+        match self.changes.pagination_max_total_hits {
             Setting::Set(max) => {
                 self.index.put_pagination_max_total_hits(self.wtxn, max)?;
             }
@@ -698,44 +1091,63 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
         FP: Fn(UpdateIndexingStep) + Sync,
         FA: Fn() -> bool + Sync,
     {
-        self.index.set_updated_at(self.wtxn, &OffsetDateTime::now_utc())?;
+        let (reindexing_settings, old_fields_ids_map) = self.update_settings()?;
+        if !reindexing_settings.is_empty() {
+            self.reindex(&progress_callback, &should_abort, old_fields_ids_map)?;
+        }
 
+        Ok(())
+    }
+
+    /// Writes the settings in the index and returns the ones that need a reindexing,
+    /// along with the fields ids map from before the update.
+    fn update_settings(&mut self) -> Result<(Vec<ReindexingSetting>, FieldsIdsMap)> {
+        // These are computed against the settings of the index before they are written.
+        let mut reindexing_settings =
+            self.changes.reindexing_settings(self.index, self.wtxn, false)?;
         let old_faceted_fields = self.index.user_defined_faceted_fields(self.wtxn)?;
+
+        self.index.set_updated_at(self.wtxn, &OffsetDateTime::now_utc())?;
+
         let old_fields_ids_map = self.index.fields_ids_map(self.wtxn)?;
 
         self.update_displayed()?;
         self.update_filterable()?;
         self.update_sortable()?;
         self.update_distinct_field()?;
+        self.update_ttl_field()?;
+        self.update_default_ttl()?;
         self.update_criteria()?;
         self.update_primary_key()?;
+        self.update_primary_key_generation()?;
+        self.update_document_schema()?;
+        self.update_soft_deleted_purge_threshold()?;
         self.update_authorize_typos()?;
         self.update_min_typo_word_len()?;
         self.update_exact_words()?;
         self.update_max_values_per_facet()?;
         self.update_pagination_max_total_hits()?;
 
-        // If there is new faceted fields we indicate that we must reindex as we must
-        // index new fields as facets. It means that the distinct attribute,
-        // an Asc/Desc criterion or a filtered attribute as be added or removed.
-        let new_faceted_fields = self.index.user_defined_faceted_fields(self.wtxn)?;
-        let faceted_updated = old_faceted_fields != new_faceted_fields;
-
-        let stop_words_updated = self.update_stop_words()?;
-        let synonyms_updated = self.update_synonyms()?;
-        let searchable_updated = self.update_searchable()?;
-        let exact_attributes_updated = self.update_exact_attributes()?;
-
-        if stop_words_updated
-            || faceted_updated
-            || synonyms_updated
-            || searchable_updated
-            || exact_attributes_updated
+        // The default ttl is stored in the ttl field of the documents, it can't work without it.
+        if self.index.default_ttl(self.wtxn)?.is_some()
+            && self.index.ttl_field(self.wtxn)?.is_none()
         {
-            self.reindex(&progress_callback, &should_abort, old_fields_ids_map)?;
+            return Err(UserError::DefaultTtlWithoutTtlField.into());
         }
 
-        Ok(())
+        // If there is new faceted fields we must reindex them as facets. It means that the
+        // distinct attribute, the ttl attribute, an Asc/Desc criterion or a filtered attribute
+        // has been added or removed.
+        if self.index.user_defined_faceted_fields(self.wtxn)? != old_faceted_fields {
+            reindexing_settings.insert(0, ReindexingSetting::FacetedFields);
+        }
+
+        self.update_stop_words()?;
+        self.update_synonyms()?;
+        self.update_searchable()?;
+        self.update_exact_attributes()?;
+
+        Ok((reindexing_settings, old_fields_ids_map))
     }
 }
 
@@ -1495,8 +1907,13 @@ mod tests {
                     criteria,
                     stop_words,
                     distinct_field,
+                    ttl_field,
+                    default_ttl,
                     synonyms,
                     primary_key,
+                    primary_key_generation,
+                    document_schema,
+                    soft_deleted_purge_threshold,
                     authorize_typos,
                     min_word_len_two_typos,
                     min_word_len_one_typo,
@@ -1512,8 +1929,13 @@ mod tests {
                 assert!(matches!(criteria, Setting::NotSet));
                 assert!(matches!(stop_words, Setting::NotSet));
                 assert!(matches!(distinct_field, Setting::NotSet));
+                assert!(matches!(ttl_field, Setting::NotSet));
+                assert!(matches!(default_ttl, Setting::NotSet));
                 assert!(matches!(synonyms, Setting::NotSet));
                 assert!(matches!(primary_key, Setting::NotSet));
+                assert!(matches!(primary_key_generation, Setting::NotSet));
+                assert!(matches!(document_schema, Setting::NotSet));
+                assert!(matches!(soft_deleted_purge_threshold, Setting::NotSet));
                 assert!(matches!(authorize_typos, Setting::NotSet));
                 assert!(matches!(min_word_len_two_typos, Setting::NotSet));
                 assert!(matches!(min_word_len_one_typo, Setting::NotSet));
@@ -1556,4 +1978,100 @@ mod tests {
         let docs = docs.unwrap();
         assert_eq!(docs.len(), 5);
     }
+
+    #[test]
+    fn settings_dry_run() {
+        let index = TempIndex::new();
+        index
+            .add_documents(documents!([
+                { "id": 1, "name": "kevin", "age": 23 },
+                { "id": 2, "name": "kevina", "age": 21 },
+                { "id": 3, "name": "benoit", "age": 34 }
+            ]))
+            .unwrap();
+
+        // Changing the displayed fields doesn't need any reindexing.
+        let rtxn = index.read_txn().unwrap();
+        let mut changes = SettingsChanges::default();
+        changes.set_displayed_fields(vec![S("name")]);
+        assert_eq!(changes.impact(&index, &rtxn).unwrap(), SettingsImpact::default());
+
+        let mut changes = SettingsChanges::default();
+        changes.set_searchable_fields(vec![S("name")]);
+        changes.set_filterable_fields(hashset! { S("_geo") });
+        let impact = changes.impact(&index, &rtxn).unwrap();
+
+        assert_eq!(
+            impact.reindexing_settings,
+            vec![ReindexingSetting::FacetedFields, ReindexingSetting::SearchableFields]
+        );
+        assert_eq!(impact.documents_to_reindex, 3);
+        assert!(impact.rebuilt_databases.contains(&db_name::WORD_DOCIDS));
+        assert!(impact.rebuilt_databases.contains(&main_key::GEO_RTREE_KEY));
+        assert!(impact.reindexed_size > 0);
+
+        // Nothing was applied.
+        assert_eq!(index.searchable_fields(&rtxn).unwrap(), None);
+        assert!(index.filterable_fields(&rtxn).unwrap().is_empty());
+        drop(rtxn);
+
+        // The impact is the one of the execution.
+        let mut changes = SettingsChanges::default();
+        changes.set_stop_words(btreeset! { S("kevin") });
+        changes.set_synonyms(hashmap! { S("kevin") => vec![S("benoit")] });
+        let rtxn = index.read_txn().unwrap();
+        let impact = changes.impact(&index, &rtxn).unwrap();
+        drop(rtxn);
+        assert_eq!(
+            impact.reindexing_settings,
+            vec![ReindexingSetting::StopWords, ReindexingSetting::Synonyms]
+        );
+        index
+            .update_settings(|settings| {
+                settings.set_stop_words(btreeset! { S("kevin") });
+                settings.set_synonyms(hashmap! { S("kevin") => vec![S("benoit")] });
+            })
+            .unwrap();
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(changes.impact(&index, &rtxn).unwrap(), SettingsImpact::default());
+    }
+
+    #[test]
+    fn validate_settings_without_writing() {
+        let index = TempIndex::new();
+
+        // Against a new index, the settings that aren't given have their default value.
+        let mut changes = SettingsChanges::default();
+        changes.set_min_word_len_one_typo(10);
+        let err = changes.validate(None).unwrap_err();
+        assert!(matches!(err, Error::UserError(UserError::InvalidMinTypoWordLenSetting(10, 9))));
+        changes.set_min_word_len_two_typos(12);
+        changes.validate(None).unwrap();
+
+        let mut changes = SettingsChanges::default();
+        changes.set_default_ttl(60);
+        let err = changes.validate(None).unwrap_err();
+        assert!(matches!(err, Error::UserError(UserError::DefaultTtlWithoutTtlField)));
+
+        let mut changes = SettingsChanges::default();
+        changes.set_soft_deleted_purge_threshold(101);
+        let err = changes.validate(None).unwrap_err();
+        assert!(matches!(err, Error::UserError(UserError::InvalidSoftDeletedPurgeThreshold(101))));
+
+        // Against an index, they have their current value.
+        index
+            .update_settings(|settings| {
+                settings.set_ttl_field(S("expires_at"));
+                settings.set_min_word_len_two_typos(12);
+            })
+            .unwrap();
+        let rtxn = index.read_txn().unwrap();
+        let mut changes = SettingsChanges::default();
+        changes.set_default_ttl(60);
+        changes.set_min_word_len_one_typo(10);
+        changes.validate(Some((&index, &rtxn))).unwrap();
+        changes.reset_ttl_field();
+        let err = changes.validate(Some((&index, &rtxn))).unwrap_err();
+        assert!(matches!(err, Error::UserError(UserError::DefaultTtlWithoutTtlField)));
+    }
 }